@@ -95,8 +95,14 @@ fn handle_whisper(matches: &ArgMatches, api: Api) {
     if let Some(input) = matches.get_one::<String>("input") {
         let options = DecodingOptionsBuilder::new().build();
         let samples = ffmpeg_preproc(input);
-        let transcript =
-            transcribe(&mut whisper, samples, options, Some(|s| println!("{}", s))).unwrap();
+        let transcript = transcribe(
+            &mut whisper,
+            samples,
+            options,
+            Some(|s| println!("{}", s)),
+            None,
+        )
+        .unwrap();
         log::info!("Processing time: {:?}", transcript.processing_time);
     } else {
         panic!("Input file not found");
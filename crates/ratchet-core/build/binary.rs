@@ -11,6 +11,7 @@ pub enum BinaryOp {
     Sub,
     Mul,
     Div,
+    SwiGLU,
 }
 
 impl BinaryOp {
@@ -20,6 +21,7 @@ impl BinaryOp {
             BinaryOp::Sub => ("sub", "-"),
             BinaryOp::Mul => ("mul", "*"),
             BinaryOp::Div => ("div", "/"),
+            BinaryOp::SwiGLU => ("swiglu", "swiglu"),
         }
     }
 }
@@ -33,23 +35,27 @@ impl Generate for BinaryOp {
         let path = renderer.templates_path.join("binary.wgsl");
         renderer.tera.add_template_file(path, Some("binary"))?;
 
-        for inplace in [true, false].iter() {
-            for (op_name, op) in &pairs {
-                for ke in KernelElement::iter() {
-                    let mut context = Context::new();
-                    context.insert("inplace", inplace);
-                    context.insert("op", op);
-                    context.insert("elem", &ke.as_wgsl(WgslDType::F32));
-                    context.insert("elem_size", &ke.as_size());
-                    let rendered = renderer.tera.render("binary", &context)?;
+        for dt in WgslDType::ACTIVATIONS {
+            for inplace in [true, false].iter() {
+                for (op_name, op) in &pairs {
+                    for ke in KernelElement::iter() {
+                        let mut context = Context::new();
+                        context.insert("dt", &dt.to_string());
+                        context.insert("inplace", inplace);
+                        context.insert("op_name", op_name);
+                        context.insert("op", op);
+                        context.insert("elem", &ke.as_wgsl(dt));
+                        context.insert("elem_size", &ke.as_size());
+                        let rendered = renderer.tera.render("binary", &context)?;
 
-                    let kernel_fname = if *inplace {
-                        format!("{}_inplace_{}.wgsl", op_name, ke)
-                    } else {
-                        format!("{}_{}.wgsl", op_name, ke)
-                    };
-                    let mut file = File::create(renderer.dest_path.join(kernel_fname))?;
-                    file.write_all(rendered.as_bytes())?;
+                        let kernel_fname = if *inplace {
+                            format!("{}_inplace_{}{}.wgsl", op_name, ke, dt.kernel_suffix())
+                        } else {
+                            format!("{}_{}{}.wgsl", op_name, ke, dt.kernel_suffix())
+                        };
+                        let mut file = File::create(renderer.dest_path.join(kernel_fname))?;
+                        file.write_all(rendered.as_bytes())?;
+                    }
                 }
             }
         }
@@ -0,0 +1,120 @@
+use std::{fs::File, io::Write};
+
+use strum::IntoEnumIterator;
+use tera::Context;
+
+use crate::{Generate, KernelElement, KernelRenderer, WgslDType};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum_macros::EnumIter)]
+pub enum CastDType {
+    F32,
+    F16,
+    BF16,
+    I32,
+    U32,
+}
+
+impl std::fmt::Display for CastDType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            CastDType::F32 => "f32",
+            CastDType::F16 => "f16",
+            CastDType::BF16 => "bf16",
+            CastDType::I32 => "i32",
+            CastDType::U32 => "u32",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl CastDType {
+    fn is_half(&self) -> bool {
+        matches!(self, CastDType::F16 | CastDType::BF16)
+    }
+
+    /// BF16 has no WGSL equivalent, so it is only ever handled packed.
+    fn as_wgsl(&self) -> Option<WgslDType> {
+        match self {
+            CastDType::F32 => Some(WgslDType::F32),
+            CastDType::F16 => Some(WgslDType::F16),
+            CastDType::BF16 => None,
+            CastDType::I32 => Some(WgslDType::I32),
+            CastDType::U32 => Some(WgslDType::U32),
+        }
+    }
+}
+
+pub struct CastOp;
+
+impl CastOp {
+    /// One element per invocation, using native types.
+    /// Kernels touching F16 require `shader-f16`.
+    fn render_direct(
+        renderer: &mut KernelRenderer,
+        src: CastDType,
+        dst: CastDType,
+    ) -> anyhow::Result<()> {
+        let (Some(src_wgsl), Some(dst_wgsl)) = (src.as_wgsl(), dst.as_wgsl()) else {
+            return Ok(());
+        };
+        for ke in KernelElement::iter() {
+            let mut context = Context::new();
+            context.insert("enable_f16", &(src.is_half() || dst.is_half()));
+            context.insert("src_elem", &ke.as_wgsl(src_wgsl));
+            context.insert("dst_elem", &ke.as_wgsl(dst_wgsl));
+            context.insert("elem_size", &ke.as_size());
+            let rendered = renderer.tera.render("cast", &context)?;
+
+            let kernel_fname = format!("cast_{}_{}_{}.wgsl", src, dst, ke);
+            let mut file = File::create(renderer.dest_path.join(kernel_fname))?;
+            file.write_all(rendered.as_bytes())?;
+        }
+        Ok(())
+    }
+
+    /// Two elements per invocation, with 16 bit types packed into a u32.
+    /// Used for BF16, and for F16 when `shader-f16` is unavailable.
+    fn render_packed(
+        renderer: &mut KernelRenderer,
+        src: CastDType,
+        dst: CastDType,
+    ) -> anyhow::Result<()> {
+        let storage_ty = |dt: CastDType| {
+            if dt.is_half() {
+                "u32".to_string()
+            } else {
+                dt.to_string()
+            }
+        };
+        let mut context = Context::new();
+        context.insert("src", &src.to_string());
+        context.insert("dst", &dst.to_string());
+        context.insert("src_ty", &storage_ty(src));
+        context.insert("dst_ty", &storage_ty(dst));
+        let rendered = renderer.tera.render("cast_packed", &context)?;
+
+        let kernel_fname = format!("cast_{}_{}_packed.wgsl", src, dst);
+        let mut file = File::create(renderer.dest_path.join(kernel_fname))?;
+        file.write_all(rendered.as_bytes())?;
+        Ok(())
+    }
+}
+
+impl Generate for CastOp {
+    fn generate(renderer: &mut KernelRenderer) -> anyhow::Result<()> {
+        let path = renderer.templates_path.join("cast.wgsl");
+        renderer.tera.add_template_file(path, Some("cast"))?;
+        let path = renderer.templates_path.join("cast_packed.wgsl");
+        renderer.tera.add_template_file(path, Some("cast_packed"))?;
+
+        for src in CastDType::iter() {
+            for dst in CastDType::iter().filter(|&dst| dst != src) {
+                Self::render_direct(renderer, src, dst)?;
+                if src.is_half() || dst.is_half() {
+                    Self::render_packed(renderer, src, dst)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
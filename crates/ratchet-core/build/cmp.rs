@@ -0,0 +1,53 @@
+use std::{fs::File, io::Write};
+
+use strum::IntoEnumIterator;
+use tera::Context;
+
+use crate::{Generate, KernelElement, KernelRenderer, WgslDType};
+
+#[derive(Debug, Clone, strum_macros::EnumIter)]
+pub enum CmpOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl CmpOp {
+    pub fn mapping(&self) -> (&'static str, &'static str) {
+        match self {
+            CmpOp::Eq => ("eq", "=="),
+            CmpOp::Ne => ("ne", "!="),
+            CmpOp::Lt => ("lt", "<"),
+            CmpOp::Le => ("le", "<="),
+            CmpOp::Gt => ("gt", ">"),
+            CmpOp::Ge => ("ge", ">="),
+        }
+    }
+}
+
+impl Generate for CmpOp {
+    fn generate(renderer: &mut KernelRenderer) -> anyhow::Result<()> {
+        let path = renderer.templates_path.join("cmp.wgsl");
+        renderer.tera.add_template_file(path, Some("cmp"))?;
+
+        for op in CmpOp::iter() {
+            let (op_name, op) = op.mapping();
+            for ke in KernelElement::iter() {
+                let mut context = Context::new();
+                context.insert("op", op);
+                context.insert("elem", &ke.as_wgsl(WgslDType::F32));
+                context.insert("out_elem", &ke.as_wgsl(WgslDType::U32));
+                context.insert("elem_size", &ke.as_size());
+                let rendered = renderer.tera.render("cmp", &context)?;
+
+                let kernel_fname = format!("{}_{}.wgsl", op_name, ke);
+                let mut file = File::create(renderer.dest_path.join(kernel_fname))?;
+                file.write_all(rendered.as_bytes())?;
+            }
+        }
+        Ok(())
+    }
+}
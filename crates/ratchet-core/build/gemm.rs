@@ -1,5 +1,5 @@
 #![allow(non_snake_case)]
-use crate::{Generate, KernelElement, KernelRenderer, WgslDType};
+use crate::{Generate, KernelElement, KernelRenderer, QuantDType, WgslDType};
 use std::{fs::File, io::Write};
 use tera::Context;
 
@@ -9,42 +9,62 @@ impl Gemm {
     const ROW_PER_THREAD: u32 = 4;
     const TILE_DIM: u32 = 32;
 
+    /// Quantized kernels are only generated for f32 activations.
+    fn kernel_stem(quant: Option<QuantDType>, dt: WgslDType) -> Option<String> {
+        match (quant, dt) {
+            (Some(q), WgslDType::F32) => Some(format!("{}gemm", q.kernel_prefix())),
+            (Some(_), _) => None,
+            (None, WgslDType::F16) => Some("hgemm".to_string()),
+            (None, _) => Some("sgemm".to_string()),
+        }
+    }
+
     pub fn generate_vectorized(renderer: &mut KernelRenderer) -> anyhow::Result<()> {
         let FIT_A_OUTER = [false, true];
         let FIT_B_OUTER = [false, true];
         let FIT_INNER = [false, true];
-        let QUANT = [false, true];
+        let QUANT = std::iter::once(None).chain(QuantDType::ALL.map(Some));
         let BIAS = [false, true];
         let ke = KernelElement::Vec4;
 
         let path = renderer.templates_path.join("gemm_vectorized.wgsl");
         renderer.tera.add_template_file(path, Some("gemm"))?;
-        for bias in BIAS.iter() {
-            for quant in QUANT.iter() {
-                for a_fit in FIT_A_OUTER.iter() {
-                    for b_fit in FIT_B_OUTER.iter() {
-                        for inner_fit in FIT_INNER.iter() {
-                            let mut context = Context::new();
-                            context.insert("BIAS", &bias);
-                            context.insert("QUANT", &quant);
-                            context.insert("FIT_A_OUTER", &a_fit);
-                            context.insert("FIT_B_OUTER", &b_fit);
-                            context.insert("FIT_INNER", &inner_fit);
-                            context.insert("TILE_DIM", &Self::TILE_DIM);
-                            context.insert("ROW_PER_THREAD", &Self::ROW_PER_THREAD);
-                            context.insert("ELEM_TYPE", &ke.as_wgsl(WgslDType::F32));
-                            context.insert("ELEM_SIZE", &ke.as_size());
+        for dt in WgslDType::ACTIVATIONS {
+            for bias in BIAS.iter() {
+                for quant in QUANT.clone() {
+                    for a_fit in FIT_A_OUTER.iter() {
+                        for b_fit in FIT_B_OUTER.iter() {
+                            for inner_fit in FIT_INNER.iter() {
+                                let Some(kernel_stem) = Self::kernel_stem(quant, dt) else {
+                                    continue;
+                                };
 
-                            let rendered = renderer.tera.render("gemm", &context)?;
+                                let mut context = Context::new();
+                                context.insert("DT", &dt.to_string());
+                                context.insert("BIAS", &bias);
+                                context.insert("QUANT", &quant.is_some());
+                                if let Some(q) = quant {
+                                    context.insert("QUANT_DT", &q.to_string());
+                                    context.insert("A_BINDINGS", &q.num_segments());
+                                }
+                                context.insert("FIT_A_OUTER", &a_fit);
+                                context.insert("FIT_B_OUTER", &b_fit);
+                                context.insert("FIT_INNER", &inner_fit);
+                                context.insert("TILE_DIM", &Self::TILE_DIM);
+                                context.insert("ROW_PER_THREAD", &Self::ROW_PER_THREAD);
+                                context.insert("ELEM_TYPE", &ke.as_wgsl(WgslDType::F32));
+                                context.insert("ELEM_SIZE", &ke.as_size());
+                                context.insert("epilogue_ty", &ke.as_wgsl(WgslDType::F32));
 
-                            let kernel_stem = if *quant { "qgemm" } else { "sgemm" };
+                                let rendered = renderer.tera.render("gemm", &context)?;
 
-                            let kernel_fname = format!(
-                                "{}_{}_{}_{}_{}_{}.wgsl",
-                                kernel_stem, bias, a_fit, b_fit, inner_fit, ke
-                            );
-                            let mut file = File::create(renderer.dest_path.join(kernel_fname))?;
-                            file.write_all(rendered.as_bytes())?;
+                                let kernel_fname = format!(
+                                    "{}_{}_{}_{}_{}_{}.wgsl",
+                                    kernel_stem, bias, a_fit, b_fit, inner_fit, ke
+                                );
+                                let mut file = File::create(renderer.dest_path.join(kernel_fname))?;
+                                file.write_all(rendered.as_bytes())?;
+                            }
                         }
                     }
                 }
@@ -57,7 +77,7 @@ impl Gemm {
         let FIT_A_OUTER = [false, true];
         let FIT_B_OUTER = [false, true];
         let FIT_INNER = [false, true];
-        let QUANT = [false, true];
+        let QUANT = std::iter::once(None).chain(QuantDType::ALL.map(Some));
         let TRANS_A = [false, true];
         let TRANS_B = [false, true];
         let TRANS_OUT = [false, true];
@@ -66,51 +86,66 @@ impl Gemm {
 
         let path = renderer.templates_path.join("gemm_scalar.wgsl");
         renderer.tera.add_template_file(path, Some("gemm"))?;
-        for quant in QUANT.iter() {
-            for bias in BIAS.iter() {
-                for trans_a in TRANS_A.iter() {
-                    for trans_b in TRANS_B.iter() {
-                        for trans_out in TRANS_OUT.iter() {
-                            for a_fit in FIT_A_OUTER.iter() {
-                                for b_fit in FIT_B_OUTER.iter() {
-                                    for inner_fit in FIT_INNER.iter() {
-                                        if *quant && *trans_a {
-                                            continue;
-                                        }
+        for dt in WgslDType::ACTIVATIONS {
+            for quant in QUANT.clone() {
+                for bias in BIAS.iter() {
+                    for trans_a in TRANS_A.iter() {
+                        for trans_b in TRANS_B.iter() {
+                            for trans_out in TRANS_OUT.iter() {
+                                for a_fit in FIT_A_OUTER.iter() {
+                                    for b_fit in FIT_B_OUTER.iter() {
+                                        for inner_fit in FIT_INNER.iter() {
+                                            if quant.is_some() && *trans_a {
+                                                continue;
+                                            }
 
-                                        let mut context = Context::new();
-                                        context.insert("BIAS", &bias);
-                                        context.insert("TRANS_A", &trans_a);
-                                        context.insert("TRANS_B", &trans_b);
-                                        context.insert("TRANS_OUT", &trans_out);
-                                        context.insert("FIT_A_OUTER", &a_fit);
-                                        context.insert("FIT_B_OUTER", &b_fit);
-                                        context.insert("FIT_INNER", &inner_fit);
-                                        context.insert("QUANT", &quant);
-                                        context.insert("TILE_DIM", &Self::TILE_DIM);
-                                        context.insert("ROW_PER_THREAD", &Self::ROW_PER_THREAD);
-                                        context.insert("ELEM_TYPE", &ke.as_wgsl(WgslDType::F32));
-                                        context.insert("ELEM_SIZE", &ke.as_size());
+                                            let Some(kernel_stem) = Self::kernel_stem(quant, dt)
+                                            else {
+                                                continue;
+                                            };
 
-                                        let rendered = renderer.tera.render("gemm", &context)?;
+                                            let mut context = Context::new();
+                                            context.insert("DT", &dt.to_string());
+                                            context.insert("BIAS", &bias);
+                                            context.insert("TRANS_A", &trans_a);
+                                            context.insert("TRANS_B", &trans_b);
+                                            context.insert("TRANS_OUT", &trans_out);
+                                            context.insert("FIT_A_OUTER", &a_fit);
+                                            context.insert("FIT_B_OUTER", &b_fit);
+                                            context.insert("FIT_INNER", &inner_fit);
+                                            context.insert("QUANT", &quant.is_some());
+                                            if let Some(q) = quant {
+                                                context.insert("QUANT_DT", &q.to_string());
+                                                context.insert("A_BINDINGS", &q.num_segments());
+                                            }
+                                            context.insert("TILE_DIM", &Self::TILE_DIM);
+                                            context.insert("ROW_PER_THREAD", &Self::ROW_PER_THREAD);
+                                            context
+                                                .insert("ELEM_TYPE", &ke.as_wgsl(WgslDType::F32));
+                                            context.insert("ELEM_SIZE", &ke.as_size());
+                                            context
+                                                .insert("epilogue_ty", &ke.as_wgsl(WgslDType::F32));
 
-                                        let kernel_stem = if *quant { "qgemm" } else { "sgemm" };
+                                            let rendered =
+                                                renderer.tera.render("gemm", &context)?;
 
-                                        let kernel_fname = format!(
-                                            "{}_{}_{}_{}_{}_{}_{}_{}_{}.wgsl",
-                                            kernel_stem,
-                                            bias,
-                                            a_fit,
-                                            b_fit,
-                                            inner_fit,
-                                            trans_a,
-                                            trans_b,
-                                            trans_out,
-                                            ke
-                                        );
-                                        let mut file =
-                                            File::create(renderer.dest_path.join(kernel_fname))?;
-                                        file.write_all(rendered.as_bytes())?;
+                                            let kernel_fname = format!(
+                                                "{}_{}_{}_{}_{}_{}_{}_{}_{}.wgsl",
+                                                kernel_stem,
+                                                bias,
+                                                a_fit,
+                                                b_fit,
+                                                inner_fit,
+                                                trans_a,
+                                                trans_b,
+                                                trans_out,
+                                                ke
+                                            );
+                                            let mut file = File::create(
+                                                renderer.dest_path.join(kernel_fname),
+                                            )?;
+                                            file.write_all(rendered.as_bytes())?;
+                                        }
                                     }
                                 }
                             }
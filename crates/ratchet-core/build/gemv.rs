@@ -1,59 +1,78 @@
 #![allow(non_snake_case)]
-use crate::{Generate, KernelElement, KernelRenderer, WgslDType};
+use crate::{Generate, KernelElement, KernelRenderer, QuantDType, WgslDType};
 use std::{fs::File, io::Write};
 use tera::Context;
 
 pub struct Gemv;
 
 impl Gemv {
+    /// Quantized kernels are only generated for f32 activations.
+    fn kernel_stem(quant: Option<QuantDType>, dt: WgslDType) -> Option<String> {
+        match (quant, dt) {
+            (Some(q), WgslDType::F32) => Some(format!("{}gemv", q.kernel_prefix())),
+            (Some(_), _) => None,
+            (None, WgslDType::F16) => Some("hgemv".to_string()),
+            (None, _) => Some("sgemv".to_string()),
+        }
+    }
+
     pub fn generate_kernel(renderer: &mut KernelRenderer) -> anyhow::Result<()> {
         let WORKGROUP_X = [4, 8, 16, 32, 64];
         let WORKGROUP_Y = [4, 8, 16, 32, 256];
         let FIT = [false, true];
         let BIAS = [false, true];
-        let QUANT = [false, true];
-        let mut ke = KernelElement::Scalar;
+        let QUANT = std::iter::once(None).chain(QuantDType::ALL.map(Some));
 
         let path = renderer.templates_path.join("gemv.wgsl");
         renderer.tera.add_template_file(path, Some("gemv"))?;
-        for quant in QUANT.iter() {
-            if *quant {
-                ke = KernelElement::Vec4;
-            }
+        for dt in WgslDType::ACTIVATIONS {
+            for quant in QUANT.clone() {
+                let ke = if quant.is_some() {
+                    KernelElement::Vec4
+                } else {
+                    KernelElement::Scalar
+                };
+                let Some(kernel_stem) = Self::kernel_stem(quant, dt) else {
+                    continue;
+                };
 
-            for wgx in WORKGROUP_X.iter() {
-                for wgy in WORKGROUP_Y.iter() {
-                    if wgx * wgy > 1024 {
-                        continue;
-                    }
-
-                    for fit in FIT.iter() {
-                        for bias in BIAS.iter() {
-                            let mut context = Context::new();
-                            context.insert("ELEM_TYPE", &ke.as_wgsl(WgslDType::F32));
-                            context.insert("ELEM_SIZE", &ke.as_size());
-                            context.insert("QUANT", &quant);
-                            context.insert("FIT", &fit);
-                            context.insert("BIAS", &bias);
-                            context.insert("workgroup_size_x", &wgx);
-                            context.insert("workgroup_size_y", &wgy);
-                            context.insert("workgroup_size_z", &1);
+                for wgx in WORKGROUP_X.iter() {
+                    for wgy in WORKGROUP_Y.iter() {
+                        if wgx * wgy > 1024 {
+                            continue;
+                        }
 
-                            let kernel_stem = if *quant { "qgemv" } else { "sgemv" };
+                        for fit in FIT.iter() {
+                            for bias in BIAS.iter() {
+                                let mut context = Context::new();
+                                context.insert("DT", &dt.to_string());
+                                context.insert("ELEM_TYPE", &ke.as_wgsl(WgslDType::F32));
+                                context.insert("ELEM_SIZE", &ke.as_size());
+                                context.insert("QUANT", &quant.is_some());
+                                if let Some(q) = quant {
+                                    context.insert("QUANT_DT", &q.to_string());
+                                    context.insert("A_BINDINGS", &q.num_segments());
+                                }
+                                context.insert("FIT", &fit);
+                                context.insert("BIAS", &bias);
+                                context.insert("workgroup_size_x", &wgx);
+                                context.insert("workgroup_size_y", &wgy);
+                                context.insert("workgroup_size_z", &1);
 
-                            let rendered = renderer.tera.render("gemv", &context)?;
+                                let rendered = renderer.tera.render("gemv", &context)?;
 
-                            let kernel_fname = format!(
-                                "{}_{}_{}_{}_{}_{}.wgsl",
-                                kernel_stem,
-                                bias,
-                                wgx,
-                                wgy,
-                                fit,
-                                KernelElement::Scalar
-                            );
-                            let mut file = File::create(renderer.dest_path.join(kernel_fname))?;
-                            file.write_all(rendered.as_bytes())?;
+                                let kernel_fname = format!(
+                                    "{}_{}_{}_{}_{}_{}.wgsl",
+                                    kernel_stem,
+                                    bias,
+                                    wgx,
+                                    wgy,
+                                    fit,
+                                    KernelElement::Scalar
+                                );
+                                let mut file = File::create(renderer.dest_path.join(kernel_fname))?;
+                                file.write_all(rendered.as_bytes())?;
+                            }
                         }
                     }
                 }
@@ -0,0 +1,36 @@
+use std::{fs::File, io::Write};
+
+use tera::Context;
+
+use crate::{Generate, KernelElement, KernelRenderer, QuantDType, WgslDType};
+
+/// Embedding lookups on quantized weights, dequantizing only the selected rows.
+///
+/// Vectorized kernels gather 4 consecutive elements at a time, and require rows of a multiple
+/// of 4. Scalar kernels handle everything else, e.g selecting along the last dimension.
+pub struct IndexSelectOp;
+
+impl Generate for IndexSelectOp {
+    fn generate(renderer: &mut KernelRenderer) -> anyhow::Result<()> {
+        let path = renderer.templates_path.join("index_select.wgsl");
+        renderer
+            .tera
+            .add_template_file(path, Some("index_select"))?;
+
+        for quant in QuantDType::ALL {
+            for ke in [KernelElement::Scalar, KernelElement::Vec4] {
+                let mut context = Context::new();
+                context.insert("QUANT_DT", &quant.to_string());
+                context.insert("A_BINDINGS", &quant.num_segments());
+                context.insert("ELEM_TYPE", &ke.as_wgsl(WgslDType::F32));
+                context.insert("ELEM_SIZE", &ke.as_size());
+                let rendered = renderer.tera.render("index_select", &context)?;
+
+                let kernel_fname = format!("{}_index_select_{}.wgsl", quant.kernel_prefix(), ke);
+                let mut file = File::create(renderer.dest_path.join(kernel_fname))?;
+                file.write_all(rendered.as_bytes())?;
+            }
+        }
+        Ok(())
+    }
+}
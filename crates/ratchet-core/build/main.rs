@@ -1,19 +1,35 @@
 mod binary;
+mod cast;
+mod cmp;
 mod concat;
 mod gemm;
 mod gemv;
+mod index_select;
 mod norm;
+mod reduce;
 mod reindex;
+mod sdpa;
+mod softmax;
+mod trilu;
 mod unary;
+mod where_cond;
 
 use anyhow::Context as anyhowCtx;
 use binary::BinaryOp;
+use cast::CastOp;
+use cmp::CmpOp;
 use concat::ConcatOp;
 use gemm::Gemm;
 use gemv::Gemv;
+use index_select::IndexSelectOp;
 use norm::NormOp;
+use reduce::ReduceOp;
 use reindex::ReindexOp;
+use sdpa::SdpaOp;
+use softmax::SoftmaxOp;
+use trilu::TriluOp;
 use unary::UnaryOp;
+use where_cond::WhereCondOp;
 
 use std::io::Write;
 use std::path::{Path, PathBuf};
@@ -45,14 +61,111 @@ impl std::fmt::Display for KernelElement {
     }
 }
 
+#[derive(Debug, Clone, Copy)]
 pub enum WgslDType {
     F32,
+    F16,
+    I32,
+    U32,
 }
 
 impl std::fmt::Display for WgslDType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             WgslDType::F32 => write!(f, "f32"),
+            WgslDType::F16 => write!(f, "f16"),
+            WgslDType::I32 => write!(f, "i32"),
+            WgslDType::U32 => write!(f, "u32"),
+        }
+    }
+}
+
+impl WgslDType {
+    /// Types activations may be stored in. F16 variants require `shader-f16`.
+    pub const ACTIVATIONS: [WgslDType; 2] = [WgslDType::F32, WgslDType::F16];
+
+    /// Appended to the file name of non f32 variants, e.g `add_vec4_f16`.
+    pub fn kernel_suffix(&self) -> String {
+        match self {
+            WgslDType::F32 => String::new(),
+            _ => format!("_{}", self),
+        }
+    }
+}
+
+/// Quantized weight formats, dequantized on the fly by the matmul kernels.
+#[derive(Debug, Clone, Copy)]
+pub enum QuantDType {
+    Q8_0,
+    Q4_0,
+    Q4_1,
+    Q5_0,
+    Q5_1,
+    Q8_1,
+    Q4K,
+    Q6K,
+    SInt4,
+}
+
+impl std::fmt::Display for QuantDType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QuantDType::Q8_0 => write!(f, "q8_0"),
+            QuantDType::Q4_0 => write!(f, "q4_0"),
+            QuantDType::Q4_1 => write!(f, "q4_1"),
+            QuantDType::Q5_0 => write!(f, "q5_0"),
+            QuantDType::Q5_1 => write!(f, "q5_1"),
+            QuantDType::Q8_1 => write!(f, "q8_1"),
+            QuantDType::Q4K => write!(f, "q4k"),
+            QuantDType::Q6K => write!(f, "q6k"),
+            QuantDType::SInt4 => write!(f, "sint4"),
+        }
+    }
+}
+
+impl QuantDType {
+    pub const ALL: [QuantDType; 9] = [
+        QuantDType::Q8_0,
+        QuantDType::Q4_0,
+        QuantDType::Q4_1,
+        QuantDType::Q5_0,
+        QuantDType::Q5_1,
+        QuantDType::Q8_1,
+        QuantDType::Q4K,
+        QuantDType::Q6K,
+        QuantDType::SInt4,
+    ];
+
+    /// Formats predating k-quants, with blocks of 32 elements.
+    pub const LEGACY: [QuantDType; 5] = [
+        QuantDType::Q4_0,
+        QuantDType::Q4_1,
+        QuantDType::Q5_0,
+        QuantDType::Q5_1,
+        QuantDType::Q8_1,
+    ];
+
+    /// Prepended to the kernel stem, e.g `q4kgemv`.
+    pub fn kernel_prefix(&self) -> &'static str {
+        match self {
+            QuantDType::Q8_0 => "q",
+            QuantDType::Q4_0 => "q4_0",
+            QuantDType::Q4_1 => "q4_1",
+            QuantDType::Q5_0 => "q5_0",
+            QuantDType::Q5_1 => "q5_1",
+            QuantDType::Q8_1 => "q8_1",
+            QuantDType::Q4K => "q4k",
+            QuantDType::Q6K => "q6k",
+            QuantDType::SInt4 => "sint4",
+        }
+    }
+
+    /// Number of bindings the weights occupy, one per segment.
+    pub fn num_segments(&self) -> usize {
+        match self {
+            QuantDType::Q8_0 | QuantDType::Q4_0 | QuantDType::Q8_1 | QuantDType::SInt4 => 2,
+            QuantDType::Q4_1 | QuantDType::Q5_0 => 3,
+            QuantDType::Q5_1 | QuantDType::Q4K | QuantDType::Q6K => 4,
         }
     }
 }
@@ -95,13 +208,29 @@ impl Default for KernelRenderer {
 
 impl KernelRenderer {
     fn generate(&mut self) -> anyhow::Result<()> {
+        //Shared by every kernel that supports a fused epilogue
+        let epilogue = self.templates_path.join("epilogue.wgsl");
+        self.tera.add_template_file(epilogue, Some("epilogue"))?;
+        //Shared by every kernel reading quantized weights, other than the Q8_0 matmuls
+        let dequantize = self.templates_path.join("dequantize.wgsl");
+        self.tera
+            .add_template_file(dequantize, Some("dequantize"))?;
+
         UnaryOp::generate(self)?;
         BinaryOp::generate(self)?;
         ReindexOp::generate(self)?;
         NormOp::generate(self)?;
+        SoftmaxOp::generate(self)?;
+        SdpaOp::generate(self)?;
         Gemm::generate(self)?;
         Gemv::generate(self)?;
+        IndexSelectOp::generate(self)?;
         ConcatOp::generate(self)?;
+        ReduceOp::generate(self)?;
+        CmpOp::generate(self)?;
+        WhereCondOp::generate(self)?;
+        TriluOp::generate(self)?;
+        CastOp::generate(self)?;
         Ok(())
     }
 }
@@ -5,15 +5,20 @@ use tera::Context;
 
 use crate::{Generate, KernelElement, KernelRenderer, WgslDType};
 
+#[allow(clippy::enum_variant_names)]
 #[derive(Debug, Clone, strum_macros::EnumIter)]
 pub enum NormOp {
     LayerNorm,
+    RMSNorm,
+    GroupNorm,
 }
 
 impl std::fmt::Display for NormOp {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let s = match self {
             NormOp::LayerNorm => "layernorm",
+            NormOp::RMSNorm => "rmsnorm",
+            NormOp::GroupNorm => "groupnorm",
         };
         write!(f, "{}", s)
     }
@@ -21,25 +26,37 @@ impl std::fmt::Display for NormOp {
 
 impl Generate for NormOp {
     fn generate(renderer: &mut KernelRenderer) -> anyhow::Result<()> {
-        for op in NormOp::iter() {
-            for ke in KernelElement::iter() {
-                let path = renderer.templates_path.join("layernorm.wgsl");
-                renderer.tera.add_template_file(path, Some("layernorm"))?;
+        for dt in WgslDType::ACTIVATIONS {
+            for op in NormOp::iter() {
+                for ke in KernelElement::iter() {
+                    let path = renderer.templates_path.join("layernorm.wgsl");
+                    renderer.tera.add_template_file(path, Some("layernorm"))?;
 
-                let mut context = Context::new();
-                context.insert("elem", &ke.as_wgsl(WgslDType::F32));
-                context.insert("elem_size", &ke.as_size());
-                let reduction_len = match ke {
-                    KernelElement::Scalar => "metadata.N",
-                    KernelElement::Vec2 => "metadata.ND2",
-                    KernelElement::Vec4 => "metadata.ND4",
-                };
-                context.insert("reduction_len", reduction_len);
-                let rendered = renderer.tera.render("layernorm", &context)?;
+                    let mut context = Context::new();
+                    context.insert("op", &op.to_string());
+                    context.insert("dt", &dt.to_string());
+                    context.insert("elem", &ke.as_wgsl(dt));
+                    //GroupNorm applies a single scale & bias per channel
+                    let affine = match op {
+                        NormOp::GroupNorm => KernelElement::Scalar.as_wgsl(dt),
+                        _ => ke.as_wgsl(dt),
+                    };
+                    context.insert("affine", &affine);
+                    //Statistics are always accumulated in f32
+                    context.insert("acc", &ke.as_wgsl(WgslDType::F32));
+                    context.insert("elem_size", &ke.as_size());
+                    let reduction_len = match ke {
+                        KernelElement::Scalar => "metadata.N",
+                        KernelElement::Vec2 => "metadata.ND2",
+                        KernelElement::Vec4 => "metadata.ND4",
+                    };
+                    context.insert("reduction_len", reduction_len);
+                    let rendered = renderer.tera.render("layernorm", &context)?;
 
-                let kernel_fname = format!("{}_{}.wgsl", op, ke);
-                let mut file = File::create(renderer.dest_path.join(kernel_fname))?;
-                file.write_all(rendered.as_bytes())?;
+                    let kernel_fname = format!("{}_{}{}.wgsl", op, ke, dt.kernel_suffix());
+                    let mut file = File::create(renderer.dest_path.join(kernel_fname))?;
+                    file.write_all(rendered.as_bytes())?;
+                }
             }
         }
         Ok(())
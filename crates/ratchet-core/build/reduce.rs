@@ -0,0 +1,77 @@
+use std::{fs::File, io::Write};
+
+use strum::IntoEnumIterator;
+use tera::Context;
+
+use crate::{Generate, KernelElement, KernelRenderer, WgslDType};
+
+#[derive(Debug, Clone, strum_macros::EnumIter)]
+pub enum ReduceOp {
+    Sum,
+    Mean,
+    Max,
+    Min,
+    ArgMax,
+    ArgMin,
+}
+
+impl std::fmt::Display for ReduceOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            ReduceOp::Sum => "sum",
+            ReduceOp::Mean => "mean",
+            ReduceOp::Max => "max",
+            ReduceOp::Min => "min",
+            ReduceOp::ArgMax => "argmax",
+            ReduceOp::ArgMin => "argmin",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl ReduceOp {
+    /// Identity element of the reduction, written as a WGSL literal.
+    fn init(&self) -> &'static str {
+        match self {
+            ReduceOp::Sum | ReduceOp::Mean => "0.0f",
+            ReduceOp::Max | ReduceOp::ArgMax => "-3.40282347e+38f",
+            ReduceOp::Min | ReduceOp::ArgMin => "3.40282347e+38f",
+        }
+    }
+
+    fn is_arg(&self) -> bool {
+        matches!(self, ReduceOp::ArgMax | ReduceOp::ArgMin)
+    }
+}
+
+impl Generate for ReduceOp {
+    fn generate(renderer: &mut KernelRenderer) -> anyhow::Result<()> {
+        let path = renderer.templates_path.join("reduce.wgsl");
+        renderer.tera.add_template_file(path, Some("reduce"))?;
+
+        //Reductions walk arbitrary strides, so only scalar kernels are generated
+        let ke = KernelElement::Scalar;
+        for dt in WgslDType::ACTIVATIONS {
+            for op in ReduceOp::iter() {
+                let mut context = Context::new();
+                context.insert("dt", &dt.to_string());
+                context.insert("op", &op.to_string());
+                context.insert("init", op.init());
+                context.insert("is_arg", &op.is_arg());
+                context.insert("elem", &ke.as_wgsl(dt));
+                let out_elem = if op.is_arg() {
+                    "i32".to_string()
+                } else {
+                    ke.as_wgsl(dt)
+                };
+                context.insert("out_elem", &out_elem);
+                let rendered = renderer.tera.render("reduce", &context)?;
+
+                let kernel_fname = format!("reduce_{}_{}{}.wgsl", op, ke, dt.kernel_suffix());
+                let mut file = File::create(renderer.dest_path.join(kernel_fname))?;
+                file.write_all(rendered.as_bytes())?;
+            }
+        }
+        Ok(())
+    }
+}
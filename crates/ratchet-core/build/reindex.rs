@@ -50,24 +50,28 @@ impl Generate for ReindexOp {
         let path = renderer.templates_path.join("reindex.wgsl");
         renderer.tera.add_template_file(path, Some("reindex"))?;
 
-        for op in ReindexOp::iter() {
-            for ke in KernelElement::iter() {
-                if matches!(ke, KernelElement::Vec4 | KernelElement::Vec2)
-                    && !matches!(op, ReindexOp::Broadcast)
-                {
-                    continue;
-                }
+        for dt in WgslDType::ACTIVATIONS {
+            for op in ReindexOp::iter() {
+                for ke in KernelElement::iter() {
+                    if matches!(ke, KernelElement::Vec4 | KernelElement::Vec2)
+                        && !matches!(op, ReindexOp::Broadcast)
+                    {
+                        continue;
+                    }
 
-                let mut context = Context::new();
-                context.insert("elem", &ke.as_wgsl(WgslDType::F32));
-                context.insert("elem_size", &ke.as_size());
-                context.insert("func_body", &op.func_body());
+                    let mut context = Context::new();
+                    context.insert("dt", &dt.to_string());
+                    context.insert("elem", &ke.as_wgsl(dt));
+                    context.insert("elem_size", &ke.as_size());
+                    context.insert("func_body", &op.func_body());
+                    context.insert("epilogue_ty", &ke.as_wgsl(dt));
 
-                let rendered = renderer.tera.render("reindex", &context)?;
+                    let rendered = renderer.tera.render("reindex", &context)?;
 
-                let kernel_fname = format!("{}_{}.wgsl", op, ke);
-                let mut file = File::create(renderer.dest_path.join(kernel_fname))?;
-                file.write_all(rendered.as_bytes())?;
+                    let kernel_fname = format!("{}_{}{}.wgsl", op, ke, dt.kernel_suffix());
+                    let mut file = File::create(renderer.dest_path.join(kernel_fname))?;
+                    file.write_all(rendered.as_bytes())?;
+                }
             }
         }
         Ok(())
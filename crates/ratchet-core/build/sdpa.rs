@@ -0,0 +1,29 @@
+use std::{fs::File, io::Write};
+
+use tera::Context;
+
+use crate::{Generate, KernelElement, KernelRenderer, WgslDType};
+
+pub struct SdpaOp;
+
+impl Generate for SdpaOp {
+    fn generate(renderer: &mut KernelRenderer) -> anyhow::Result<()> {
+        let path = renderer.templates_path.join("sdpa.wgsl");
+        renderer.tera.add_template_file(path, Some("sdpa"))?;
+
+        for dt in WgslDType::ACTIVATIONS {
+            for mask in [false, true] {
+                let mut context = Context::new();
+                context.insert("dt", &dt.to_string());
+                context.insert("elem", &KernelElement::Scalar.as_wgsl(dt));
+                context.insert("mask", &mask);
+                let rendered = renderer.tera.render("sdpa", &context)?;
+
+                let kernel_fname = format!("sdpa_{}{}.wgsl", mask, dt.kernel_suffix());
+                let mut file = File::create(renderer.dest_path.join(kernel_fname))?;
+                file.write_all(rendered.as_bytes())?;
+            }
+        }
+        Ok(())
+    }
+}
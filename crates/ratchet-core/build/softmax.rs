@@ -0,0 +1,56 @@
+use std::{fs::File, io::Write};
+
+use strum::IntoEnumIterator;
+use tera::Context;
+
+use crate::{Generate, KernelElement, KernelRenderer, WgslDType};
+
+pub struct SoftmaxOp;
+
+impl Generate for SoftmaxOp {
+    fn generate(renderer: &mut KernelRenderer) -> anyhow::Result<()> {
+        let path = renderer.templates_path.join("softmax.wgsl");
+        renderer.tera.add_template_file(path, Some("softmax"))?;
+
+        for dt in WgslDType::ACTIVATIONS {
+            //Variants reading a fused scale & mask, see SoftmaxPrologue
+            for prologue in [false, true] {
+                for ke in KernelElement::iter() {
+                    let mut context = Context::new();
+                    context.insert("dt", &dt.to_string());
+                    context.insert("prologue", &prologue);
+                    context.insert("elem", &ke.as_wgsl(dt));
+                    //Row max & sum are always computed in f32
+                    context.insert("acc", &ke.as_wgsl(WgslDType::F32));
+                    let (reduction_len, reduce_max, reduce_sum) = match ke {
+                        KernelElement::Scalar => ("metadata.N", "smem[0]", "smem[0]"),
+                        KernelElement::Vec2 => (
+                            "metadata.ND2",
+                            "max(smem[0].x, smem[0].y)",
+                            "dot(smem[0], vec2<f32>(1.0))",
+                        ),
+                        KernelElement::Vec4 => (
+                            "metadata.ND4",
+                            "max(smem[0].x, max(smem[0].y, max(smem[0].z, smem[0].w)))",
+                            "dot(smem[0], vec4<f32>(1.0))",
+                        ),
+                    };
+                    context.insert("reduction_len", reduction_len);
+                    context.insert("reduce_max", reduce_max);
+                    context.insert("reduce_sum", reduce_sum);
+                    let rendered = renderer.tera.render("softmax", &context)?;
+
+                    let stem = if prologue {
+                        "softmax_prologue"
+                    } else {
+                        "softmax"
+                    };
+                    let kernel_fname = format!("{}_{}{}.wgsl", stem, ke, dt.kernel_suffix());
+                    let mut file = File::create(renderer.dest_path.join(kernel_fname))?;
+                    file.write_all(rendered.as_bytes())?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
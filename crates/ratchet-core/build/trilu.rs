@@ -0,0 +1,43 @@
+use std::{fs::File, io::Write};
+
+use strum::IntoEnumIterator;
+use tera::Context;
+
+use crate::{Generate, KernelElement, KernelRenderer, WgslDType};
+
+#[derive(Debug, Clone, strum_macros::EnumIter)]
+pub enum TriluOp {
+    Triu,
+    Tril,
+}
+
+impl std::fmt::Display for TriluOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            TriluOp::Triu => "triu",
+            TriluOp::Tril => "tril",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl Generate for TriluOp {
+    fn generate(renderer: &mut KernelRenderer) -> anyhow::Result<()> {
+        let path = renderer.templates_path.join("trilu.wgsl");
+        renderer.tera.add_template_file(path, Some("trilu"))?;
+
+        //Each element needs its own row & column, so only scalar kernels are generated
+        let ke = KernelElement::Scalar;
+        for op in TriluOp::iter() {
+            let mut context = Context::new();
+            context.insert("upper", &matches!(op, TriluOp::Triu));
+            context.insert("elem", &ke.as_wgsl(WgslDType::F32));
+            let rendered = renderer.tera.render("trilu", &context)?;
+
+            let kernel_fname = format!("{}_{}.wgsl", op, ke);
+            let mut file = File::create(renderer.dest_path.join(kernel_fname))?;
+            file.write_all(rendered.as_bytes())?;
+        }
+        Ok(())
+    }
+}
@@ -19,6 +19,13 @@ pub enum UnaryOp {
     Floor,
     Ceil,
     Neg,
+    Silu,
+    Sigmoid,
+    GeluErf,
+    LeakyRelu,
+    Elu,
+    Softplus,
+    Reciprocal,
 }
 
 impl std::fmt::Display for UnaryOp {
@@ -36,6 +43,13 @@ impl std::fmt::Display for UnaryOp {
             UnaryOp::Floor => "floor",
             UnaryOp::Ceil => "ceil",
             UnaryOp::Neg => "neg",
+            UnaryOp::Silu => "silu",
+            UnaryOp::Sigmoid => "sigmoid",
+            UnaryOp::GeluErf => "gelu_erf",
+            UnaryOp::LeakyRelu => "leaky_relu",
+            UnaryOp::Elu => "elu",
+            UnaryOp::Softplus => "softplus",
+            UnaryOp::Reciprocal => "reciprocal",
         };
         write!(f, "{}", s)
     }
@@ -46,28 +60,31 @@ impl Generate for UnaryOp {
         let path = renderer.templates_path.join("unary.wgsl");
         renderer.tera.add_template_file(path, Some("unary"))?;
 
-        for inplace in [true, false].iter() {
-            for func in UnaryOp::iter() {
-                for ke in KernelElement::iter() {
-                    let mut context = Context::new();
-                    let tera_func = match func {
-                        UnaryOp::Tanh => String::from("safe_tanh"),
-                        UnaryOp::Neg => String::from("-"),
-                        _ => func.to_string(),
-                    };
-                    context.insert("inplace", inplace);
-                    context.insert("func", &tera_func);
-                    context.insert("elem", &ke.as_wgsl(WgslDType::F32));
-                    context.insert("elem_size", &ke.as_size());
-                    let rendered = renderer.tera.render("unary", &context)?;
+        for dt in WgslDType::ACTIVATIONS {
+            for inplace in [true, false].iter() {
+                for func in UnaryOp::iter() {
+                    for ke in KernelElement::iter() {
+                        let mut context = Context::new();
+                        let tera_func = match func {
+                            UnaryOp::Tanh => String::from("safe_tanh"),
+                            UnaryOp::Neg => String::from("-"),
+                            _ => func.to_string(),
+                        };
+                        context.insert("dt", &dt.to_string());
+                        context.insert("inplace", inplace);
+                        context.insert("func", &tera_func);
+                        context.insert("elem", &ke.as_wgsl(dt));
+                        context.insert("elem_size", &ke.as_size());
+                        let rendered = renderer.tera.render("unary", &context)?;
 
-                    let kernel_fname = if *inplace {
-                        format!("{}_inplace_{}.wgsl", func, ke)
-                    } else {
-                        format!("{}_{}.wgsl", func, ke)
-                    };
-                    let mut file = File::create(renderer.dest_path.join(kernel_fname))?;
-                    file.write_all(rendered.as_bytes())?;
+                        let kernel_fname = if *inplace {
+                            format!("{}_inplace_{}{}.wgsl", func, ke, dt.kernel_suffix())
+                        } else {
+                            format!("{}_{}{}.wgsl", func, ke, dt.kernel_suffix())
+                        };
+                        let mut file = File::create(renderer.dest_path.join(kernel_fname))?;
+                        file.write_all(rendered.as_bytes())?;
+                    }
                 }
             }
         }
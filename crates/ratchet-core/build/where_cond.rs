@@ -0,0 +1,31 @@
+use std::{fs::File, io::Write};
+
+use strum::IntoEnumIterator;
+use tera::Context;
+
+use crate::{Generate, KernelElement, KernelRenderer, WgslDType};
+
+pub struct WhereCondOp;
+
+impl Generate for WhereCondOp {
+    fn generate(renderer: &mut KernelRenderer) -> anyhow::Result<()> {
+        let path = renderer.templates_path.join("where_cond.wgsl");
+        renderer.tera.add_template_file(path, Some("where_cond"))?;
+
+        for dt in WgslDType::ACTIVATIONS {
+            for ke in KernelElement::iter() {
+                let mut context = Context::new();
+                context.insert("dt", &dt.to_string());
+                context.insert("elem", &ke.as_wgsl(dt));
+                context.insert("cond_elem", &ke.as_wgsl(WgslDType::U32));
+                context.insert("elem_size", &ke.as_size());
+                let rendered = renderer.tera.render("where_cond", &context)?;
+
+                let kernel_fname = format!("where_cond_{}{}.wgsl", ke, dt.kernel_suffix());
+                let mut file = File::create(renderer.dest_path.join(kernel_fname))?;
+                file.write_all(rendered.as_bytes())?;
+            }
+        }
+        Ok(())
+    }
+}
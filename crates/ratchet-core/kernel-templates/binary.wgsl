@@ -1,3 +1,7 @@
+{% if dt == "f16" -%}
+enable f16;
+
+{% endif -%}
 {% if inplace %}
 @group(0) @binding(0)
 var<storage, read_write> A: array<{{ elem }}>;
@@ -22,7 +26,11 @@ struct Meta {
 
 @group(1) @binding(0)
 var<uniform> metadata: Meta;
-
+{% if op_name == "swiglu" %}
+fn swiglu(gate: {{ elem }}, up: {{ elem }}) -> {{ elem }} {
+    return gate / ({{ elem }}(1.0) + exp(-gate)) * up;
+}
+{% endif %}
 @compute @workgroup_size(8, 8, 1)
 fn main(
     @builtin(global_invocation_id) global_id: vec3<u32>,
@@ -38,8 +46,16 @@ fn main(
 
     {% if inplace %}
         let val = A[index];
+        {%- if op_name == "swiglu" %}
+        A[index] = swiglu(val, B[index]);
+        {%- else %}
         A[index] = val {{ op }} B[index];
+        {%- endif %}
     {% else %}
+        {%- if op_name == "swiglu" %}
+        Y[index] = swiglu(A[index], B[index]);
+        {%- else %}
         Y[index] = A[index] {{ op }} B[index];
+        {%- endif %}
     {% endif %}
 }
@@ -0,0 +1,56 @@
+@group(0) @binding(0)
+var<storage, read> X: array<{{ src_ty }}>;
+
+@group(0) @binding(1)
+var<storage, read_write> Y: array<{{ dst_ty }}>;
+
+struct Meta {
+    numel: u32,
+}
+
+@group(1) @binding(0)
+var<uniform> metadata: Meta;
+{% if dst == "bf16" %}
+//Round to nearest even, keeping NaNs as quiet NaNs
+fn to_bf16(x: f32) -> u32 {
+    let bits = bitcast<u32>(x);
+    if ((bits & 0x7FFFFFFFu) > 0x7F800000u) {
+        return (bits >> 16u) | 0x40u;
+    }
+    return (bits + 0x7FFFu + ((bits >> 16u) & 1u)) >> 16u;
+}
+{% endif %}
+@compute @workgroup_size(8, 8, 1)
+fn main(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(workgroup_id) group_id: vec3<u32>,
+    @builtin(local_invocation_index) local_index: u32,
+    @builtin(num_workgroups) num_groups: vec3<u32>
+) {
+    let x_offset = group_id.x * 64u;
+    let index = (group_id.y * num_groups.x * 64u) + x_offset + local_index;
+    //Each invocation handles the pair of elements sharing a packed u32
+    if (index >= (metadata.numel + 1u) / 2u) {
+        return;
+    }
+    let lo = index * 2u;
+    let hi = min(lo + 1u, metadata.numel - 1u);
+{%- if src == "f16" %}
+    let val = unpack2x16float(X[index]);
+{%- elif src == "bf16" %}
+    let packed = X[index];
+    let val = vec2<f32>(bitcast<f32>(packed << 16u), bitcast<f32>(packed & 0xFFFF0000u));
+{%- else %}
+    let val = vec2<f32>(f32(X[lo]), f32(X[hi]));
+{%- endif %}
+{%- if dst == "f16" %}
+    Y[index] = pack2x16float(val);
+{%- elif dst == "bf16" %}
+    Y[index] = to_bf16(val.x) | (to_bf16(val.y) << 16u);
+{%- else %}
+    Y[lo] = {{ dst_ty }}(val.x);
+    if (lo + 1u < metadata.numel) {
+        Y[lo + 1u] = {{ dst_ty }}(val.y);
+    }
+{%- endif %}
+}
@@ -0,0 +1,135 @@
+//Dequantization of GGUF weights, bound one segment at a time.
+//`dequantize` returns 4 consecutive elements of a row, starting at a multiple of 4.
+{% if QUANT_DT == "q4_0" or QUANT_DT == "q4_1" %}
+    @group(0) @binding(0) var<storage, read> A_qs: array<u32>;
+    @group(0) @binding(1) var<storage, read> A_d: array<f32>;
+    {% if QUANT_DT == "q4_1" %}
+        @group(0) @binding(2) var<storage, read> A_m: array<f32>;
+    {% endif %}
+
+    fn dequantize(index: i32) -> vec4<f32> {
+        let block = u32(index) / 32u;
+        let j = u32(index) % 32u;
+        //Low nibbles fill the first 16 elements of a block, high nibbles the last 16
+        let high = j / 16u;
+        let packed = A_qs[(block * 16u + j % 16u) / 4u];
+        let q = unpack4x8unorm((packed >> (4u * high)) & 0x0F0F0F0Fu) * 255.0;
+        {% if QUANT_DT == "q4_1" %}
+            return A_d[block] * q + vec4<f32>(A_m[block]);
+        {% else %}
+            return A_d[block] * (q - 8.0);
+        {% endif %}
+    }
+{% elif QUANT_DT == "q5_0" or QUANT_DT == "q5_1" %}
+    @group(0) @binding(0) var<storage, read> A_qs: array<u32>;
+    @group(0) @binding(1) var<storage, read> A_qh: array<u32>;
+    @group(0) @binding(2) var<storage, read> A_d: array<f32>;
+    {% if QUANT_DT == "q5_1" %}
+        @group(0) @binding(3) var<storage, read> A_m: array<f32>;
+    {% endif %}
+
+    fn dequantize(index: i32) -> vec4<f32> {
+        let block = u32(index) / 32u;
+        let j = u32(index) % 32u;
+        //As Q4, with the fifth bit of element `j` in bit `j` of qh
+        let high = j / 16u;
+        let packed = A_qs[(block * 16u + j % 16u) / 4u];
+        let low = (packed >> (4u * high)) & 0x0F0F0F0Fu;
+        let bits = A_qh[block] >> j;
+        let fifth = vec4<u32>(bits, bits >> 1u, bits >> 2u, bits >> 3u) & vec4<u32>(1u);
+        let q = unpack4x8unorm(low) * 255.0 + vec4<f32>(fifth * 16u);
+        {% if QUANT_DT == "q5_1" %}
+            return A_d[block] * q + vec4<f32>(A_m[block]);
+        {% else %}
+            return A_d[block] * (q - 16.0);
+        {% endif %}
+    }
+{% elif QUANT_DT == "q8_0" or QUANT_DT == "q8_1" %}
+    @group(0) @binding(0) var<storage, read> A_qs: array<u32>;
+    @group(0) @binding(1) var<storage, read> A_d: array<f32>;
+
+    fn dequantize(index: i32) -> vec4<f32> {
+        let packed = bitcast<i32>(A_qs[index / 4]);
+        let q = vec4<i32>(
+            extractBits(packed, 0u, 8u),
+            extractBits(packed, 8u, 8u),
+            extractBits(packed, 16u, 8u),
+            extractBits(packed, 24u, 8u)
+        );
+        return A_d[index / 32] * vec4<f32>(q);
+    }
+{% elif QUANT_DT == "sint4" %}
+    @group(0) @binding(0) var<storage, read> A_qs: array<u32>;
+    @group(0) @binding(1) var<storage, read> A_d: array<f32>;
+
+    //The group size is only known at runtime, so is read from the matmul metadata
+    fn dequantize(index: i32) -> vec4<f32> {
+        let packed = bitcast<i32>(A_qs[index / 8]);
+        let shift = u32(index % 8) * 4u;
+        let q = vec4<i32>(
+            extractBits(packed, shift, 4u),
+            extractBits(packed, shift + 4u, 4u),
+            extractBits(packed, shift + 8u, 4u),
+            extractBits(packed, shift + 12u, 4u)
+        );
+        return A_d[index / metadata.groupSize] * vec4<f32>(q);
+    }
+{% elif QUANT_DT == "q4k" %}
+    @group(0) @binding(0) var<storage, read> A_d: array<f32>;
+    @group(0) @binding(1) var<storage, read> A_dmin: array<f32>;
+    @group(0) @binding(2) var<storage, read> A_scales: array<u32>;
+    @group(0) @binding(3) var<storage, read> A_qs: array<u32>;
+
+    fn scaleByte(block: u32, index: u32) -> u32 {
+        let i = block * 12u + index;
+        return (A_scales[i / 4u] >> (8u * (i % 4u))) & 0xFFu;
+    }
+
+    //6 bit scale and min of sub-block `j`, packed into 12 bytes per super-block
+    fn scaleMin(block: u32, j: u32) -> vec2<f32> {
+        if (j < 4u) {
+            return vec2<f32>(f32(scaleByte(block, j) & 63u), f32(scaleByte(block, j + 4u) & 63u));
+        }
+        let sc = (scaleByte(block, j + 4u) & 0xFu) | ((scaleByte(block, j - 4u) >> 6u) << 4u);
+        let m = (scaleByte(block, j + 4u) >> 4u) | ((scaleByte(block, j) >> 6u) << 4u);
+        return vec2<f32>(f32(sc), f32(m));
+    }
+
+    fn dequantize(index: i32) -> vec4<f32> {
+        let block = u32(index) / 256u;
+        let j = u32(index) % 256u;
+        //Each 64 elements share 32 bytes, low nibbles first
+        let chunk = j / 64u;
+        let high = (j % 64u) / 32u;
+        let l = j % 32u;
+
+        let sm = scaleMin(block, 2u * chunk + high);
+        let packed = A_qs[(block * 128u + chunk * 32u + l) / 4u];
+        let q = unpack4x8unorm((packed >> (4u * high)) & 0x0F0F0F0Fu) * 255.0;
+        return A_d[block] * sm.x * q - vec4<f32>(A_dmin[block] * sm.y);
+    }
+{% elif QUANT_DT == "q6k" %}
+    @group(0) @binding(0) var<storage, read> A_ql: array<u32>;
+    @group(0) @binding(1) var<storage, read> A_qh: array<u32>;
+    @group(0) @binding(2) var<storage, read> A_scales: array<u32>;
+    @group(0) @binding(3) var<storage, read> A_d: array<f32>;
+
+    fn dequantize(index: i32) -> vec4<f32> {
+        let block = u32(index) / 256u;
+        let j = u32(index) % 256u;
+        //Each 128 elements share 64 bytes of ql and 32 bytes of qh, in quarters of 32
+        let part = j / 128u;
+        let quarter = (j % 128u) / 32u;
+        let l = j % 32u;
+
+        let ql = A_ql[(block * 128u + part * 64u + (quarter % 2u) * 32u + l) / 4u];
+        let qh = A_qh[(block * 64u + part * 32u + l) / 4u];
+        let low = (ql >> (4u * (quarter / 2u))) & 0x0F0F0F0Fu;
+        let high = (qh >> (2u * quarter)) & 0x03030303u;
+        let q = unpack4x8unorm(low | (high << 4u)) * 255.0 - 32.0;
+
+        let s = block * 16u + part * 8u + l / 16u + 2u * quarter;
+        let scale = extractBits(bitcast<i32>(A_scales[s / 4u]), 8u * (s % 4u), 8u);
+        return A_d[block] * f32(scale) * q;
+    }
+{% endif %}
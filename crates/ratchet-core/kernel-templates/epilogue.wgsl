@@ -0,0 +1,21 @@
+//Elementwise op fused onto the output of this kernel, selected at runtime by `metadata.epilogue`.
+//Must match `UnaryOp::epilogue_id`: 0 = none, 1 = gelu, 2 = relu, 3 = silu
+fn epilogue(val: {{ epilogue_ty }}) -> {{ epilogue_ty }} {
+    switch metadata.epilogue {
+        case 1u: {
+            let inner = val * ({{ epilogue_ty }}(0.035677408136300125) * (val * val) + {{ epilogue_ty }}(0.7978845608028654));
+            //Tanh is broken for large values on MSL
+            let t = select(tanh(inner), sign(inner), abs(inner) >= {{ epilogue_ty }}(10.0));
+            return val * ({{ epilogue_ty }}(0.5) + {{ epilogue_ty }}(0.5) * t);
+        }
+        case 2u: {
+            return max(val, {{ epilogue_ty }}(0.0));
+        }
+        case 3u: {
+            return val / ({{ epilogue_ty }}(1.0) + exp(-val));
+        }
+        default: {
+            return val;
+        }
+    }
+}
@@ -1,3 +1,7 @@
+{% if DT == "f16" -%}
+enable f16;
+
+{% endif -%}
 fn getAIndexFromCoords3D(coords : vec3<i32>) -> i32 {
     return dot(coords, metadata.aStrides);
 }
@@ -11,7 +15,7 @@ fn getOutputIndexFromCoords(coords : vec3<i32>) -> i32 {
 }
         
 fn setOutputAtIndex(flatIndex: i32, value: f32) {
-    result[flatIndex] = f32(value);
+    result[flatIndex] = {{ DT }}(epilogue(value));
 }
 
 fn setOutputAtCoords(d0: i32, d1: i32, d2: i32, value: f32) {
@@ -19,7 +23,7 @@ fn setOutputAtCoords(d0: i32, d1: i32, d2: i32, value: f32) {
     setOutputAtIndex(flatIndex, value);
 }
 
-{% if QUANT %}
+{% if QUANT and QUANT_DT == "q8_0" %}
     fn unpack4x8snorm_gguf(x: u32) -> vec4<f32> {
         return unpack4x8snorm(x) * 127f;
     }
@@ -32,6 +36,15 @@ fn setOutputAtCoords(d0: i32, d1: i32, d2: i32, value: f32) {
         let abs_index = getAIndexFromCoords3D(vec3<i32>(d0,d1,d2)) / 32;
         return scale[abs_index]; 
     }
+{% elif QUANT %}
+    fn getA(d0 : i32, d1 : i32, d2 : i32) -> vec4<f32> {
+        return dequantize(getAIndexFromCoords3D(vec3<i32>(d0,d1,d2)));
+    }
+
+    //Scales are applied while dequantizing
+    fn getAbsMax(d0 : i32, d1 : i32, d2 : i32) -> f32 {
+        return 1.0;
+    }
 {% else %}
     fn getA(d0: i32, d1: i32, d2: i32) -> f32 {
         return f32(A[getAIndexFromCoords3D(vec3<i32>(d0, d1, d2))]);
@@ -123,26 +136,30 @@ var<private> globalId: vec3<u32>;
 var<private> workgroupId: vec3<u32>;
 
 {% if QUANT %}
-    @group(0) @binding(0) var<storage, read> A: array<u32>;
-    @group(0) @binding(1) var<storage, read> scale: array<f32>;
-    @group(0) @binding(2) var<storage, read> B: array<f32>;
+    {% if QUANT_DT == "q8_0" %}
+        @group(0) @binding(0) var<storage, read> A: array<u32>;
+        @group(0) @binding(1) var<storage, read> scale: array<f32>;
+    {% else %}
+        {% include "dequantize" %}
+    {% endif %}
+    @group(0) @binding({{ A_BINDINGS }}) var<storage, read> B: array<f32>;
 
     {% if BIAS %}
-        @group(0) @binding(3) var<storage, read> bias: array<f32>;
-        @group(0) @binding(4) var<storage, read_write> result: array<f32>;
+        @group(0) @binding({{ A_BINDINGS + 1 }}) var<storage, read> bias: array<f32>;
+        @group(0) @binding({{ A_BINDINGS + 2 }}) var<storage, read_write> result: array<f32>;
     {% else %}
-        @group(0) @binding(3) var<storage, read_write> result: array<f32>;
+        @group(0) @binding({{ A_BINDINGS + 1 }}) var<storage, read_write> result: array<f32>;
     {% endif %}
 
 {% else %}
-    @group(0) @binding(0) var<storage, read> A: array<f32>;
-    @group(0) @binding(1) var<storage, read> B: array<f32>;
+    @group(0) @binding(0) var<storage, read> A: array<{{ DT }}>;
+    @group(0) @binding(1) var<storage, read> B: array<{{ DT }}>;
 
     {% if BIAS %}
-        @group(0) @binding(2) var<storage, read> bias: array<f32>;
-        @group(0) @binding(3) var<storage, read_write> result: array<f32>;
+        @group(0) @binding(2) var<storage, read> bias: array<{{ DT }}>;
+        @group(0) @binding(3) var<storage, read_write> result: array<{{ DT }}>;
     {% else %}
-        @group(0) @binding(2) var<storage, read_write> result: array<f32>;
+        @group(0) @binding(2) var<storage, read_write> result: array<{{ DT }}>;
     {% endif %}
 {% endif %}
 
@@ -159,7 +176,11 @@ struct Meta {
     dimAOuter: i32,
     dimBOuter: i32,
     dimInner: i32,
+    epilogue: u32,
+    groupSize: i32,
 }
+
+{% include "epilogue" %}
   
 var<workgroup> mm_Asub : array<array<f32, 32>, 32>;
 var<workgroup> mm_Bsub : array<array<f32, 32>, 32>;
@@ -250,9 +271,9 @@ fn main(@builtin(local_invocation_id) localId : vec3<u32>,
         {%- for col in range(end=4) -%}
             {%- if BIAS %}
                 {% if TRANS_OUT %}
-                    val = acc[{{ row }}][{{ col }}] + bias[globalRow + {{ row }}];
+                    val = acc[{{ row }}][{{ col }}] + f32(bias[globalRow + {{ row }}]);
                 {% else %}
-                    val = acc[{{ row }}][{{ col }}] + bias[globalCol + {{ col }}];
+                    val = acc[{{ row }}][{{ col }}] + f32(bias[globalCol + {{ col }}]);
                 {% endif %}
             {%- else %}
                 val = acc[{{ row }}][{{ col }}];
@@ -1,3 +1,7 @@
+{% if DT == "f16" -%}
+enable f16;
+
+{% endif -%}
 fn getAIndexFromCoords3D(coords : vec3<i32>) -> i32 {
     return dot(coords, metadata.aStrides);
 }
@@ -11,7 +15,7 @@ fn getOutputIndexFromCoords(coords : vec3<i32>) -> i32 {
 }
         
 fn setOutputAtIndex(flatIndex : i32, value : vec4<f32>) {
-    result[flatIndex] = vec4<f32>(value);
+    result[flatIndex] = vec4<{{ DT }}>(epilogue(value));
 }
 
 fn setOutputAtCoords(d0 : i32, d1 : i32, d2 : i32, value : vec4<f32>) {
@@ -19,7 +23,7 @@ fn setOutputAtCoords(d0 : i32, d1 : i32, d2 : i32, value : vec4<f32>) {
     setOutputAtIndex(flatIndex / 4, value);
 }
 
-{% if QUANT %}
+{% if QUANT and QUANT_DT == "q8_0" %}
     fn unpack4x8snorm_gguf(x: u32) -> vec4<f32> {
         return unpack4x8snorm(x) * 127f;
     }
@@ -32,6 +36,15 @@ fn setOutputAtCoords(d0 : i32, d1 : i32, d2 : i32, value : vec4<f32>) {
         let abs_index = getAIndexFromCoords3D(vec3<i32>(d0,d1,d2)) / 32;
         return scale[abs_index]; 
     }
+{% elif QUANT %}
+    fn getA(d0 : i32, d1 : i32, d2 : i32) -> vec4<f32> {
+        return dequantize(getAIndexFromCoords3D(vec3<i32>(d0,d1,d2)));
+    }
+
+    //Scales are applied while dequantizing
+    fn getAbsMax(d0 : i32, d1 : i32, d2 : i32) -> f32 {
+        return 1.0;
+    }
 {% else %}
     fn getA(d0 : i32, d1 : i32, d2 : i32) -> vec4<f32> {
         return vec4<f32>(A[getAIndexFromCoords3D(vec3<i32>(d0,d1,d2)) / 4]);
@@ -95,35 +108,39 @@ var<private> workgroupId: vec3<u32>;
 
 
 {% if QUANT %}
-    @group(0) @binding(0) var<storage, read> A: array<u32>;
-    @group(0) @binding(1) var<storage, read> scale: array<f32>;
+    {% if QUANT_DT == "q8_0" %}
+        @group(0) @binding(0) var<storage, read> A: array<u32>;
+        @group(0) @binding(1) var<storage, read> scale: array<f32>;
+    {% else %}
+        {% include "dequantize" %}
+    {% endif %}
 
     {% if TRANS_RHS %}
-        @group(0) @binding(2) var<storage, read> B: array<f32>;
+        @group(0) @binding({{ A_BINDINGS }}) var<storage, read> B: array<f32>;
     {% else %}
-        @group(0) @binding(2) var<storage, read> B: array<vec4<f32>>;
+        @group(0) @binding({{ A_BINDINGS }}) var<storage, read> B: array<vec4<f32>>;
     {% endif %}
 
     {% if BIAS %}
-        @group(0) @binding(3) var<storage, read> bias: array<vec4<f32>>;
-        @group(0) @binding(4) var<storage, read_write> result: array<vec4<f32>>;
+        @group(0) @binding({{ A_BINDINGS + 1 }}) var<storage, read> bias: array<vec4<f32>>;
+        @group(0) @binding({{ A_BINDINGS + 2 }}) var<storage, read_write> result: array<vec4<f32>>;
     {% else %}
-        @group(0) @binding(3) var<storage, read_write> result: array<vec4<f32>>;
+        @group(0) @binding({{ A_BINDINGS + 1 }}) var<storage, read_write> result: array<vec4<f32>>;
     {% endif %}
 {% else %}
-    @group(0) @binding(0) var<storage, read> A: array<vec4<f32>>;
+    @group(0) @binding(0) var<storage, read> A: array<vec4<{{ DT }}>>;
         
     {% if TRANS_RHS %} 
-        @group(0) @binding(1) var<storage, read> B: array<f32>;
+        @group(0) @binding(1) var<storage, read> B: array<{{ DT }}>;
     {% else %}
-        @group(0) @binding(1) var<storage, read> B: array<vec4<f32>>;
+        @group(0) @binding(1) var<storage, read> B: array<vec4<{{ DT }}>>;
     {% endif %}
 
     {% if BIAS %}
-        @group(0) @binding(2) var<storage, read> bias: array<vec4<f32>>;
-        @group(0) @binding(3) var<storage, read_write> result: array<vec4<f32>>;
+        @group(0) @binding(2) var<storage, read> bias: array<vec4<{{ DT }}>>;
+        @group(0) @binding(3) var<storage, read_write> result: array<vec4<{{ DT }}>>;
     {% else %}
-        @group(0) @binding(2) var<storage, read_write> result: array<vec4<f32>>;
+        @group(0) @binding(2) var<storage, read_write> result: array<vec4<{{ DT }}>>;
     {% endif %}
 {% endif %}
 
@@ -137,11 +154,15 @@ struct Meta {
     dimAOuter: i32,
     dimBOuter: i32,
     dimInner: i32,
+    epilogue: u32,
+    groupSize: i32,
 }
 
 @group(1) @binding(0)
 var<uniform> metadata: Meta;
 
+{% include "epilogue" %}
+
 var<workgroup> mm_Asub : array<array<vec4<f32>, {{ TILE_DIM / 4 }}>, {{ TILE_DIM }}>; 
 var<workgroup> mm_Bsub : array<array<vec4<f32>, {{ TILE_DIM / 4 }}>, {{ TILE_DIM }}>;
   
@@ -222,7 +243,7 @@ fn main(@builtin(local_invocation_id) localId : vec3<u32>,
     var val: vec4<f32>;
     {% for innerRow in range(end=ROW_PER_THREAD) %}
         {% if BIAS -%}
-            val = acc[{{ innerRow }}] + bias[globalCol / 4];
+            val = acc[{{ innerRow }}] + vec4<f32>(bias[globalCol / 4]);
         {% else %}
             val = acc[{{ innerRow }}];
         {% endif %}
@@ -1,27 +1,35 @@
+{% if DT == "f16" -%}
+enable f16;
+
+{% endif -%}
 var<private> localId: vec3<u32>;
 var<private> globalId: vec3<u32>;
 var<private> workgroupId: vec3<u32>;
 
 {% if QUANT %}
-    @group(0) @binding(0) var<storage, read> A: array<u32>;
-    @group(0) @binding(1) var<storage, read> scale: array<f32>;
+    {% if QUANT_DT == "q8_0" %}
+        @group(0) @binding(0) var<storage, read> A: array<u32>;
+        @group(0) @binding(1) var<storage, read> scale: array<f32>;
+    {% else %}
+        {% include "dequantize" %}
+    {% endif %}
     {% if BIAS %}
-        @group(0) @binding(2) var<storage, read> X: array<vec4<f32>>;
-        @group(0) @binding(3) var<storage, read> bias: array<f32>;
-        @group(0) @binding(4) var<storage, read_write> result: array<f32>;
+        @group(0) @binding({{ A_BINDINGS }}) var<storage, read> X: array<vec4<f32>>;
+        @group(0) @binding({{ A_BINDINGS + 1 }}) var<storage, read> bias: array<f32>;
+        @group(0) @binding({{ A_BINDINGS + 2 }}) var<storage, read_write> result: array<f32>;
     {% else %}
-        @group(0) @binding(2) var<storage, read> X: array<vec4<f32>>;
-        @group(0) @binding(3) var<storage, read_write> result: array<f32>;
+        @group(0) @binding({{ A_BINDINGS }}) var<storage, read> X: array<vec4<f32>>;
+        @group(0) @binding({{ A_BINDINGS + 1 }}) var<storage, read_write> result: array<f32>;
     {% endif %}
 {% else %}
-    @group(0) @binding(0) var<storage, read> A: array<f32>;
+    @group(0) @binding(0) var<storage, read> A: array<{{ DT }}>;
     {% if BIAS %}
-        @group(0) @binding(1) var<storage, read> X: array<f32>;
-        @group(0) @binding(2) var<storage, read> bias: array<f32>;
-        @group(0) @binding(3) var<storage, read_write> result: array<f32>;
+        @group(0) @binding(1) var<storage, read> X: array<{{ DT }}>;
+        @group(0) @binding(2) var<storage, read> bias: array<{{ DT }}>;
+        @group(0) @binding(3) var<storage, read_write> result: array<{{ DT }}>;
     {% else %}
-        @group(0) @binding(1) var<storage, read> X: array<f32>;
-        @group(0) @binding(2) var<storage, read_write> result: array<f32>;
+        @group(0) @binding(1) var<storage, read> X: array<{{ DT }}>;
+        @group(0) @binding(2) var<storage, read_write> result: array<{{ DT }}>;
     {% endif %}
 {% endif %}
 
@@ -37,13 +45,15 @@ struct Meta {
     dimAOuter: i32,
     dimBOuter: i32,
     dimInner: i32,
+    epilogue: u32,
+    groupSize: i32,
 }
 
 fn unpack4x8snorm_gguf(x: u32) -> vec4<f32> {
     return unpack4x8snorm(x) * 127f;
 }
 
-var<workgroup> work: array<{{ ELEM_TYPE }}, {{workgroup_size_x * workgroup_size_y / ELEM_SIZE}}>;
+var<workgroup> work: array<{{ ELEM_TYPE }}, {{workgroup_size_x * workgroup_size_y}}>;
 
 @compute @workgroup_size({{workgroup_size_x}},{{workgroup_size_y}},{{workgroup_size_z}})
 fn main(@builtin(local_invocation_id) localId : vec3<u32>,
@@ -69,18 +79,24 @@ fn main(@builtin(local_invocation_id) localId : vec3<u32>,
     let aIndex = aOffset + row * metadata.aStrides.y / {{ELEM_SIZE}};
 
     {% if QUANT %}
-        let sIndex = (aOffset / 4) + row * metadata.aStrides.y / 32;
-        for (var k = i32(globalId.y); k < metadata.dimInner / 4; k+={{workgroup_size_y / 4}}) {
-            sum = fma(unpack4x8snorm_gguf(A[aIndex + k]) * scale[sIndex + (k/8)], X[k], sum);
-        }
+        {% if QUANT_DT == "q8_0" %}
+            let sIndex = (aOffset / 4) + row * metadata.aStrides.y / 32;
+            for (var k = i32(globalId.y); k < metadata.dimInner / 4; k+={{workgroup_size_y}}) {
+                sum = fma(unpack4x8snorm_gguf(A[aIndex + k]) * scale[sIndex + (k/8)], X[k], sum);
+            }
+        {% else %}
+            for (var k = i32(globalId.y); k < metadata.dimInner / 4; k+={{workgroup_size_y}}) {
+                sum = fma(dequantize((aIndex + k) * 4), X[k], sum);
+            }
+        {% endif %}
     {% else %}
         for (var k = i32(globalId.y); k < metadata.dimInner; k+={{workgroup_size_y}}) {
-            sum = fma(A[aIndex + k], X[bOffset + k], sum);
+            sum = fma(f32(A[aIndex + k]), f32(X[bOffset + k]), sum);
         }
     {% endif %}
 
     let rows = {{workgroup_size_x}}u;
-    let cols = {{workgroup_size_y / ELEM_SIZE}}u;
+    let cols = {{workgroup_size_y}}u;
     let ii = u32(localId.x);
     let jj = u32(localId.y);
     work[ii + rows * jj] = sum;
@@ -103,9 +119,9 @@ fn main(@builtin(local_invocation_id) localId : vec3<u32>,
             {% endif %}
         {% else %}
             {% if BIAS %}
-                result[outOffset + row] = work[ii] + bias[row];
+                result[outOffset + row] = {{ DT }}(work[ii] + f32(bias[row]));
             {% else %}
-                result[outOffset + row] = work[ii];
+                result[outOffset + row] = {{ DT }}(work[ii]);
             {% endif %}
         {% endif %}
     }
@@ -0,0 +1,46 @@
+{% include "dequantize" %}
+
+@group(0) @binding({{ A_BINDINGS }})
+var<storage, read> I: array<i32>;
+
+@group(0) @binding({{ A_BINDINGS + 1 }})
+var<storage, read_write> Y: array<{{ ELEM_TYPE }}>;
+
+struct Meta {
+    dst_numel: u32,
+    right_numel: u32,
+    ids_numel: u32,
+    src_dim_numel: u32,
+    groupSize: i32,
+}
+
+@group(1) @binding(0)
+var<uniform> metadata: Meta;
+
+@compute @workgroup_size(8,8,1)
+fn main( 
+        @builtin(local_invocation_id) local_id: vec3<u32>,
+        @builtin(local_invocation_index) local_index: u32,
+        @builtin(workgroup_id) group_id: vec3<u32>,
+        @builtin(num_workgroups) num_groups: vec3<u32>
+) {
+    let tid = group_id.x * 64u + local_index;
+    let right_numel = metadata.right_numel / {{ ELEM_SIZE }}u;
+
+    if (tid >= metadata.dst_numel / {{ ELEM_SIZE }}u) {
+        return;
+    }
+
+    let id_i = (tid / right_numel) % metadata.ids_numel;
+    let input_i = min(u32(max(I[id_i], 0)), metadata.src_dim_numel - 1u);
+    let right_rank_i = tid % right_numel;
+    let left_rank_i = tid / (right_numel * metadata.ids_numel);
+
+    let src_i = left_rank_i * metadata.src_dim_numel * right_numel + input_i * right_numel + right_rank_i;
+    {% if ELEM_SIZE == 4 %}
+        Y[tid] = dequantize(i32(src_i * 4u));
+    {% else %}
+        //Dequantize the 4 elements containing the one we want
+        Y[tid] = dequantize(i32(src_i & ~3u))[src_i % 4u];
+    {% endif %}
+}
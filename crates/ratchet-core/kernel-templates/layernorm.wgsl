@@ -1,21 +1,31 @@
+{% if dt == "f16" -%}
+enable f16;
+
+{% endif -%}
 @group(0) @binding(0)
 var<storage, read> X: array<{{ elem }}>;
 
 @group(0) @binding(1)
-var<storage, read> S: array<{{ elem }}>;
-
+var<storage, read> S: array<{{ affine }}>;
+{% if op == "rmsnorm" %}
 @group(0) @binding(2)
-var<storage, read> B: array<{{ elem }}>;
+var<storage, read_write> Y: array<{{ elem }}>;
+{% else %}
+@group(0) @binding(2)
+var<storage, read> B: array<{{ affine }}>;
 
 @group(0) @binding(3)
 var<storage, read_write> Y: array<{{ elem }}>;
-
+{% endif %}
 struct Meta {
     M: u32,
     N: u32,
     ND2: u32,
     ND4: u32,
     eps: f32,
+{%- if op == "groupnorm" %}
+    L: u32,
+{%- endif %}
 }
 
 @group(1) @binding(0)
@@ -23,7 +33,7 @@ var<uniform> metadata: Meta;
 
 const BLOCK_SIZE: u32 = 128u;
 
-var<workgroup> smem: array<{{ elem }}, BLOCK_SIZE>; //max 16kb
+var<workgroup> smem: array<{{ acc }}, BLOCK_SIZE>; //max 16kb
 
 fn block_sum(index: u32, stride: u32) {
     if index < stride {
@@ -33,9 +43,9 @@ fn block_sum(index: u32, stride: u32) {
 }
 
 fn mu(local_id: vec3<u32>, anchor: u32) -> f32 {
-    var threadSum = {{ elem }}(0.0);
+    var threadSum = {{ acc }}(0.0);
     for (var i: u32 = local_id.x; i < {{ reduction_len }}; i += BLOCK_SIZE) {
-        threadSum += X[anchor + i];
+        threadSum += {{ acc }}(X[anchor + i]);
     }
     workgroupBarrier();
     smem[local_id.x] = threadSum;
@@ -49,18 +59,18 @@ fn mu(local_id: vec3<u32>, anchor: u32) -> f32 {
     block_sum(local_id.x, 2u);
     block_sum(local_id.x, 1u);
 
-    {% if elem == "f32" -%}
+    {% if acc == "f32" -%}
         return smem[0] / f32(metadata.N);
     {% else -%}
-        return dot(smem[0], {{ elem }}(1.0)) / f32(metadata.N); 
+        return dot(smem[0], {{ acc }}(1.0)) / f32(metadata.N); 
     {% endif %}
 }
 
 fn sigma(local_id: vec3<u32>, anchor: u32, mu: f32) -> f32 {
-    var threadSum = {{ elem }}(0.0);
+    var threadSum = {{ acc }}(0.0);
     //Compute σ
     for (var i: u32 = local_id.x; i < {{ reduction_len }}; i += BLOCK_SIZE) {
-        let val = X[anchor + i] - mu;
+        let val = {{ acc }}(X[anchor + i]) - mu;
         threadSum = fma(val, val, threadSum);
     }
 
@@ -76,10 +86,10 @@ fn sigma(local_id: vec3<u32>, anchor: u32, mu: f32) -> f32 {
     block_sum(local_id.x, 2u);
     block_sum(local_id.x, 1u);
 
-    {% if elem == "f32" -%}
+    {% if acc == "f32" -%}
         return smem[0] / f32(metadata.N);
     {% else -%}
-        return dot(smem[0], {{ elem }}(1.0)) / f32(metadata.N); 
+        return dot(smem[0], {{ acc }}(1.0)) / f32(metadata.N); 
     {% endif %}
 }
 
@@ -90,13 +100,27 @@ fn main(
         @builtin(global_invocation_id) global_id: vec3<u32>
 ) {
     let anchor = (group_id.y * metadata.M * {{ reduction_len }}) + group_id.x * {{ reduction_len }}; 
+{%- if op == "rmsnorm" %}
+    let sigma = sigma(local_id, anchor, 0.0);
+{%- else %}
     let mu = mu(local_id, anchor);
     let sigma = sigma(local_id, anchor, mu);
+{%- endif %}
 
-    let denom = inverseSqrt(sigma + {{ elem }}(metadata.eps));
+    let denom = inverseSqrt(sigma + {{ acc }}(metadata.eps));
 
     for(var i: u32 = local_id.x; i < {{ reduction_len }}; i += BLOCK_SIZE) {
-        let val = (X[anchor + i] - mu) * denom;
-        Y[anchor + i] = fma(val, S[i], B[i]); 
+{%- if op == "rmsnorm" %}
+        let val = {{ acc }}(X[anchor + i]) * denom;
+        Y[anchor + i] = {{ elem }}(val * {{ acc }}(S[i]));
+{%- elif op == "groupnorm" %}
+        //L is a multiple of the element size, so all lanes share a channel
+        let channel = group_id.x * (metadata.N / metadata.L) + (i * {{ elem_size }}u) / metadata.L;
+        let val = ({{ acc }}(X[anchor + i]) - mu) * denom;
+        Y[anchor + i] = {{ elem }}(fma(val, {{ acc }}(f32(S[channel])), {{ acc }}(f32(B[channel]))));
+{%- else %}
+        let val = ({{ acc }}(X[anchor + i]) - mu) * denom;
+        Y[anchor + i] = {{ elem }}(fma(val, {{ acc }}(S[i]), {{ acc }}(B[i]))); 
+{%- endif %}
     }
 }
@@ -0,0 +1,143 @@
+{% if dt == "f16" -%}
+enable f16;
+
+{% endif -%}
+@group(0) @binding(0)
+var<storage, read> X: array<{{ elem }}>;
+
+@group(0) @binding(1)
+var<storage, read_write> Y: array<{{ out_elem }}>;
+
+struct Meta {
+    src_stride: vec4<u32>,
+    dst_stride: vec4<u32>,
+    red_stride: vec4<u32>,
+    dst_numel: u32,
+    red_numel: u32,
+}
+
+@group(1) @binding(0)
+var<uniform> metadata: Meta;
+
+const BLOCK_SIZE: u32 = 128u;
+
+//Reductions accumulate in f32, whatever the input type
+var<workgroup> smem: array<f32, BLOCK_SIZE>;
+{% if is_arg -%}
+var<workgroup> sidx: array<u32, BLOCK_SIZE>;
+{%- endif %}
+
+//Converts 1D offset into 4D index
+fn offsetToNdIndex(offset: u32, stride: vec4<u32>) -> vec4<u32> {
+    var index: vec4<u32> = vec4<u32>(0u, 0u, 0u, 0u);
+    var remaining = offset;
+
+    var idx = 0u;
+    {% for i in range(end=3) -%}
+        idx = remaining / stride[{{ i }}];
+        index[{{ i }}] = idx;
+        remaining -= idx * stride[{{ i }}];
+    {%- endfor %}
+    index.w = remaining;
+    return index;
+}
+
+{% if is_arg -%}
+const NONE: u32 = 0xFFFFFFFFu;
+
+//Ties are broken by the lowest index, matching PyTorch.
+//The first element seen always replaces NONE, so rows of NaN or ±FLT_MAX still yield an index.
+fn better(val: f32, idx: u32, best: f32, best_idx: u32) -> bool {
+    if idx == NONE {
+        return false;
+    }
+    {% if op == "argmax" -%}
+        return best_idx == NONE || val > best || (val == best && idx < best_idx);
+    {%- else -%}
+        return best_idx == NONE || val < best || (val == best && idx < best_idx);
+    {%- endif %}
+}
+
+fn block_reduce(index: u32, stride: u32) {
+    if index < stride && better(smem[index + stride], sidx[index + stride], smem[index], sidx[index]) {
+        smem[index] = smem[index + stride];
+        sidx[index] = sidx[index + stride];
+    }
+    workgroupBarrier();
+}
+{%- else -%}
+fn combine(a: f32, b: f32) -> f32 {
+    {% if op == "max" -%}
+        return max(a, b);
+    {%- elif op == "min" -%}
+        return min(a, b);
+    {%- else -%}
+        return a + b;
+    {%- endif %}
+}
+
+fn block_reduce(index: u32, stride: u32) {
+    if index < stride {
+        smem[index] = combine(smem[index], smem[index + stride]);
+    }
+    workgroupBarrier();
+}
+{%- endif %}
+
+@compute @workgroup_size(128, 1, 1)
+fn main(
+        @builtin(local_invocation_id) local_id: vec3<u32>,
+        @builtin(workgroup_id) group_id: vec3<u32>,
+        @builtin(num_workgroups) num_groups: vec3<u32>
+) {
+    //Dispatch 1 workgroup per output element
+    let dst_offset = group_id.y * num_groups.x + group_id.x;
+    if (dst_offset >= metadata.dst_numel) {
+        return;
+    }
+
+    //Reduced dims are 1 in the destination, so this is the first element of the reduction
+    let dst_index = offsetToNdIndex(dst_offset, metadata.dst_stride);
+    let anchor = dot(dst_index, metadata.src_stride);
+
+    var acc = {{ init }};
+    {% if is_arg -%}
+    var acc_idx = NONE;
+    {%- endif %}
+    for (var i: u32 = local_id.x; i < metadata.red_numel; i += BLOCK_SIZE) {
+        let red_index = offsetToNdIndex(i, metadata.red_stride);
+        let val = f32(X[anchor + dot(red_index, metadata.src_stride)]);
+        {% if is_arg -%}
+        if better(val, i, acc, acc_idx) {
+            acc = val;
+            acc_idx = i;
+        }
+        {%- else -%}
+        acc = combine(acc, val);
+        {%- endif %}
+    }
+
+    smem[local_id.x] = acc;
+    {% if is_arg -%}
+    sidx[local_id.x] = acc_idx;
+    {%- endif %}
+    workgroupBarrier();
+
+    block_reduce(local_id.x, 64u);
+    block_reduce(local_id.x, 32u);
+    block_reduce(local_id.x, 16u);
+    block_reduce(local_id.x, 8u);
+    block_reduce(local_id.x, 4u);
+    block_reduce(local_id.x, 2u);
+    block_reduce(local_id.x, 1u);
+
+    if (local_id.x == 0u) {
+        {% if is_arg -%}
+            Y[dst_offset] = i32(sidx[0]);
+        {%- elif op == "mean" -%}
+            Y[dst_offset] = {{ out_elem }}(smem[0] / f32(metadata.red_numel));
+        {%- else -%}
+            Y[dst_offset] = {{ out_elem }}(smem[0]);
+        {%- endif %}
+    }
+}
@@ -1,3 +1,7 @@
+{% if dt == "f16" -%}
+enable f16;
+
+{% endif -%}
 @group(0) @binding(0)
 var<storage, read> X: array<{{ elem }}>;
 
@@ -13,11 +17,14 @@ struct Meta {
     dst_numel: u32,
     perm: vec4<u32>,
     src_offsets: vec4<u32>,
+    epilogue: u32,
 }
 
 @group(1) @binding(0)
 var<uniform> metadata: Meta;
 
+{% include "epilogue" %}
+
 //Converts 1D offset into 4D index
 fn offsetToNdIndex(offset: u32, stride: vec4<u32>) -> vec4<u32> {
     var index: vec4<u32> = vec4<u32>(0u, 0u, 0u, 0u);
@@ -63,5 +70,5 @@ fn main(
     let src_offset = ndIndexToOffset(src_index, metadata.src_offsets, metadata.src_stride);
 
     //Read from input buffer and write to output buffer
-    Y[dst_offset] = X[src_offset];
+    Y[dst_offset] = epilogue(X[src_offset]);
 }
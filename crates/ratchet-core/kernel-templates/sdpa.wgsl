@@ -0,0 +1,153 @@
+{% if dt == "f16" -%}
+enable f16;
+
+{% endif -%}
+@group(0) @binding(0)
+var<storage, read> Q: array<{{ elem }}>;
+
+@group(0) @binding(1)
+var<storage, read> K: array<{{ elem }}>;
+
+@group(0) @binding(2)
+var<storage, read> V: array<{{ elem }}>;
+{% if mask %}
+@group(0) @binding(3)
+var<storage, read> M: array<{{ elem }}>;
+
+@group(0) @binding(4)
+var<storage, read_write> O: array<{{ elem }}>;
+{% else %}
+@group(0) @binding(3)
+var<storage, read_write> O: array<{{ elem }}>;
+{% endif %}
+struct Meta {
+    Sq: u32,
+    Sk: u32,
+    D: u32,
+    Dv: u32,
+    scale: f32,
+    causal: u32,
+    kv_len: u32,
+}
+
+@group(1) @binding(0)
+var<uniform> metadata: Meta;
+
+const BLOCK_SIZE: u32 = 64u;
+const MAX_D: u32 = 256u;
+const DV_PER_THREAD: u32 = 4u;
+const minFloat: f32 = -3.402823e+38f;
+
+var<workgroup> q: array<f32, MAX_D>;
+var<workgroup> p: array<f32, BLOCK_SIZE>;
+var<workgroup> smem: array<f32, BLOCK_SIZE>;
+
+fn block_max(index: u32, stride: u32) {
+    if index < stride {
+        smem[index] = max(smem[index], smem[index + stride]);
+    }
+    workgroupBarrier();
+}
+
+fn block_sum(index: u32, stride: u32) {
+    if index < stride {
+        smem[index] += smem[index + stride];
+    }
+    workgroupBarrier();
+}
+
+//Each workgroup produces a single output row, streaming K & V in tiles of BLOCK_SIZE keys.
+//The softmax is computed online, so the [Sq, Sk] scores are never written to memory.
+@compute @workgroup_size(64, 1, 1)
+fn main(
+        @builtin(local_invocation_id) local_id: vec3<u32>,
+        @builtin(workgroup_id) group_id: vec3<u32>,
+) {
+    let row = group_id.x;
+    let head = group_id.y;
+    let index = local_id.x;
+
+    let q_offset = (head * metadata.Sq + row) * metadata.D;
+    let k_offset = head * metadata.Sk * metadata.D;
+    let v_offset = head * metadata.Sk * metadata.Dv;
+
+    for (var d: u32 = index; d < metadata.D; d += BLOCK_SIZE) {
+        q[d] = f32(Q[q_offset + d]) * metadata.scale;
+    }
+    workgroupBarrier();
+
+    //Causal attention is aligned to the bottom right, so queries are the last Sq positions
+    var kv_len = metadata.kv_len;
+    if metadata.causal == 1u {
+        kv_len = min(metadata.kv_len, row + 1u + metadata.kv_len - metadata.Sq);
+    }
+
+    var m = minFloat;
+    var l = 0.0;
+    var acc: array<f32, DV_PER_THREAD>;
+
+    for (var tile: u32 = 0u; tile < kv_len; tile += BLOCK_SIZE) {
+        let j = tile + index;
+        var s = minFloat;
+        if j < kv_len {
+            var dot = 0.0;
+            for (var d: u32 = 0u; d < metadata.D; d += 1u) {
+                dot = fma(q[d], f32(K[k_offset + j * metadata.D + d]), dot);
+            }
+            {%- if mask %}
+            s = dot + f32(M[row * metadata.Sk + j]);
+            {%- else %}
+            s = dot;
+            {%- endif %}
+        }
+        smem[index] = s;
+        workgroupBarrier();
+
+        block_max(index, 32u);
+        block_max(index, 16u);
+        block_max(index, 8u);
+        block_max(index, 4u);
+        block_max(index, 2u);
+        block_max(index, 1u);
+
+        let m_new = max(m, smem[0]);
+        workgroupBarrier();
+
+        let p_j = select(0.0, exp(s - m_new), j < kv_len);
+        p[index] = p_j;
+        smem[index] = p_j;
+        workgroupBarrier();
+
+        block_sum(index, 32u);
+        block_sum(index, 16u);
+        block_sum(index, 8u);
+        block_sum(index, 4u);
+        block_sum(index, 2u);
+        block_sum(index, 1u);
+
+        let correction = exp(m - m_new);
+        l = fma(l, correction, smem[0]);
+        m = m_new;
+
+        let tile_len = min(BLOCK_SIZE, kv_len - tile);
+        for (var i: u32 = 0u; i < DV_PER_THREAD; i += 1u) {
+            let d = index + i * BLOCK_SIZE;
+            if d < metadata.Dv {
+                var sum = acc[i] * correction;
+                for (var t: u32 = 0u; t < tile_len; t += 1u) {
+                    sum = fma(p[t], f32(V[v_offset + (tile + t) * metadata.Dv + d]), sum);
+                }
+                acc[i] = sum;
+            }
+        }
+        workgroupBarrier();
+    }
+
+    let o_offset = (head * metadata.Sq + row) * metadata.Dv;
+    for (var i: u32 = 0u; i < DV_PER_THREAD; i += 1u) {
+        let d = index + i * BLOCK_SIZE;
+        if d < metadata.Dv {
+            O[o_offset + d] = {{ elem }}(acc[i] / l);
+        }
+    }
+}
@@ -0,0 +1,106 @@
+{% if dt == "f16" -%}
+enable f16;
+
+{% endif -%}
+//https://developer.download.nvidia.com/assets/cuda/files/reduction.pdf
+@group(0) @binding(0)
+var<storage, read_write> X: array<{{ elem }}>;
+{% if prologue %}
+@group(0) @binding(1)
+var<storage, read> S: array<{{ dt }}>;
+
+@group(0) @binding(2)
+var<storage, read> Mask: array<{{ elem }}>;
+{% endif %}
+struct Meta {
+    M: u32,
+    N: u32,
+    ND2: u32,
+    ND4: u32,
+    mask_stride: u32,
+}
+
+@group(1) @binding(0)
+var<uniform> metadata: Meta;
+
+var<workgroup> smem: array<{{ acc }}, 128>; // max size is 16kb
+var<workgroup> maximum: f32;
+var<workgroup> sum: f32;
+
+const BLOCK_SIZE = 128u;
+const minFloat: f32 = -3.402823e+38f;
+
+fn block_sum(index: u32, stride: u32) {
+    if index < stride {
+        smem[index] += smem[index + stride];
+    }
+    workgroupBarrier();
+}
+
+fn block_max(index: u32, stride: u32) {
+    if index < stride {
+        smem[index] = max(smem[index], smem[index + stride]);
+    }
+    workgroupBarrier();
+}
+
+@compute @workgroup_size(128, 1, 1)
+fn main( 
+        @builtin(local_invocation_id) local_id: vec3<u32>,
+        @builtin(workgroup_id) group_id: vec3<u32>,
+) {
+    let batch_stride = group_id.y * metadata.M * {{ reduction_len }};
+    let row_start = batch_stride + group_id.x * {{ reduction_len }}; 
+    let index = local_id.x;
+{% if prologue %}
+    //X = X * S + Mask, written back so that the passes below read the scaled & masked input
+    let scale = f32(S[0]);
+    let mask_start = group_id.y * metadata.mask_stride + group_id.x * {{ reduction_len }};
+    for (var i: u32 = index; i < {{ reduction_len }}; i += BLOCK_SIZE) {
+        let val = {{ acc }}(X[row_start + i]) * scale + {{ acc }}(Mask[mask_start + i]);
+        X[row_start + i] = {{ elem }}(val);
+    }
+{% endif %}
+    smem[index] = {{ acc }}(minFloat);
+    for (var i: u32 = index; i < {{ reduction_len }}; i += BLOCK_SIZE) {
+        smem[index] = max(smem[index], {{ acc }}(X[row_start + i])); 
+    }
+    workgroupBarrier();
+
+    block_max(index, 64u);
+    block_max(index, 32u);
+    block_max(index, 16u);
+    block_max(index, 8u);
+    block_max(index, 4u);
+    block_max(index, 2u);
+    block_max(index, 1u);
+
+    if index == 0u{
+        maximum = {{ reduce_max }};
+    }
+    workgroupBarrier();
+
+    smem[index] = {{ acc }}(0.0);
+    for (var i: u32 = index; i < {{ reduction_len }}; i += BLOCK_SIZE) {
+        smem[index] += exp({{ acc }}(X[row_start + i]) - maximum);
+    }
+    
+    workgroupBarrier();
+    block_sum(index, 64u);
+    block_sum(index, 32u);
+    block_sum(index, 16u);
+    block_sum(index, 8u);
+    block_sum(index, 4u);
+    block_sum(index, 2u);
+    block_sum(index, 1u);
+
+    if index == 0u {
+        sum = {{ reduce_sum }}; 
+    }
+    workgroupBarrier();
+
+    for(var i: u32 = index; i < {{ reduction_len }}; i += BLOCK_SIZE) {
+        var val = {{ acc }}(X[row_start + i]);
+        X[row_start + i] = {{ elem }}(exp(val - maximum) / sum);
+    }
+}
@@ -0,0 +1,38 @@
+@group(0) @binding(0)
+var<storage, read> X: array<{{ elem }}>;
+
+@group(0) @binding(1)
+var<storage, read_write> Y: array<{{ elem }}>;
+
+struct Meta {
+    M: u32,
+    N: u32,
+    numel: u32,
+    k: i32,
+}
+
+@group(1) @binding(0)
+var<uniform> metadata: Meta;
+
+@compute @workgroup_size(8, 8, 1)
+fn main(
+    @builtin(workgroup_id) group_id: vec3<u32>,
+    @builtin(local_invocation_index) local_index: u32,
+    @builtin(num_workgroups) num_groups: vec3<u32>
+) {
+    let x_offset = group_id.x * 64u;
+    let index = (group_id.y * num_groups.x * 64u) + x_offset + local_index;
+    if (index >= metadata.numel) {
+        return;
+    }
+
+    //Operates on the trailing [M, N] matrices
+    let col = i32(index % metadata.N);
+    let row = i32((index / metadata.N) % metadata.M);
+    {% if upper -%}
+    let keep = col - row >= metadata.k;
+    {%- else -%}
+    let keep = col - row <= metadata.k;
+    {%- endif %}
+    Y[index] = select({{ elem }}(0.0), X[index], keep);
+}
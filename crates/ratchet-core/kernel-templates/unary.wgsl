@@ -1,3 +1,7 @@
+{% if dt == "f16" -%}
+enable f16;
+
+{% endif -%}
 {% if inplace %}
 
 @group(0) @binding(0)
@@ -13,30 +17,27 @@ var<storage, read_write> Y: array<{{ elem }}>;
 
 struct Meta {
     numel: u32,
+    alpha: f32,
 }
 
 @group(1) @binding(0)
 var<uniform> metadata: Meta;
 
-{% if elem == "vec4<f32>" -%}
-    const NORM_CONST: vec4<f32> = vec4<f32>(0.5f, 0.5f, 0.5f, 0.5f);
-    const SQRT_2_OVER_PI: vec4<f32> = vec4<f32>(0.7978845608028654f, 0.7978845608028654f, 0.7978845608028654f, 0.7978845608028654f);
-    const SCALED_SQRT_2_OVER_PI: vec4<f32> = vec4<f32>(0.035677408136300125f, 0.035677408136300125f, 0.035677408136300125f, 0.035677408136300125f);
-    const TANH_LIMIT: vec4<f32> = vec4<f32>(10.0f, 10.0f, 10.0f, 10.0f);
-    const RELU_CONST: vec4<f32> = vec4<f32>(0.0f, 0.0f, 0.0f, 0.0f);
-{%- elif elem == "vec2<f32>" -%}
-    const NORM_CONST: vec2<f32> = vec2<f32>(0.5f, 0.5f);
-    const SQRT_2_OVER_PI: vec2<f32> = vec2<f32>(0.7978845608028654f, 0.7978845608028654f);
-    const SCALED_SQRT_2_OVER_PI: vec2<f32> = vec2<f32>(0.035677408136300125f, 0.035677408136300125f);
-    const TANH_LIMIT: vec2<f32> = vec2<f32>(10.0f, 10.0f);
-    const RELU_CONST: vec2<f32> = vec2<f32>(0.0f, 0.0f);
-{%- else -%}
-    const NORM_CONST: f32 = 0.5f; 
-    const SQRT_2_OVER_PI: f32 = 0.7978845608028654f;
-    const SCALED_SQRT_2_OVER_PI: f32 = 0.035677408136300125f;
-    const TANH_LIMIT: f32 = 10.0f;
-    const RELU_CONST: f32 = 0.0f;
-{% endif %}
+const NORM_CONST: {{ elem }} = {{ elem }}(0.5);
+const SQRT_2_OVER_PI: {{ elem }} = {{ elem }}(0.7978845608028654);
+const SCALED_SQRT_2_OVER_PI: {{ elem }} = {{ elem }}(0.035677408136300125);
+const TANH_LIMIT: {{ elem }} = {{ elem }}(10.0);
+const RELU_CONST: {{ elem }} = {{ elem }}(0.0);
+const ONE: {{ elem }} = {{ elem }}(1.0);
+const FRAC_1_SQRT_2: {{ elem }} = {{ elem }}(0.7071067811865476);
+
+//Abramowitz & Stegun 7.1.26
+const ERF_P: {{ elem }} = {{ elem }}(0.3275911);
+const ERF_A1: {{ elem }} = {{ elem }}(0.254829592);
+const ERF_A2: {{ elem }} = {{ elem }}(-0.284496736);
+const ERF_A3: {{ elem }} = {{ elem }}(1.421413741);
+const ERF_A4: {{ elem }} = {{ elem }}(-1.453152027);
+const ERF_A5: {{ elem }} = {{ elem }}(1.061405429);
 
 
 //Tanh is broken for large values on MSL
@@ -53,6 +54,43 @@ fn relu(val: {{ elem }}) -> {{ elem }} {
     return max(val, RELU_CONST);
 }
 
+fn sigmoid(val: {{ elem }}) -> {{ elem }} {
+    return ONE / (ONE + exp(-val));
+}
+
+fn silu(val: {{ elem }}) -> {{ elem }} {
+    return val * sigmoid(val);
+}
+
+fn erf(val: {{ elem }}) -> {{ elem }} {
+    let t = ONE / (ONE + ERF_P * abs(val));
+    let poly = ((((ERF_A5 * t + ERF_A4) * t + ERF_A3) * t + ERF_A2) * t + ERF_A1) * t;
+    return sign(val) * (ONE - poly * exp(-val * val));
+}
+
+fn gelu_erf(val: {{ elem }}) -> {{ elem }} {
+    return val * NORM_CONST * (ONE + erf(val * FRAC_1_SQRT_2));
+}
+
+fn leaky_relu(val: {{ elem }}) -> {{ elem }} {
+    let slope = {{ elem }}({{ dt }}(metadata.alpha));
+    return select(val * slope, val, val >= RELU_CONST);
+}
+
+fn elu(val: {{ elem }}) -> {{ elem }} {
+    let alpha = {{ elem }}({{ dt }}(metadata.alpha));
+    return select(alpha * (exp(val) - ONE), val, val > RELU_CONST);
+}
+
+//log(1 + exp(x)), without overflowing for large x
+fn softplus(val: {{ elem }}) -> {{ elem }} {
+    return max(val, RELU_CONST) + log(ONE + exp(-abs(val)));
+}
+
+fn reciprocal(val: {{ elem }}) -> {{ elem }} {
+    return ONE / val;
+}
+
 @compute @workgroup_size(8,8,1)
 fn main( 
         @builtin(local_invocation_index) local_index: u32,
@@ -0,0 +1,59 @@
+@group(0) @binding(0)
+var<storage, read_write> C: array<f32>;
+
+@group(0) @binding(1)
+var<storage, read> S: array<f32>;
+
+struct Meta {
+    cache_stride: vec4<u32>,
+    src_stride: vec4<u32>,
+    dst_stride: vec4<u32>,
+    src_numel: u32,
+    cum0: u32, //offset of the source in the cache
+    cum1: u32, //offset + source entries
+    dim: u32,
+}
+
+@group(1) @binding(0)
+var<uniform> metadata: Meta;
+
+//Converts 1D offset into 4D index
+fn offsetToNdIndex(offset: u32, stride: vec4<u32>) -> vec4<u32> {
+    var index: vec4<u32> = vec4<u32>(0u, 0u, 0u, 0u);
+    var remaining = offset;
+
+    for (var i: i32 = 0; i < 3; i++) {
+        let idx = remaining / stride[i];
+        index[i] = idx;
+        remaining -= idx * stride[i];
+    }
+    index.w = remaining;
+    return index;
+}
+
+//Converts 4D index into 1D offset
+fn ndIndexToOffset(index: vec4<u32>, stride: vec4<u32>) -> u32 {
+    var offset: u32 = 0u;
+    for (var i: i32 = 0; i < 4; i++) {
+        offset += index[i] * stride[i];
+    }
+    return offset;
+}
+
+@compute @workgroup_size(8,8,1)
+fn main( 
+        @builtin(local_invocation_id) local_id: vec3<u32>,
+        @builtin(local_invocation_index) local_index: u32,
+        @builtin(workgroup_id) group_id: vec3<u32>,
+        @builtin(num_workgroups) num_groups: vec3<u32>
+) {
+    //Dispatch 1 thread per source element, the rest of the cache is left untouched
+    let x_offset = group_id.x * 64u;
+    let src_offset = (group_id.y * num_groups.x * 64u) + x_offset + local_index;
+    if (src_offset >= metadata.src_numel) {
+        return;
+    }
+    var index = offsetToNdIndex(src_offset, metadata.src_stride);
+    index[metadata.dim] += metadata.cum0;
+    C[ndIndexToOffset(index, metadata.cache_stride)] = S[src_offset];
+}
@@ -61,7 +61,7 @@ fn main(
     var dst_index = offsetToNdIndex(dst_offset, metadata.dst_stride);
 
     let dim = metadata.dim;
-    if (dst_index[dim] < metadata.cum0) {
+    if (dst_index[dim] < metadata.cum0 || dst_index[dim] >= metadata.cum1) {
         //Inside cache, just copy from cache to DST
         let src_offset = ndIndexToOffset(dst_index, metadata.cache_stride);
         D[dst_offset] = C[src_offset];
@@ -2,7 +2,7 @@ use crate::gpu::{
     BindGroupDescriptor, BindGroupLayoutHandle, ComputePipelineHandle, GpuBindGroup, WgpuDevice,
     WorkgroupCount,
 };
-use crate::{drvec, rvec, OperationError, RVec, Tensor};
+use crate::{drvec, rvec, OperationError, RVec, Tensor, TensorId};
 use derive_new::new;
 use wgpu::DynamicOffset;
 
@@ -16,6 +16,7 @@ pub struct CompiledOp {
     storage_groups: RVec<GpuBindGroup>,
     offset: DynamicOffset, //offset into the metadata uniform buffer
     kernel_key: String,
+    id: TensorId, //the tensor this op produces
 }
 
 impl CompiledOp {
@@ -80,4 +81,8 @@ impl CompiledOp {
     pub fn kernel_key(&self) -> &str {
         &self.kernel_key
     }
+
+    pub fn id(&self) -> TensorId {
+        self.id
+    }
 }
@@ -0,0 +1,157 @@
+//! # CPU
+//!
+//! Reference implementations of all operations, used to resolve tensors living on [crate::Device::CPU].
+//! Correctness over speed: every op reads its inputs as contiguous host memory and writes a fresh
+//! [crate::CPUBuffer].
+use half::{bf16, f16};
+
+use crate::{
+    gguf::GGUFDType, rvec, CPUBuffer, DType, InvariantError, OperationError, Quantizer, RVec,
+    Shape, Tensor,
+};
+
+/// Returns a copy of the raw bytes backing a resolved CPU tensor.
+pub(crate) fn cpu_bytes(t: &Tensor) -> Result<Vec<u8>, OperationError> {
+    let storage_guard = t.storage();
+    let storage = storage_guard
+        .as_ref()
+        .ok_or_else(|| anyhow::anyhow!("Tensor {:?} is missing storage", t.id()))?;
+    Ok(storage.try_cpu()?.inner().as_bytes().to_vec())
+}
+
+/// Reads a resolved CPU tensor as f32, upcasting or dequantizing if required.
+pub(crate) fn cpu_f32(t: &Tensor) -> Result<Vec<f32>, OperationError> {
+    bytes_to_f32(&cpu_bytes(t)?, t.dt(), t.shape().numel())
+}
+
+/// Interprets the first `numel` elements of `bytes` as `dt`, and converts them to f32.
+pub(crate) fn bytes_to_f32(
+    bytes: &[u8],
+    dt: DType,
+    numel: usize,
+) -> Result<Vec<f32>, OperationError> {
+    match dt {
+        DType::F32 => Ok(bytemuck::cast_slice::<u8, f32>(&bytes[..numel * 4]).to_vec()),
+        DType::F16 => Ok(bytemuck::cast_slice::<u8, f16>(&bytes[..numel * 2])
+            .iter()
+            .map(|x| x.to_f32())
+            .collect()),
+        DType::BF16 => Ok(bytemuck::cast_slice::<u8, bf16>(&bytes[..numel * 2])
+            .iter()
+            .map(|x| x.to_f32())
+            .collect()),
+        DType::GGUF(GGUFDType::Q8_0(_)) => Ok(Quantizer::sint8_dequantize_raw(bytes, numel)),
+        DType::GGUF(GGUFDType::Q4K(_)) => Ok(Quantizer::q4k_dequantize_raw(bytes, numel)),
+        DType::GGUF(GGUFDType::Q6K(_)) => Ok(Quantizer::q6k_dequantize_raw(bytes, numel)),
+        DType::GGUF(g) => Ok(Quantizer::legacy_dequantize_raw(g, bytes, numel)),
+        DType::SInt4(s) => Ok(Quantizer::sint4_dequantize_raw(s, bytes, numel)),
+        dt => Err(InvariantError::UnsupportedDType(dt).into()),
+    }
+}
+
+/// Writes f32 results into a buffer of `dt`, the inverse of [cpu_f32] for float types.
+pub(crate) fn cpu_from_f32(
+    data: &[f32],
+    dt: DType,
+    shape: &Shape,
+) -> Result<CPUBuffer, OperationError> {
+    match dt {
+        DType::F32 => Ok(CPUBuffer::from_slice(data, shape)),
+        DType::F16 => {
+            let data = data.iter().map(|&x| f16::from_f32(x)).collect::<Vec<_>>();
+            Ok(CPUBuffer::from_slice(&data, shape))
+        }
+        DType::BF16 => {
+            let data = data.iter().map(|&x| bf16::from_f32(x)).collect::<Vec<_>>();
+            Ok(CPUBuffer::from_slice(&data, shape))
+        }
+        dt => Err(InvariantError::UnsupportedDType(dt).into()),
+    }
+}
+
+/// Reads a resolved CPU tensor of indices.
+pub(crate) fn cpu_i32(t: &Tensor) -> Result<Vec<i32>, OperationError> {
+    match t.dt() {
+        DType::I32 => Ok(t.to_vec::<i32>()?),
+        dt => Err(InvariantError::UnsupportedDType(dt).into()),
+    }
+}
+
+/// Reads a resolved CPU tensor of booleans, as produced by comparisons.
+pub(crate) fn cpu_u32(t: &Tensor) -> Result<Vec<u32>, OperationError> {
+    match t.dt() {
+        DType::U32 => Ok(t.to_vec::<u32>()?),
+        dt => Err(InvariantError::UnsupportedDType(dt).into()),
+    }
+}
+
+/// Row major strides of a contiguous tensor, in elements.
+pub(crate) fn contiguous_strides(shape: &Shape) -> RVec<usize> {
+    let mut strides = rvec![0; shape.rank()];
+    let mut stride = 1;
+    for (i, &dim) in shape.iter().enumerate().rev() {
+        strides[i] = stride;
+        stride *= dim;
+    }
+    strides
+}
+
+/// Converts a linear offset into an N-D index.
+pub(crate) fn unravel(mut offset: usize, strides: &[usize]) -> RVec<usize> {
+    let mut index = rvec![0; strides.len()];
+    for (i, &stride) in strides.iter().enumerate() {
+        index[i] = offset / stride;
+        offset -= index[i] * stride;
+    }
+    index
+}
+
+/// Converts an N-D index into a linear offset.
+pub(crate) fn ravel(index: &[usize], strides: &[usize]) -> usize {
+    index.iter().zip(strides).map(|(i, s)| i * s).sum()
+}
+
+/// Maps an offset into `dst` back to the element of `src` it was broadcast from.
+///
+/// For rules, see https://numpy.org/doc/stable/user/basics.broadcasting.html
+pub(crate) fn broadcast_offset(src: &Shape, dst: &Shape, dst_offset: usize) -> usize {
+    if src == dst {
+        return dst_offset;
+    } else if src.is_scalar() {
+        return 0;
+    }
+    let src = Shape::promote(src.clone(), dst.rank());
+    let (src_strides, dst_strides) = (contiguous_strides(&src), contiguous_strides(dst));
+    let mut index = unravel(dst_offset, &dst_strides);
+    for (i, dim) in src.iter().enumerate() {
+        if *dim == 1 {
+            index[i] = 0;
+        }
+    }
+    ravel(&index, &src_strides)
+}
+
+/// Builds a new buffer of `numel` elements, where element `i` is copied from `src[index(i)]`.
+///
+/// Works on raw bytes, so is agnostic to the (unquantized) element type.
+pub(crate) fn gather_bytes(
+    src: &[u8],
+    elem_size: usize,
+    numel: usize,
+    index: impl Fn(usize) -> usize,
+) -> Vec<u8> {
+    let mut dst = vec![0u8; numel * elem_size];
+    for (i, chunk) in dst.chunks_exact_mut(elem_size).enumerate() {
+        let s = index(i) * elem_size;
+        chunk.copy_from_slice(&src[s..s + elem_size]);
+    }
+    dst
+}
+
+/// Only unquantized types can be moved around elementwise.
+pub(crate) fn elementwise_size(dt: DType) -> Result<usize, OperationError> {
+    if dt.is_quantized() {
+        return Err(InvariantError::UnsupportedDType(dt).into());
+    }
+    Ok(dt.size_of())
+}
@@ -17,6 +17,34 @@ pub enum DeviceError {
     InvalidBufferUsage(wgpu::BufferUsages, wgpu::BufferUsages),
     #[error("Failed to transfer buffer with error: {0:?}")]
     BufferTransferFailed(#[from] wgpu::BufferAsyncError),
+    #[error("Device is missing feature {0:?}, required by {1}")]
+    MissingFeature(wgpu::Features, String),
+    #[error("Device ran out of memory: {0}")]
+    OutOfMemory(String),
+    #[error("Validation error: {0}")]
+    ValidationError(String),
+    #[error("Device was lost")]
+    DeviceLost,
+}
+
+/// Known gap: wgpu 0.18 has no device lost callback, and reports calls on a lost device as
+/// validation errors. We recognise these by wgpu's "Parent device is lost" message, so a change
+/// to that wording upstream will surface device loss as [DeviceError::ValidationError] instead.
+/// Move to `Device::set_device_lost_callback` once we're on a wgpu that has it.
+impl From<wgpu::Error> for DeviceError {
+    fn from(error: wgpu::Error) -> Self {
+        match error {
+            wgpu::Error::OutOfMemory { source } => DeviceError::OutOfMemory(source.to_string()),
+            wgpu::Error::Validation { description, .. }
+                if description.contains("Parent device is lost") =>
+            {
+                DeviceError::DeviceLost
+            }
+            wgpu::Error::Validation { description, .. } => {
+                DeviceError::ValidationError(description)
+            }
+        }
+    }
 }
 
 pub enum DeviceRequest {
@@ -81,3 +109,29 @@ impl Device {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::DeviceError;
+
+    fn validation(description: &str) -> wgpu::Error {
+        wgpu::Error::Validation {
+            source: Box::new(std::io::Error::other(description.to_string())),
+            description: description.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_wgpu_error_conversion() {
+        let lost = DeviceError::from(validation("Parent device is lost"));
+        assert!(matches!(lost, DeviceError::DeviceLost));
+
+        let invalid = DeviceError::from(validation("Buffer is bound twice"));
+        assert!(matches!(invalid, DeviceError::ValidationError(d) if d.contains("bound twice")));
+
+        let oom = DeviceError::from(wgpu::Error::OutOfMemory {
+            source: Box::new(std::io::Error::other("Not enough memory left")),
+        });
+        assert!(matches!(oom, DeviceError::OutOfMemory(_)));
+    }
+}
@@ -18,29 +18,78 @@ pub const QK8_1: usize = 32;
 /// For actual blocks extracted from GGUF, see `ratchet-loader`.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum GGUFDType {
+    Q4_0(Q4_0),
+    Q4_1(Q4_1),
+    Q5_0(Q5_0),
+    Q5_1(Q5_1),
+    Q8_1(Q8_1),
     Q4K(Q4K),
     Q6K(Q6K),
     Q8_0(Q8_0),
 }
 
 impl GGUFDType {
+    /// Size of a single block in bytes, as laid out in Ratchet.
     pub fn size_of(self) -> usize {
         match self {
+            GGUFDType::Q4_0(_) => 20, //16 + 4
+            GGUFDType::Q4_1(_) => 24, //16 + 4 + 4
+            GGUFDType::Q5_0(_) => 24, //16 + 4 + 4
+            GGUFDType::Q5_1(_) => 28, //16 + 4 + 4 + 4
+            GGUFDType::Q8_1(_) => 36, //32 + 4
+            GGUFDType::Q4K(_) => 148, //4 + 4 + 12 + 128
+            GGUFDType::Q6K(_) => 212, //128 + 64 + 16 + 4
             GGUFDType::Q8_0(_) => 36, //32 + 4
-            _ => unimplemented!(),
+        }
+    }
+
+    /// Number of elements in a single block.
+    pub fn block_numel(self) -> usize {
+        match self {
+            GGUFDType::Q4_0(_) => QK4_0,
+            GGUFDType::Q4_1(_) => QK4_1,
+            GGUFDType::Q5_0(_) => QK5_0,
+            GGUFDType::Q5_1(_) => QK5_1,
+            GGUFDType::Q8_1(_) => QK8_1,
+            GGUFDType::Q4K(_) | GGUFDType::Q6K(_) => QK_K,
+            GGUFDType::Q8_0(_) => QK8_0,
         }
     }
 
     pub(crate) fn to_u32(self) -> u32 {
         match self {
+            GGUFDType::Q4_0(_) => 2,
+            GGUFDType::Q4_1(_) => 3,
+            GGUFDType::Q5_0(_) => 6,
+            GGUFDType::Q5_1(_) => 7,
             GGUFDType::Q8_0(_) => 8,
+            GGUFDType::Q8_1(_) => 9,
             GGUFDType::Q4K(_) => 12,
             GGUFDType::Q6K(_) => 14,
         }
     }
 
+    /// Prepended to the stem of the kernels reading this format, e.g `q4kgemv`.
+    pub(crate) fn kernel_prefix(self) -> &'static str {
+        match self {
+            GGUFDType::Q4_0(_) => "q4_0",
+            GGUFDType::Q4_1(_) => "q4_1",
+            GGUFDType::Q5_0(_) => "q5_0",
+            GGUFDType::Q5_1(_) => "q5_1",
+            GGUFDType::Q8_1(_) => "q8_1",
+            GGUFDType::Q4K(_) => "q4k",
+            GGUFDType::Q6K(_) => "q6k",
+            GGUFDType::Q8_0(_) => "q",
+        }
+    }
+
     pub fn segments(&self, numel: usize) -> RVec<BufferSegment> {
         match self {
+            GGUFDType::Q4_0(_) => Q4_0::segments(numel),
+            GGUFDType::Q4_1(_) => Q4_1::segments(numel),
+            GGUFDType::Q5_0(_) => Q5_0::segments(numel),
+            GGUFDType::Q5_1(_) => Q5_1::segments(numel),
+            GGUFDType::Q8_1(_) => Q8_1::segments(numel),
             GGUFDType::Q4K(_) => Q4K::segments(numel),
             GGUFDType::Q6K(_) => Q6K::segments(numel),
             GGUFDType::Q8_0(_) => Q8_0::segments(numel),
@@ -53,19 +102,20 @@ pub struct Q4K;
 
 impl Segments for Q4K {
     fn segments(numel: usize) -> RVec<BufferSegment> {
+        let nblocks = numel / QK_K;
         let mut offset = 0;
-        let ds_len: u64 = (numel * 4).align() as u64;
+        let ds_len: u64 = (nblocks * 4).align() as u64;
         let ds_segment = BufferSegment::new(offset, ds_len);
 
-        let dmins_len: u64 = (numel * 4).align() as u64;
+        let dmins_len: u64 = (nblocks * 4).align() as u64;
         offset += ds_len;
         let dmins_segment = BufferSegment::new(offset, dmins_len);
 
-        let scales_len: u64 = (numel * K_SCALE_SIZE).align() as u64;
+        let scales_len: u64 = (nblocks * K_SCALE_SIZE).align() as u64;
         offset += dmins_len;
         let scales_segment = BufferSegment::new(offset, scales_len);
 
-        let qs_len: u64 = (numel * QK_K / 2).align() as u64;
+        let qs_len: u64 = (numel / 2).align() as u64;
         offset += scales_len;
         let qs_segment = BufferSegment::new(offset, qs_len);
 
@@ -78,23 +128,24 @@ pub struct Q6K;
 
 impl Segments for Q6K {
     fn segments(numel: usize) -> RVec<BufferSegment> {
+        let nblocks = numel / QK_K;
         let mut offset = 0;
-        let ql_len: u64 = (numel * QK_K / 2).align() as u64;
+        let ql_len: u64 = (numel / 2).align() as u64;
         let ql_segment = BufferSegment::new(offset, ql_len);
 
-        let qh_len: u64 = (numel * QK_K / 4).align() as u64;
+        let qh_len: u64 = (numel / 4).align() as u64;
         offset += ql_len;
         let qh_segment = BufferSegment::new(offset, qh_len);
 
-        let scales_len: u64 = (numel * QK_K / 16).align() as u64;
+        let scales_len: u64 = (numel / 16).align() as u64;
         offset += qh_len;
         let scales_segment = BufferSegment::new(offset, scales_len);
 
-        let q_len: u64 = (numel * 4).align() as u64;
+        let d_len: u64 = (nblocks * 4).align() as u64;
         offset += scales_len;
-        let q_segment = BufferSegment::new(offset, q_len);
+        let d_segment = BufferSegment::new(offset, d_len);
 
-        rvec![ql_segment, qh_segment, scales_segment, q_segment,]
+        rvec![ql_segment, qh_segment, scales_segment, d_segment,]
     }
 }
 
@@ -114,3 +165,70 @@ impl Segments for Q8_0 {
         rvec![qs_segment, d_segment,]
     }
 }
+
+/// Lays out consecutive segments, each aligned to the storage buffer alignment.
+fn aligned_segments(lens: &[usize]) -> RVec<BufferSegment> {
+    let mut offset = 0;
+    lens.iter().fold(rvec![], |mut segments, len| {
+        let len = len.align() as u64;
+        segments.push(BufferSegment::new(offset, len));
+        offset += len;
+        segments
+    })
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default, new)]
+pub struct Q4_0;
+
+impl Segments for Q4_0 {
+    //| qs | d |
+    fn segments(numel: usize) -> RVec<BufferSegment> {
+        let nblocks = numel / QK4_0;
+        aligned_segments(&[numel / 2, nblocks * 4])
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default, new)]
+pub struct Q4_1;
+
+impl Segments for Q4_1 {
+    //| qs | d | m |
+    fn segments(numel: usize) -> RVec<BufferSegment> {
+        let nblocks = numel / QK4_1;
+        aligned_segments(&[numel / 2, nblocks * 4, nblocks * 4])
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default, new)]
+pub struct Q5_0;
+
+impl Segments for Q5_0 {
+    //| qs | qh | d |
+    fn segments(numel: usize) -> RVec<BufferSegment> {
+        let nblocks = numel / QK5_0;
+        aligned_segments(&[numel / 2, nblocks * 4, nblocks * 4])
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default, new)]
+pub struct Q5_1;
+
+impl Segments for Q5_1 {
+    //| qs | qh | d | m |
+    fn segments(numel: usize) -> RVec<BufferSegment> {
+        let nblocks = numel / QK5_1;
+        aligned_segments(&[numel / 2, nblocks * 4, nblocks * 4, nblocks * 4])
+    }
+}
+
+/// The block sum of GGUF Q8_1 is only used by quantized dot products, and is not stored.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default, new)]
+pub struct Q8_1;
+
+impl Segments for Q8_1 {
+    //| qs | d |
+    fn segments(numel: usize) -> RVec<BufferSegment> {
+        let nblocks = numel / QK8_1;
+        aligned_segments(&[numel, nblocks * 4])
+    }
+}
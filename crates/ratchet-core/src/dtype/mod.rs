@@ -6,8 +6,10 @@ use crate::{gpu::MIN_STORAGE_BUFFER_SIZE, rvec, RVec};
 
 pub mod gguf;
 mod segments;
+mod sint4;
 
 pub use segments::*;
+pub use sint4::*;
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Default, Hash)]
 pub enum DType {
@@ -19,15 +21,19 @@ pub enum DType {
     I32,
     U32,
     GGUF(gguf::GGUFDType),
+    SInt4(SInt4),
 }
 
 impl DType {
     pub fn segments(&self, numel: usize) -> RVec<BufferSegment> {
         match self {
             DType::GGUF(g) => g.segments(numel),
+            DType::SInt4(s) => s.segments(numel),
             _ => {
                 let mut total_bytes = numel * self.size_of();
                 total_bytes = max(total_bytes, MIN_STORAGE_BUFFER_SIZE);
+                //Storage bindings must be a multiple of 4 bytes
+                total_bytes = total_bytes.next_multiple_of(4);
                 rvec![BufferSegment::new(0, total_bytes as u64)]
             }
         }
@@ -43,6 +49,7 @@ impl DType {
     }
 
     /// Returns the size of the type in bytes.
+    /// For quantized types, this is the size of a single block.
     pub fn size_of(self) -> usize {
         match self {
             DType::Q8 => 1,
@@ -52,12 +59,52 @@ impl DType {
             DType::I32 => 4,
             DType::U32 => 4,
             DType::GGUF(g) => g.size_of(),
+            DType::SInt4(s) => s.size_of(),
+        }
+    }
+
+    /// Number of bytes required to store `numel` elements.
+    /// Quantized types are stored as individually aligned segments, and include their padding.
+    pub fn num_bytes(self, numel: usize) -> usize {
+        match self {
+            DType::GGUF(_) | DType::SInt4(_) => self
+                .segments(numel)
+                .iter()
+                .map(|s| s.size.get() as usize)
+                .sum(),
+            _ => numel * self.size_of(),
+        }
+    }
+
+    /// Alignment of the host allocation backing a tensor of this type.
+    /// Quantized tensors are read as words, as their blocks aren't a power of two in size.
+    pub(crate) fn alignment(self) -> usize {
+        match self {
+            DType::GGUF(_) | DType::SInt4(_) => 4,
+            _ => self.size_of(),
+        }
+    }
+
+    /// Number of consecutive elements of a row sharing the same scale.
+    pub(crate) fn block_numel(self) -> usize {
+        match self {
+            DType::GGUF(g) => g.block_numel(),
+            DType::SInt4(s) => s.group_size(),
+            _ => 1,
+        }
+    }
+
+    /// Suffix of the generated kernels operating on activations of this type.
+    pub(crate) fn kernel_suffix(self) -> &'static str {
+        match self {
+            DType::F16 => "_f16",
+            _ => "",
         }
     }
 
     pub fn is_quantized(self) -> bool {
         match self {
-            DType::GGUF(_) => true,
+            DType::GGUF(_) | DType::SInt4(_) => true,
             _ => false,
         }
     }
@@ -0,0 +1,35 @@
+use crate::{rvec, Align, BufferSegment, RVec};
+use derive_new::new;
+
+/// # SInt4
+///
+/// Ratchet's own 4 bit format, produced by [Quantization::SInt4](crate::Quantization::SInt4).
+///
+/// Each group of `group_size` consecutive elements is quantized symmetrically to [-7, 7], and
+/// shares a single f32 scale. 8 nibbles are packed into every u32, the first element in the
+/// lowest bits.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, new)]
+pub struct SInt4 {
+    group_size: usize,
+}
+
+impl SInt4 {
+    pub fn group_size(self) -> usize {
+        self.group_size
+    }
+
+    /// Size of a single group in bytes.
+    pub fn size_of(self) -> usize {
+        self.group_size / 2 + 4
+    }
+
+    //| qs | d |
+    pub fn segments(self, numel: usize) -> RVec<BufferSegment> {
+        let qs_len = (numel / 2).align() as u64;
+        let d_len = (numel / self.group_size * 4).align() as u64;
+        rvec![
+            BufferSegment::new(0, qs_len),
+            BufferSegment::new(qs_len, d_len)
+        ]
+    }
+}
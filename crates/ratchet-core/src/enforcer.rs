@@ -34,4 +34,12 @@ pub enum InvariantError {
     DuplicateDims,
     #[error("Broadcasting failed: {0:?}")]
     BroadcastingFailed(Vec<Shape>),
+    #[error(
+        "Cache overflow, writing {len} entries at {offset} exceeds the capacity of {capacity}."
+    )]
+    CacheOverflow {
+        offset: usize,
+        len: usize,
+        capacity: usize,
+    },
 }
@@ -1,7 +1,16 @@
-use crate::gpu::{GpuUniform, PoolError, StaticResourcePoolAccessor, WgpuDevice};
-use crate::CompiledOp;
+use std::collections::VecDeque;
+use std::ops::ControlFlow;
+use std::sync::Arc;
+
+use crate::gpu::{
+    BufferAllocator, BufferDescriptor, BufferUsagesExt, ComputePipelineHandle, CpuUniform,
+    GpuBufferHandle, GpuUniform, PoolError, PooledGPUBuffer, StaticResourcePoolAccessor,
+    StaticResourcePoolReadLockAccessor, WgpuDevice,
+};
+use crate::{CompiledOp, DeviceError, GPUBuffer, TensorId};
 use derive_new::new;
-use wgpu::SubmissionIndex;
+use parking_lot::Mutex;
+use wgpu::{BufferUsages, SubmissionIndex};
 
 /// # Executable
 ///
@@ -13,18 +22,123 @@ pub struct Executable {
     gpu_uniform: GpuUniform,
 }
 
+/// Progress of an [Executable] submitted in chunks, see [Executable::dispatch_chunked].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChunkProgress {
+    /// Steps that have finished executing.
+    pub steps_done: usize,
+    pub num_steps: usize,
+}
+
 //this error ExecutionError
 #[derive(Debug, thiserror::Error)]
 pub enum ExecutionError {
     #[error(transparent)]
     PipelineNotFound(#[from] PoolError),
+    #[error(transparent)]
+    DeviceError(#[from] DeviceError),
+    #[error("Failed to execute {kernel_key} for {id:?}: {source}")]
+    DispatchError {
+        kernel_key: String,
+        id: TensorId,
+        source: DeviceError,
+    },
+    #[error("Execution cancelled after {0} steps")]
+    Cancelled(usize),
 }
 
 impl Executable {
-    #[cfg(not(feature = "gpu-profiling"))]
+    pub fn steps(&self) -> &[CompiledOp] {
+        &self.steps
+    }
+
+    /// Encodes every step into a single command buffer, and submits it.
+    ///
+    /// Validation & out of memory errors raised along the way are returned, rather than
+    /// panicking. Set `RATCHET_CHECKED` before the first dispatch to submit every step on its own,
+    /// so that an error can be attributed to the op that raised it.
     pub fn dispatch_operations(
         &self,
         device: &WgpuDevice,
+    ) -> Result<SubmissionIndex, ExecutionError> {
+        if crate::gpu::checked() {
+            return self.dispatch_checked(device);
+        }
+        device.push_error_scope();
+        let index = self.dispatch_steps(device, &self.steps);
+        let scoped = device.pop_error_scope();
+        let index = index?;
+        scoped?;
+        Ok(index)
+    }
+
+    /// Submits the steps `chunk_size` at a time, each chunk once the previous one has finished
+    /// executing, so that no single submission runs long enough to trip a watchdog.
+    ///
+    /// `on_chunk` is called after every chunk. Returning [ControlFlow::Break] from it cancels the
+    /// chunks that remain, failing with [ExecutionError::Cancelled].
+    pub async fn dispatch_chunked<F>(
+        &self,
+        device: &WgpuDevice,
+        chunk_size: usize,
+        mut on_chunk: F,
+    ) -> Result<(), ExecutionError>
+    where
+        F: FnMut(ChunkProgress) -> ControlFlow<()>,
+    {
+        assert!(chunk_size > 0, "Chunks must hold at least one step");
+        let num_steps = self.steps.len();
+        let mut steps_done = 0;
+        for chunk in self.steps.chunks(chunk_size) {
+            device.push_error_scope();
+            let index = self.dispatch_steps(device, chunk);
+            let scoped = device.pop_error_scope();
+            let index = index?;
+            scoped?;
+            device.on_submitted_work_done(index).await;
+
+            steps_done += chunk.len();
+            let progress = ChunkProgress {
+                steps_done,
+                num_steps,
+            };
+            if on_chunk(progress).is_break() && steps_done < num_steps {
+                return Err(ExecutionError::Cancelled(steps_done));
+            }
+        }
+        Ok(())
+    }
+
+    fn encode_step<'a>(
+        &'a self,
+        cpass: &mut wgpu::ComputePass<'a>,
+        step: &'a CompiledOp,
+        pipelines: &'a StaticResourcePoolReadLockAccessor<
+            '_,
+            ComputePipelineHandle,
+            wgpu::ComputePipeline,
+        >,
+    ) -> Result<(), ExecutionError> {
+        cpass.set_pipeline(pipelines.get(step.pipeline_handle())?);
+
+        for (group_index, bind_group) in step.storage_groups().iter().enumerate() {
+            cpass.set_bind_group(group_index as u32, bind_group, &[]);
+        }
+
+        let uniform_group_index = step.storage_groups().len() as u32;
+        let uniform_group = self.gpu_uniform.bind_group();
+        cpass.set_bind_group(uniform_group_index, uniform_group, &[step.offset()]);
+
+        let [x_count, y_count, z_count] = step.workgroup_count().as_slice();
+        cpass.dispatch_workgroups(x_count, y_count, z_count);
+        Ok(())
+    }
+
+    #[cfg(not(feature = "gpu-profiling"))]
+    fn dispatch_steps(
+        &self,
+        device: &WgpuDevice,
+        steps: &[CompiledOp],
     ) -> Result<SubmissionIndex, ExecutionError> {
         let pipeline_resources = device.pipeline_resources();
         let mut encoder =
@@ -35,28 +149,18 @@ impl Executable {
                 label: Some("ratchet inference pass"),
                 timestamp_writes: None,
             });
-            for step in self.steps.iter() {
-                cpass.set_pipeline(pipeline_resources.get(step.pipeline_handle())?);
-
-                for (group_index, bind_group) in step.storage_groups().iter().enumerate() {
-                    cpass.set_bind_group(group_index as u32, bind_group, &[]);
-                }
-
-                let uniform_group_index = step.storage_groups().len() as u32;
-                let uniform_group = self.gpu_uniform.bind_group();
-                cpass.set_bind_group(uniform_group_index, uniform_group, &[step.offset()]);
-
-                let [x_count, y_count, z_count] = step.workgroup_count().as_slice();
-                cpass.dispatch_workgroups(x_count, y_count, z_count);
+            for step in steps.iter() {
+                self.encode_step(&mut cpass, step, &pipeline_resources)?;
             }
         }
         Ok(device.queue().submit(Some(encoder.finish())))
     }
 
     #[cfg(feature = "gpu-profiling")]
-    pub fn dispatch_operations(
+    fn dispatch_steps(
         &self,
         device: &WgpuDevice,
+        steps: &[CompiledOp],
     ) -> Result<SubmissionIndex, ExecutionError> {
         use crate::gpu::Profiler;
 
@@ -64,9 +168,9 @@ impl Executable {
         let mut encoder =
             device.create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
 
-        let mut profiler = Profiler::new(device.clone(), self.steps.len() as _);
+        let mut profiler = Profiler::new(device.clone(), steps.len() as _);
         {
-            for step in self.steps.iter() {
+            for step in steps.iter() {
                 let label = format!(
                     "{}_{}",
                     step.kernel_key(),
@@ -77,18 +181,7 @@ impl Executable {
                     label: None,
                     timestamp_writes,
                 });
-                cpass.set_pipeline(pipeline_resources.get(step.pipeline_handle())?);
-
-                for (group_index, bind_group) in step.storage_groups().iter().enumerate() {
-                    cpass.set_bind_group(group_index as u32, bind_group, &[]);
-                }
-
-                let uniform_group_index = step.storage_groups().len() as u32;
-                let uniform_group = self.gpu_uniform.bind_group();
-                cpass.set_bind_group(uniform_group_index, uniform_group, &[step.offset()]);
-
-                let [x_count, y_count, z_count] = step.workgroup_count().as_slice();
-                cpass.dispatch_workgroups(x_count, y_count, z_count);
+                self.encode_step(&mut cpass, step, &pipeline_resources)?;
             }
         }
 
@@ -97,4 +190,394 @@ impl Executable {
         profiler.read_timestamps(true);
         Ok(index)
     }
+
+    /// Submits every step in a command buffer & error scope of its own.
+    fn dispatch_checked(&self, device: &WgpuDevice) -> Result<SubmissionIndex, ExecutionError> {
+        let pipeline_resources = device.pipeline_resources();
+        let mut index = None;
+        for step in self.steps.iter() {
+            device.push_error_scope();
+            let mut encoder =
+                device.create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+            let encoded = {
+                let mut cpass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                    label: Some(step.kernel_key()),
+                    timestamp_writes: None,
+                });
+                self.encode_step(&mut cpass, step, &pipeline_resources)
+            };
+            let submitted = encoded.map(|_| device.queue().submit(Some(encoder.finish())));
+            let scoped = device
+                .pop_error_scope()
+                .map_err(|source| ExecutionError::DispatchError {
+                    kernel_key: step.kernel_key().to_string(),
+                    id: step.id(),
+                    source,
+                });
+            index = Some(submitted?);
+            scoped?;
+        }
+        Ok(index.unwrap_or_else(|| device.queue().submit(None)))
+    }
+}
+
+/// # Captured Executable
+///
+/// An [Executable] that keeps every buffer it was compiled against, so that the same graph can be
+/// replayed without recompiling, similar to CUDA Graphs.
+///
+/// Inputs are copied into static buffers owned by the executable before each dispatch, and the
+/// output is copied out afterwards, so neither the caller's inputs nor previous results are
+/// overwritten by a replay.
+pub struct CapturedExecutable {
+    executable: Executable,
+    inputs: Vec<PooledGPUBuffer>,
+    output: GPUBuffer,
+    /// Held from writing the uniform until the output is copied out, as replays share both the
+    /// uniform and the static buffers.
+    replaying: Mutex<()>,
+    /// Every buffer the executable binds, reported as captured until it is dropped.
+    pinned: Vec<GpuBufferHandle>,
+    allocator: Arc<BufferAllocator>,
+}
+
+impl Drop for CapturedExecutable {
+    fn drop(&mut self) {
+        self.allocator.unpin_captured(&self.pinned);
+    }
+}
+
+impl CapturedExecutable {
+    pub fn new(
+        executable: Executable,
+        inputs: Vec<PooledGPUBuffer>,
+        output: GPUBuffer,
+        device: &WgpuDevice,
+    ) -> Self {
+        let bound = executable
+            .steps
+            .iter()
+            .flat_map(|step| step.storage_groups().iter())
+            .flat_map(|group| group.buffers().iter());
+        let mut pinned = inputs
+            .iter()
+            .chain(std::iter::once(&output.inner))
+            .chain(bound)
+            .map(|buffer| buffer.handle)
+            .collect::<Vec<_>>();
+        pinned.sort_unstable();
+        pinned.dedup();
+
+        let allocator = device.buffer_allocator().clone();
+        allocator.pin_captured(&pinned);
+        Self {
+            executable,
+            inputs,
+            output,
+            replaying: Mutex::new(()),
+            pinned,
+            allocator,
+        }
+    }
+
+    /// Runs the graph on `inputs`, given in the order they were captured with.
+    /// Returns a new buffer that holds the output once the returned submission has completed.
+    ///
+    /// `uniform` holds the metadata of the graph being replayed, which may differ from the one
+    /// that was captured in anything that doesn't change its shapes, such as slice offsets.
+    ///
+    /// Replays of the same executable from several threads are submitted one after the other.
+    pub fn replay(
+        &self,
+        inputs: &[&GPUBuffer],
+        uniform: CpuUniform,
+        device: &WgpuDevice,
+    ) -> Result<(PooledGPUBuffer, SubmissionIndex), ExecutionError> {
+        assert_eq!(inputs.len(), self.inputs.len());
+        //Submissions execute in order, so the next replay can't overwrite what this one reads
+        let _replaying = self.replaying.lock();
+        self.executable.gpu_uniform.write(uniform, device);
+        let mut encoder =
+            device.create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+        for (src, dst) in inputs.iter().zip(self.inputs.iter()) {
+            let size = src.size.min(dst.size());
+            encoder.copy_buffer_to_buffer(&src.inner, src.offset, dst, 0, size);
+        }
+        device.queue().submit(Some(encoder.finish()));
+
+        self.executable.dispatch_operations(device)?;
+
+        let result = device.get_or_create_buffer(
+            &BufferDescriptor::new(self.output.size, BufferUsages::standard(), false),
+            false,
+        )?;
+        let (output, offset, size) = (&self.output.inner, self.output.offset, self.output.size);
+        let mut encoder =
+            device.create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+        encoder.copy_buffer_to_buffer(output, offset, &result, 0, size);
+        let index = device.queue().submit(Some(encoder.finish()));
+        Ok((result, index))
+    }
+}
+
+/// # Executable Cache
+///
+/// Captured executables, keyed by a structural hash of the graph they were compiled from.
+///
+/// Every entry pins all of the buffers used by its graph, reported as
+/// [crate::gpu::MemoryStats::captured_bytes], so once `capacity` entries are held the least
+/// recently used one is evicted.
+pub struct ExecutableCache {
+    capacity: usize,
+    entries: Mutex<VecDeque<(u64, Arc<CapturedExecutable>)>>,
+}
+
+impl std::fmt::Debug for ExecutableCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ExecutableCache")
+            .field("capacity", &self.capacity)
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+impl ExecutableCache {
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "ExecutableCache must hold at least one entry");
+        Self {
+            capacity,
+            entries: Mutex::new(VecDeque::with_capacity(capacity)),
+        }
+    }
+
+    pub(crate) fn get(&self, key: u64) -> Option<Arc<CapturedExecutable>> {
+        let mut entries = self.entries.lock();
+        let index = entries.iter().position(|(k, _)| *k == key)?;
+        let entry = entries.remove(index)?;
+        let captured = entry.1.clone();
+        entries.push_back(entry);
+        Some(captured)
+    }
+
+    /// Inserts `captured` as the most recently used entry, replacing any held under `key`.
+    pub(crate) fn insert(&self, key: u64, captured: Arc<CapturedExecutable>) {
+        let mut entries = self.entries.lock();
+        if let Some(index) = entries.iter().position(|(k, _)| *k == key) {
+            entries.remove(index);
+        } else if entries.len() == self.capacity {
+            entries.pop_front();
+        }
+        entries.push_back((key, captured));
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.lock().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Drops every captured executable, releasing their buffers back to the pool.
+    pub fn clear(&self) {
+        self.entries.lock().clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{shape, Device, Tensor};
+
+    fn linear(x: Tensor, w: Tensor) -> anyhow::Result<Tensor> {
+        x.matmul(w, false, false)?.gelu()
+    }
+
+    #[test]
+    fn test_graph_key() -> anyhow::Result<()> {
+        let w = Tensor::randn::<f32>(shape![16, 32], Device::CPU);
+        let key = |x: &Tensor, w: &Tensor| -> anyhow::Result<u64> {
+            let out = linear(x.clone(), w.clone())?;
+            Ok(Tensor::graph_key(&out.execution_order(), &[x])?.0)
+        };
+
+        let (x1, x2) = (
+            Tensor::randn::<f32>(shape![8, 16], Device::CPU),
+            Tensor::randn::<f32>(shape![8, 16], Device::CPU),
+        );
+        assert_eq!(key(&x1, &w)?, key(&x2, &w)?);
+
+        let x3 = Tensor::randn::<f32>(shape![4, 16], Device::CPU);
+        assert_ne!(key(&x1, &w)?, key(&x3, &w)?);
+
+        let w2 = Tensor::randn::<f32>(shape![16, 32], Device::CPU);
+        assert_ne!(key(&x1, &w)?, key(&x1, &w2)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_graph_key_ignores_offsets() -> anyhow::Result<()> {
+        let cache = Tensor::zeros::<f32>(&shape![1, 2, 16, 8], &Device::CPU);
+        let step = |offset: usize| -> anyhow::Result<(u64, Vec<u8>)> {
+            let x = Tensor::randn::<f32>(shape![1, 2, 1, 8], Device::CPU);
+            let kv = cache.clone().cache_dynamic(x.clone(), 2, offset)?;
+            let out = x
+                .clone()
+                .sdpa_dynamic(kv.clone(), kv, None, 1., true, offset + 1)?;
+            let (key, uniform) = Tensor::graph_key(&out.execution_order(), &[&x])?;
+            Ok((key, uniform.into_inner()))
+        };
+
+        //The same compiled step serves every offset, only its metadata differs
+        let ((key1, uniform1), (key2, uniform2)) = (step(3)?, step(4)?);
+        assert_eq!(key1, key2);
+        assert_ne!(uniform1, uniform2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_graph_key_ignores_constants() -> anyhow::Result<()> {
+        let (k, v) = (
+            Tensor::randn::<f32>(shape![1, 2, 6, 8], Device::CPU),
+            Tensor::randn::<f32>(shape![1, 2, 6, 8], Device::CPU),
+        );
+        let step = |scale: f32| -> anyhow::Result<(u64, usize)> {
+            let q = Tensor::randn::<f32>(shape![1, 2, 6, 8], Device::CPU);
+            let out = q
+                .clone()
+                .sdpa_decomposed(k.clone(), v.clone(), None, scale, true)?;
+            let order = out.execution_order();
+            let inputs = Tensor::replay_inputs(&order, &[&q]);
+            Ok((Tensor::graph_key(&order, &inputs)?.0, inputs.len()))
+        };
+
+        //The scale & causal mask are rebuilt every step, so are copied in rather than baked
+        let ((key1, inputs1), (key2, inputs2)) = (step(0.5)?, step(0.25)?);
+        assert_eq!(key1, key2);
+        assert_eq!((inputs1, inputs2), (3, 3));
+        Ok(())
+    }
+
+    #[cfg(feature = "pyo3")]
+    #[test]
+    fn test_replay() -> anyhow::Result<()> {
+        use crate::{DeviceRequest, ExecutableCache};
+
+        let device = Device::request_device(DeviceRequest::GPU)?;
+        let cache = ExecutableCache::new(4);
+        let w = Tensor::randn::<f32>(shape![64, 48], Device::CPU);
+        let w_gpu = w.to(&device)?;
+
+        let mut results = vec![];
+        for _ in 0..3 {
+            let x = Tensor::randn::<f32>(shape![33, 64], Device::CPU);
+            let ground = linear(x.clone(), w.clone())?.resolve()?;
+            let x_gpu = x.to(&device)?;
+            let ours = linear(x_gpu.clone(), w_gpu.clone())?.resolve_cached(&cache, &[&x_gpu])?;
+            results.push((ground, ours));
+        }
+        assert_eq!(cache.len(), 1);
+        //Earlier results must survive later replays
+        for (ground, ours) in results {
+            ground.all_close(&ours.to(&Device::CPU)?, 1e-4, 1e-4)?;
+        }
+
+        let gpu = device.try_gpu()?;
+        assert!(gpu.memory_stats().captured_bytes > 0);
+        cache.clear();
+        assert_eq!(gpu.memory_stats().captured_bytes, 0);
+        Ok(())
+    }
+
+    #[cfg(feature = "pyo3")]
+    #[test]
+    fn test_replay_concurrent() -> anyhow::Result<()> {
+        use crate::{DeviceRequest, ExecutableCache};
+
+        let device = Device::request_device(DeviceRequest::GPU)?;
+        let cache = ExecutableCache::new(4);
+        let w = Tensor::randn::<f32>(shape![64, 48], Device::CPU);
+        let w_gpu = w.to(&device)?;
+        //Replays differ only in the slice offset, which lives in the shared uniform
+        let run = |start: usize| -> anyhow::Result<()> {
+            let graph = |x: Tensor, w: Tensor| linear(x.slice(&[start..start + 16, 0..64])?, w);
+            let x = Tensor::randn::<f32>(shape![33, 64], Device::CPU);
+            let ground = graph(x.clone(), w.clone())?.resolve()?;
+            let x_gpu = x.to(&device)?;
+            let ours = graph(x_gpu.clone(), w_gpu.clone())?.resolve_cached(&cache, &[&x_gpu])?;
+            ground.all_close(&ours.to(&Device::CPU)?, 1e-4, 1e-4)
+        };
+
+        run(0)?;
+        std::thread::scope(|s| {
+            let workers = (0..4)
+                .map(|t| s.spawn(move || (0..8).try_for_each(|i| run((t * 8 + i) % 17))))
+                .collect::<Vec<_>>();
+            workers.into_iter().try_for_each(|w| w.join().unwrap())
+        })?;
+        assert_eq!(cache.len(), 1);
+        Ok(())
+    }
+
+    #[cfg(feature = "pyo3")]
+    #[test]
+    fn test_replay_dynamic_cache() -> anyhow::Result<()> {
+        use crate::{DeviceRequest, ExecutableCache};
+
+        let device = Device::request_device(DeviceRequest::GPU)?;
+        let executables = ExecutableCache::new(4);
+        let cpu_cache = Tensor::randn::<f32>(shape![1, 2, 16, 8], Device::CPU);
+        let gpu_cache = cpu_cache.to(&device)?;
+        let step = |cache: &Tensor, x: Tensor, offset: usize| -> anyhow::Result<Tensor> {
+            let kv = cache.clone().cache_dynamic(x.clone(), 2, offset)?;
+            x.sdpa_dynamic(kv.clone(), kv, None, 1., true, offset + 1)
+        };
+
+        //A single captured step, replayed at every offset
+        for offset in [3, 4, 5, 9, 15] {
+            let x = Tensor::randn::<f32>(shape![1, 2, 1, 8], Device::CPU);
+            let ground = step(&cpu_cache, x.clone(), offset)?.resolve()?;
+            let x_gpu = x.to(&device)?;
+            let ours =
+                step(&gpu_cache, x_gpu.clone(), offset)?.resolve_cached(&executables, &[&x_gpu])?;
+            ground.all_close(&ours.to(&Device::CPU)?, 1e-4, 1e-4)?;
+        }
+        assert_eq!(executables.len(), 1);
+        //Every step wrote its row into the cache itself
+        cpu_cache.all_close(&gpu_cache.to(&Device::CPU)?, 1e-5, 1e-5)?;
+        Ok(())
+    }
+
+    #[cfg(feature = "pyo3")]
+    #[test]
+    fn test_resolve_chunked() -> anyhow::Result<()> {
+        use crate::{DeviceRequest, ExecutionError, TensorError};
+        use std::ops::ControlFlow;
+
+        let device = Device::request_device(DeviceRequest::GPU)?;
+        let x = Tensor::randn::<f32>(shape![33, 64], Device::CPU);
+        let w = Tensor::randn::<f32>(shape![64, 64], Device::CPU);
+        let graph = |x: Tensor, w: Tensor| -> anyhow::Result<Tensor> {
+            let h = x.matmul(w.clone(), false, false)?.tanh()?;
+            Ok(h.matmul(w, false, false)?.relu()?)
+        };
+        let ground = graph(x.clone(), w.clone())?.resolve()?;
+        let (x, w) = (x.to(&device)?, w.to(&device)?);
+
+        let mut progress = vec![];
+        let ours = pollster::block_on(graph(x.clone(), w.clone())?.resolve_chunked(1, |p| {
+            progress.push(p.steps_done);
+            ControlFlow::Continue(())
+        }))?;
+        ground.all_close(&ours.to(&Device::CPU)?, 1e-4, 1e-4)?;
+        assert!(progress.len() > 1);
+        assert!(progress.windows(2).all(|w| w[0] + 1 == w[1]));
+
+        let cancelled =
+            pollster::block_on(graph(x, w)?.resolve_chunked(1, |_| ControlFlow::Break(())));
+        assert!(matches!(
+            cancelled,
+            Err(TensorError::ExecutionError(ExecutionError::Cancelled(1)))
+        ));
+        Ok(())
+    }
 }
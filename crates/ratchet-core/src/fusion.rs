@@ -0,0 +1,382 @@
+//! # Fusion
+//!
+//! Before compilation, chains of operations that a single kernel can execute are collapsed:
+//! - A broadcasted bias added to the output of a GEMM becomes the GEMM's bias.
+//! - An elementwise activation of a GEMM or reindex output becomes that kernel's epilogue.
+//! - An elementwise activation consumed only by a reindex becomes the reindex's epilogue.
+//! - A scale & mask applied to the input of a softmax, i.e `mul → add → softmax` as in attention,
+//!   becomes the softmax's prologue.
+//!
+//! The graph itself is left untouched. The plan records the op to compile in place of the head of
+//! each chain, and which tensors no longer need a dispatch of their own. Skipped tensors are
+//! never written, so they are only bound to a buffer when they alias the one of their head.
+//! A fused op may read tensors its head never did, e.g. a reindex reading the input of the
+//! activation it absorbed, so the allocator plans lifetimes over [FusionPlan::srcs].
+//!
+//! Set `RATCHET_NO_FUSION` to compile every operation individually.
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+    Binary, BinaryOp, LazyOp, RVec, Reindex, Softmax, SoftmaxPrologue, Tensor, TensorId, Unary,
+};
+
+#[derive(Debug, Default)]
+pub(crate) struct FusionPlan {
+    fused: FxHashMap<TensorId, LazyOp>,
+    skipped: FxHashSet<TensorId>,
+}
+
+impl FusionPlan {
+    pub fn new(execution_order: &[&Tensor]) -> Self {
+        let mut plan = Self::default();
+        if std::env::var("RATCHET_NO_FUSION").is_ok() {
+            return plan;
+        }
+
+        //Maps the last tensor of a chain to the tensor whose op absorbed it
+        let mut heads = FxHashMap::default();
+        for &t in execution_order.iter().filter(|t| !t.resolved()) {
+            match t.op() {
+                LazyOp::Binary(b) => plan.fuse_bias(t, b, &mut heads),
+                LazyOp::Unary(u) => plan.fuse_epilogue(t, u, &mut heads),
+                LazyOp::Reindex(r) => plan.fuse_prologue(t, r),
+                LazyOp::Softmax(s) => plan.fuse_softmax_prologue(t, s),
+                _ => {}
+            }
+        }
+        plan
+    }
+
+    /// Whether the tensor is computed by the op of another tensor.
+    pub fn skips(&self, id: TensorId) -> bool {
+        self.skipped.contains(&id)
+    }
+
+    /// The op to compile in place of the tensor's own, if anything was fused into it.
+    pub fn fused_op(&self, id: TensorId) -> Option<&LazyOp> {
+        self.fused.get(&id)
+    }
+
+    /// Every tensor read while computing `t`: the sources of the op fused in its place if there
+    /// is one, otherwise those of its own op. Skipped tensors read nothing.
+    pub fn srcs<'a>(&'a self, t: &'a Tensor) -> RVec<&'a Tensor> {
+        if self.skips(t.id()) {
+            return RVec::new();
+        }
+        match self.fused_op(t.id()) {
+            Some(op) => op.srcs(),
+            None => t.op().srcs(),
+        }
+    }
+
+    /// Only tensors with a single consumer can be folded into it.
+    fn exclusive(t: &Tensor) -> bool {
+        !t.resolved() && t.strong_count() == 1
+    }
+
+    fn current_op(&self, t: &Tensor) -> LazyOp {
+        self.fused_op(t.id()).unwrap_or(t.op()).clone()
+    }
+
+    fn fuse_bias<'a>(
+        &mut self,
+        t: &'a Tensor,
+        binary: &'a Binary,
+        heads: &mut FxHashMap<TensorId, &'a Tensor>,
+    ) {
+        let (lhs, rhs) = (binary.lhs(), binary.rhs());
+        let LazyOp::Reindex(Reindex::Broadcast(broadcast)) = rhs.op() else {
+            return;
+        };
+        let bias = &broadcast.src;
+        let bias_len = t.shape()[t.rank() - 1];
+        if !matches!(binary.op(), BinaryOp::Add)
+            || !Self::exclusive(lhs)
+            || !Self::exclusive(rhs)
+            || !bias.resolved()
+            || bias.dt() != t.dt()
+            || bias.shape().rank() != 1
+            || bias.shape()[0] != bias_len
+        {
+            return;
+        }
+
+        let head = heads.get(&lhs.id()).copied().unwrap_or(lhs);
+        let LazyOp::GEMM(gemm) = self.current_op(head) else {
+            return;
+        };
+        if gemm.bias().is_some() || gemm.epilogue().is_some() {
+            return;
+        }
+        self.fused
+            .insert(head.id(), LazyOp::GEMM(gemm.with_bias(bias.clone())));
+        self.skipped.extend([rhs.id(), t.id()]);
+        heads.insert(t.id(), head);
+    }
+
+    fn fuse_epilogue<'a>(
+        &mut self,
+        t: &'a Tensor,
+        unary: &'a Unary,
+        heads: &mut FxHashMap<TensorId, &'a Tensor>,
+    ) {
+        let input = unary.input();
+        if unary.op().epilogue_id().is_none() || !Self::exclusive(input) {
+            return;
+        }
+
+        let head = heads.get(&input.id()).copied().unwrap_or(input);
+        let op = unary.op().clone();
+        let fused = match self.current_op(head) {
+            LazyOp::GEMM(g) if !g.is_gemv() && g.epilogue().is_none() => {
+                LazyOp::GEMM(g.with_epilogue(op))
+            }
+            LazyOp::Reindex(r) if r.epilogue().is_none() => LazyOp::Reindex(r.with_epilogue(op)),
+            _ => return,
+        };
+        self.fused.insert(head.id(), fused);
+        self.skipped.insert(t.id());
+        heads.insert(t.id(), head);
+    }
+
+    fn fuse_prologue(&mut self, t: &Tensor, reindex: &Reindex) {
+        let src = reindex.src();
+        let LazyOp::Unary(unary) = src.op() else {
+            return;
+        };
+        //If the activation was already absorbed by its producer, its buffer holds the result
+        if unary.op().epilogue_id().is_none() || !Self::exclusive(src) || self.skips(src.id()) {
+            return;
+        }
+        //The input is read by the fused reindex, which the allocator accounts for in its lifetime
+        let input = unary.input();
+        let fused = reindex
+            .clone()
+            .with_src(input.clone())
+            .with_epilogue(unary.op().clone());
+        self.fused.insert(t.id(), LazyOp::Reindex(fused));
+        self.skipped.insert(src.id());
+    }
+
+    /// The source of a broadcast that only `t`'s consumer reads, with the broadcast to skip.
+    fn unbroadcast(t: &Tensor) -> (&Tensor, Option<TensorId>) {
+        match t.op() {
+            LazyOp::Reindex(Reindex::Broadcast(b)) if Self::exclusive(t) => (&b.src, Some(t.id())),
+            _ => (t, None),
+        }
+    }
+
+    fn fuse_softmax_prologue(&mut self, t: &Tensor, softmax: &Softmax) {
+        let add = softmax.input();
+        let LazyOp::Binary(masked) = add.op() else {
+            return;
+        };
+        let mul = masked.lhs();
+        let LazyOp::Binary(scaled) = mul.op() else {
+            return;
+        };
+        //The softmax runs inplace, so it must be free to overwrite the input
+        let input = scaled.lhs();
+        if !matches!(masked.op(), BinaryOp::Add)
+            || !matches!(scaled.op(), BinaryOp::Mul)
+            || softmax.dim() != t.rank() - 1
+            || !Self::exclusive(add)
+            || !Self::exclusive(mul)
+            || !Self::exclusive(input)
+            || self.skips(input.id())
+        {
+            return;
+        }
+
+        let (scale, scale_broadcast) = Self::unbroadcast(scaled.rhs());
+        let (mask, mask_broadcast) = Self::unbroadcast(masked.rhs());
+        if scale.shape().numel() != 1
+            || self.skips(scale.id())
+            || self.skips(mask.id())
+            || !SoftmaxPrologue::supports_mask(input, mask)
+        {
+            return;
+        }
+
+        let fused = softmax
+            .clone()
+            .with_prologue(input.clone(), scale.clone(), mask.clone());
+        self.fused.insert(t.id(), LazyOp::Softmax(fused));
+        self.skipped.extend([add.id(), mul.id()]);
+        self.skipped
+            .extend(scale_broadcast.into_iter().chain(mask_broadcast));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FusionPlan;
+    use crate::{shape, Device, LazyOp, Tensor};
+
+    #[test]
+    fn test_fuse_linear_gelu() -> anyhow::Result<()> {
+        let x = Tensor::randn::<f32>(shape![2, 8, 16], Device::CPU);
+        let w = Tensor::randn::<f32>(shape![16, 32], Device::CPU);
+        let b = Tensor::randn::<f32>(shape![32], Device::CPU);
+        let out = x.matmul(w, false, false)?.add(b)?.gelu()?;
+
+        let LazyOp::Unary(gelu) = out.op() else {
+            panic!("Expected gelu, got {:?}", out.op());
+        };
+        let add = gelu.input();
+        let LazyOp::Binary(binary) = add.op() else {
+            panic!("Expected add, got {:?}", add.op());
+        };
+        let (gemm, broadcast) = (binary.lhs(), binary.rhs());
+
+        let plan = FusionPlan::new(&out.execution_order());
+        for skipped in [&out, add, broadcast] {
+            assert!(plan.skips(skipped.id()));
+        }
+        assert!(!plan.skips(gemm.id()));
+        match plan.fused_op(gemm.id()) {
+            Some(LazyOp::GEMM(g)) => assert!(g.bias().is_some() && g.epilogue().is_some()),
+            op => panic!("Expected fused GEMM, got {:?}", op),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_no_fusion_with_multiple_consumers() -> anyhow::Result<()> {
+        let x = Tensor::randn::<f32>(shape![8, 16], Device::CPU);
+        let w = Tensor::randn::<f32>(shape![16, 32], Device::CPU);
+        let y = x.matmul(w, false, false)?;
+        let out = y.clone().gelu()?.add(y)?;
+
+        let plan = FusionPlan::new(&out.execution_order());
+        assert!(plan.fused.is_empty() && plan.skipped.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_fuse_activation_into_permute() -> anyhow::Result<()> {
+        let x = Tensor::randn::<f32>(shape![2, 4, 8, 16], Device::CPU);
+        let out = x.silu()?.permute(&[0, 2, 1, 3])?;
+
+        let plan = FusionPlan::new(&out.execution_order());
+        let LazyOp::Reindex(permute) = out.op() else {
+            panic!("Expected permute, got {:?}", out.op());
+        };
+        assert!(plan.skips(permute.src().id()));
+        match plan.fused_op(out.id()) {
+            Some(LazyOp::Reindex(r)) => {
+                assert!(r.epilogue().is_some());
+                assert!(r.src().resolved());
+            }
+            op => panic!("Expected fused permute, got {:?}", op),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_fuse_scaled_masked_softmax() -> anyhow::Result<()> {
+        let q = Tensor::randn::<f32>(shape![2, 4, 8, 16], Device::CPU);
+        let k = Tensor::randn::<f32>(shape![2, 4, 8, 16], Device::CPU);
+        let scale = Tensor::from_data([0.25f32], shape![1], Device::CPU);
+        let mask = Tensor::randn::<f32>(shape![8, 8], Device::CPU);
+        let scores = q.matmul(k, false, true)?;
+        let scores_id = scores.id();
+        let out = scores.mul(scale)?.add(mask)?.softmax(3)?;
+
+        let order = out.execution_order();
+        let plan = FusionPlan::new(&order);
+        let skipped = order.iter().filter(|t| plan.skips(t.id())).count();
+        //The mul & add, and the broadcasts of the scale & mask
+        assert_eq!(skipped, 4);
+        match plan.fused_op(out.id()) {
+            Some(LazyOp::Softmax(s)) => {
+                assert!(s.prologue().is_some());
+                assert_eq!(s.input().id(), scores_id);
+            }
+            op => panic!("Expected fused softmax, got {:?}", op),
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "pyo3")]
+    #[test]
+    fn test_fused_matches_cpu() -> anyhow::Result<()> {
+        use crate::DeviceRequest;
+
+        let device = Device::request_device(DeviceRequest::GPU)?;
+        let x = Tensor::randn::<f32>(shape![2, 33, 64], Device::CPU);
+        let w = Tensor::randn::<f32>(shape![64, 48], Device::CPU);
+        let b = Tensor::randn::<f32>(shape![48], Device::CPU);
+        let graph = |x: Tensor, w: Tensor, b: Tensor| -> anyhow::Result<Tensor> {
+            let h = x.matmul(w, false, false)?.add(b)?.gelu()?;
+            Ok(h.permute(&[0, 2, 1])?.relu()?)
+        };
+
+        let ground = graph(x.clone(), w.clone(), b.clone())?.resolve()?;
+        let ours = graph(x.to(&device)?, w.to(&device)?, b.to(&device)?)?.resolve()?;
+        ground.all_close(&ours.to(&Device::CPU)?, 1e-4, 1e-4)?;
+        Ok(())
+    }
+
+    #[cfg(feature = "pyo3")]
+    #[test]
+    fn test_fused_prologue_matches_cpu() -> anyhow::Result<()> {
+        use crate::DeviceRequest;
+
+        let device = Device::request_device(DeviceRequest::GPU)?;
+        let x = Tensor::randn::<f32>(shape![2, 33, 48], Device::CPU);
+        let w = Tensor::randn::<f32>(shape![48, 48], Device::CPU);
+        //The input of the activation lives in an arena, and is read past its last use
+        let softmax = |x: Tensor, w: Tensor| -> anyhow::Result<Tensor> {
+            let y = x.matmul(w, false, false)?.softmax(2)?;
+            Ok(y.silu()?.permute(&[0, 2, 1])?)
+        };
+        //The input of the activation has another consumer, once the permute has run
+        let shared = |x: Tensor, w: Tensor| -> anyhow::Result<Tensor> {
+            let y = x.matmul(w, false, false)?;
+            Ok(y.clone()
+                .silu()?
+                .permute(&[0, 2, 1])?
+                .matmul(y, false, false)?)
+        };
+
+        for graph in [softmax, shared] {
+            let out = graph(x.clone(), w.clone())?;
+            let plan = FusionPlan::new(&out.execution_order());
+            assert!(plan.fused.values().any(|op| matches!(
+                op,
+                LazyOp::Reindex(r) if r.epilogue().is_some() && !r.src().resolved()
+            )));
+            drop(plan);
+
+            let ground = out.resolve()?;
+            let ours = graph(x.to(&device)?, w.to(&device)?)?.resolve()?;
+            ground.all_close(&ours.to(&Device::CPU)?, 1e-4, 1e-4)?;
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "pyo3")]
+    #[test]
+    fn test_fused_softmax_matches_cpu() -> anyhow::Result<()> {
+        use crate::DeviceRequest;
+
+        let device = Device::request_device(DeviceRequest::GPU)?;
+        let q = Tensor::randn::<f32>(shape![2, 4, 9, 16], Device::CPU);
+        let k = Tensor::randn::<f32>(shape![2, 4, 12, 16], Device::CPU);
+        //Vectorized rows, with a mask shared by every batch & one of its own per batch
+        for mask_shape in [shape![9, 12], shape![2, 4, 9, 12]] {
+            let mask = Tensor::randn::<f32>(mask_shape, Device::CPU);
+            let graph = |q: Tensor, k: Tensor, mask: Tensor| -> anyhow::Result<Tensor> {
+                let scale = Tensor::from_data([0.25f32], shape![1], q.device().clone());
+                q.matmul(k, false, true)?.mul(scale)?.add(mask)?.softmax(3)
+            };
+
+            let ground = graph(q.clone(), k.clone(), mask.clone())?.resolve()?;
+            let (q, k, mask) = (q.to(&device)?, k.to(&device)?, mask.to(&device)?);
+            let ours = graph(q, k, mask)?.resolve()?;
+            ground.all_close(&ours.to(&Device::CPU)?, 1e-5, 1e-5)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,302 @@
+use super::{TensorUsageRecord, TensorUsageRecords};
+use crate::{gpu::MIN_STORAGE_BUFFER_SIZE, TensorId};
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::cmp::Reverse;
+
+/// Where an activation lives: a byte offset into one of the arenas of a [MemoryPlan].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArenaSlot {
+    pub arena: usize,
+    pub offset: usize,
+}
+
+/// Activation memory of the most recently planned graph.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ActivationStats {
+    /// Bytes required if every activation had a buffer of its own.
+    pub unshared_bytes: usize,
+    /// Largest number of bytes alive at any point in the graph, a lower bound for any plan.
+    pub peak_live_bytes: usize,
+    /// Bytes actually allocated for the arenas.
+    pub planned_bytes: usize,
+    pub num_arenas: usize,
+}
+
+/// # Memory Plan
+///
+/// Offline assignment of every activation to a range of a few large arenas, using the
+/// "Greedy by Size for Offset Calculation" strategy of https://arxiv.org/pdf/2001.03288.pdf.
+///
+/// Records are placed largest first, and longest lived first among records of the same size.
+/// Each is put in the smallest gap, between the records of an
+/// arena whose lifetimes overlap with it, that can hold it. If no gap is large enough, it is
+/// placed after them, growing the arena as little as possible.
+///
+/// A dispatch may not bind the same buffer as both read-only and read-write, so an activation is
+/// never placed in the same arena as any of its `conflicts`, the inputs of the op producing it.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryPlan {
+    slots: FxHashMap<TensorId, ArenaSlot>,
+    arenas: Vec<usize>,
+    stats: ActivationStats,
+}
+
+struct Placed<'a> {
+    record: &'a TensorUsageRecord,
+    offset: usize,
+    size: usize,
+}
+
+impl Placed<'_> {
+    fn overlaps(&self, record: &TensorUsageRecord) -> bool {
+        let max_first = std::cmp::max(self.record.producer, record.producer);
+        let min_last = std::cmp::min(self.record.last_consumer, record.last_consumer);
+        max_first <= Some(min_last)
+    }
+}
+
+impl MemoryPlan {
+    pub fn new(
+        records: &TensorUsageRecords,
+        conflicts: &FxHashSet<(TensorId, TensorId)>,
+        alignment: usize,
+        max_arena_size: usize,
+    ) -> Self {
+        let slot_size = |r: &TensorUsageRecord| Self::slot_size(r, alignment);
+        let mut records = records.0.iter().collect::<Vec<_>>();
+        //Among records of the same size, long lived ones are the hardest to fit around the others
+        let lifetime = |r: &TensorUsageRecord| r.last_consumer - r.producer.unwrap_or_default();
+        records.sort_by_key(|r| (Reverse(slot_size(r)), Reverse(lifetime(r)), r.producer));
+
+        let mut arenas: Vec<Vec<Placed>> = vec![];
+        let mut arena_sizes: Vec<usize> = vec![];
+        let mut slots = FxHashMap::default();
+        for record in records.iter() {
+            let id = record.id.unwrap();
+            let size = slot_size(record);
+
+            //(growth, waste, arena, offset)
+            let mut best: Option<(usize, usize, usize, usize)> = None;
+            for (index, placed) in arenas.iter().enumerate() {
+                let conflicting = placed
+                    .iter()
+                    .any(|p| conflicts.contains(&(id, p.record.id.unwrap())));
+                if conflicting {
+                    continue;
+                }
+                let mut live = placed
+                    .iter()
+                    .filter(|p| p.overlaps(record))
+                    .collect::<Vec<_>>();
+                live.sort_by_key(|p| p.offset);
+
+                let mut candidate = None;
+                let mut prev_end = 0;
+                for p in live {
+                    if p.offset >= prev_end + size {
+                        let waste = p.offset - prev_end - size;
+                        if !matches!(candidate, Some((_, w)) if w <= waste) {
+                            candidate = Some((prev_end, waste));
+                        }
+                    }
+                    prev_end = prev_end.max(p.offset + p.size);
+                }
+                let arena_size = arena_sizes[index];
+                let (offset, waste) =
+                    candidate.unwrap_or((prev_end, arena_size.saturating_sub(prev_end + size)));
+                if offset + size > max_arena_size {
+                    continue;
+                }
+                let growth = (offset + size).saturating_sub(arena_size);
+                if !matches!(best, Some((g, w, _, _)) if (g, w) <= (growth, waste)) {
+                    best = Some((growth, waste, index, offset));
+                }
+            }
+
+            let (arena, offset) = match best {
+                Some((_, _, arena, offset)) => (arena, offset),
+                None => {
+                    arenas.push(vec![]);
+                    arena_sizes.push(0);
+                    (arenas.len() - 1, 0)
+                }
+            };
+            arena_sizes[arena] = arena_sizes[arena].max(offset + size);
+            arenas[arena].push(Placed {
+                record,
+                offset,
+                size,
+            });
+            slots.insert(id, ArenaSlot { arena, offset });
+        }
+        Self::from_slots(&records, slots, arena_sizes, alignment)
+    }
+
+    /// Every activation in an arena of its own, so that no two ever share memory.
+    /// Used in place of [MemoryPlan::new] when `RATCHET_DEBUG` is set.
+    pub fn unshared(records: &TensorUsageRecords, alignment: usize) -> Self {
+        let records = records.0.iter().collect::<Vec<_>>();
+        let slots = records
+            .iter()
+            .enumerate()
+            .map(|(arena, r)| (r.id.unwrap(), ArenaSlot { arena, offset: 0 }))
+            .collect();
+        let arena_sizes = records
+            .iter()
+            .map(|r| Self::slot_size(r, alignment))
+            .collect();
+        Self::from_slots(&records, slots, arena_sizes, alignment)
+    }
+
+    fn slot_size(record: &TensorUsageRecord, alignment: usize) -> usize {
+        record
+            .size
+            .max(MIN_STORAGE_BUFFER_SIZE)
+            .next_multiple_of(alignment)
+    }
+
+    fn from_slots(
+        records: &[&TensorUsageRecord],
+        slots: FxHashMap<TensorId, ArenaSlot>,
+        arena_sizes: Vec<usize>,
+        alignment: usize,
+    ) -> Self {
+        let slot_size = |r: &TensorUsageRecord| Self::slot_size(r, alignment);
+        let peak_live_bytes = (0..=records.iter().map(|r| r.last_consumer).max().unwrap_or(0))
+            .map(|step| {
+                records
+                    .iter()
+                    .filter(|r| r.producer <= Some(step) && step <= r.last_consumer)
+                    .map(|r| slot_size(r))
+                    .sum::<usize>()
+            })
+            .max()
+            .unwrap_or(0);
+        let stats = ActivationStats {
+            unshared_bytes: records.iter().map(|r| slot_size(r)).sum(),
+            peak_live_bytes,
+            planned_bytes: arena_sizes.iter().sum(),
+            num_arenas: arena_sizes.len(),
+        };
+        Self {
+            slots,
+            arenas: arena_sizes,
+            stats,
+        }
+    }
+
+    pub fn slot(&self, id: TensorId) -> Option<ArenaSlot> {
+        self.slots.get(&id).copied()
+    }
+
+    /// Size in bytes of every arena, indexed by [ArenaSlot::arena].
+    pub fn arenas(&self) -> &[usize] {
+        &self.arenas
+    }
+
+    pub fn stats(&self) -> ActivationStats {
+        self.stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MemoryPlan;
+    use crate::gpu::{TensorUsageRecord, TensorUsageRecords};
+    use crate::TensorId;
+    use rustc_hash::FxHashSet;
+
+    fn record(id: usize, producer: usize, last_consumer: usize, size: usize) -> TensorUsageRecord {
+        TensorUsageRecord {
+            id: Some(TensorId::debug(id)),
+            producer: Some(producer),
+            last_consumer,
+            #[cfg(debug_assertions)]
+            last_consumer_id: TensorId::debug(0),
+            size,
+        }
+    }
+
+    fn assert_valid(plan: &MemoryPlan, records: &TensorUsageRecords) {
+        for a in records.0.iter() {
+            let sa = plan.slot(a.id.unwrap()).unwrap();
+            assert!(sa.offset + a.size <= plan.arenas()[sa.arena]);
+            for b in records.0.iter().filter(|b| b.id != a.id) {
+                let sb = plan.slot(b.id.unwrap()).unwrap();
+                let alive_together =
+                    a.producer.max(b.producer) <= Some(a.last_consumer.min(b.last_consumer));
+                let disjoint = sa.offset + a.size <= sb.offset || sb.offset + b.size <= sa.offset;
+                assert!(sa.arena != sb.arena || !alive_together || disjoint);
+            }
+        }
+    }
+
+    #[test]
+    fn test_plan_reuses_dead_ranges() {
+        //A chain, where each activation is consumed by the next op
+        let records = TensorUsageRecords(vec![
+            record(1, 0, 1, 4096),
+            record(2, 1, 2, 1024),
+            record(3, 2, 3, 4096),
+            record(4, 3, 4, 1024),
+        ]);
+        let plan = MemoryPlan::new(&records, &FxHashSet::default(), 256, usize::MAX);
+        assert_valid(&plan, &records);
+
+        let stats = plan.stats();
+        assert_eq!(stats.unshared_bytes, 10240);
+        assert_eq!(stats.peak_live_bytes, 5120);
+        assert_eq!(stats.planned_bytes, 5120);
+        assert_eq!(stats.num_arenas, 1);
+    }
+
+    #[test]
+    fn test_plan_separates_conflicts() {
+        let records = TensorUsageRecords(vec![
+            record(1, 0, 1, 4096),
+            record(2, 1, 2, 1024),
+            record(3, 2, 3, 4096),
+        ]);
+        let (t1, t2, t3) = (TensorId::debug(1), TensorId::debug(2), TensorId::debug(3));
+        let conflicts = [(t1, t2), (t2, t1), (t2, t3), (t3, t2)]
+            .into_iter()
+            .collect::<FxHashSet<_>>();
+        let plan = MemoryPlan::new(&records, &conflicts, 256, usize::MAX);
+        assert_valid(&plan, &records);
+
+        let arena = |id| plan.slot(id).unwrap().arena;
+        assert_ne!(arena(t1), arena(t2));
+        assert_ne!(arena(t2), arena(t3));
+        assert_eq!(arena(t1), arena(t3));
+        assert_eq!(plan.stats().planned_bytes, 5120);
+    }
+
+    #[test]
+    fn test_unshared_plan() {
+        let records = TensorUsageRecords(vec![
+            record(1, 0, 1, 4096),
+            record(2, 1, 2, 1000),
+            record(3, 2, 3, 4096),
+        ]);
+        let plan = MemoryPlan::unshared(&records, 256);
+        assert_valid(&plan, &records);
+
+        let stats = plan.stats();
+        assert_eq!(stats.num_arenas, 3);
+        assert_eq!(stats.planned_bytes, stats.unshared_bytes);
+        assert_eq!(plan.arenas(), &[4096, 1024, 4096]);
+    }
+
+    #[test]
+    fn test_plan_respects_max_arena_size() {
+        let records = TensorUsageRecords(vec![
+            record(1, 0, 2, 1000),
+            record(2, 1, 3, 1000),
+            record(3, 2, 4, 1000),
+        ]);
+        let plan = MemoryPlan::new(&records, &FxHashSet::default(), 256, 2048);
+        assert_valid(&plan, &records);
+        assert_eq!(plan.stats().num_arenas, 2);
+        assert!(plan.arenas().iter().all(|&size| size <= 2048));
+    }
+}
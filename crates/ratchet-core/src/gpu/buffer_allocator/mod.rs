@@ -1,5 +1,7 @@
 mod allocator;
+mod memory_plan;
 mod tensor_usage_record;
 
 pub use allocator::*;
+pub use memory_plan::*;
 pub use tensor_usage_record::*;
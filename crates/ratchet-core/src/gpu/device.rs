@@ -1,7 +1,12 @@
-use crate::{gpu::*, Tensor, TensorId};
+use crate::{
+    fusion::FusionPlan, gpu::*, rvec, GPUBuffer, OperationError, Tensor, TensorId, KERNELS,
+};
+use parking_lot::RwLock;
 use rustc_hash::FxHashMap;
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
-use wgpu::{Adapter, Limits};
+use wgpu::{Adapter, Limits, SubmissionIndex};
 
 use crate::DeviceError;
 
@@ -19,11 +24,15 @@ pub struct WgpuDevice {
     device: Arc<wgpu::Device>,
     queue: Arc<wgpu::Queue>,
     ordinal: u32,
+    adapter_info: wgpu::AdapterInfo,
     buffer_allocator: Arc<BufferAllocator>,
-    bind_group_pool: Arc<BindGroupPool>,
+    bind_group_pool: Arc<RwLock<BindGroupPool>>,
     bind_group_layout_pool: Arc<BindGroupLayoutPool>,
     pipeline_layout_pool: Arc<PipelineLayoutPool>,
     compute_pipeline_pool: Arc<ComputePipelinePool>,
+    pass_index: Arc<AtomicU64>,
+    #[cfg(not(target_arch = "wasm32"))]
+    poller: Arc<Poller>,
 }
 
 impl std::ops::Deref for WgpuDevice {
@@ -61,6 +70,12 @@ impl WgpuDevice {
         {
             features |= wgpu::Features::TIMESTAMP_QUERY;
         }
+        //naga can't parse `enable f16;` yet, so native halves are only used in the browser, where
+        //tests/f16.rs checks them. Native F16 ops fail with DeviceError::MissingFeature.
+        #[cfg(target_arch = "wasm32")]
+        if adapter.features().contains(wgpu::Features::SHADER_F16) {
+            features |= wgpu::Features::SHADER_F16;
+        }
 
         let mut device_descriptor = wgpu::DeviceDescriptor {
             label: Some("Ratchet"),
@@ -85,11 +100,15 @@ impl WgpuDevice {
         Ok(Self {
             queue: Arc::new(queue),
             ordinal: 0,
+            adapter_info: adapter.get_info(),
             buffer_allocator: Arc::new(BufferAllocator::new()),
-            bind_group_pool: Arc::new(BindGroupPool::new()),
+            bind_group_pool: Arc::new(RwLock::new(BindGroupPool::new())),
             bind_group_layout_pool: Arc::new(BindGroupLayoutPool::new()),
             pipeline_layout_pool: Arc::new(PipelineLayoutPool::new()),
             compute_pipeline_pool: Arc::new(ComputePipelinePool::new()),
+            pass_index: Default::default(),
+            #[cfg(not(target_arch = "wasm32"))]
+            poller: Arc::new(Poller::default()),
             device: Arc::new(device),
         })
     }
@@ -102,6 +121,66 @@ impl WgpuDevice {
         self.ordinal
     }
 
+    /// Identifies the adapter & driver, pipelines compiled for one may not be valid on another.
+    pub fn adapter_key(&self) -> String {
+        let info = &self.adapter_info;
+        format!(
+            "{}:{:x}:{:x}:{:?}:{}:{}",
+            info.name, info.vendor, info.device, info.backend, info.driver, info.driver_info
+        )
+    }
+
+    /// Returns a future that completes once the GPU has finished the work of `index`,
+    /// without blocking the calling thread.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn on_submitted_work_done(&self, index: SubmissionIndex) -> impl Future<Output = ()> {
+        self.poller.wait(&self.device, index)
+    }
+
+    /// Returns a future that completes once the GPU has finished the work of `index`.
+    /// The browser polls the device, so this is the same as waiting on everything submitted.
+    #[cfg(target_arch = "wasm32")]
+    pub fn on_submitted_work_done(&self, _: SubmissionIndex) -> impl Future<Output = ()> {
+        let (tx, rx) = futures_intrusive::channel::shared::oneshot_channel();
+        self.queue.on_submitted_work_done(move || {
+            let _ = tx.send(());
+        });
+        async move {
+            rx.receive().await;
+        }
+    }
+
+    /// Captures the validation & out of memory errors raised by the following calls, until
+    /// [Self::pop_error_scope], rather than panicking in the uncaptured error handler.
+    ///
+    /// In the browser popping a scope is asynchronous, so errors are left to the uncaptured
+    /// error handler instead, which logs them to the console.
+    pub(crate) fn push_error_scope(&self) {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            self.device.push_error_scope(wgpu::ErrorFilter::OutOfMemory);
+            self.device.push_error_scope(wgpu::ErrorFilter::Validation);
+        }
+    }
+
+    /// Returns the first error captured since the matching [Self::push_error_scope].
+    pub(crate) fn pop_error_scope(&self) -> Result<(), DeviceError> {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let validation = pollster::block_on(self.device.pop_error_scope());
+            let out_of_memory = pollster::block_on(self.device.pop_error_scope());
+            if let Some(error) = validation.or(out_of_memory) {
+                return Err(error.into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether kernels may use the WGSL `f16` type.
+    pub fn supports_f16(&self) -> bool {
+        self.features().contains(wgpu::Features::SHADER_F16)
+    }
+
     #[cfg(target_arch = "wasm32")]
     async fn select_adapter() -> Result<Adapter, DeviceError> {
         let instance = wgpu::Instance::default();
@@ -168,7 +247,7 @@ impl WgpuDevice {
         &self,
         desc: &BindGroupDescriptor,
     ) -> Result<GpuBindGroup, PoolError> {
-        Ok(self.bind_group_pool.get_or_create(desc, self))
+        Ok(self.bind_group_pool.read().get_or_create(desc, self))
     }
 
     pub fn get_or_create_bind_group_layout(
@@ -192,6 +271,72 @@ impl WgpuDevice {
         Ok(self.compute_pipeline_pool.get_or_create(desc, self))
     }
 
+    /// Creates the pipeline for `kernel_key`, with `storage_layout` bound before the uniform.
+    /// Every pipeline is recorded, see [Self::pipeline_manifest].
+    pub(crate) fn get_or_create_kernel(
+        &self,
+        kernel_key: &str,
+        storage_layout: &BindGroupLayoutDescriptor,
+    ) -> Result<(BindGroupLayoutHandle, ComputePipelineHandle), OperationError> {
+        if !KERNELS.contains_key(kernel_key) {
+            return Err(OperationError::KernelNotFound(kernel_key.to_string()));
+        }
+        let storage_layout_handle = self.get_or_create_bind_group_layout(storage_layout)?;
+        let uniform_layout =
+            self.get_or_create_bind_group_layout(&BindGroupLayoutDescriptor::uniform())?;
+        let pipeline_layout = self.get_or_create_pipeline_layout(&PipelineLayoutDescriptor {
+            entries: rvec![storage_layout_handle, uniform_layout],
+        })?;
+
+        let pipeline_handle = self.get_or_create_compute_pipeline(&ComputePipelineDescriptor {
+            pipeline_layout,
+            kernel_key: kernel_key.to_string(),
+        })?;
+        if let Some(entry) = PipelineEntry::new(kernel_key, storage_layout) {
+            self.compute_pipeline_pool.record(entry);
+        }
+        Ok((storage_layout_handle, pipeline_handle))
+    }
+
+    /// Every pipeline created on this device so far. Save it once a model has run, and
+    /// [Self::warm] the device with it in the next process.
+    pub fn pipeline_manifest(&self) -> PipelineManifest {
+        PipelineManifest {
+            adapter: self.adapter_key(),
+            pipelines: self.compute_pipeline_pool.recorded(),
+        }
+    }
+
+    /// Creates every pipeline in `manifest`, so that no shader is compiled during inference.
+    /// Returns the number of pipelines in the manifest that could be used.
+    ///
+    /// A manifest recorded on another adapter is ignored, as are kernels that no longer exist.
+    pub fn warm(&self, manifest: &PipelineManifest) -> Result<usize, OperationError> {
+        if manifest.adapter != self.adapter_key() {
+            log::warn!(
+                "Pipeline manifest was recorded on {}, not warming.",
+                manifest.adapter
+            );
+            return Ok(0);
+        }
+        self.push_error_scope();
+        let mut warmed = 0;
+        let result = manifest.pipelines.iter().try_for_each(|entry| {
+            match self.get_or_create_kernel(&entry.kernel_key, &entry.storage_layout()) {
+                Ok(_) => warmed += 1,
+                Err(OperationError::KernelNotFound(key)) => {
+                    log::warn!("Kernel {} no longer exists, skipping.", key)
+                }
+                Err(e) => return Err(e),
+            }
+            Ok(())
+        });
+        let scoped = self.pop_error_scope();
+        result?;
+        scoped?;
+        Ok(warmed)
+    }
+
     pub fn bind_group_layout_resources(
         &self,
     ) -> StaticResourcePoolReadLockAccessor<'_, BindGroupLayoutHandle, wgpu::BindGroupLayout> {
@@ -211,16 +356,55 @@ impl WgpuDevice {
     }
 
     /// Allocates all buffers required for storage of activations.
-    /// Additionally, allocates buffer for leaf node, the tensor upon which resolve was called.
-    pub fn allocate_cfg(
+    /// Additionally, allocates buffers for the outputs, the tensors upon which resolve was called.
+    pub(crate) fn allocate_cfg(
         &self,
         execution_order: &[&Tensor],
+        outputs: &[&Tensor],
+        fusion: &FusionPlan,
         device: &WgpuDevice,
-    ) -> Result<FxHashMap<TensorId, PooledGPUBuffer>, DeviceError> {
-        self.buffer_allocator.allocate_cfg(execution_order, device)
+    ) -> Result<FxHashMap<TensorId, GPUBuffer>, DeviceError> {
+        self.buffer_allocator
+            .allocate_cfg(execution_order, outputs, fusion, device)
+    }
+
+    /// Activation memory planned for the last graph resolved on this device.
+    pub fn activation_stats(&self) -> ActivationStats {
+        self.buffer_allocator.activation_stats()
+    }
+
+    pub(crate) fn buffer_allocator(&self) -> &Arc<BufferAllocator> {
+        &self.buffer_allocator
+    }
+
+    /// GPU memory held by this device, by what it is used for.
+    pub fn memory_stats(&self) -> MemoryStats {
+        self.buffer_allocator.memory_stats()
+    }
+
+    /// Destroys every pooled buffer that isn't in use, returning the number of bytes freed.
+    /// Following passes will have to allocate their buffers again.
+    pub fn trim(&self) -> u64 {
+        self.buffer_allocator.trim()
+    }
+
+    /// Sets when pooled buffers that aren't in use anymore are destroyed, checked on every pass.
+    pub fn set_eviction_policy(&self, policy: EvictionPolicy) {
+        self.buffer_allocator.set_eviction_policy(policy);
+    }
+
+    /// Releases everything this device holds that isn't in use, and waits for the GPU to free it.
+    /// Call it after dropping a model, so that its weights are gone before loading the next one.
+    pub fn purge(&self) -> u64 {
+        self.bind_group_pool.write().trim();
+        let freed = self.buffer_allocator.trim();
+        self.device.poll(wgpu::Maintain::Wait);
+        freed
     }
 
     pub fn begin_pass(&self) {
-        self.buffer_allocator.begin_pass(0);
+        let pass_index = self.pass_index.fetch_add(1, Ordering::Relaxed) + 1;
+        self.bind_group_pool.write().begin_pass(pass_index);
+        self.buffer_allocator.begin_pass(pass_index);
     }
 }
@@ -4,6 +4,8 @@ mod pools;
 mod uniform;
 mod workload;
 
+#[cfg(not(target_arch = "wasm32"))]
+mod poller;
 #[cfg(feature = "gpu-profiling")]
 mod profiler;
 
@@ -13,12 +15,23 @@ pub use pools::*;
 pub use uniform::*;
 pub use workload::*;
 
+#[cfg(not(target_arch = "wasm32"))]
+pub use poller::*;
 #[cfg(feature = "gpu-profiling")]
 pub use profiler::*;
 
 pub const MIN_STORAGE_BUFFER_SIZE: usize = 16;
 pub const STORAGE_BUFFER_ALIGN: usize = 256; //TODO: should be a device limit
 
+/// Whether `RATCHET_CHECKED` is set, read once on first use.
+///
+/// Checked mode validates shaders at compile time & submits every step of an executable on its
+/// own, trading speed for errors that can be attributed to the op that raised them.
+pub(crate) fn checked() -> bool {
+    static CHECKED: std::sync::OnceLock<bool> = std::sync::OnceLock::new();
+    *CHECKED.get_or_init(|| std::env::var("RATCHET_CHECKED").is_ok())
+}
+
 /// Usages we use everywhere
 pub trait BufferUsagesExt {
     fn standard() -> Self;
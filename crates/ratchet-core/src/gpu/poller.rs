@@ -0,0 +1,79 @@
+use futures_intrusive::channel::shared::{oneshot_channel, OneshotSender};
+use parking_lot::Mutex;
+use std::future::Future;
+use std::sync::{mpsc, Arc, Weak};
+use wgpu::SubmissionIndex;
+
+type Job = (SubmissionIndex, OneshotSender<()>);
+
+/// # Poller
+///
+/// On native, callbacks such as buffer mappings only fire while the device is polled, and polling
+/// until a submission is done blocks the calling thread.
+/// Instead, submissions are handed to a background thread that polls on their behalf, so the
+/// caller only has to await a future. The thread is spawned on first use, and exits once the
+/// device is dropped.
+#[derive(Default)]
+pub struct Poller {
+    jobs: Mutex<Option<mpsc::Sender<Job>>>,
+}
+
+impl Poller {
+    /// Returns a future that completes once the GPU has finished the work of `index`.
+    pub fn wait(
+        &self,
+        device: &Arc<wgpu::Device>,
+        index: SubmissionIndex,
+    ) -> impl Future<Output = ()> {
+        let (tx, rx) = oneshot_channel();
+        let mut jobs = self.jobs.lock();
+        let sender = jobs.get_or_insert_with(|| Self::spawn(Arc::downgrade(device)));
+        if let Err(mpsc::SendError((index, tx))) = sender.send((index, tx)) {
+            //The thread is gone, wait in place and spawn another next time
+            log::warn!("Poller thread exited, waiting on the calling thread.");
+            *jobs = None;
+            device.poll(wgpu::Maintain::WaitForSubmissionIndex(index));
+            let _ = tx.send(());
+        }
+        async move {
+            rx.receive().await;
+        }
+    }
+
+    fn spawn(device: Weak<wgpu::Device>) -> mpsc::Sender<Job> {
+        let (tx, rx) = mpsc::channel::<Job>();
+        std::thread::Builder::new()
+            .name("ratchet-poller".to_string())
+            .spawn(move || {
+                for (index, done) in rx {
+                    if let Some(device) = device.upgrade() {
+                        device.poll(wgpu::Maintain::WaitForSubmissionIndex(index));
+                    }
+                    let _ = done.send(());
+                }
+            })
+            .expect("Failed to spawn poller thread");
+        tx
+    }
+}
+
+#[cfg(all(test, feature = "pyo3"))]
+mod tests {
+    use crate::{shape, Device, DeviceRequest, Tensor};
+
+    #[test]
+    fn test_resolve_async() -> anyhow::Result<()> {
+        let device = Device::request_device(DeviceRequest::GPU)?;
+        let x = Tensor::randn::<f32>(shape![33, 64], Device::CPU);
+        let w = Tensor::randn::<f32>(shape![64, 48], Device::CPU);
+        let ground = x.clone().matmul(w.clone(), false, false)?.resolve()?;
+
+        let (x, w) = (x.to(&device)?, w.to(&device)?);
+        let ours = pollster::block_on(async {
+            let out = x.matmul(w, false, false)?.resolve_async().await?;
+            anyhow::Ok(out.to_async(&Device::CPU).await?)
+        })?;
+        ground.all_close(&ours, 1e-4, 1e-4)?;
+        Ok(())
+    }
+}
@@ -11,7 +11,7 @@ slotmap::new_key_type! { pub struct GpuBindGroupHandle; }
 #[derive(Clone)]
 pub struct GpuBindGroup {
     resource: Arc<DynamicResource<GpuBindGroupHandle, BindGroupDescriptor, wgpu::BindGroup>>,
-    _owned_buffers: RVec<PooledGPUBuffer>,
+    owned_buffers: RVec<PooledGPUBuffer>,
 }
 
 impl std::fmt::Debug for GpuBindGroup {
@@ -22,6 +22,13 @@ impl std::fmt::Debug for GpuBindGroup {
     }
 }
 
+impl GpuBindGroup {
+    /// The buffers bound by this bind group, kept alive for as long as it is.
+    pub fn buffers(&self) -> &[PooledGPUBuffer] {
+        &self.owned_buffers
+    }
+}
+
 impl std::ops::Deref for GpuBindGroup {
     type Target = wgpu::BindGroup;
 
@@ -138,11 +145,16 @@ impl BindGroupPool {
 
         GpuBindGroup {
             resource,
-            _owned_buffers: owned_buffers,
+            owned_buffers,
         }
     }
 
     pub fn begin_pass(&mut self, pass_index: u64) {
         self.inner.begin_pass(pass_index, |_res| {});
     }
+
+    /// Drops every bind group that isn't in use.
+    pub fn trim(&mut self) {
+        self.inner.trim(|_res| {});
+    }
 }
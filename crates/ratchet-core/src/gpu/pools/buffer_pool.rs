@@ -1,7 +1,9 @@
 use std::sync::Arc;
 
 // Adapted from https://github.com/rerun-io/rerun MIT licensed
-use super::{DynamicResource, DynamicResourcePool, DynamicResourcesDesc, PoolError};
+use super::{
+    DynamicResource, DynamicResourcePool, DynamicResourcesDesc, EvictionPolicy, PoolError,
+};
 use crate::{
     gpu::{WgpuDevice, MIN_STORAGE_BUFFER_SIZE},
     RawGPUBuffer,
@@ -73,14 +75,14 @@ impl BufferPool {
         device: &WgpuDevice,
         immediate: bool,
     ) -> PooledGPUBuffer {
-        let descriptor = if (desc.size as usize) < MIN_STORAGE_BUFFER_SIZE {
-            BufferDescriptor {
-                size: MIN_STORAGE_BUFFER_SIZE as _,
-                usage: desc.usage,
-                mapped_at_creation: desc.mapped_at_creation,
-            }
-        } else {
-            desc.clone()
+        //Storage bindings must be a multiple of 4 bytes, e.g an odd number of halves is padded
+        let descriptor = BufferDescriptor {
+            size: desc
+                .size
+                .max(MIN_STORAGE_BUFFER_SIZE as _)
+                .next_multiple_of(wgpu::COPY_BUFFER_ALIGNMENT),
+            usage: desc.usage,
+            mapped_at_creation: desc.mapped_at_creation,
         };
         PooledGPUBuffer(self.inner.get_or_create(&descriptor, |descriptor| {
             let (size, usage, mapped_at_creation) = descriptor.fields();
@@ -102,6 +104,15 @@ impl BufferPool {
         self.inner.begin_pass(pass_index, |res| res.destroy());
     }
 
+    /// Destroys every buffer that isn't in use, returning the number of bytes freed.
+    pub fn trim(&mut self) -> u64 {
+        self.inner.trim(|res| res.destroy())
+    }
+
+    pub fn set_eviction_policy(&mut self, policy: EvictionPolicy) {
+        self.inner.set_eviction_policy(policy);
+    }
+
     /// Method to retrieve a resource from a weak handle (used by [`super::GpuBindGroupPool`])
     pub fn get(&self, handle: GpuBufferHandle) -> Result<PooledGPUBuffer, PoolError> {
         Ok(PooledGPUBuffer(self.inner.get_from_handle(handle)?))
@@ -122,4 +133,15 @@ impl BufferPool {
     pub fn total_gpu_size_in_bytes(&self) -> u64 {
         self.inner.total_resource_size_in_bytes()
     }
+
+    pub fn peak_gpu_size_in_bytes(&self) -> u64 {
+        self.inner.peak_resource_size_in_bytes()
+    }
+}
+
+/// The browser only garbage collects dropped buffers, destroy them as soon as the device is gone.
+impl Drop for BufferPool {
+    fn drop(&mut self) {
+        self.trim();
+    }
 }
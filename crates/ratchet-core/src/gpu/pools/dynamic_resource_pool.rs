@@ -9,7 +9,7 @@ use std::{
 };
 
 use parking_lot::RwLock;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use slotmap::{Key, SlotMap};
 
 pub trait DynamicResourcesDesc {
@@ -20,6 +20,26 @@ pub trait DynamicResourcesDesc {
     fn allow_reuse(&self) -> bool;
 }
 
+/// When a [`DynamicResourcePool`] destroys the resources nobody holds anymore.
+///
+/// Idle resources are kept around to be re-used by following passes, the pool destroys them once
+/// they have been idle for `max_idle_passes` passes, or oldest first while more than
+/// `max_pooled_bytes` are idle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EvictionPolicy {
+    pub max_idle_passes: u64,
+    pub max_pooled_bytes: Option<u64>,
+}
+
+impl Default for EvictionPolicy {
+    fn default() -> Self {
+        Self {
+            max_idle_passes: 1,
+            max_pooled_bytes: None,
+        }
+    }
+}
+
 #[derive(derive_new::new, Debug)]
 pub struct DynamicResource<Handle, Desc: Debug, Res> {
     pub inner: Res,
@@ -40,14 +60,15 @@ where
 }
 
 struct DynamicResourcePoolProtectedState<Handle: Key, Desc: Debug, Res> {
-    /// All resources, including both resources that are in use and those that are marked as dead via [`Self::last_pass_deallocated`]
+    /// All resources, including both resources that are in use and those that are marked as dead via [`Self::deallocated`]
     ///
     /// We store any ref counted handle we give out in [`DynamicResourcePool::allocate`] here in order to keep it alive.
     /// Every [`DynamicResourcePool::begin_pass`] we check if the pool is now the only owner of the handle, as if so deallocate.
     all_resources: SlotMap<Handle, Arc<DynamicResource<Handle, Desc, Res>>>,
 
-    /// Any resource that has been deallocated last pass, potentially to be re-used in the next pass.
-    last_pass_deallocated: FxHashMap<Desc, RVec<Handle>>,
+    /// Any resource that has been deallocated, potentially to be re-used in the next passes.
+    /// Alongside the pass in which it was found idle, oldest first.
+    deallocated: FxHashMap<Desc, RVec<(Handle, u64)>>,
 }
 
 /// Generic resource pool for all resources that have varying contents beyond their description.
@@ -57,7 +78,9 @@ struct DynamicResourcePoolProtectedState<Handle: Key, Desc: Debug, Res> {
 pub(super) struct DynamicResourcePool<Handle: Key, Desc: Debug, Res> {
     state: RwLock<DynamicResourcePoolProtectedState<Handle, Desc, Res>>,
     current_pass_index: u64,
+    policy: EvictionPolicy,
     total_resource_size_in_bytes: AtomicU64,
+    peak_resource_size_in_bytes: AtomicU64,
 }
 
 /// We cannot #derive(Default) as that would require Handle/Desc/Res to implement Default too.
@@ -69,10 +92,12 @@ where
         Self {
             state: RwLock::new(DynamicResourcePoolProtectedState {
                 all_resources: Default::default(),
-                last_pass_deallocated: Default::default(),
+                deallocated: Default::default(),
             }),
             current_pass_index: Default::default(),
+            policy: Default::default(),
             total_resource_size_in_bytes: AtomicU64::new(0),
+            peak_resource_size_in_bytes: AtomicU64::new(0),
         }
     }
 }
@@ -91,9 +116,9 @@ where
 
         // First check if we can reclaim a resource we have around from a previous pass.
         if desc.allow_reuse() {
-            if let Entry::Occupied(mut entry) = state.last_pass_deallocated.entry(desc.clone()) {
+            if let Entry::Occupied(mut entry) = state.deallocated.entry(desc.clone()) {
                 log::debug!("Re-using resource {:?}", desc);
-                let handle = entry.get_mut().pop().unwrap();
+                let (handle, _) = entry.get_mut().pop().unwrap();
                 if entry.get().is_empty() {
                     entry.remove();
                 }
@@ -105,10 +130,12 @@ where
         // Otherwise create a new resource
         log::debug!("Creating new resource: {:?}", desc);
         let inner_resource = { constructor(desc) };
-        self.total_resource_size_in_bytes.fetch_add(
-            desc.resource_size_in_bytes(),
-            std::sync::atomic::Ordering::Relaxed,
-        );
+        let size = desc.resource_size_in_bytes();
+        let total = self
+            .total_resource_size_in_bytes
+            .fetch_add(size, std::sync::atomic::Ordering::Relaxed);
+        self.peak_resource_size_in_bytes
+            .fetch_max(total + size, std::sync::atomic::Ordering::Relaxed);
 
         let handle = state.all_resources.insert_with_key(|handle| {
             Arc::new(DynamicResource {
@@ -144,6 +171,7 @@ where
         D: FnMut(&Res),
     {
         self.current_pass_index = pass_index;
+        let policy = self.policy;
         let state = self.state.get_mut();
 
         let update_stats = |creation_desc: &Desc| {
@@ -153,44 +181,96 @@ where
             );
         };
 
-        // Throw out any resources that we haven't reclaimed last pass.
-        for (desc, resources) in state.last_pass_deallocated.drain() {
-            for resource in resources {
-                let Some(removed_resource) = state.all_resources.remove(resource) else {
-                    debug_assert!(false, "a resource was marked as destroyed last pass that we no longer kept track of");
-                    continue;
-                };
-                update_stats(&desc);
-                log::debug!("Dropping resource {:?}", desc);
-                destructor(&removed_resource);
-            }
-        }
-
         // If the strong count went down to 1, we must be the only ones holding on to handle.
-        // If that's the case, push it to the re-use-next-pass list or discard.
+        // If that's the case, push it to the re-use list or discard.
         //
         // thread safety:
         // Since the count is pushed from 1 to 2 by `alloc`, it should not be possible to ever
         // get temporarily get back down to 1 without dropping the last user available copy of the Arc<Handle>.
-        state.all_resources.retain(|_, resource| {
-            if Arc::strong_count(resource) == 1 {
-                if resource.descriptor.allow_reuse() {
-                    state
-                        .last_pass_deallocated
-                        .entry(resource.descriptor.clone())
-                        .or_default()
-                        .push(resource.handle);
-                    true
-                } else {
-                    update_stats(&resource.descriptor);
-                    log::debug!("Dropping resource {:?}", resource.descriptor);
-                    destructor(&resource.inner);
-                    false
-                }
-            } else {
+        let already_idle = state
+            .deallocated
+            .values()
+            .flatten()
+            .map(|&(handle, _)| handle)
+            .collect::<FxHashSet<_>>();
+        state.all_resources.retain(|handle, resource| {
+            if Arc::strong_count(resource) > 1 || already_idle.contains(&handle) {
+                return true;
+            }
+            if resource.descriptor.allow_reuse() {
+                state
+                    .deallocated
+                    .entry(resource.descriptor.clone())
+                    .or_default()
+                    .push((handle, pass_index));
                 true
+            } else {
+                update_stats(&resource.descriptor);
+                log::debug!("Dropping resource {:?}", resource.descriptor);
+                destructor(&resource.inner);
+                false
             }
         });
+
+        // Throw out any resources that have been idle for too long, or don't fit the budget.
+        let mut idle = state
+            .deallocated
+            .drain()
+            .flat_map(|(_, r)| r)
+            .collect::<Vec<_>>();
+        idle.sort_by_key(|&(_, since)| since);
+        let size_of = |handle| {
+            state.all_resources[handle]
+                .descriptor
+                .resource_size_in_bytes()
+        };
+        let mut pooled_bytes = idle.iter().map(|&(h, _)| size_of(h)).sum::<u64>();
+        for (handle, since) in idle {
+            let expired = pass_index.saturating_sub(since) >= policy.max_idle_passes;
+            let over_budget = matches!(policy.max_pooled_bytes, Some(max) if pooled_bytes > max);
+            let resource = &state.all_resources[handle];
+            if !expired && !over_budget {
+                state
+                    .deallocated
+                    .entry(resource.descriptor.clone())
+                    .or_default()
+                    .push((handle, since));
+                continue;
+            }
+            let removed_resource = state.all_resources.remove(handle).unwrap();
+            pooled_bytes -= removed_resource.descriptor.resource_size_in_bytes();
+            update_stats(&removed_resource.descriptor);
+            log::debug!("Dropping resource {:?}", removed_resource.descriptor);
+            destructor(&removed_resource.inner);
+        }
+    }
+
+    /// Destroys every resource that is only held by the pool, whether or not it was released
+    /// during the last pass. Returns the number of bytes freed.
+    pub fn trim<D>(&mut self, mut destructor: D) -> u64
+    where
+        D: FnMut(&Res),
+    {
+        let state = self.state.get_mut();
+        state.deallocated.clear();
+
+        let mut freed = 0;
+        state.all_resources.retain(|_, resource| {
+            if Arc::strong_count(resource) > 1 {
+                return true;
+            }
+            log::debug!("Trimming resource {:?}", resource.descriptor);
+            freed += resource.descriptor.resource_size_in_bytes();
+            destructor(&resource.inner);
+            false
+        });
+        self.total_resource_size_in_bytes
+            .fetch_sub(freed, std::sync::atomic::Ordering::Relaxed);
+        freed
+    }
+
+    pub fn set_eviction_policy(&mut self, policy: EvictionPolicy) {
+        self.policy = policy;
     }
 
     pub fn num_resources(&self) -> usize {
@@ -205,13 +285,19 @@ where
         self.total_resource_size_in_bytes
             .load(std::sync::atomic::Ordering::Relaxed)
     }
+
+    /// The most bytes the pool has held at once.
+    pub fn peak_resource_size_in_bytes(&self) -> u64 {
+        self.peak_resource_size_in_bytes
+            .load(std::sync::atomic::Ordering::Relaxed)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::{cell::Cell, sync::Arc};
 
-    use super::{DynamicResourcePool, DynamicResourcesDesc};
+    use super::{DynamicResourcePool, DynamicResourcesDesc, EvictionPolicy};
 
     slotmap::new_key_type! { pub struct ConcreteHandle; }
 
@@ -304,6 +390,75 @@ mod tests {
         }
     }
 
+    #[test]
+    fn trim_drops_idle_resources() {
+        let mut pool = Pool::default();
+        let kept = pool.get_or_create(&ConcreteResourceDesc(0), |_| ConcreteResource);
+        let released = pool.get_or_create(&ConcreteResourceDesc(1), |_| ConcreteResource);
+        drop(pool.get_or_create(&ConcreteResourceDesc(2), |_| ConcreteResource));
+        drop(released);
+        //One released before the pass, one after it
+        pool.begin_pass(1, |_| {});
+        assert_eq!(pool.peak_resource_size_in_bytes(), 3);
+
+        let mut destroyed = 0;
+        assert_eq!(pool.trim(|_| destroyed += 1), 2);
+        assert_eq!(destroyed, 2);
+        assert_eq!(pool.num_resources(), 1);
+        assert_eq!(pool.total_resource_size_in_bytes(), 1);
+        assert_eq!(pool.peak_resource_size_in_bytes(), 3);
+
+        //Trimmed resources are not handed out again
+        let created = Cell::new(false);
+        pool.get_or_create(&ConcreteResourceDesc(1), |_| {
+            created.set(true);
+            ConcreteResource
+        });
+        assert!(created.get());
+        drop(kept);
+    }
+
+    #[test]
+    fn evicts_after_max_idle_passes() {
+        let mut pool = Pool::default();
+        pool.set_eviction_policy(EvictionPolicy {
+            max_idle_passes: 3,
+            max_pooled_bytes: None,
+        });
+        drop(pool.get_or_create(&ConcreteResourceDesc(0), |_| ConcreteResource));
+
+        let mut destroyed = 0;
+        for pass_index in 1..=3 {
+            pool.begin_pass(pass_index, |_| destroyed += 1);
+            assert_eq!(destroyed, 0);
+        }
+        pool.begin_pass(4, |_| destroyed += 1);
+        assert_eq!(destroyed, 1);
+        assert_eq!(pool.num_resources(), 0);
+    }
+
+    #[test]
+    fn evicts_oldest_over_max_pooled_bytes() {
+        let mut pool = Pool::default();
+        pool.set_eviction_policy(EvictionPolicy {
+            max_idle_passes: u64::MAX,
+            max_pooled_bytes: Some(2),
+        });
+        let oldest = pool.get_or_create(&ConcreteResourceDesc(0), |_| ConcreteResource);
+        let oldest_handle = oldest.handle;
+        drop(oldest);
+        pool.begin_pass(1, |_| {});
+        for desc in [1, 2] {
+            drop(pool.get_or_create(&ConcreteResourceDesc(desc), |_| ConcreteResource));
+        }
+
+        let mut destroyed = 0;
+        pool.begin_pass(2, |_| destroyed += 1);
+        assert_eq!(destroyed, 1);
+        assert_eq!(pool.total_resource_size_in_bytes(), 2);
+        assert!(pool.get_from_handle(oldest_handle).is_err());
+    }
+
     // Two resources have two different handles.
     #[test]
     fn individual_handles() {
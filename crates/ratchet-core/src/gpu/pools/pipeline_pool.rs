@@ -1,10 +1,14 @@
 use std::borrow::Cow;
 
+use parking_lot::RwLock;
+use rustc_hash::FxHashSet;
+use serde::{Deserialize, Serialize};
+
 use crate::{gpu::WgpuDevice, KERNELS};
 
 use super::{
-    PipelineLayoutHandle, StaticResourcePool, StaticResourcePoolAccessor,
-    StaticResourcePoolReadLockAccessor,
+    BindGroupLayoutDescriptor, BindGroupLayoutEntryExt, PipelineLayoutHandle, StaticResourcePool,
+    StaticResourcePoolAccessor, StaticResourcePoolReadLockAccessor,
 };
 
 slotmap::new_key_type! { pub struct ComputePipelineHandle; }
@@ -16,18 +20,102 @@ pub struct ComputePipelineDescriptor {
     //aux_ctx: Option<RVec<(&'static str, u32)>>, Used for sizing SMEM
 }
 
+/// A compute pipeline, described independently of the process that created it.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct PipelineEntry {
+    pub kernel_key: String,
+    /// Binding index, and whether it is read only, of every storage buffer of the kernel.
+    pub storage_bindings: Vec<(u32, bool)>,
+}
+
+impl PipelineEntry {
+    /// `None` if the layout holds anything other than storage buffers.
+    pub(crate) fn new(kernel_key: &str, layout: &BindGroupLayoutDescriptor) -> Option<Self> {
+        let storage_bindings = layout
+            .entries
+            .iter()
+            .map(|entry| match entry.ty {
+                wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Storage { read_only },
+                    ..
+                } => Some((entry.binding, read_only)),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Some(Self {
+            kernel_key: kernel_key.to_string(),
+            storage_bindings,
+        })
+    }
+
+    pub(crate) fn storage_layout(&self) -> BindGroupLayoutDescriptor {
+        BindGroupLayoutDescriptor {
+            entries: self
+                .storage_bindings
+                .iter()
+                .map(|&(binding, read_only)| {
+                    wgpu::BindGroupLayoutEntry::compute_storage_buffer(binding, read_only)
+                })
+                .collect(),
+        }
+    }
+}
+
+/// # Pipeline Manifest
+///
+/// Every pipeline created on a device, so that a later process can create them all up front with
+/// [WgpuDevice::warm], rather than compiling each shader the first time it is dispatched.
+///
+/// wgpu doesn't expose the driver's pipeline cache, so the backend compilation still happens, but
+/// before the first inference instead of during it. A manifest is only used on the adapter it
+/// was recorded on.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PipelineManifest {
+    pub adapter: String,
+    pub pipelines: Vec<PipelineEntry>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl PipelineManifest {
+    pub fn load(path: impl AsRef<std::path::Path>) -> anyhow::Result<Self> {
+        let file = std::fs::File::open(path)?;
+        Ok(serde_json::from_reader(std::io::BufReader::new(file))?)
+    }
+
+    pub fn save(&self, path: impl AsRef<std::path::Path>) -> anyhow::Result<()> {
+        let file = std::fs::File::create(path)?;
+        serde_json::to_writer(std::io::BufWriter::new(file), self)?;
+        Ok(())
+    }
+}
+
 pub struct ComputePipelinePool {
     inner:
         StaticResourcePool<ComputePipelineHandle, ComputePipelineDescriptor, wgpu::ComputePipeline>,
+    recorded: RwLock<FxHashSet<PipelineEntry>>,
 }
 
 impl ComputePipelinePool {
     pub fn new() -> Self {
         Self {
             inner: StaticResourcePool::default(),
+            recorded: RwLock::new(FxHashSet::default()),
+        }
+    }
+
+    pub fn record(&self, entry: PipelineEntry) {
+        if !self.recorded.read().contains(&entry) {
+            self.recorded.write().insert(entry);
         }
     }
 
+    /// Every pipeline recorded so far, sorted so that manifests are stable across runs.
+    pub fn recorded(&self) -> Vec<PipelineEntry> {
+        let mut recorded = self.recorded.read().iter().cloned().collect::<Vec<_>>();
+        recorded.sort();
+        recorded
+    }
+
     pub fn get_or_create(
         &self,
         desc: &ComputePipelineDescriptor,
@@ -47,7 +135,7 @@ impl ComputePipelinePool {
             };
 
             //We don't cache shader modules because pipelines are cached
-            let module = if std::env::var("RATCHET_CHECKED").is_ok() {
+            let module = if crate::gpu::checked() {
                 log::warn!("Using checked shader compilation");
                 device.create_shader_module(shader_module_desc)
             } else {
@@ -75,3 +163,50 @@ impl ComputePipelinePool {
         self.inner.resources()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{PipelineEntry, PipelineManifest};
+    use crate::gpu::BindGroupLayoutDescriptor;
+
+    #[test]
+    fn test_manifest_roundtrip() -> anyhow::Result<()> {
+        let layout = BindGroupLayoutDescriptor::binary_inplace();
+        let entry = PipelineEntry::new("add_scalar", &layout).unwrap();
+        assert_eq!(entry.storage_layout(), layout);
+        assert!(PipelineEntry::new("add_scalar", &BindGroupLayoutDescriptor::uniform()).is_none());
+
+        let manifest = PipelineManifest {
+            adapter: "test".to_string(),
+            pipelines: vec![entry],
+        };
+        let path = std::env::temp_dir().join("ratchet_test_manifest.json");
+        manifest.save(&path)?;
+        assert_eq!(PipelineManifest::load(&path)?, manifest);
+        std::fs::remove_file(path)?;
+        Ok(())
+    }
+
+    #[cfg(feature = "pyo3")]
+    #[test]
+    fn test_warm() -> anyhow::Result<()> {
+        use crate::{shape, Device, DeviceRequest, Tensor};
+
+        let device = Device::request_device(DeviceRequest::GPU)?;
+        let x = Tensor::randn::<f32>(shape![8, 16], Device::CPU).to(&device)?;
+        let w = Tensor::randn::<f32>(shape![16, 32], Device::CPU).to(&device)?;
+        x.matmul(w, false, false)?.gelu()?.warm()?;
+
+        let gpu = device.try_gpu()?;
+        let manifest = gpu.pipeline_manifest();
+        assert!(!manifest.pipelines.is_empty());
+        assert_eq!(gpu.warm(&manifest)?, manifest.pipelines.len());
+
+        let foreign = PipelineManifest {
+            adapter: "elsewhere".to_string(),
+            ..manifest
+        };
+        assert_eq!(gpu.warm(&foreign)?, 0);
+        Ok(())
+    }
+}
@@ -61,6 +61,16 @@ impl GpuUniform {
     pub fn bind_group(&self) -> &GpuBindGroup {
         &self.bind_group
     }
+
+    /// Overwrites the metadata of every operation, e.g with the offsets of the next decoding
+    /// step. `uniform` must have been written by the same sequence of operations.
+    pub(crate) fn write(&self, uniform: CpuUniform, device: &WgpuDevice) {
+        let uniform = uniform.into_inner();
+        assert!(uniform.len() as u64 <= self.buf.size());
+        device
+            .queue()
+            .write_buffer(&self.buf, 0, uniform.as_slice());
+    }
 }
 
 impl std::ops::Deref for CpuUniform {
@@ -2665,10 +2665,6 @@ lazy_static! {
             "index_write_scalar",
             include_str!(r"../kernels/index_write_scalar.wgsl"),
         );
-        m.insert(
-            "wq8_index_select_scalar",
-            include_str!(r"../kernels/wq8_index_select_scalar.wgsl"),
-        );
         m.insert(
             "wq8_index_select_coarse_scalar",
             include_str!(r"../kernels/wq8_index_select_coarse_scalar.wgsl"),
@@ -2680,7 +2676,7 @@ lazy_static! {
         );
         m.insert(
             "softmax_vec2",
-            include_str!(r"../kernels/softmax_vec2.wgsl"),
+            include_str!(r"../kernels/generated/softmax_vec2.wgsl"),
         );
         m.insert(
             "broadcast_single_vec4",
@@ -2688,11 +2684,8340 @@ lazy_static! {
         );
         m.insert(
             "softmax_scalar",
-            include_str!(r"../kernels/softmax_scalar.wgsl"),
+            include_str!(r"../kernels/generated/softmax_scalar.wgsl"),
         );
         m.insert(
             "softmax_vec4",
-            include_str!(r"../kernels/softmax_vec4.wgsl"),
+            include_str!(r"../kernels/generated/softmax_vec4.wgsl"),
+        );
+        m.insert(
+            "reduce_sum_scalar",
+            include_str!(r"../kernels/generated/reduce_sum_scalar.wgsl"),
+        );
+        m.insert(
+            "reduce_mean_scalar",
+            include_str!(r"../kernels/generated/reduce_mean_scalar.wgsl"),
+        );
+        m.insert(
+            "reduce_max_scalar",
+            include_str!(r"../kernels/generated/reduce_max_scalar.wgsl"),
+        );
+        m.insert(
+            "reduce_min_scalar",
+            include_str!(r"../kernels/generated/reduce_min_scalar.wgsl"),
+        );
+        m.insert(
+            "reduce_argmax_scalar",
+            include_str!(r"../kernels/generated/reduce_argmax_scalar.wgsl"),
+        );
+        m.insert(
+            "reduce_argmin_scalar",
+            include_str!(r"../kernels/generated/reduce_argmin_scalar.wgsl"),
+        );
+        m.insert(
+            "eq_scalar",
+            include_str!(r"../kernels/generated/eq_scalar.wgsl"),
+        );
+        m.insert(
+            "eq_vec2",
+            include_str!(r"../kernels/generated/eq_vec2.wgsl"),
+        );
+        m.insert(
+            "eq_vec4",
+            include_str!(r"../kernels/generated/eq_vec4.wgsl"),
+        );
+        m.insert(
+            "ge_scalar",
+            include_str!(r"../kernels/generated/ge_scalar.wgsl"),
+        );
+        m.insert(
+            "ge_vec2",
+            include_str!(r"../kernels/generated/ge_vec2.wgsl"),
+        );
+        m.insert(
+            "ge_vec4",
+            include_str!(r"../kernels/generated/ge_vec4.wgsl"),
+        );
+        m.insert(
+            "gt_scalar",
+            include_str!(r"../kernels/generated/gt_scalar.wgsl"),
+        );
+        m.insert(
+            "gt_vec2",
+            include_str!(r"../kernels/generated/gt_vec2.wgsl"),
+        );
+        m.insert(
+            "gt_vec4",
+            include_str!(r"../kernels/generated/gt_vec4.wgsl"),
+        );
+        m.insert(
+            "le_scalar",
+            include_str!(r"../kernels/generated/le_scalar.wgsl"),
+        );
+        m.insert(
+            "le_vec2",
+            include_str!(r"../kernels/generated/le_vec2.wgsl"),
+        );
+        m.insert(
+            "le_vec4",
+            include_str!(r"../kernels/generated/le_vec4.wgsl"),
+        );
+        m.insert(
+            "lt_scalar",
+            include_str!(r"../kernels/generated/lt_scalar.wgsl"),
+        );
+        m.insert(
+            "lt_vec2",
+            include_str!(r"../kernels/generated/lt_vec2.wgsl"),
+        );
+        m.insert(
+            "lt_vec4",
+            include_str!(r"../kernels/generated/lt_vec4.wgsl"),
+        );
+        m.insert(
+            "ne_scalar",
+            include_str!(r"../kernels/generated/ne_scalar.wgsl"),
+        );
+        m.insert(
+            "ne_vec2",
+            include_str!(r"../kernels/generated/ne_vec2.wgsl"),
+        );
+        m.insert(
+            "ne_vec4",
+            include_str!(r"../kernels/generated/ne_vec4.wgsl"),
+        );
+        m.insert(
+            "tril_scalar",
+            include_str!(r"../kernels/generated/tril_scalar.wgsl"),
+        );
+        m.insert(
+            "triu_scalar",
+            include_str!(r"../kernels/generated/triu_scalar.wgsl"),
+        );
+        m.insert(
+            "where_cond_scalar",
+            include_str!(r"../kernels/generated/where_cond_scalar.wgsl"),
+        );
+        m.insert(
+            "where_cond_vec2",
+            include_str!(r"../kernels/generated/where_cond_vec2.wgsl"),
+        );
+        m.insert(
+            "where_cond_vec4",
+            include_str!(r"../kernels/generated/where_cond_vec4.wgsl"),
+        );
+        m.insert(
+            "cast_bf16_f16_packed",
+            include_str!(r"../kernels/generated/cast_bf16_f16_packed.wgsl"),
+        );
+        m.insert(
+            "cast_bf16_f32_packed",
+            include_str!(r"../kernels/generated/cast_bf16_f32_packed.wgsl"),
+        );
+        m.insert(
+            "cast_bf16_i32_packed",
+            include_str!(r"../kernels/generated/cast_bf16_i32_packed.wgsl"),
+        );
+        m.insert(
+            "cast_bf16_u32_packed",
+            include_str!(r"../kernels/generated/cast_bf16_u32_packed.wgsl"),
+        );
+        m.insert(
+            "cast_f16_bf16_packed",
+            include_str!(r"../kernels/generated/cast_f16_bf16_packed.wgsl"),
+        );
+        m.insert(
+            "cast_f16_f32_packed",
+            include_str!(r"../kernels/generated/cast_f16_f32_packed.wgsl"),
+        );
+        m.insert(
+            "cast_f16_f32_scalar",
+            include_str!(r"../kernels/generated/cast_f16_f32_scalar.wgsl"),
+        );
+        m.insert(
+            "cast_f16_f32_vec2",
+            include_str!(r"../kernels/generated/cast_f16_f32_vec2.wgsl"),
+        );
+        m.insert(
+            "cast_f16_f32_vec4",
+            include_str!(r"../kernels/generated/cast_f16_f32_vec4.wgsl"),
+        );
+        m.insert(
+            "cast_f16_i32_packed",
+            include_str!(r"../kernels/generated/cast_f16_i32_packed.wgsl"),
+        );
+        m.insert(
+            "cast_f16_i32_scalar",
+            include_str!(r"../kernels/generated/cast_f16_i32_scalar.wgsl"),
+        );
+        m.insert(
+            "cast_f16_i32_vec2",
+            include_str!(r"../kernels/generated/cast_f16_i32_vec2.wgsl"),
+        );
+        m.insert(
+            "cast_f16_i32_vec4",
+            include_str!(r"../kernels/generated/cast_f16_i32_vec4.wgsl"),
+        );
+        m.insert(
+            "cast_f16_u32_packed",
+            include_str!(r"../kernels/generated/cast_f16_u32_packed.wgsl"),
+        );
+        m.insert(
+            "cast_f16_u32_scalar",
+            include_str!(r"../kernels/generated/cast_f16_u32_scalar.wgsl"),
+        );
+        m.insert(
+            "cast_f16_u32_vec2",
+            include_str!(r"../kernels/generated/cast_f16_u32_vec2.wgsl"),
+        );
+        m.insert(
+            "cast_f16_u32_vec4",
+            include_str!(r"../kernels/generated/cast_f16_u32_vec4.wgsl"),
+        );
+        m.insert(
+            "cast_f32_bf16_packed",
+            include_str!(r"../kernels/generated/cast_f32_bf16_packed.wgsl"),
+        );
+        m.insert(
+            "cast_f32_f16_packed",
+            include_str!(r"../kernels/generated/cast_f32_f16_packed.wgsl"),
+        );
+        m.insert(
+            "cast_f32_f16_scalar",
+            include_str!(r"../kernels/generated/cast_f32_f16_scalar.wgsl"),
+        );
+        m.insert(
+            "cast_f32_f16_vec2",
+            include_str!(r"../kernels/generated/cast_f32_f16_vec2.wgsl"),
+        );
+        m.insert(
+            "cast_f32_f16_vec4",
+            include_str!(r"../kernels/generated/cast_f32_f16_vec4.wgsl"),
+        );
+        m.insert(
+            "cast_f32_i32_scalar",
+            include_str!(r"../kernels/generated/cast_f32_i32_scalar.wgsl"),
+        );
+        m.insert(
+            "cast_f32_i32_vec2",
+            include_str!(r"../kernels/generated/cast_f32_i32_vec2.wgsl"),
+        );
+        m.insert(
+            "cast_f32_i32_vec4",
+            include_str!(r"../kernels/generated/cast_f32_i32_vec4.wgsl"),
+        );
+        m.insert(
+            "cast_f32_u32_scalar",
+            include_str!(r"../kernels/generated/cast_f32_u32_scalar.wgsl"),
+        );
+        m.insert(
+            "cast_f32_u32_vec2",
+            include_str!(r"../kernels/generated/cast_f32_u32_vec2.wgsl"),
+        );
+        m.insert(
+            "cast_f32_u32_vec4",
+            include_str!(r"../kernels/generated/cast_f32_u32_vec4.wgsl"),
+        );
+        m.insert(
+            "cast_i32_bf16_packed",
+            include_str!(r"../kernels/generated/cast_i32_bf16_packed.wgsl"),
+        );
+        m.insert(
+            "cast_i32_f16_packed",
+            include_str!(r"../kernels/generated/cast_i32_f16_packed.wgsl"),
+        );
+        m.insert(
+            "cast_i32_f16_scalar",
+            include_str!(r"../kernels/generated/cast_i32_f16_scalar.wgsl"),
+        );
+        m.insert(
+            "cast_i32_f16_vec2",
+            include_str!(r"../kernels/generated/cast_i32_f16_vec2.wgsl"),
+        );
+        m.insert(
+            "cast_i32_f16_vec4",
+            include_str!(r"../kernels/generated/cast_i32_f16_vec4.wgsl"),
+        );
+        m.insert(
+            "cast_i32_f32_scalar",
+            include_str!(r"../kernels/generated/cast_i32_f32_scalar.wgsl"),
+        );
+        m.insert(
+            "cast_i32_f32_vec2",
+            include_str!(r"../kernels/generated/cast_i32_f32_vec2.wgsl"),
+        );
+        m.insert(
+            "cast_i32_f32_vec4",
+            include_str!(r"../kernels/generated/cast_i32_f32_vec4.wgsl"),
+        );
+        m.insert(
+            "cast_i32_u32_scalar",
+            include_str!(r"../kernels/generated/cast_i32_u32_scalar.wgsl"),
+        );
+        m.insert(
+            "cast_i32_u32_vec2",
+            include_str!(r"../kernels/generated/cast_i32_u32_vec2.wgsl"),
+        );
+        m.insert(
+            "cast_i32_u32_vec4",
+            include_str!(r"../kernels/generated/cast_i32_u32_vec4.wgsl"),
+        );
+        m.insert(
+            "cast_u32_bf16_packed",
+            include_str!(r"../kernels/generated/cast_u32_bf16_packed.wgsl"),
+        );
+        m.insert(
+            "cast_u32_f16_packed",
+            include_str!(r"../kernels/generated/cast_u32_f16_packed.wgsl"),
+        );
+        m.insert(
+            "cast_u32_f16_scalar",
+            include_str!(r"../kernels/generated/cast_u32_f16_scalar.wgsl"),
+        );
+        m.insert(
+            "cast_u32_f16_vec2",
+            include_str!(r"../kernels/generated/cast_u32_f16_vec2.wgsl"),
+        );
+        m.insert(
+            "cast_u32_f16_vec4",
+            include_str!(r"../kernels/generated/cast_u32_f16_vec4.wgsl"),
+        );
+        m.insert(
+            "cast_u32_f32_scalar",
+            include_str!(r"../kernels/generated/cast_u32_f32_scalar.wgsl"),
+        );
+        m.insert(
+            "cast_u32_f32_vec2",
+            include_str!(r"../kernels/generated/cast_u32_f32_vec2.wgsl"),
+        );
+        m.insert(
+            "cast_u32_f32_vec4",
+            include_str!(r"../kernels/generated/cast_u32_f32_vec4.wgsl"),
+        );
+        m.insert(
+            "cast_u32_i32_scalar",
+            include_str!(r"../kernels/generated/cast_u32_i32_scalar.wgsl"),
+        );
+        m.insert(
+            "cast_u32_i32_vec2",
+            include_str!(r"../kernels/generated/cast_u32_i32_vec2.wgsl"),
+        );
+        m.insert(
+            "cast_u32_i32_vec4",
+            include_str!(r"../kernels/generated/cast_u32_i32_vec4.wgsl"),
+        );
+        m.insert(
+            "abs_inplace_scalar_f16",
+            include_str!(r"../kernels/generated/abs_inplace_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "abs_inplace_vec2_f16",
+            include_str!(r"../kernels/generated/abs_inplace_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "abs_inplace_vec4_f16",
+            include_str!(r"../kernels/generated/abs_inplace_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "abs_scalar_f16",
+            include_str!(r"../kernels/generated/abs_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "abs_vec2_f16",
+            include_str!(r"../kernels/generated/abs_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "abs_vec4_f16",
+            include_str!(r"../kernels/generated/abs_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "add_inplace_scalar_f16",
+            include_str!(r"../kernels/generated/add_inplace_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "add_inplace_vec2_f16",
+            include_str!(r"../kernels/generated/add_inplace_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "add_inplace_vec4_f16",
+            include_str!(r"../kernels/generated/add_inplace_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "add_scalar_f16",
+            include_str!(r"../kernels/generated/add_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "add_vec2_f16",
+            include_str!(r"../kernels/generated/add_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "add_vec4_f16",
+            include_str!(r"../kernels/generated/add_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "broadcast_scalar_f16",
+            include_str!(r"../kernels/generated/broadcast_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "broadcast_vec2_f16",
+            include_str!(r"../kernels/generated/broadcast_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "broadcast_vec4_f16",
+            include_str!(r"../kernels/generated/broadcast_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "ceil_inplace_scalar_f16",
+            include_str!(r"../kernels/generated/ceil_inplace_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "ceil_inplace_vec2_f16",
+            include_str!(r"../kernels/generated/ceil_inplace_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "ceil_inplace_vec4_f16",
+            include_str!(r"../kernels/generated/ceil_inplace_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "ceil_scalar_f16",
+            include_str!(r"../kernels/generated/ceil_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "ceil_vec2_f16",
+            include_str!(r"../kernels/generated/ceil_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "ceil_vec4_f16",
+            include_str!(r"../kernels/generated/ceil_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "cos_inplace_scalar_f16",
+            include_str!(r"../kernels/generated/cos_inplace_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "cos_inplace_vec2_f16",
+            include_str!(r"../kernels/generated/cos_inplace_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "cos_inplace_vec4_f16",
+            include_str!(r"../kernels/generated/cos_inplace_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "cos_scalar_f16",
+            include_str!(r"../kernels/generated/cos_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "cos_vec2_f16",
+            include_str!(r"../kernels/generated/cos_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "cos_vec4_f16",
+            include_str!(r"../kernels/generated/cos_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "div_inplace_scalar_f16",
+            include_str!(r"../kernels/generated/div_inplace_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "div_inplace_vec2_f16",
+            include_str!(r"../kernels/generated/div_inplace_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "div_inplace_vec4_f16",
+            include_str!(r"../kernels/generated/div_inplace_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "div_scalar_f16",
+            include_str!(r"../kernels/generated/div_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "div_vec2_f16",
+            include_str!(r"../kernels/generated/div_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "div_vec4_f16",
+            include_str!(r"../kernels/generated/div_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "exp_inplace_scalar_f16",
+            include_str!(r"../kernels/generated/exp_inplace_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "exp_inplace_vec2_f16",
+            include_str!(r"../kernels/generated/exp_inplace_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "exp_inplace_vec4_f16",
+            include_str!(r"../kernels/generated/exp_inplace_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "exp_scalar_f16",
+            include_str!(r"../kernels/generated/exp_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "exp_vec2_f16",
+            include_str!(r"../kernels/generated/exp_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "exp_vec4_f16",
+            include_str!(r"../kernels/generated/exp_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "floor_inplace_scalar_f16",
+            include_str!(r"../kernels/generated/floor_inplace_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "floor_inplace_vec2_f16",
+            include_str!(r"../kernels/generated/floor_inplace_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "floor_inplace_vec4_f16",
+            include_str!(r"../kernels/generated/floor_inplace_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "floor_scalar_f16",
+            include_str!(r"../kernels/generated/floor_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "floor_vec2_f16",
+            include_str!(r"../kernels/generated/floor_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "floor_vec4_f16",
+            include_str!(r"../kernels/generated/floor_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "gelu_inplace_scalar_f16",
+            include_str!(r"../kernels/generated/gelu_inplace_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "gelu_inplace_vec2_f16",
+            include_str!(r"../kernels/generated/gelu_inplace_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "gelu_inplace_vec4_f16",
+            include_str!(r"../kernels/generated/gelu_inplace_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "gelu_scalar_f16",
+            include_str!(r"../kernels/generated/gelu_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "gelu_vec2_f16",
+            include_str!(r"../kernels/generated/gelu_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "gelu_vec4_f16",
+            include_str!(r"../kernels/generated/gelu_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "hgemm_false_false_false_false_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_false_false_false_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_false_false_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_false_false_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_false_false_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_false_false_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_false_false_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_false_false_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_false_false_false_true_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_false_false_false_true_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_false_false_false_true_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_false_false_false_true_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_false_false_false_true_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_false_false_false_true_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_false_false_false_true_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_false_false_false_true_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_false_false_false_vec4",
+            include_str!(r"../kernels/generated/hgemm_false_false_false_false_vec4.wgsl"),
+        );
+        m.insert(
+            "hgemm_false_false_false_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_false_false_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_false_false_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_false_false_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_false_false_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_false_false_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_false_false_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_false_false_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_false_false_true_true_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_false_false_true_true_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_false_false_true_true_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_false_false_true_true_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_false_false_true_true_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_false_false_true_true_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_false_false_true_true_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_false_false_true_true_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_false_false_true_vec4",
+            include_str!(r"../kernels/generated/hgemm_false_false_false_true_vec4.wgsl"),
+        );
+        m.insert(
+            "hgemm_false_false_true_false_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_false_true_false_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_false_true_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_false_true_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_false_true_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_false_true_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_false_true_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_false_true_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_false_true_false_true_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_false_true_false_true_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_false_true_false_true_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_false_true_false_true_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_false_true_false_true_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_false_true_false_true_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_false_true_false_true_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_false_true_false_true_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_false_true_false_vec4",
+            include_str!(r"../kernels/generated/hgemm_false_false_true_false_vec4.wgsl"),
+        );
+        m.insert(
+            "hgemm_false_false_true_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_false_true_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_false_true_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_false_true_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_false_true_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_false_true_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_false_true_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_false_true_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_false_true_true_true_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_false_true_true_true_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_false_true_true_true_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_false_true_true_true_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_false_true_true_true_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_false_true_true_true_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_false_true_true_true_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_false_true_true_true_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_false_true_true_vec4",
+            include_str!(r"../kernels/generated/hgemm_false_false_true_true_vec4.wgsl"),
+        );
+        m.insert(
+            "hgemm_false_true_false_false_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_true_false_false_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_true_false_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_true_false_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_true_false_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_true_false_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_true_false_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_true_false_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_true_false_false_true_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_true_false_false_true_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_true_false_false_true_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_true_false_false_true_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_true_false_false_true_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_true_false_false_true_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_true_false_false_true_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_true_false_false_true_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_true_false_false_vec4",
+            include_str!(r"../kernels/generated/hgemm_false_true_false_false_vec4.wgsl"),
+        );
+        m.insert(
+            "hgemm_false_true_false_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_true_false_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_true_false_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_true_false_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_true_false_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_true_false_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_true_false_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_true_false_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_true_false_true_true_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_true_false_true_true_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_true_false_true_true_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_true_false_true_true_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_true_false_true_true_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_true_false_true_true_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_true_false_true_true_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_true_false_true_true_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_true_false_true_vec4",
+            include_str!(r"../kernels/generated/hgemm_false_true_false_true_vec4.wgsl"),
+        );
+        m.insert(
+            "hgemm_false_true_true_false_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_true_true_false_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_true_true_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_true_true_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_true_true_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_true_true_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_true_true_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_true_true_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_true_true_false_true_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_true_true_false_true_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_true_true_false_true_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_true_true_false_true_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_true_true_false_true_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_true_true_false_true_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_true_true_false_true_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_true_true_false_true_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_true_true_false_vec4",
+            include_str!(r"../kernels/generated/hgemm_false_true_true_false_vec4.wgsl"),
+        );
+        m.insert(
+            "hgemm_false_true_true_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_true_true_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_true_true_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_true_true_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_true_true_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_true_true_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_true_true_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_true_true_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_true_true_true_true_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_true_true_true_true_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_true_true_true_true_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_true_true_true_true_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_true_true_true_true_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_true_true_true_true_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_true_true_true_true_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_false_true_true_true_true_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_false_true_true_true_vec4",
+            include_str!(r"../kernels/generated/hgemm_false_true_true_true_vec4.wgsl"),
+        );
+        m.insert(
+            "hgemm_true_false_false_false_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_false_false_false_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_false_false_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_false_false_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_false_false_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_false_false_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_false_false_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_false_false_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_false_false_false_true_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_false_false_false_true_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_false_false_false_true_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_false_false_false_true_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_false_false_false_true_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_false_false_false_true_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_false_false_false_true_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_false_false_false_true_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_false_false_false_vec4",
+            include_str!(r"../kernels/generated/hgemm_true_false_false_false_vec4.wgsl"),
+        );
+        m.insert(
+            "hgemm_true_false_false_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_false_false_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_false_false_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_false_false_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_false_false_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_false_false_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_false_false_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_false_false_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_false_false_true_true_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_false_false_true_true_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_false_false_true_true_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_false_false_true_true_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_false_false_true_true_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_false_false_true_true_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_false_false_true_true_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_false_false_true_true_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_false_false_true_vec4",
+            include_str!(r"../kernels/generated/hgemm_true_false_false_true_vec4.wgsl"),
+        );
+        m.insert(
+            "hgemm_true_false_true_false_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_false_true_false_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_false_true_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_false_true_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_false_true_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_false_true_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_false_true_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_false_true_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_false_true_false_true_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_false_true_false_true_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_false_true_false_true_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_false_true_false_true_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_false_true_false_true_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_false_true_false_true_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_false_true_false_true_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_false_true_false_true_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_false_true_false_vec4",
+            include_str!(r"../kernels/generated/hgemm_true_false_true_false_vec4.wgsl"),
+        );
+        m.insert(
+            "hgemm_true_false_true_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_false_true_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_false_true_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_false_true_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_false_true_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_false_true_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_false_true_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_false_true_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_false_true_true_true_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_false_true_true_true_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_false_true_true_true_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_false_true_true_true_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_false_true_true_true_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_false_true_true_true_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_false_true_true_true_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_false_true_true_true_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_false_true_true_vec4",
+            include_str!(r"../kernels/generated/hgemm_true_false_true_true_vec4.wgsl"),
+        );
+        m.insert(
+            "hgemm_true_true_false_false_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_true_false_false_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_true_false_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_true_false_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_true_false_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_true_false_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_true_false_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_true_false_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_true_false_false_true_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_true_false_false_true_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_true_false_false_true_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_true_false_false_true_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_true_false_false_true_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_true_false_false_true_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_true_false_false_true_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_true_false_false_true_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_true_false_false_vec4",
+            include_str!(r"../kernels/generated/hgemm_true_true_false_false_vec4.wgsl"),
+        );
+        m.insert(
+            "hgemm_true_true_false_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_true_false_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_true_false_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_true_false_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_true_false_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_true_false_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_true_false_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_true_false_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_true_false_true_true_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_true_false_true_true_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_true_false_true_true_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_true_false_true_true_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_true_false_true_true_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_true_false_true_true_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_true_false_true_true_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_true_false_true_true_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_true_false_true_vec4",
+            include_str!(r"../kernels/generated/hgemm_true_true_false_true_vec4.wgsl"),
+        );
+        m.insert(
+            "hgemm_true_true_true_false_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_true_true_false_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_true_true_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_true_true_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_true_true_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_true_true_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_true_true_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_true_true_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_true_true_false_true_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_true_true_false_true_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_true_true_false_true_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_true_true_false_true_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_true_true_false_true_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_true_true_false_true_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_true_true_false_true_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_true_true_false_true_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_true_true_false_vec4",
+            include_str!(r"../kernels/generated/hgemm_true_true_true_false_vec4.wgsl"),
+        );
+        m.insert(
+            "hgemm_true_true_true_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_true_true_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_true_true_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_true_true_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_true_true_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_true_true_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_true_true_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_true_true_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_true_true_true_true_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_true_true_true_true_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_true_true_true_true_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_true_true_true_true_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_true_true_true_true_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_true_true_true_true_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_true_true_true_true_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/hgemm_true_true_true_true_true_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "hgemm_true_true_true_true_vec4",
+            include_str!(r"../kernels/generated/hgemm_true_true_true_true_vec4.wgsl"),
+        );
+        m.insert(
+            "hgemv_false_16_16_false_scalar",
+            include_str!(r"../kernels/generated/hgemv_false_16_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_false_16_16_true_scalar",
+            include_str!(r"../kernels/generated/hgemv_false_16_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_false_16_32_false_scalar",
+            include_str!(r"../kernels/generated/hgemv_false_16_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_false_16_32_true_scalar",
+            include_str!(r"../kernels/generated/hgemv_false_16_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_false_16_4_false_scalar",
+            include_str!(r"../kernels/generated/hgemv_false_16_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_false_16_4_true_scalar",
+            include_str!(r"../kernels/generated/hgemv_false_16_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_false_16_8_false_scalar",
+            include_str!(r"../kernels/generated/hgemv_false_16_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_false_16_8_true_scalar",
+            include_str!(r"../kernels/generated/hgemv_false_16_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_false_32_16_false_scalar",
+            include_str!(r"../kernels/generated/hgemv_false_32_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_false_32_16_true_scalar",
+            include_str!(r"../kernels/generated/hgemv_false_32_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_false_32_32_false_scalar",
+            include_str!(r"../kernels/generated/hgemv_false_32_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_false_32_32_true_scalar",
+            include_str!(r"../kernels/generated/hgemv_false_32_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_false_32_4_false_scalar",
+            include_str!(r"../kernels/generated/hgemv_false_32_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_false_32_4_true_scalar",
+            include_str!(r"../kernels/generated/hgemv_false_32_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_false_32_8_false_scalar",
+            include_str!(r"../kernels/generated/hgemv_false_32_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_false_32_8_true_scalar",
+            include_str!(r"../kernels/generated/hgemv_false_32_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_false_4_16_false_scalar",
+            include_str!(r"../kernels/generated/hgemv_false_4_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_false_4_16_true_scalar",
+            include_str!(r"../kernels/generated/hgemv_false_4_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_false_4_256_false_scalar",
+            include_str!(r"../kernels/generated/hgemv_false_4_256_false_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_false_4_256_true_scalar",
+            include_str!(r"../kernels/generated/hgemv_false_4_256_true_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_false_4_32_false_scalar",
+            include_str!(r"../kernels/generated/hgemv_false_4_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_false_4_32_true_scalar",
+            include_str!(r"../kernels/generated/hgemv_false_4_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_false_4_4_false_scalar",
+            include_str!(r"../kernels/generated/hgemv_false_4_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_false_4_4_true_scalar",
+            include_str!(r"../kernels/generated/hgemv_false_4_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_false_4_8_false_scalar",
+            include_str!(r"../kernels/generated/hgemv_false_4_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_false_4_8_true_scalar",
+            include_str!(r"../kernels/generated/hgemv_false_4_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_false_64_16_false_scalar",
+            include_str!(r"../kernels/generated/hgemv_false_64_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_false_64_16_true_scalar",
+            include_str!(r"../kernels/generated/hgemv_false_64_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_false_64_4_false_scalar",
+            include_str!(r"../kernels/generated/hgemv_false_64_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_false_64_4_true_scalar",
+            include_str!(r"../kernels/generated/hgemv_false_64_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_false_64_8_false_scalar",
+            include_str!(r"../kernels/generated/hgemv_false_64_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_false_64_8_true_scalar",
+            include_str!(r"../kernels/generated/hgemv_false_64_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_false_8_16_false_scalar",
+            include_str!(r"../kernels/generated/hgemv_false_8_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_false_8_16_true_scalar",
+            include_str!(r"../kernels/generated/hgemv_false_8_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_false_8_32_false_scalar",
+            include_str!(r"../kernels/generated/hgemv_false_8_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_false_8_32_true_scalar",
+            include_str!(r"../kernels/generated/hgemv_false_8_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_false_8_4_false_scalar",
+            include_str!(r"../kernels/generated/hgemv_false_8_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_false_8_4_true_scalar",
+            include_str!(r"../kernels/generated/hgemv_false_8_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_false_8_8_false_scalar",
+            include_str!(r"../kernels/generated/hgemv_false_8_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_false_8_8_true_scalar",
+            include_str!(r"../kernels/generated/hgemv_false_8_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_true_16_16_false_scalar",
+            include_str!(r"../kernels/generated/hgemv_true_16_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_true_16_16_true_scalar",
+            include_str!(r"../kernels/generated/hgemv_true_16_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_true_16_32_false_scalar",
+            include_str!(r"../kernels/generated/hgemv_true_16_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_true_16_32_true_scalar",
+            include_str!(r"../kernels/generated/hgemv_true_16_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_true_16_4_false_scalar",
+            include_str!(r"../kernels/generated/hgemv_true_16_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_true_16_4_true_scalar",
+            include_str!(r"../kernels/generated/hgemv_true_16_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_true_16_8_false_scalar",
+            include_str!(r"../kernels/generated/hgemv_true_16_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_true_16_8_true_scalar",
+            include_str!(r"../kernels/generated/hgemv_true_16_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_true_32_16_false_scalar",
+            include_str!(r"../kernels/generated/hgemv_true_32_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_true_32_16_true_scalar",
+            include_str!(r"../kernels/generated/hgemv_true_32_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_true_32_32_false_scalar",
+            include_str!(r"../kernels/generated/hgemv_true_32_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_true_32_32_true_scalar",
+            include_str!(r"../kernels/generated/hgemv_true_32_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_true_32_4_false_scalar",
+            include_str!(r"../kernels/generated/hgemv_true_32_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_true_32_4_true_scalar",
+            include_str!(r"../kernels/generated/hgemv_true_32_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_true_32_8_false_scalar",
+            include_str!(r"../kernels/generated/hgemv_true_32_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_true_32_8_true_scalar",
+            include_str!(r"../kernels/generated/hgemv_true_32_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_true_4_16_false_scalar",
+            include_str!(r"../kernels/generated/hgemv_true_4_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_true_4_16_true_scalar",
+            include_str!(r"../kernels/generated/hgemv_true_4_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_true_4_256_false_scalar",
+            include_str!(r"../kernels/generated/hgemv_true_4_256_false_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_true_4_256_true_scalar",
+            include_str!(r"../kernels/generated/hgemv_true_4_256_true_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_true_4_32_false_scalar",
+            include_str!(r"../kernels/generated/hgemv_true_4_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_true_4_32_true_scalar",
+            include_str!(r"../kernels/generated/hgemv_true_4_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_true_4_4_false_scalar",
+            include_str!(r"../kernels/generated/hgemv_true_4_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_true_4_4_true_scalar",
+            include_str!(r"../kernels/generated/hgemv_true_4_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_true_4_8_false_scalar",
+            include_str!(r"../kernels/generated/hgemv_true_4_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_true_4_8_true_scalar",
+            include_str!(r"../kernels/generated/hgemv_true_4_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_true_64_16_false_scalar",
+            include_str!(r"../kernels/generated/hgemv_true_64_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_true_64_16_true_scalar",
+            include_str!(r"../kernels/generated/hgemv_true_64_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_true_64_4_false_scalar",
+            include_str!(r"../kernels/generated/hgemv_true_64_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_true_64_4_true_scalar",
+            include_str!(r"../kernels/generated/hgemv_true_64_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_true_64_8_false_scalar",
+            include_str!(r"../kernels/generated/hgemv_true_64_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_true_64_8_true_scalar",
+            include_str!(r"../kernels/generated/hgemv_true_64_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_true_8_16_false_scalar",
+            include_str!(r"../kernels/generated/hgemv_true_8_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_true_8_16_true_scalar",
+            include_str!(r"../kernels/generated/hgemv_true_8_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_true_8_32_false_scalar",
+            include_str!(r"../kernels/generated/hgemv_true_8_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_true_8_32_true_scalar",
+            include_str!(r"../kernels/generated/hgemv_true_8_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_true_8_4_false_scalar",
+            include_str!(r"../kernels/generated/hgemv_true_8_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_true_8_4_true_scalar",
+            include_str!(r"../kernels/generated/hgemv_true_8_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_true_8_8_false_scalar",
+            include_str!(r"../kernels/generated/hgemv_true_8_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "hgemv_true_8_8_true_scalar",
+            include_str!(r"../kernels/generated/hgemv_true_8_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "layernorm_scalar_f16",
+            include_str!(r"../kernels/generated/layernorm_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "layernorm_vec2_f16",
+            include_str!(r"../kernels/generated/layernorm_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "layernorm_vec4_f16",
+            include_str!(r"../kernels/generated/layernorm_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "log_inplace_scalar_f16",
+            include_str!(r"../kernels/generated/log_inplace_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "log_inplace_vec2_f16",
+            include_str!(r"../kernels/generated/log_inplace_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "log_inplace_vec4_f16",
+            include_str!(r"../kernels/generated/log_inplace_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "log_scalar_f16",
+            include_str!(r"../kernels/generated/log_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "log_vec2_f16",
+            include_str!(r"../kernels/generated/log_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "log_vec4_f16",
+            include_str!(r"../kernels/generated/log_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "mul_inplace_scalar_f16",
+            include_str!(r"../kernels/generated/mul_inplace_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "mul_inplace_vec2_f16",
+            include_str!(r"../kernels/generated/mul_inplace_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "mul_inplace_vec4_f16",
+            include_str!(r"../kernels/generated/mul_inplace_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "mul_scalar_f16",
+            include_str!(r"../kernels/generated/mul_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "mul_vec2_f16",
+            include_str!(r"../kernels/generated/mul_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "mul_vec4_f16",
+            include_str!(r"../kernels/generated/mul_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "neg_inplace_scalar_f16",
+            include_str!(r"../kernels/generated/neg_inplace_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "neg_inplace_vec2_f16",
+            include_str!(r"../kernels/generated/neg_inplace_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "neg_inplace_vec4_f16",
+            include_str!(r"../kernels/generated/neg_inplace_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "neg_scalar_f16",
+            include_str!(r"../kernels/generated/neg_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "neg_vec2_f16",
+            include_str!(r"../kernels/generated/neg_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "neg_vec4_f16",
+            include_str!(r"../kernels/generated/neg_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "permute_scalar_f16",
+            include_str!(r"../kernels/generated/permute_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "relu_inplace_scalar_f16",
+            include_str!(r"../kernels/generated/relu_inplace_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "relu_inplace_vec2_f16",
+            include_str!(r"../kernels/generated/relu_inplace_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "relu_inplace_vec4_f16",
+            include_str!(r"../kernels/generated/relu_inplace_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "relu_scalar_f16",
+            include_str!(r"../kernels/generated/relu_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "relu_vec2_f16",
+            include_str!(r"../kernels/generated/relu_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "relu_vec4_f16",
+            include_str!(r"../kernels/generated/relu_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "sin_inplace_scalar_f16",
+            include_str!(r"../kernels/generated/sin_inplace_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "sin_inplace_vec2_f16",
+            include_str!(r"../kernels/generated/sin_inplace_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "sin_inplace_vec4_f16",
+            include_str!(r"../kernels/generated/sin_inplace_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "sin_scalar_f16",
+            include_str!(r"../kernels/generated/sin_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "sin_vec2_f16",
+            include_str!(r"../kernels/generated/sin_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "sin_vec4_f16",
+            include_str!(r"../kernels/generated/sin_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "slice_scalar_f16",
+            include_str!(r"../kernels/generated/slice_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "softmax_scalar_f16",
+            include_str!(r"../kernels/generated/softmax_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "softmax_vec2_f16",
+            include_str!(r"../kernels/generated/softmax_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "softmax_vec4_f16",
+            include_str!(r"../kernels/generated/softmax_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "sqrt_inplace_scalar_f16",
+            include_str!(r"../kernels/generated/sqrt_inplace_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "sqrt_inplace_vec2_f16",
+            include_str!(r"../kernels/generated/sqrt_inplace_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "sqrt_inplace_vec4_f16",
+            include_str!(r"../kernels/generated/sqrt_inplace_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "sqrt_scalar_f16",
+            include_str!(r"../kernels/generated/sqrt_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "sqrt_vec2_f16",
+            include_str!(r"../kernels/generated/sqrt_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "sqrt_vec4_f16",
+            include_str!(r"../kernels/generated/sqrt_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "sub_inplace_scalar_f16",
+            include_str!(r"../kernels/generated/sub_inplace_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "sub_inplace_vec2_f16",
+            include_str!(r"../kernels/generated/sub_inplace_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "sub_inplace_vec4_f16",
+            include_str!(r"../kernels/generated/sub_inplace_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "sub_scalar_f16",
+            include_str!(r"../kernels/generated/sub_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "sub_vec2_f16",
+            include_str!(r"../kernels/generated/sub_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "sub_vec4_f16",
+            include_str!(r"../kernels/generated/sub_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "tanh_inplace_scalar_f16",
+            include_str!(r"../kernels/generated/tanh_inplace_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "tanh_inplace_vec2_f16",
+            include_str!(r"../kernels/generated/tanh_inplace_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "tanh_inplace_vec4_f16",
+            include_str!(r"../kernels/generated/tanh_inplace_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "tanh_scalar_f16",
+            include_str!(r"../kernels/generated/tanh_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "tanh_vec2_f16",
+            include_str!(r"../kernels/generated/tanh_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "tanh_vec4_f16",
+            include_str!(r"../kernels/generated/tanh_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "groupnorm_scalar",
+            include_str!(r"../kernels/generated/groupnorm_scalar.wgsl"),
+        );
+        m.insert(
+            "groupnorm_scalar_f16",
+            include_str!(r"../kernels/generated/groupnorm_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "groupnorm_vec2",
+            include_str!(r"../kernels/generated/groupnorm_vec2.wgsl"),
+        );
+        m.insert(
+            "groupnorm_vec2_f16",
+            include_str!(r"../kernels/generated/groupnorm_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "groupnorm_vec4",
+            include_str!(r"../kernels/generated/groupnorm_vec4.wgsl"),
+        );
+        m.insert(
+            "groupnorm_vec4_f16",
+            include_str!(r"../kernels/generated/groupnorm_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "rmsnorm_scalar",
+            include_str!(r"../kernels/generated/rmsnorm_scalar.wgsl"),
+        );
+        m.insert(
+            "rmsnorm_scalar_f16",
+            include_str!(r"../kernels/generated/rmsnorm_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "rmsnorm_vec2",
+            include_str!(r"../kernels/generated/rmsnorm_vec2.wgsl"),
+        );
+        m.insert(
+            "rmsnorm_vec2_f16",
+            include_str!(r"../kernels/generated/rmsnorm_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "rmsnorm_vec4",
+            include_str!(r"../kernels/generated/rmsnorm_vec4.wgsl"),
+        );
+        m.insert(
+            "rmsnorm_vec4_f16",
+            include_str!(r"../kernels/generated/rmsnorm_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "elu_inplace_scalar",
+            include_str!(r"../kernels/generated/elu_inplace_scalar.wgsl"),
+        );
+        m.insert(
+            "elu_inplace_scalar_f16",
+            include_str!(r"../kernels/generated/elu_inplace_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "elu_inplace_vec2",
+            include_str!(r"../kernels/generated/elu_inplace_vec2.wgsl"),
+        );
+        m.insert(
+            "elu_inplace_vec2_f16",
+            include_str!(r"../kernels/generated/elu_inplace_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "elu_inplace_vec4",
+            include_str!(r"../kernels/generated/elu_inplace_vec4.wgsl"),
+        );
+        m.insert(
+            "elu_inplace_vec4_f16",
+            include_str!(r"../kernels/generated/elu_inplace_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "elu_scalar",
+            include_str!(r"../kernels/generated/elu_scalar.wgsl"),
+        );
+        m.insert(
+            "elu_scalar_f16",
+            include_str!(r"../kernels/generated/elu_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "elu_vec2",
+            include_str!(r"../kernels/generated/elu_vec2.wgsl"),
+        );
+        m.insert(
+            "elu_vec2_f16",
+            include_str!(r"../kernels/generated/elu_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "elu_vec4",
+            include_str!(r"../kernels/generated/elu_vec4.wgsl"),
+        );
+        m.insert(
+            "elu_vec4_f16",
+            include_str!(r"../kernels/generated/elu_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "gelu_erf_inplace_scalar",
+            include_str!(r"../kernels/generated/gelu_erf_inplace_scalar.wgsl"),
+        );
+        m.insert(
+            "gelu_erf_inplace_scalar_f16",
+            include_str!(r"../kernels/generated/gelu_erf_inplace_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "gelu_erf_inplace_vec2",
+            include_str!(r"../kernels/generated/gelu_erf_inplace_vec2.wgsl"),
+        );
+        m.insert(
+            "gelu_erf_inplace_vec2_f16",
+            include_str!(r"../kernels/generated/gelu_erf_inplace_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "gelu_erf_inplace_vec4",
+            include_str!(r"../kernels/generated/gelu_erf_inplace_vec4.wgsl"),
+        );
+        m.insert(
+            "gelu_erf_inplace_vec4_f16",
+            include_str!(r"../kernels/generated/gelu_erf_inplace_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "gelu_erf_scalar",
+            include_str!(r"../kernels/generated/gelu_erf_scalar.wgsl"),
+        );
+        m.insert(
+            "gelu_erf_scalar_f16",
+            include_str!(r"../kernels/generated/gelu_erf_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "gelu_erf_vec2",
+            include_str!(r"../kernels/generated/gelu_erf_vec2.wgsl"),
+        );
+        m.insert(
+            "gelu_erf_vec2_f16",
+            include_str!(r"../kernels/generated/gelu_erf_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "gelu_erf_vec4",
+            include_str!(r"../kernels/generated/gelu_erf_vec4.wgsl"),
+        );
+        m.insert(
+            "gelu_erf_vec4_f16",
+            include_str!(r"../kernels/generated/gelu_erf_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "leaky_relu_inplace_scalar",
+            include_str!(r"../kernels/generated/leaky_relu_inplace_scalar.wgsl"),
+        );
+        m.insert(
+            "leaky_relu_inplace_scalar_f16",
+            include_str!(r"../kernels/generated/leaky_relu_inplace_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "leaky_relu_inplace_vec2",
+            include_str!(r"../kernels/generated/leaky_relu_inplace_vec2.wgsl"),
+        );
+        m.insert(
+            "leaky_relu_inplace_vec2_f16",
+            include_str!(r"../kernels/generated/leaky_relu_inplace_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "leaky_relu_inplace_vec4",
+            include_str!(r"../kernels/generated/leaky_relu_inplace_vec4.wgsl"),
+        );
+        m.insert(
+            "leaky_relu_inplace_vec4_f16",
+            include_str!(r"../kernels/generated/leaky_relu_inplace_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "leaky_relu_scalar",
+            include_str!(r"../kernels/generated/leaky_relu_scalar.wgsl"),
+        );
+        m.insert(
+            "leaky_relu_scalar_f16",
+            include_str!(r"../kernels/generated/leaky_relu_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "leaky_relu_vec2",
+            include_str!(r"../kernels/generated/leaky_relu_vec2.wgsl"),
+        );
+        m.insert(
+            "leaky_relu_vec2_f16",
+            include_str!(r"../kernels/generated/leaky_relu_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "leaky_relu_vec4",
+            include_str!(r"../kernels/generated/leaky_relu_vec4.wgsl"),
+        );
+        m.insert(
+            "leaky_relu_vec4_f16",
+            include_str!(r"../kernels/generated/leaky_relu_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "reciprocal_inplace_scalar",
+            include_str!(r"../kernels/generated/reciprocal_inplace_scalar.wgsl"),
+        );
+        m.insert(
+            "reciprocal_inplace_scalar_f16",
+            include_str!(r"../kernels/generated/reciprocal_inplace_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "reciprocal_inplace_vec2",
+            include_str!(r"../kernels/generated/reciprocal_inplace_vec2.wgsl"),
+        );
+        m.insert(
+            "reciprocal_inplace_vec2_f16",
+            include_str!(r"../kernels/generated/reciprocal_inplace_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "reciprocal_inplace_vec4",
+            include_str!(r"../kernels/generated/reciprocal_inplace_vec4.wgsl"),
+        );
+        m.insert(
+            "reciprocal_inplace_vec4_f16",
+            include_str!(r"../kernels/generated/reciprocal_inplace_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "reciprocal_scalar",
+            include_str!(r"../kernels/generated/reciprocal_scalar.wgsl"),
+        );
+        m.insert(
+            "reciprocal_scalar_f16",
+            include_str!(r"../kernels/generated/reciprocal_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "reciprocal_vec2",
+            include_str!(r"../kernels/generated/reciprocal_vec2.wgsl"),
+        );
+        m.insert(
+            "reciprocal_vec2_f16",
+            include_str!(r"../kernels/generated/reciprocal_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "reciprocal_vec4",
+            include_str!(r"../kernels/generated/reciprocal_vec4.wgsl"),
+        );
+        m.insert(
+            "reciprocal_vec4_f16",
+            include_str!(r"../kernels/generated/reciprocal_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "sigmoid_inplace_scalar",
+            include_str!(r"../kernels/generated/sigmoid_inplace_scalar.wgsl"),
+        );
+        m.insert(
+            "sigmoid_inplace_scalar_f16",
+            include_str!(r"../kernels/generated/sigmoid_inplace_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "sigmoid_inplace_vec2",
+            include_str!(r"../kernels/generated/sigmoid_inplace_vec2.wgsl"),
+        );
+        m.insert(
+            "sigmoid_inplace_vec2_f16",
+            include_str!(r"../kernels/generated/sigmoid_inplace_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "sigmoid_inplace_vec4",
+            include_str!(r"../kernels/generated/sigmoid_inplace_vec4.wgsl"),
+        );
+        m.insert(
+            "sigmoid_inplace_vec4_f16",
+            include_str!(r"../kernels/generated/sigmoid_inplace_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "sigmoid_scalar",
+            include_str!(r"../kernels/generated/sigmoid_scalar.wgsl"),
+        );
+        m.insert(
+            "sigmoid_scalar_f16",
+            include_str!(r"../kernels/generated/sigmoid_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "sigmoid_vec2",
+            include_str!(r"../kernels/generated/sigmoid_vec2.wgsl"),
+        );
+        m.insert(
+            "sigmoid_vec2_f16",
+            include_str!(r"../kernels/generated/sigmoid_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "sigmoid_vec4",
+            include_str!(r"../kernels/generated/sigmoid_vec4.wgsl"),
+        );
+        m.insert(
+            "sigmoid_vec4_f16",
+            include_str!(r"../kernels/generated/sigmoid_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "silu_inplace_scalar",
+            include_str!(r"../kernels/generated/silu_inplace_scalar.wgsl"),
+        );
+        m.insert(
+            "silu_inplace_scalar_f16",
+            include_str!(r"../kernels/generated/silu_inplace_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "silu_inplace_vec2",
+            include_str!(r"../kernels/generated/silu_inplace_vec2.wgsl"),
+        );
+        m.insert(
+            "silu_inplace_vec2_f16",
+            include_str!(r"../kernels/generated/silu_inplace_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "silu_inplace_vec4",
+            include_str!(r"../kernels/generated/silu_inplace_vec4.wgsl"),
+        );
+        m.insert(
+            "silu_inplace_vec4_f16",
+            include_str!(r"../kernels/generated/silu_inplace_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "silu_scalar",
+            include_str!(r"../kernels/generated/silu_scalar.wgsl"),
+        );
+        m.insert(
+            "silu_scalar_f16",
+            include_str!(r"../kernels/generated/silu_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "silu_vec2",
+            include_str!(r"../kernels/generated/silu_vec2.wgsl"),
+        );
+        m.insert(
+            "silu_vec2_f16",
+            include_str!(r"../kernels/generated/silu_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "silu_vec4",
+            include_str!(r"../kernels/generated/silu_vec4.wgsl"),
+        );
+        m.insert(
+            "silu_vec4_f16",
+            include_str!(r"../kernels/generated/silu_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "softplus_inplace_scalar",
+            include_str!(r"../kernels/generated/softplus_inplace_scalar.wgsl"),
+        );
+        m.insert(
+            "softplus_inplace_scalar_f16",
+            include_str!(r"../kernels/generated/softplus_inplace_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "softplus_inplace_vec2",
+            include_str!(r"../kernels/generated/softplus_inplace_vec2.wgsl"),
+        );
+        m.insert(
+            "softplus_inplace_vec2_f16",
+            include_str!(r"../kernels/generated/softplus_inplace_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "softplus_inplace_vec4",
+            include_str!(r"../kernels/generated/softplus_inplace_vec4.wgsl"),
+        );
+        m.insert(
+            "softplus_inplace_vec4_f16",
+            include_str!(r"../kernels/generated/softplus_inplace_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "softplus_scalar",
+            include_str!(r"../kernels/generated/softplus_scalar.wgsl"),
+        );
+        m.insert(
+            "softplus_scalar_f16",
+            include_str!(r"../kernels/generated/softplus_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "softplus_vec2",
+            include_str!(r"../kernels/generated/softplus_vec2.wgsl"),
+        );
+        m.insert(
+            "softplus_vec2_f16",
+            include_str!(r"../kernels/generated/softplus_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "softplus_vec4",
+            include_str!(r"../kernels/generated/softplus_vec4.wgsl"),
+        );
+        m.insert(
+            "softplus_vec4_f16",
+            include_str!(r"../kernels/generated/softplus_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "swiglu_inplace_scalar",
+            include_str!(r"../kernels/generated/swiglu_inplace_scalar.wgsl"),
+        );
+        m.insert(
+            "swiglu_inplace_scalar_f16",
+            include_str!(r"../kernels/generated/swiglu_inplace_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "swiglu_inplace_vec2",
+            include_str!(r"../kernels/generated/swiglu_inplace_vec2.wgsl"),
+        );
+        m.insert(
+            "swiglu_inplace_vec2_f16",
+            include_str!(r"../kernels/generated/swiglu_inplace_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "swiglu_inplace_vec4",
+            include_str!(r"../kernels/generated/swiglu_inplace_vec4.wgsl"),
+        );
+        m.insert(
+            "swiglu_inplace_vec4_f16",
+            include_str!(r"../kernels/generated/swiglu_inplace_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "swiglu_scalar",
+            include_str!(r"../kernels/generated/swiglu_scalar.wgsl"),
+        );
+        m.insert(
+            "swiglu_scalar_f16",
+            include_str!(r"../kernels/generated/swiglu_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "swiglu_vec2",
+            include_str!(r"../kernels/generated/swiglu_vec2.wgsl"),
+        );
+        m.insert(
+            "swiglu_vec2_f16",
+            include_str!(r"../kernels/generated/swiglu_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "swiglu_vec4",
+            include_str!(r"../kernels/generated/swiglu_vec4.wgsl"),
+        );
+        m.insert(
+            "swiglu_vec4_f16",
+            include_str!(r"../kernels/generated/swiglu_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "sdpa_false",
+            include_str!(r"../kernels/generated/sdpa_false.wgsl"),
+        );
+        m.insert(
+            "sdpa_true",
+            include_str!(r"../kernels/generated/sdpa_true.wgsl"),
+        );
+        m.insert(
+            "sdpa_false_f16",
+            include_str!(r"../kernels/generated/sdpa_false_f16.wgsl"),
+        );
+        m.insert(
+            "sdpa_true_f16",
+            include_str!(r"../kernels/generated/sdpa_true_f16.wgsl"),
+        );
+        m.insert("q4kgemm_false_false_false_false_false_false_false_scalar", include_str!(r"../kernels/generated/q4kgemm_false_false_false_false_false_false_false_scalar.wgsl"));
+        m.insert(
+            "q4kgemm_false_false_false_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_false_false_false_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_false_false_false_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_false_false_false_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_false_false_false_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_false_false_false_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_false_false_false_false_vec4",
+            include_str!(r"../kernels/generated/q4kgemm_false_false_false_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q4kgemm_false_false_false_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_false_false_false_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_false_false_false_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_false_false_false_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_false_false_false_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_false_false_false_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_false_false_false_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_false_false_false_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_false_false_false_true_vec4",
+            include_str!(r"../kernels/generated/q4kgemm_false_false_false_true_vec4.wgsl"),
+        );
+        m.insert(
+            "q4kgemm_false_false_true_false_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_false_false_true_false_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_false_false_true_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_false_false_true_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_false_false_true_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_false_false_true_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_false_false_true_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_false_false_true_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_false_false_true_false_vec4",
+            include_str!(r"../kernels/generated/q4kgemm_false_false_true_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q4kgemm_false_false_true_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_false_false_true_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_false_false_true_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_false_false_true_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_false_false_true_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_false_false_true_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_false_false_true_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_false_false_true_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_false_false_true_true_vec4",
+            include_str!(r"../kernels/generated/q4kgemm_false_false_true_true_vec4.wgsl"),
+        );
+        m.insert(
+            "q4kgemm_false_true_false_false_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_false_true_false_false_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_false_true_false_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_false_true_false_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_false_true_false_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_false_true_false_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_false_true_false_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_false_true_false_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_false_true_false_false_vec4",
+            include_str!(r"../kernels/generated/q4kgemm_false_true_false_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q4kgemm_false_true_false_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_false_true_false_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_false_true_false_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_false_true_false_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_false_true_false_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_false_true_false_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_false_true_false_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_false_true_false_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_false_true_false_true_vec4",
+            include_str!(r"../kernels/generated/q4kgemm_false_true_false_true_vec4.wgsl"),
+        );
+        m.insert(
+            "q4kgemm_false_true_true_false_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_false_true_true_false_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_false_true_true_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_false_true_true_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_false_true_true_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_false_true_true_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_false_true_true_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_false_true_true_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_false_true_true_false_vec4",
+            include_str!(r"../kernels/generated/q4kgemm_false_true_true_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q4kgemm_false_true_true_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_false_true_true_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_false_true_true_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_false_true_true_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_false_true_true_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_false_true_true_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_false_true_true_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_false_true_true_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_false_true_true_true_vec4",
+            include_str!(r"../kernels/generated/q4kgemm_false_true_true_true_vec4.wgsl"),
+        );
+        m.insert(
+            "q4kgemm_true_false_false_false_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_true_false_false_false_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_true_false_false_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_true_false_false_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_true_false_false_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_true_false_false_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_true_false_false_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_true_false_false_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_true_false_false_false_vec4",
+            include_str!(r"../kernels/generated/q4kgemm_true_false_false_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q4kgemm_true_false_false_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_true_false_false_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_true_false_false_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_true_false_false_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_true_false_false_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_true_false_false_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_true_false_false_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_true_false_false_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_true_false_false_true_vec4",
+            include_str!(r"../kernels/generated/q4kgemm_true_false_false_true_vec4.wgsl"),
+        );
+        m.insert(
+            "q4kgemm_true_false_true_false_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_true_false_true_false_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_true_false_true_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_true_false_true_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_true_false_true_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_true_false_true_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_true_false_true_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_true_false_true_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_true_false_true_false_vec4",
+            include_str!(r"../kernels/generated/q4kgemm_true_false_true_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q4kgemm_true_false_true_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_true_false_true_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_true_false_true_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_true_false_true_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_true_false_true_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_true_false_true_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_true_false_true_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_true_false_true_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_true_false_true_true_vec4",
+            include_str!(r"../kernels/generated/q4kgemm_true_false_true_true_vec4.wgsl"),
+        );
+        m.insert(
+            "q4kgemm_true_true_false_false_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_true_true_false_false_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_true_true_false_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_true_true_false_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_true_true_false_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_true_true_false_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_true_true_false_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_true_true_false_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_true_true_false_false_vec4",
+            include_str!(r"../kernels/generated/q4kgemm_true_true_false_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q4kgemm_true_true_false_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_true_true_false_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_true_true_false_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_true_true_false_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_true_true_false_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_true_true_false_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_true_true_false_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_true_true_false_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_true_true_false_true_vec4",
+            include_str!(r"../kernels/generated/q4kgemm_true_true_false_true_vec4.wgsl"),
+        );
+        m.insert(
+            "q4kgemm_true_true_true_false_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_true_true_true_false_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_true_true_true_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_true_true_true_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_true_true_true_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_true_true_true_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_true_true_true_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_true_true_true_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_true_true_true_false_vec4",
+            include_str!(r"../kernels/generated/q4kgemm_true_true_true_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q4kgemm_true_true_true_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_true_true_true_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_true_true_true_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_true_true_true_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_true_true_true_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_true_true_true_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_true_true_true_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4kgemm_true_true_true_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4kgemm_true_true_true_true_vec4",
+            include_str!(r"../kernels/generated/q4kgemm_true_true_true_true_vec4.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_false_16_16_false_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_false_16_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_false_16_16_true_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_false_16_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_false_16_32_false_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_false_16_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_false_16_32_true_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_false_16_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_false_16_4_false_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_false_16_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_false_16_4_true_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_false_16_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_false_16_8_false_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_false_16_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_false_16_8_true_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_false_16_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_false_32_16_false_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_false_32_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_false_32_16_true_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_false_32_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_false_32_32_false_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_false_32_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_false_32_32_true_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_false_32_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_false_32_4_false_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_false_32_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_false_32_4_true_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_false_32_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_false_32_8_false_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_false_32_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_false_32_8_true_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_false_32_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_false_4_16_false_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_false_4_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_false_4_16_true_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_false_4_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_false_4_256_false_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_false_4_256_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_false_4_256_true_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_false_4_256_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_false_4_32_false_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_false_4_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_false_4_32_true_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_false_4_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_false_4_4_false_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_false_4_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_false_4_4_true_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_false_4_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_false_4_8_false_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_false_4_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_false_4_8_true_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_false_4_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_false_64_16_false_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_false_64_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_false_64_16_true_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_false_64_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_false_64_4_false_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_false_64_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_false_64_4_true_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_false_64_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_false_64_8_false_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_false_64_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_false_64_8_true_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_false_64_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_false_8_16_false_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_false_8_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_false_8_16_true_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_false_8_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_false_8_32_false_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_false_8_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_false_8_32_true_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_false_8_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_false_8_4_false_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_false_8_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_false_8_4_true_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_false_8_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_false_8_8_false_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_false_8_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_false_8_8_true_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_false_8_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_true_16_16_false_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_true_16_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_true_16_16_true_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_true_16_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_true_16_32_false_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_true_16_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_true_16_32_true_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_true_16_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_true_16_4_false_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_true_16_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_true_16_4_true_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_true_16_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_true_16_8_false_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_true_16_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_true_16_8_true_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_true_16_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_true_32_16_false_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_true_32_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_true_32_16_true_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_true_32_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_true_32_32_false_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_true_32_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_true_32_32_true_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_true_32_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_true_32_4_false_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_true_32_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_true_32_4_true_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_true_32_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_true_32_8_false_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_true_32_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_true_32_8_true_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_true_32_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_true_4_16_false_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_true_4_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_true_4_16_true_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_true_4_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_true_4_256_false_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_true_4_256_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_true_4_256_true_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_true_4_256_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_true_4_32_false_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_true_4_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_true_4_32_true_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_true_4_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_true_4_4_false_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_true_4_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_true_4_4_true_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_true_4_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_true_4_8_false_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_true_4_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_true_4_8_true_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_true_4_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_true_64_16_false_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_true_64_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_true_64_16_true_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_true_64_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_true_64_4_false_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_true_64_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_true_64_4_true_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_true_64_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_true_64_8_false_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_true_64_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_true_64_8_true_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_true_64_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_true_8_16_false_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_true_8_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_true_8_16_true_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_true_8_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_true_8_32_false_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_true_8_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_true_8_32_true_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_true_8_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_true_8_4_false_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_true_8_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_true_8_4_true_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_true_8_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_true_8_8_false_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_true_8_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4kgemv_true_8_8_true_scalar",
+            include_str!(r"../kernels/generated/q4kgemv_true_8_8_true_scalar.wgsl"),
+        );
+        m.insert("q6kgemm_false_false_false_false_false_false_false_scalar", include_str!(r"../kernels/generated/q6kgemm_false_false_false_false_false_false_false_scalar.wgsl"));
+        m.insert(
+            "q6kgemm_false_false_false_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_false_false_false_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_false_false_false_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_false_false_false_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_false_false_false_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_false_false_false_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_false_false_false_false_vec4",
+            include_str!(r"../kernels/generated/q6kgemm_false_false_false_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q6kgemm_false_false_false_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_false_false_false_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_false_false_false_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_false_false_false_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_false_false_false_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_false_false_false_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_false_false_false_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_false_false_false_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_false_false_false_true_vec4",
+            include_str!(r"../kernels/generated/q6kgemm_false_false_false_true_vec4.wgsl"),
+        );
+        m.insert(
+            "q6kgemm_false_false_true_false_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_false_false_true_false_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_false_false_true_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_false_false_true_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_false_false_true_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_false_false_true_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_false_false_true_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_false_false_true_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_false_false_true_false_vec4",
+            include_str!(r"../kernels/generated/q6kgemm_false_false_true_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q6kgemm_false_false_true_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_false_false_true_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_false_false_true_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_false_false_true_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_false_false_true_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_false_false_true_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_false_false_true_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_false_false_true_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_false_false_true_true_vec4",
+            include_str!(r"../kernels/generated/q6kgemm_false_false_true_true_vec4.wgsl"),
+        );
+        m.insert(
+            "q6kgemm_false_true_false_false_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_false_true_false_false_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_false_true_false_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_false_true_false_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_false_true_false_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_false_true_false_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_false_true_false_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_false_true_false_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_false_true_false_false_vec4",
+            include_str!(r"../kernels/generated/q6kgemm_false_true_false_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q6kgemm_false_true_false_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_false_true_false_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_false_true_false_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_false_true_false_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_false_true_false_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_false_true_false_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_false_true_false_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_false_true_false_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_false_true_false_true_vec4",
+            include_str!(r"../kernels/generated/q6kgemm_false_true_false_true_vec4.wgsl"),
+        );
+        m.insert(
+            "q6kgemm_false_true_true_false_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_false_true_true_false_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_false_true_true_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_false_true_true_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_false_true_true_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_false_true_true_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_false_true_true_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_false_true_true_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_false_true_true_false_vec4",
+            include_str!(r"../kernels/generated/q6kgemm_false_true_true_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q6kgemm_false_true_true_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_false_true_true_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_false_true_true_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_false_true_true_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_false_true_true_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_false_true_true_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_false_true_true_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_false_true_true_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_false_true_true_true_vec4",
+            include_str!(r"../kernels/generated/q6kgemm_false_true_true_true_vec4.wgsl"),
+        );
+        m.insert(
+            "q6kgemm_true_false_false_false_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_true_false_false_false_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_true_false_false_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_true_false_false_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_true_false_false_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_true_false_false_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_true_false_false_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_true_false_false_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_true_false_false_false_vec4",
+            include_str!(r"../kernels/generated/q6kgemm_true_false_false_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q6kgemm_true_false_false_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_true_false_false_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_true_false_false_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_true_false_false_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_true_false_false_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_true_false_false_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_true_false_false_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_true_false_false_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_true_false_false_true_vec4",
+            include_str!(r"../kernels/generated/q6kgemm_true_false_false_true_vec4.wgsl"),
+        );
+        m.insert(
+            "q6kgemm_true_false_true_false_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_true_false_true_false_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_true_false_true_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_true_false_true_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_true_false_true_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_true_false_true_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_true_false_true_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_true_false_true_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_true_false_true_false_vec4",
+            include_str!(r"../kernels/generated/q6kgemm_true_false_true_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q6kgemm_true_false_true_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_true_false_true_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_true_false_true_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_true_false_true_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_true_false_true_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_true_false_true_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_true_false_true_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_true_false_true_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_true_false_true_true_vec4",
+            include_str!(r"../kernels/generated/q6kgemm_true_false_true_true_vec4.wgsl"),
+        );
+        m.insert(
+            "q6kgemm_true_true_false_false_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_true_true_false_false_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_true_true_false_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_true_true_false_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_true_true_false_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_true_true_false_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_true_true_false_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_true_true_false_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_true_true_false_false_vec4",
+            include_str!(r"../kernels/generated/q6kgemm_true_true_false_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q6kgemm_true_true_false_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_true_true_false_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_true_true_false_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_true_true_false_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_true_true_false_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_true_true_false_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_true_true_false_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_true_true_false_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_true_true_false_true_vec4",
+            include_str!(r"../kernels/generated/q6kgemm_true_true_false_true_vec4.wgsl"),
+        );
+        m.insert(
+            "q6kgemm_true_true_true_false_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_true_true_true_false_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_true_true_true_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_true_true_true_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_true_true_true_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_true_true_true_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_true_true_true_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_true_true_true_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_true_true_true_false_vec4",
+            include_str!(r"../kernels/generated/q6kgemm_true_true_true_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q6kgemm_true_true_true_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_true_true_true_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_true_true_true_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_true_true_true_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_true_true_true_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_true_true_true_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_true_true_true_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q6kgemm_true_true_true_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q6kgemm_true_true_true_true_vec4",
+            include_str!(r"../kernels/generated/q6kgemm_true_true_true_true_vec4.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_false_16_16_false_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_false_16_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_false_16_16_true_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_false_16_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_false_16_32_false_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_false_16_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_false_16_32_true_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_false_16_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_false_16_4_false_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_false_16_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_false_16_4_true_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_false_16_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_false_16_8_false_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_false_16_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_false_16_8_true_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_false_16_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_false_32_16_false_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_false_32_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_false_32_16_true_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_false_32_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_false_32_32_false_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_false_32_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_false_32_32_true_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_false_32_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_false_32_4_false_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_false_32_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_false_32_4_true_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_false_32_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_false_32_8_false_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_false_32_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_false_32_8_true_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_false_32_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_false_4_16_false_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_false_4_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_false_4_16_true_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_false_4_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_false_4_256_false_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_false_4_256_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_false_4_256_true_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_false_4_256_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_false_4_32_false_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_false_4_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_false_4_32_true_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_false_4_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_false_4_4_false_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_false_4_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_false_4_4_true_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_false_4_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_false_4_8_false_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_false_4_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_false_4_8_true_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_false_4_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_false_64_16_false_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_false_64_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_false_64_16_true_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_false_64_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_false_64_4_false_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_false_64_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_false_64_4_true_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_false_64_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_false_64_8_false_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_false_64_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_false_64_8_true_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_false_64_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_false_8_16_false_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_false_8_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_false_8_16_true_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_false_8_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_false_8_32_false_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_false_8_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_false_8_32_true_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_false_8_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_false_8_4_false_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_false_8_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_false_8_4_true_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_false_8_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_false_8_8_false_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_false_8_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_false_8_8_true_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_false_8_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_true_16_16_false_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_true_16_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_true_16_16_true_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_true_16_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_true_16_32_false_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_true_16_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_true_16_32_true_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_true_16_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_true_16_4_false_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_true_16_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_true_16_4_true_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_true_16_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_true_16_8_false_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_true_16_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_true_16_8_true_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_true_16_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_true_32_16_false_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_true_32_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_true_32_16_true_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_true_32_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_true_32_32_false_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_true_32_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_true_32_32_true_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_true_32_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_true_32_4_false_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_true_32_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_true_32_4_true_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_true_32_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_true_32_8_false_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_true_32_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_true_32_8_true_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_true_32_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_true_4_16_false_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_true_4_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_true_4_16_true_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_true_4_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_true_4_256_false_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_true_4_256_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_true_4_256_true_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_true_4_256_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_true_4_32_false_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_true_4_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_true_4_32_true_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_true_4_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_true_4_4_false_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_true_4_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_true_4_4_true_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_true_4_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_true_4_8_false_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_true_4_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_true_4_8_true_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_true_4_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_true_64_16_false_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_true_64_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_true_64_16_true_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_true_64_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_true_64_4_false_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_true_64_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_true_64_4_true_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_true_64_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_true_64_8_false_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_true_64_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_true_64_8_true_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_true_64_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_true_8_16_false_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_true_8_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_true_8_16_true_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_true_8_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_true_8_32_false_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_true_8_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_true_8_32_true_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_true_8_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_true_8_4_false_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_true_8_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_true_8_4_true_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_true_8_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_true_8_8_false_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_true_8_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q6kgemv_true_8_8_true_scalar",
+            include_str!(r"../kernels/generated/q6kgemv_true_8_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0_index_select_scalar",
+            include_str!(r"../kernels/generated/q4_0_index_select_scalar.wgsl"),
+        );
+        m.insert("q4_0gemm_false_false_false_false_false_false_false_scalar", include_str!(r"../kernels/generated/q4_0gemm_false_false_false_false_false_false_false_scalar.wgsl"));
+        m.insert("q4_0gemm_false_false_false_false_false_false_true_scalar", include_str!(r"../kernels/generated/q4_0gemm_false_false_false_false_false_false_true_scalar.wgsl"));
+        m.insert("q4_0gemm_false_false_false_false_false_true_false_scalar", include_str!(r"../kernels/generated/q4_0gemm_false_false_false_false_false_true_false_scalar.wgsl"));
+        m.insert(
+            "q4_0gemm_false_false_false_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_false_false_false_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_false_false_false_false_vec4",
+            include_str!(r"../kernels/generated/q4_0gemm_false_false_false_false_vec4.wgsl"),
+        );
+        m.insert("q4_0gemm_false_false_false_true_false_false_false_scalar", include_str!(r"../kernels/generated/q4_0gemm_false_false_false_true_false_false_false_scalar.wgsl"));
+        m.insert(
+            "q4_0gemm_false_false_false_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_false_false_false_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_false_false_false_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_false_false_false_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_false_false_false_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_false_false_false_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_false_false_false_true_vec4",
+            include_str!(r"../kernels/generated/q4_0gemm_false_false_false_true_vec4.wgsl"),
+        );
+        m.insert("q4_0gemm_false_false_true_false_false_false_false_scalar", include_str!(r"../kernels/generated/q4_0gemm_false_false_true_false_false_false_false_scalar.wgsl"));
+        m.insert(
+            "q4_0gemm_false_false_true_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_false_false_true_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_false_false_true_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_false_false_true_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_false_false_true_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_false_false_true_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_false_false_true_false_vec4",
+            include_str!(r"../kernels/generated/q4_0gemm_false_false_true_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q4_0gemm_false_false_true_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_false_false_true_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_false_false_true_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_false_false_true_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_false_false_true_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_false_false_true_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_false_false_true_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_false_false_true_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_false_false_true_true_vec4",
+            include_str!(r"../kernels/generated/q4_0gemm_false_false_true_true_vec4.wgsl"),
+        );
+        m.insert("q4_0gemm_false_true_false_false_false_false_false_scalar", include_str!(r"../kernels/generated/q4_0gemm_false_true_false_false_false_false_false_scalar.wgsl"));
+        m.insert(
+            "q4_0gemm_false_true_false_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_false_true_false_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_false_true_false_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_false_true_false_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_false_true_false_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_false_true_false_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_false_true_false_false_vec4",
+            include_str!(r"../kernels/generated/q4_0gemm_false_true_false_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q4_0gemm_false_true_false_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_false_true_false_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_false_true_false_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_false_true_false_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_false_true_false_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_false_true_false_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_false_true_false_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_false_true_false_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_false_true_false_true_vec4",
+            include_str!(r"../kernels/generated/q4_0gemm_false_true_false_true_vec4.wgsl"),
+        );
+        m.insert(
+            "q4_0gemm_false_true_true_false_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_false_true_true_false_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_false_true_true_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_false_true_true_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_false_true_true_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_false_true_true_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_false_true_true_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_false_true_true_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_false_true_true_false_vec4",
+            include_str!(r"../kernels/generated/q4_0gemm_false_true_true_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q4_0gemm_false_true_true_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_false_true_true_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_false_true_true_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_false_true_true_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_false_true_true_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_false_true_true_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_false_true_true_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_false_true_true_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_false_true_true_true_vec4",
+            include_str!(r"../kernels/generated/q4_0gemm_false_true_true_true_vec4.wgsl"),
+        );
+        m.insert("q4_0gemm_true_false_false_false_false_false_false_scalar", include_str!(r"../kernels/generated/q4_0gemm_true_false_false_false_false_false_false_scalar.wgsl"));
+        m.insert(
+            "q4_0gemm_true_false_false_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_true_false_false_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_true_false_false_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_true_false_false_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_true_false_false_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_true_false_false_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_true_false_false_false_vec4",
+            include_str!(r"../kernels/generated/q4_0gemm_true_false_false_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q4_0gemm_true_false_false_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_true_false_false_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_true_false_false_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_true_false_false_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_true_false_false_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_true_false_false_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_true_false_false_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_true_false_false_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_true_false_false_true_vec4",
+            include_str!(r"../kernels/generated/q4_0gemm_true_false_false_true_vec4.wgsl"),
+        );
+        m.insert(
+            "q4_0gemm_true_false_true_false_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_true_false_true_false_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_true_false_true_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_true_false_true_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_true_false_true_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_true_false_true_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_true_false_true_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_true_false_true_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_true_false_true_false_vec4",
+            include_str!(r"../kernels/generated/q4_0gemm_true_false_true_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q4_0gemm_true_false_true_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_true_false_true_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_true_false_true_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_true_false_true_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_true_false_true_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_true_false_true_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_true_false_true_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_true_false_true_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_true_false_true_true_vec4",
+            include_str!(r"../kernels/generated/q4_0gemm_true_false_true_true_vec4.wgsl"),
+        );
+        m.insert(
+            "q4_0gemm_true_true_false_false_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_true_true_false_false_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_true_true_false_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_true_true_false_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_true_true_false_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_true_true_false_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_true_true_false_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_true_true_false_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_true_true_false_false_vec4",
+            include_str!(r"../kernels/generated/q4_0gemm_true_true_false_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q4_0gemm_true_true_false_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_true_true_false_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_true_true_false_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_true_true_false_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_true_true_false_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_true_true_false_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_true_true_false_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_true_true_false_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_true_true_false_true_vec4",
+            include_str!(r"../kernels/generated/q4_0gemm_true_true_false_true_vec4.wgsl"),
+        );
+        m.insert(
+            "q4_0gemm_true_true_true_false_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_true_true_true_false_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_true_true_true_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_true_true_true_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_true_true_true_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_true_true_true_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_true_true_true_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_true_true_true_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_true_true_true_false_vec4",
+            include_str!(r"../kernels/generated/q4_0gemm_true_true_true_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q4_0gemm_true_true_true_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_true_true_true_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_true_true_true_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_true_true_true_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_true_true_true_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_true_true_true_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_true_true_true_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_0gemm_true_true_true_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_0gemm_true_true_true_true_vec4",
+            include_str!(r"../kernels/generated/q4_0gemm_true_true_true_true_vec4.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_false_16_16_false_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_false_16_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_false_16_16_true_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_false_16_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_false_16_32_false_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_false_16_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_false_16_32_true_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_false_16_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_false_16_4_false_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_false_16_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_false_16_4_true_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_false_16_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_false_16_8_false_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_false_16_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_false_16_8_true_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_false_16_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_false_32_16_false_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_false_32_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_false_32_16_true_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_false_32_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_false_32_32_false_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_false_32_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_false_32_32_true_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_false_32_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_false_32_4_false_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_false_32_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_false_32_4_true_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_false_32_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_false_32_8_false_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_false_32_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_false_32_8_true_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_false_32_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_false_4_16_false_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_false_4_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_false_4_16_true_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_false_4_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_false_4_256_false_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_false_4_256_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_false_4_256_true_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_false_4_256_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_false_4_32_false_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_false_4_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_false_4_32_true_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_false_4_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_false_4_4_false_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_false_4_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_false_4_4_true_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_false_4_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_false_4_8_false_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_false_4_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_false_4_8_true_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_false_4_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_false_64_16_false_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_false_64_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_false_64_16_true_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_false_64_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_false_64_4_false_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_false_64_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_false_64_4_true_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_false_64_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_false_64_8_false_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_false_64_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_false_64_8_true_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_false_64_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_false_8_16_false_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_false_8_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_false_8_16_true_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_false_8_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_false_8_32_false_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_false_8_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_false_8_32_true_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_false_8_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_false_8_4_false_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_false_8_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_false_8_4_true_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_false_8_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_false_8_8_false_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_false_8_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_false_8_8_true_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_false_8_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_true_16_16_false_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_true_16_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_true_16_16_true_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_true_16_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_true_16_32_false_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_true_16_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_true_16_32_true_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_true_16_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_true_16_4_false_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_true_16_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_true_16_4_true_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_true_16_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_true_16_8_false_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_true_16_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_true_16_8_true_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_true_16_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_true_32_16_false_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_true_32_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_true_32_16_true_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_true_32_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_true_32_32_false_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_true_32_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_true_32_32_true_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_true_32_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_true_32_4_false_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_true_32_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_true_32_4_true_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_true_32_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_true_32_8_false_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_true_32_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_true_32_8_true_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_true_32_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_true_4_16_false_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_true_4_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_true_4_16_true_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_true_4_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_true_4_256_false_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_true_4_256_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_true_4_256_true_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_true_4_256_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_true_4_32_false_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_true_4_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_true_4_32_true_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_true_4_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_true_4_4_false_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_true_4_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_true_4_4_true_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_true_4_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_true_4_8_false_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_true_4_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_true_4_8_true_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_true_4_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_true_64_16_false_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_true_64_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_true_64_16_true_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_true_64_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_true_64_4_false_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_true_64_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_true_64_4_true_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_true_64_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_true_64_8_false_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_true_64_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_true_64_8_true_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_true_64_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_true_8_16_false_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_true_8_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_true_8_16_true_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_true_8_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_true_8_32_false_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_true_8_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_true_8_32_true_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_true_8_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_true_8_4_false_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_true_8_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_true_8_4_true_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_true_8_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_true_8_8_false_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_true_8_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0gemv_true_8_8_true_scalar",
+            include_str!(r"../kernels/generated/q4_0gemv_true_8_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1_index_select_scalar",
+            include_str!(r"../kernels/generated/q4_1_index_select_scalar.wgsl"),
+        );
+        m.insert("q4_1gemm_false_false_false_false_false_false_false_scalar", include_str!(r"../kernels/generated/q4_1gemm_false_false_false_false_false_false_false_scalar.wgsl"));
+        m.insert("q4_1gemm_false_false_false_false_false_false_true_scalar", include_str!(r"../kernels/generated/q4_1gemm_false_false_false_false_false_false_true_scalar.wgsl"));
+        m.insert("q4_1gemm_false_false_false_false_false_true_false_scalar", include_str!(r"../kernels/generated/q4_1gemm_false_false_false_false_false_true_false_scalar.wgsl"));
+        m.insert(
+            "q4_1gemm_false_false_false_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_false_false_false_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_false_false_false_false_vec4",
+            include_str!(r"../kernels/generated/q4_1gemm_false_false_false_false_vec4.wgsl"),
+        );
+        m.insert("q4_1gemm_false_false_false_true_false_false_false_scalar", include_str!(r"../kernels/generated/q4_1gemm_false_false_false_true_false_false_false_scalar.wgsl"));
+        m.insert(
+            "q4_1gemm_false_false_false_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_false_false_false_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_false_false_false_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_false_false_false_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_false_false_false_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_false_false_false_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_false_false_false_true_vec4",
+            include_str!(r"../kernels/generated/q4_1gemm_false_false_false_true_vec4.wgsl"),
+        );
+        m.insert("q4_1gemm_false_false_true_false_false_false_false_scalar", include_str!(r"../kernels/generated/q4_1gemm_false_false_true_false_false_false_false_scalar.wgsl"));
+        m.insert(
+            "q4_1gemm_false_false_true_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_false_false_true_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_false_false_true_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_false_false_true_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_false_false_true_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_false_false_true_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_false_false_true_false_vec4",
+            include_str!(r"../kernels/generated/q4_1gemm_false_false_true_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q4_1gemm_false_false_true_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_false_false_true_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_false_false_true_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_false_false_true_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_false_false_true_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_false_false_true_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_false_false_true_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_false_false_true_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_false_false_true_true_vec4",
+            include_str!(r"../kernels/generated/q4_1gemm_false_false_true_true_vec4.wgsl"),
+        );
+        m.insert("q4_1gemm_false_true_false_false_false_false_false_scalar", include_str!(r"../kernels/generated/q4_1gemm_false_true_false_false_false_false_false_scalar.wgsl"));
+        m.insert(
+            "q4_1gemm_false_true_false_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_false_true_false_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_false_true_false_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_false_true_false_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_false_true_false_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_false_true_false_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_false_true_false_false_vec4",
+            include_str!(r"../kernels/generated/q4_1gemm_false_true_false_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q4_1gemm_false_true_false_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_false_true_false_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_false_true_false_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_false_true_false_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_false_true_false_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_false_true_false_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_false_true_false_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_false_true_false_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_false_true_false_true_vec4",
+            include_str!(r"../kernels/generated/q4_1gemm_false_true_false_true_vec4.wgsl"),
+        );
+        m.insert(
+            "q4_1gemm_false_true_true_false_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_false_true_true_false_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_false_true_true_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_false_true_true_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_false_true_true_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_false_true_true_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_false_true_true_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_false_true_true_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_false_true_true_false_vec4",
+            include_str!(r"../kernels/generated/q4_1gemm_false_true_true_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q4_1gemm_false_true_true_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_false_true_true_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_false_true_true_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_false_true_true_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_false_true_true_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_false_true_true_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_false_true_true_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_false_true_true_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_false_true_true_true_vec4",
+            include_str!(r"../kernels/generated/q4_1gemm_false_true_true_true_vec4.wgsl"),
+        );
+        m.insert("q4_1gemm_true_false_false_false_false_false_false_scalar", include_str!(r"../kernels/generated/q4_1gemm_true_false_false_false_false_false_false_scalar.wgsl"));
+        m.insert(
+            "q4_1gemm_true_false_false_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_true_false_false_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_true_false_false_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_true_false_false_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_true_false_false_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_true_false_false_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_true_false_false_false_vec4",
+            include_str!(r"../kernels/generated/q4_1gemm_true_false_false_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q4_1gemm_true_false_false_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_true_false_false_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_true_false_false_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_true_false_false_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_true_false_false_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_true_false_false_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_true_false_false_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_true_false_false_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_true_false_false_true_vec4",
+            include_str!(r"../kernels/generated/q4_1gemm_true_false_false_true_vec4.wgsl"),
+        );
+        m.insert(
+            "q4_1gemm_true_false_true_false_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_true_false_true_false_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_true_false_true_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_true_false_true_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_true_false_true_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_true_false_true_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_true_false_true_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_true_false_true_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_true_false_true_false_vec4",
+            include_str!(r"../kernels/generated/q4_1gemm_true_false_true_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q4_1gemm_true_false_true_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_true_false_true_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_true_false_true_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_true_false_true_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_true_false_true_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_true_false_true_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_true_false_true_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_true_false_true_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_true_false_true_true_vec4",
+            include_str!(r"../kernels/generated/q4_1gemm_true_false_true_true_vec4.wgsl"),
+        );
+        m.insert(
+            "q4_1gemm_true_true_false_false_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_true_true_false_false_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_true_true_false_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_true_true_false_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_true_true_false_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_true_true_false_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_true_true_false_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_true_true_false_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_true_true_false_false_vec4",
+            include_str!(r"../kernels/generated/q4_1gemm_true_true_false_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q4_1gemm_true_true_false_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_true_true_false_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_true_true_false_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_true_true_false_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_true_true_false_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_true_true_false_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_true_true_false_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_true_true_false_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_true_true_false_true_vec4",
+            include_str!(r"../kernels/generated/q4_1gemm_true_true_false_true_vec4.wgsl"),
+        );
+        m.insert(
+            "q4_1gemm_true_true_true_false_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_true_true_true_false_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_true_true_true_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_true_true_true_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_true_true_true_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_true_true_true_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_true_true_true_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_true_true_true_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_true_true_true_false_vec4",
+            include_str!(r"../kernels/generated/q4_1gemm_true_true_true_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q4_1gemm_true_true_true_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_true_true_true_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_true_true_true_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_true_true_true_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_true_true_true_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_true_true_true_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_true_true_true_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q4_1gemm_true_true_true_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q4_1gemm_true_true_true_true_vec4",
+            include_str!(r"../kernels/generated/q4_1gemm_true_true_true_true_vec4.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_false_16_16_false_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_false_16_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_false_16_16_true_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_false_16_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_false_16_32_false_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_false_16_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_false_16_32_true_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_false_16_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_false_16_4_false_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_false_16_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_false_16_4_true_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_false_16_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_false_16_8_false_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_false_16_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_false_16_8_true_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_false_16_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_false_32_16_false_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_false_32_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_false_32_16_true_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_false_32_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_false_32_32_false_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_false_32_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_false_32_32_true_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_false_32_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_false_32_4_false_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_false_32_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_false_32_4_true_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_false_32_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_false_32_8_false_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_false_32_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_false_32_8_true_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_false_32_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_false_4_16_false_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_false_4_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_false_4_16_true_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_false_4_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_false_4_256_false_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_false_4_256_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_false_4_256_true_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_false_4_256_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_false_4_32_false_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_false_4_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_false_4_32_true_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_false_4_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_false_4_4_false_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_false_4_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_false_4_4_true_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_false_4_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_false_4_8_false_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_false_4_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_false_4_8_true_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_false_4_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_false_64_16_false_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_false_64_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_false_64_16_true_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_false_64_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_false_64_4_false_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_false_64_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_false_64_4_true_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_false_64_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_false_64_8_false_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_false_64_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_false_64_8_true_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_false_64_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_false_8_16_false_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_false_8_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_false_8_16_true_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_false_8_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_false_8_32_false_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_false_8_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_false_8_32_true_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_false_8_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_false_8_4_false_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_false_8_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_false_8_4_true_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_false_8_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_false_8_8_false_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_false_8_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_false_8_8_true_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_false_8_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_true_16_16_false_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_true_16_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_true_16_16_true_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_true_16_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_true_16_32_false_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_true_16_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_true_16_32_true_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_true_16_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_true_16_4_false_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_true_16_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_true_16_4_true_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_true_16_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_true_16_8_false_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_true_16_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_true_16_8_true_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_true_16_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_true_32_16_false_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_true_32_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_true_32_16_true_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_true_32_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_true_32_32_false_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_true_32_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_true_32_32_true_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_true_32_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_true_32_4_false_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_true_32_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_true_32_4_true_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_true_32_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_true_32_8_false_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_true_32_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_true_32_8_true_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_true_32_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_true_4_16_false_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_true_4_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_true_4_16_true_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_true_4_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_true_4_256_false_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_true_4_256_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_true_4_256_true_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_true_4_256_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_true_4_32_false_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_true_4_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_true_4_32_true_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_true_4_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_true_4_4_false_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_true_4_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_true_4_4_true_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_true_4_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_true_4_8_false_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_true_4_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_true_4_8_true_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_true_4_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_true_64_16_false_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_true_64_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_true_64_16_true_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_true_64_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_true_64_4_false_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_true_64_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_true_64_4_true_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_true_64_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_true_64_8_false_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_true_64_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_true_64_8_true_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_true_64_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_true_8_16_false_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_true_8_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_true_8_16_true_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_true_8_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_true_8_32_false_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_true_8_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_true_8_32_true_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_true_8_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_true_8_4_false_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_true_8_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_true_8_4_true_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_true_8_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_true_8_8_false_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_true_8_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_1gemv_true_8_8_true_scalar",
+            include_str!(r"../kernels/generated/q4_1gemv_true_8_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0_index_select_scalar",
+            include_str!(r"../kernels/generated/q5_0_index_select_scalar.wgsl"),
+        );
+        m.insert("q5_0gemm_false_false_false_false_false_false_false_scalar", include_str!(r"../kernels/generated/q5_0gemm_false_false_false_false_false_false_false_scalar.wgsl"));
+        m.insert("q5_0gemm_false_false_false_false_false_false_true_scalar", include_str!(r"../kernels/generated/q5_0gemm_false_false_false_false_false_false_true_scalar.wgsl"));
+        m.insert("q5_0gemm_false_false_false_false_false_true_false_scalar", include_str!(r"../kernels/generated/q5_0gemm_false_false_false_false_false_true_false_scalar.wgsl"));
+        m.insert(
+            "q5_0gemm_false_false_false_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_false_false_false_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_false_false_false_false_vec4",
+            include_str!(r"../kernels/generated/q5_0gemm_false_false_false_false_vec4.wgsl"),
+        );
+        m.insert("q5_0gemm_false_false_false_true_false_false_false_scalar", include_str!(r"../kernels/generated/q5_0gemm_false_false_false_true_false_false_false_scalar.wgsl"));
+        m.insert(
+            "q5_0gemm_false_false_false_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_false_false_false_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_false_false_false_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_false_false_false_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_false_false_false_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_false_false_false_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_false_false_false_true_vec4",
+            include_str!(r"../kernels/generated/q5_0gemm_false_false_false_true_vec4.wgsl"),
+        );
+        m.insert("q5_0gemm_false_false_true_false_false_false_false_scalar", include_str!(r"../kernels/generated/q5_0gemm_false_false_true_false_false_false_false_scalar.wgsl"));
+        m.insert(
+            "q5_0gemm_false_false_true_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_false_false_true_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_false_false_true_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_false_false_true_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_false_false_true_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_false_false_true_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_false_false_true_false_vec4",
+            include_str!(r"../kernels/generated/q5_0gemm_false_false_true_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q5_0gemm_false_false_true_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_false_false_true_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_false_false_true_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_false_false_true_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_false_false_true_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_false_false_true_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_false_false_true_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_false_false_true_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_false_false_true_true_vec4",
+            include_str!(r"../kernels/generated/q5_0gemm_false_false_true_true_vec4.wgsl"),
+        );
+        m.insert("q5_0gemm_false_true_false_false_false_false_false_scalar", include_str!(r"../kernels/generated/q5_0gemm_false_true_false_false_false_false_false_scalar.wgsl"));
+        m.insert(
+            "q5_0gemm_false_true_false_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_false_true_false_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_false_true_false_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_false_true_false_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_false_true_false_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_false_true_false_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_false_true_false_false_vec4",
+            include_str!(r"../kernels/generated/q5_0gemm_false_true_false_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q5_0gemm_false_true_false_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_false_true_false_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_false_true_false_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_false_true_false_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_false_true_false_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_false_true_false_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_false_true_false_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_false_true_false_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_false_true_false_true_vec4",
+            include_str!(r"../kernels/generated/q5_0gemm_false_true_false_true_vec4.wgsl"),
+        );
+        m.insert(
+            "q5_0gemm_false_true_true_false_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_false_true_true_false_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_false_true_true_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_false_true_true_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_false_true_true_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_false_true_true_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_false_true_true_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_false_true_true_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_false_true_true_false_vec4",
+            include_str!(r"../kernels/generated/q5_0gemm_false_true_true_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q5_0gemm_false_true_true_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_false_true_true_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_false_true_true_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_false_true_true_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_false_true_true_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_false_true_true_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_false_true_true_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_false_true_true_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_false_true_true_true_vec4",
+            include_str!(r"../kernels/generated/q5_0gemm_false_true_true_true_vec4.wgsl"),
+        );
+        m.insert("q5_0gemm_true_false_false_false_false_false_false_scalar", include_str!(r"../kernels/generated/q5_0gemm_true_false_false_false_false_false_false_scalar.wgsl"));
+        m.insert(
+            "q5_0gemm_true_false_false_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_true_false_false_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_true_false_false_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_true_false_false_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_true_false_false_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_true_false_false_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_true_false_false_false_vec4",
+            include_str!(r"../kernels/generated/q5_0gemm_true_false_false_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q5_0gemm_true_false_false_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_true_false_false_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_true_false_false_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_true_false_false_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_true_false_false_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_true_false_false_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_true_false_false_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_true_false_false_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_true_false_false_true_vec4",
+            include_str!(r"../kernels/generated/q5_0gemm_true_false_false_true_vec4.wgsl"),
+        );
+        m.insert(
+            "q5_0gemm_true_false_true_false_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_true_false_true_false_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_true_false_true_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_true_false_true_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_true_false_true_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_true_false_true_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_true_false_true_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_true_false_true_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_true_false_true_false_vec4",
+            include_str!(r"../kernels/generated/q5_0gemm_true_false_true_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q5_0gemm_true_false_true_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_true_false_true_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_true_false_true_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_true_false_true_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_true_false_true_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_true_false_true_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_true_false_true_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_true_false_true_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_true_false_true_true_vec4",
+            include_str!(r"../kernels/generated/q5_0gemm_true_false_true_true_vec4.wgsl"),
+        );
+        m.insert(
+            "q5_0gemm_true_true_false_false_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_true_true_false_false_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_true_true_false_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_true_true_false_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_true_true_false_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_true_true_false_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_true_true_false_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_true_true_false_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_true_true_false_false_vec4",
+            include_str!(r"../kernels/generated/q5_0gemm_true_true_false_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q5_0gemm_true_true_false_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_true_true_false_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_true_true_false_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_true_true_false_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_true_true_false_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_true_true_false_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_true_true_false_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_true_true_false_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_true_true_false_true_vec4",
+            include_str!(r"../kernels/generated/q5_0gemm_true_true_false_true_vec4.wgsl"),
+        );
+        m.insert(
+            "q5_0gemm_true_true_true_false_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_true_true_true_false_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_true_true_true_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_true_true_true_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_true_true_true_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_true_true_true_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_true_true_true_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_true_true_true_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_true_true_true_false_vec4",
+            include_str!(r"../kernels/generated/q5_0gemm_true_true_true_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q5_0gemm_true_true_true_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_true_true_true_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_true_true_true_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_true_true_true_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_true_true_true_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_true_true_true_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_true_true_true_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_0gemm_true_true_true_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_0gemm_true_true_true_true_vec4",
+            include_str!(r"../kernels/generated/q5_0gemm_true_true_true_true_vec4.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_false_16_16_false_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_false_16_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_false_16_16_true_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_false_16_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_false_16_32_false_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_false_16_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_false_16_32_true_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_false_16_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_false_16_4_false_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_false_16_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_false_16_4_true_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_false_16_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_false_16_8_false_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_false_16_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_false_16_8_true_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_false_16_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_false_32_16_false_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_false_32_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_false_32_16_true_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_false_32_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_false_32_32_false_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_false_32_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_false_32_32_true_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_false_32_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_false_32_4_false_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_false_32_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_false_32_4_true_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_false_32_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_false_32_8_false_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_false_32_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_false_32_8_true_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_false_32_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_false_4_16_false_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_false_4_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_false_4_16_true_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_false_4_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_false_4_256_false_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_false_4_256_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_false_4_256_true_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_false_4_256_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_false_4_32_false_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_false_4_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_false_4_32_true_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_false_4_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_false_4_4_false_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_false_4_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_false_4_4_true_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_false_4_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_false_4_8_false_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_false_4_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_false_4_8_true_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_false_4_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_false_64_16_false_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_false_64_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_false_64_16_true_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_false_64_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_false_64_4_false_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_false_64_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_false_64_4_true_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_false_64_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_false_64_8_false_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_false_64_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_false_64_8_true_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_false_64_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_false_8_16_false_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_false_8_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_false_8_16_true_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_false_8_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_false_8_32_false_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_false_8_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_false_8_32_true_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_false_8_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_false_8_4_false_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_false_8_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_false_8_4_true_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_false_8_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_false_8_8_false_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_false_8_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_false_8_8_true_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_false_8_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_true_16_16_false_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_true_16_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_true_16_16_true_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_true_16_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_true_16_32_false_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_true_16_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_true_16_32_true_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_true_16_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_true_16_4_false_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_true_16_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_true_16_4_true_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_true_16_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_true_16_8_false_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_true_16_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_true_16_8_true_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_true_16_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_true_32_16_false_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_true_32_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_true_32_16_true_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_true_32_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_true_32_32_false_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_true_32_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_true_32_32_true_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_true_32_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_true_32_4_false_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_true_32_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_true_32_4_true_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_true_32_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_true_32_8_false_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_true_32_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_true_32_8_true_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_true_32_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_true_4_16_false_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_true_4_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_true_4_16_true_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_true_4_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_true_4_256_false_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_true_4_256_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_true_4_256_true_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_true_4_256_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_true_4_32_false_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_true_4_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_true_4_32_true_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_true_4_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_true_4_4_false_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_true_4_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_true_4_4_true_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_true_4_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_true_4_8_false_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_true_4_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_true_4_8_true_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_true_4_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_true_64_16_false_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_true_64_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_true_64_16_true_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_true_64_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_true_64_4_false_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_true_64_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_true_64_4_true_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_true_64_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_true_64_8_false_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_true_64_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_true_64_8_true_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_true_64_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_true_8_16_false_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_true_8_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_true_8_16_true_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_true_8_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_true_8_32_false_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_true_8_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_true_8_32_true_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_true_8_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_true_8_4_false_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_true_8_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_true_8_4_true_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_true_8_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_true_8_8_false_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_true_8_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_0gemv_true_8_8_true_scalar",
+            include_str!(r"../kernels/generated/q5_0gemv_true_8_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1_index_select_scalar",
+            include_str!(r"../kernels/generated/q5_1_index_select_scalar.wgsl"),
+        );
+        m.insert("q5_1gemm_false_false_false_false_false_false_false_scalar", include_str!(r"../kernels/generated/q5_1gemm_false_false_false_false_false_false_false_scalar.wgsl"));
+        m.insert("q5_1gemm_false_false_false_false_false_false_true_scalar", include_str!(r"../kernels/generated/q5_1gemm_false_false_false_false_false_false_true_scalar.wgsl"));
+        m.insert("q5_1gemm_false_false_false_false_false_true_false_scalar", include_str!(r"../kernels/generated/q5_1gemm_false_false_false_false_false_true_false_scalar.wgsl"));
+        m.insert(
+            "q5_1gemm_false_false_false_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_false_false_false_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_false_false_false_false_vec4",
+            include_str!(r"../kernels/generated/q5_1gemm_false_false_false_false_vec4.wgsl"),
+        );
+        m.insert("q5_1gemm_false_false_false_true_false_false_false_scalar", include_str!(r"../kernels/generated/q5_1gemm_false_false_false_true_false_false_false_scalar.wgsl"));
+        m.insert(
+            "q5_1gemm_false_false_false_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_false_false_false_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_false_false_false_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_false_false_false_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_false_false_false_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_false_false_false_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_false_false_false_true_vec4",
+            include_str!(r"../kernels/generated/q5_1gemm_false_false_false_true_vec4.wgsl"),
+        );
+        m.insert("q5_1gemm_false_false_true_false_false_false_false_scalar", include_str!(r"../kernels/generated/q5_1gemm_false_false_true_false_false_false_false_scalar.wgsl"));
+        m.insert(
+            "q5_1gemm_false_false_true_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_false_false_true_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_false_false_true_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_false_false_true_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_false_false_true_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_false_false_true_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_false_false_true_false_vec4",
+            include_str!(r"../kernels/generated/q5_1gemm_false_false_true_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q5_1gemm_false_false_true_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_false_false_true_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_false_false_true_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_false_false_true_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_false_false_true_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_false_false_true_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_false_false_true_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_false_false_true_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_false_false_true_true_vec4",
+            include_str!(r"../kernels/generated/q5_1gemm_false_false_true_true_vec4.wgsl"),
+        );
+        m.insert("q5_1gemm_false_true_false_false_false_false_false_scalar", include_str!(r"../kernels/generated/q5_1gemm_false_true_false_false_false_false_false_scalar.wgsl"));
+        m.insert(
+            "q5_1gemm_false_true_false_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_false_true_false_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_false_true_false_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_false_true_false_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_false_true_false_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_false_true_false_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_false_true_false_false_vec4",
+            include_str!(r"../kernels/generated/q5_1gemm_false_true_false_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q5_1gemm_false_true_false_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_false_true_false_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_false_true_false_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_false_true_false_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_false_true_false_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_false_true_false_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_false_true_false_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_false_true_false_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_false_true_false_true_vec4",
+            include_str!(r"../kernels/generated/q5_1gemm_false_true_false_true_vec4.wgsl"),
+        );
+        m.insert(
+            "q5_1gemm_false_true_true_false_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_false_true_true_false_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_false_true_true_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_false_true_true_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_false_true_true_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_false_true_true_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_false_true_true_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_false_true_true_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_false_true_true_false_vec4",
+            include_str!(r"../kernels/generated/q5_1gemm_false_true_true_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q5_1gemm_false_true_true_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_false_true_true_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_false_true_true_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_false_true_true_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_false_true_true_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_false_true_true_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_false_true_true_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_false_true_true_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_false_true_true_true_vec4",
+            include_str!(r"../kernels/generated/q5_1gemm_false_true_true_true_vec4.wgsl"),
+        );
+        m.insert("q5_1gemm_true_false_false_false_false_false_false_scalar", include_str!(r"../kernels/generated/q5_1gemm_true_false_false_false_false_false_false_scalar.wgsl"));
+        m.insert(
+            "q5_1gemm_true_false_false_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_true_false_false_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_true_false_false_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_true_false_false_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_true_false_false_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_true_false_false_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_true_false_false_false_vec4",
+            include_str!(r"../kernels/generated/q5_1gemm_true_false_false_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q5_1gemm_true_false_false_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_true_false_false_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_true_false_false_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_true_false_false_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_true_false_false_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_true_false_false_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_true_false_false_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_true_false_false_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_true_false_false_true_vec4",
+            include_str!(r"../kernels/generated/q5_1gemm_true_false_false_true_vec4.wgsl"),
+        );
+        m.insert(
+            "q5_1gemm_true_false_true_false_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_true_false_true_false_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_true_false_true_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_true_false_true_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_true_false_true_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_true_false_true_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_true_false_true_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_true_false_true_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_true_false_true_false_vec4",
+            include_str!(r"../kernels/generated/q5_1gemm_true_false_true_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q5_1gemm_true_false_true_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_true_false_true_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_true_false_true_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_true_false_true_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_true_false_true_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_true_false_true_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_true_false_true_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_true_false_true_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_true_false_true_true_vec4",
+            include_str!(r"../kernels/generated/q5_1gemm_true_false_true_true_vec4.wgsl"),
+        );
+        m.insert(
+            "q5_1gemm_true_true_false_false_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_true_true_false_false_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_true_true_false_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_true_true_false_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_true_true_false_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_true_true_false_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_true_true_false_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_true_true_false_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_true_true_false_false_vec4",
+            include_str!(r"../kernels/generated/q5_1gemm_true_true_false_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q5_1gemm_true_true_false_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_true_true_false_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_true_true_false_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_true_true_false_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_true_true_false_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_true_true_false_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_true_true_false_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_true_true_false_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_true_true_false_true_vec4",
+            include_str!(r"../kernels/generated/q5_1gemm_true_true_false_true_vec4.wgsl"),
+        );
+        m.insert(
+            "q5_1gemm_true_true_true_false_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_true_true_true_false_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_true_true_true_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_true_true_true_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_true_true_true_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_true_true_true_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_true_true_true_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_true_true_true_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_true_true_true_false_vec4",
+            include_str!(r"../kernels/generated/q5_1gemm_true_true_true_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q5_1gemm_true_true_true_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_true_true_true_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_true_true_true_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_true_true_true_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_true_true_true_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_true_true_true_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_true_true_true_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q5_1gemm_true_true_true_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q5_1gemm_true_true_true_true_vec4",
+            include_str!(r"../kernels/generated/q5_1gemm_true_true_true_true_vec4.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_false_16_16_false_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_false_16_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_false_16_16_true_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_false_16_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_false_16_32_false_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_false_16_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_false_16_32_true_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_false_16_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_false_16_4_false_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_false_16_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_false_16_4_true_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_false_16_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_false_16_8_false_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_false_16_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_false_16_8_true_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_false_16_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_false_32_16_false_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_false_32_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_false_32_16_true_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_false_32_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_false_32_32_false_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_false_32_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_false_32_32_true_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_false_32_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_false_32_4_false_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_false_32_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_false_32_4_true_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_false_32_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_false_32_8_false_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_false_32_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_false_32_8_true_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_false_32_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_false_4_16_false_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_false_4_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_false_4_16_true_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_false_4_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_false_4_256_false_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_false_4_256_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_false_4_256_true_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_false_4_256_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_false_4_32_false_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_false_4_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_false_4_32_true_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_false_4_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_false_4_4_false_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_false_4_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_false_4_4_true_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_false_4_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_false_4_8_false_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_false_4_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_false_4_8_true_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_false_4_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_false_64_16_false_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_false_64_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_false_64_16_true_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_false_64_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_false_64_4_false_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_false_64_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_false_64_4_true_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_false_64_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_false_64_8_false_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_false_64_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_false_64_8_true_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_false_64_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_false_8_16_false_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_false_8_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_false_8_16_true_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_false_8_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_false_8_32_false_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_false_8_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_false_8_32_true_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_false_8_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_false_8_4_false_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_false_8_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_false_8_4_true_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_false_8_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_false_8_8_false_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_false_8_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_false_8_8_true_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_false_8_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_true_16_16_false_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_true_16_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_true_16_16_true_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_true_16_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_true_16_32_false_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_true_16_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_true_16_32_true_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_true_16_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_true_16_4_false_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_true_16_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_true_16_4_true_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_true_16_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_true_16_8_false_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_true_16_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_true_16_8_true_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_true_16_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_true_32_16_false_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_true_32_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_true_32_16_true_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_true_32_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_true_32_32_false_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_true_32_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_true_32_32_true_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_true_32_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_true_32_4_false_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_true_32_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_true_32_4_true_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_true_32_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_true_32_8_false_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_true_32_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_true_32_8_true_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_true_32_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_true_4_16_false_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_true_4_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_true_4_16_true_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_true_4_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_true_4_256_false_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_true_4_256_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_true_4_256_true_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_true_4_256_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_true_4_32_false_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_true_4_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_true_4_32_true_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_true_4_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_true_4_4_false_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_true_4_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_true_4_4_true_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_true_4_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_true_4_8_false_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_true_4_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_true_4_8_true_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_true_4_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_true_64_16_false_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_true_64_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_true_64_16_true_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_true_64_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_true_64_4_false_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_true_64_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_true_64_4_true_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_true_64_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_true_64_8_false_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_true_64_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_true_64_8_true_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_true_64_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_true_8_16_false_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_true_8_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_true_8_16_true_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_true_8_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_true_8_32_false_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_true_8_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_true_8_32_true_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_true_8_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_true_8_4_false_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_true_8_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_true_8_4_true_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_true_8_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_true_8_8_false_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_true_8_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q5_1gemv_true_8_8_true_scalar",
+            include_str!(r"../kernels/generated/q5_1gemv_true_8_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1_index_select_scalar",
+            include_str!(r"../kernels/generated/q8_1_index_select_scalar.wgsl"),
+        );
+        m.insert("q8_1gemm_false_false_false_false_false_false_false_scalar", include_str!(r"../kernels/generated/q8_1gemm_false_false_false_false_false_false_false_scalar.wgsl"));
+        m.insert("q8_1gemm_false_false_false_false_false_false_true_scalar", include_str!(r"../kernels/generated/q8_1gemm_false_false_false_false_false_false_true_scalar.wgsl"));
+        m.insert("q8_1gemm_false_false_false_false_false_true_false_scalar", include_str!(r"../kernels/generated/q8_1gemm_false_false_false_false_false_true_false_scalar.wgsl"));
+        m.insert(
+            "q8_1gemm_false_false_false_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_false_false_false_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_false_false_false_false_vec4",
+            include_str!(r"../kernels/generated/q8_1gemm_false_false_false_false_vec4.wgsl"),
+        );
+        m.insert("q8_1gemm_false_false_false_true_false_false_false_scalar", include_str!(r"../kernels/generated/q8_1gemm_false_false_false_true_false_false_false_scalar.wgsl"));
+        m.insert(
+            "q8_1gemm_false_false_false_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_false_false_false_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_false_false_false_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_false_false_false_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_false_false_false_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_false_false_false_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_false_false_false_true_vec4",
+            include_str!(r"../kernels/generated/q8_1gemm_false_false_false_true_vec4.wgsl"),
+        );
+        m.insert("q8_1gemm_false_false_true_false_false_false_false_scalar", include_str!(r"../kernels/generated/q8_1gemm_false_false_true_false_false_false_false_scalar.wgsl"));
+        m.insert(
+            "q8_1gemm_false_false_true_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_false_false_true_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_false_false_true_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_false_false_true_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_false_false_true_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_false_false_true_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_false_false_true_false_vec4",
+            include_str!(r"../kernels/generated/q8_1gemm_false_false_true_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q8_1gemm_false_false_true_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_false_false_true_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_false_false_true_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_false_false_true_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_false_false_true_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_false_false_true_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_false_false_true_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_false_false_true_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_false_false_true_true_vec4",
+            include_str!(r"../kernels/generated/q8_1gemm_false_false_true_true_vec4.wgsl"),
+        );
+        m.insert("q8_1gemm_false_true_false_false_false_false_false_scalar", include_str!(r"../kernels/generated/q8_1gemm_false_true_false_false_false_false_false_scalar.wgsl"));
+        m.insert(
+            "q8_1gemm_false_true_false_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_false_true_false_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_false_true_false_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_false_true_false_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_false_true_false_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_false_true_false_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_false_true_false_false_vec4",
+            include_str!(r"../kernels/generated/q8_1gemm_false_true_false_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q8_1gemm_false_true_false_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_false_true_false_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_false_true_false_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_false_true_false_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_false_true_false_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_false_true_false_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_false_true_false_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_false_true_false_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_false_true_false_true_vec4",
+            include_str!(r"../kernels/generated/q8_1gemm_false_true_false_true_vec4.wgsl"),
+        );
+        m.insert(
+            "q8_1gemm_false_true_true_false_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_false_true_true_false_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_false_true_true_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_false_true_true_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_false_true_true_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_false_true_true_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_false_true_true_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_false_true_true_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_false_true_true_false_vec4",
+            include_str!(r"../kernels/generated/q8_1gemm_false_true_true_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q8_1gemm_false_true_true_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_false_true_true_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_false_true_true_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_false_true_true_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_false_true_true_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_false_true_true_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_false_true_true_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_false_true_true_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_false_true_true_true_vec4",
+            include_str!(r"../kernels/generated/q8_1gemm_false_true_true_true_vec4.wgsl"),
+        );
+        m.insert("q8_1gemm_true_false_false_false_false_false_false_scalar", include_str!(r"../kernels/generated/q8_1gemm_true_false_false_false_false_false_false_scalar.wgsl"));
+        m.insert(
+            "q8_1gemm_true_false_false_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_true_false_false_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_true_false_false_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_true_false_false_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_true_false_false_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_true_false_false_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_true_false_false_false_vec4",
+            include_str!(r"../kernels/generated/q8_1gemm_true_false_false_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q8_1gemm_true_false_false_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_true_false_false_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_true_false_false_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_true_false_false_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_true_false_false_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_true_false_false_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_true_false_false_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_true_false_false_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_true_false_false_true_vec4",
+            include_str!(r"../kernels/generated/q8_1gemm_true_false_false_true_vec4.wgsl"),
+        );
+        m.insert(
+            "q8_1gemm_true_false_true_false_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_true_false_true_false_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_true_false_true_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_true_false_true_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_true_false_true_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_true_false_true_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_true_false_true_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_true_false_true_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_true_false_true_false_vec4",
+            include_str!(r"../kernels/generated/q8_1gemm_true_false_true_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q8_1gemm_true_false_true_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_true_false_true_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_true_false_true_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_true_false_true_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_true_false_true_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_true_false_true_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_true_false_true_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_true_false_true_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_true_false_true_true_vec4",
+            include_str!(r"../kernels/generated/q8_1gemm_true_false_true_true_vec4.wgsl"),
+        );
+        m.insert(
+            "q8_1gemm_true_true_false_false_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_true_true_false_false_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_true_true_false_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_true_true_false_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_true_true_false_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_true_true_false_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_true_true_false_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_true_true_false_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_true_true_false_false_vec4",
+            include_str!(r"../kernels/generated/q8_1gemm_true_true_false_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q8_1gemm_true_true_false_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_true_true_false_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_true_true_false_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_true_true_false_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_true_true_false_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_true_true_false_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_true_true_false_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_true_true_false_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_true_true_false_true_vec4",
+            include_str!(r"../kernels/generated/q8_1gemm_true_true_false_true_vec4.wgsl"),
+        );
+        m.insert(
+            "q8_1gemm_true_true_true_false_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_true_true_true_false_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_true_true_true_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_true_true_true_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_true_true_true_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_true_true_true_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_true_true_true_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_true_true_true_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_true_true_true_false_vec4",
+            include_str!(r"../kernels/generated/q8_1gemm_true_true_true_false_vec4.wgsl"),
+        );
+        m.insert(
+            "q8_1gemm_true_true_true_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_true_true_true_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_true_true_true_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_true_true_true_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_true_true_true_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_true_true_true_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_true_true_true_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/q8_1gemm_true_true_true_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "q8_1gemm_true_true_true_true_vec4",
+            include_str!(r"../kernels/generated/q8_1gemm_true_true_true_true_vec4.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_false_16_16_false_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_false_16_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_false_16_16_true_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_false_16_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_false_16_32_false_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_false_16_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_false_16_32_true_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_false_16_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_false_16_4_false_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_false_16_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_false_16_4_true_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_false_16_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_false_16_8_false_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_false_16_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_false_16_8_true_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_false_16_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_false_32_16_false_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_false_32_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_false_32_16_true_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_false_32_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_false_32_32_false_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_false_32_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_false_32_32_true_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_false_32_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_false_32_4_false_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_false_32_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_false_32_4_true_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_false_32_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_false_32_8_false_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_false_32_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_false_32_8_true_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_false_32_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_false_4_16_false_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_false_4_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_false_4_16_true_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_false_4_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_false_4_256_false_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_false_4_256_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_false_4_256_true_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_false_4_256_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_false_4_32_false_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_false_4_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_false_4_32_true_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_false_4_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_false_4_4_false_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_false_4_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_false_4_4_true_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_false_4_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_false_4_8_false_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_false_4_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_false_4_8_true_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_false_4_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_false_64_16_false_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_false_64_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_false_64_16_true_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_false_64_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_false_64_4_false_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_false_64_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_false_64_4_true_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_false_64_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_false_64_8_false_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_false_64_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_false_64_8_true_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_false_64_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_false_8_16_false_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_false_8_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_false_8_16_true_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_false_8_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_false_8_32_false_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_false_8_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_false_8_32_true_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_false_8_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_false_8_4_false_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_false_8_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_false_8_4_true_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_false_8_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_false_8_8_false_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_false_8_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_false_8_8_true_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_false_8_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_true_16_16_false_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_true_16_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_true_16_16_true_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_true_16_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_true_16_32_false_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_true_16_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_true_16_32_true_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_true_16_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_true_16_4_false_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_true_16_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_true_16_4_true_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_true_16_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_true_16_8_false_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_true_16_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_true_16_8_true_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_true_16_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_true_32_16_false_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_true_32_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_true_32_16_true_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_true_32_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_true_32_32_false_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_true_32_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_true_32_32_true_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_true_32_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_true_32_4_false_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_true_32_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_true_32_4_true_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_true_32_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_true_32_8_false_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_true_32_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_true_32_8_true_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_true_32_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_true_4_16_false_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_true_4_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_true_4_16_true_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_true_4_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_true_4_256_false_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_true_4_256_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_true_4_256_true_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_true_4_256_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_true_4_32_false_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_true_4_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_true_4_32_true_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_true_4_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_true_4_4_false_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_true_4_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_true_4_4_true_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_true_4_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_true_4_8_false_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_true_4_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_true_4_8_true_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_true_4_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_true_64_16_false_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_true_64_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_true_64_16_true_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_true_64_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_true_64_4_false_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_true_64_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_true_64_4_true_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_true_64_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_true_64_8_false_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_true_64_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_true_64_8_true_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_true_64_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_true_8_16_false_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_true_8_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_true_8_16_true_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_true_8_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_true_8_32_false_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_true_8_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_true_8_32_true_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_true_8_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_true_8_4_false_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_true_8_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_true_8_4_true_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_true_8_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_true_8_8_false_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_true_8_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "q8_1gemv_true_8_8_true_scalar",
+            include_str!(r"../kernels/generated/q8_1gemv_true_8_8_true_scalar.wgsl"),
+        );
+        m.insert("sint4gemm_false_false_false_false_false_false_false_scalar", include_str!(r"../kernels/generated/sint4gemm_false_false_false_false_false_false_false_scalar.wgsl"));
+        m.insert("sint4gemm_false_false_false_false_false_false_true_scalar", include_str!(r"../kernels/generated/sint4gemm_false_false_false_false_false_false_true_scalar.wgsl"));
+        m.insert("sint4gemm_false_false_false_false_false_true_false_scalar", include_str!(r"../kernels/generated/sint4gemm_false_false_false_false_false_true_false_scalar.wgsl"));
+        m.insert("sint4gemm_false_false_false_false_false_true_true_scalar", include_str!(r"../kernels/generated/sint4gemm_false_false_false_false_false_true_true_scalar.wgsl"));
+        m.insert(
+            "sint4gemm_false_false_false_false_vec4",
+            include_str!(r"../kernels/generated/sint4gemm_false_false_false_false_vec4.wgsl"),
+        );
+        m.insert("sint4gemm_false_false_false_true_false_false_false_scalar", include_str!(r"../kernels/generated/sint4gemm_false_false_false_true_false_false_false_scalar.wgsl"));
+        m.insert("sint4gemm_false_false_false_true_false_false_true_scalar", include_str!(r"../kernels/generated/sint4gemm_false_false_false_true_false_false_true_scalar.wgsl"));
+        m.insert("sint4gemm_false_false_false_true_false_true_false_scalar", include_str!(r"../kernels/generated/sint4gemm_false_false_false_true_false_true_false_scalar.wgsl"));
+        m.insert(
+            "sint4gemm_false_false_false_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/sint4gemm_false_false_false_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "sint4gemm_false_false_false_true_vec4",
+            include_str!(r"../kernels/generated/sint4gemm_false_false_false_true_vec4.wgsl"),
+        );
+        m.insert("sint4gemm_false_false_true_false_false_false_false_scalar", include_str!(r"../kernels/generated/sint4gemm_false_false_true_false_false_false_false_scalar.wgsl"));
+        m.insert("sint4gemm_false_false_true_false_false_false_true_scalar", include_str!(r"../kernels/generated/sint4gemm_false_false_true_false_false_false_true_scalar.wgsl"));
+        m.insert("sint4gemm_false_false_true_false_false_true_false_scalar", include_str!(r"../kernels/generated/sint4gemm_false_false_true_false_false_true_false_scalar.wgsl"));
+        m.insert(
+            "sint4gemm_false_false_true_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/sint4gemm_false_false_true_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "sint4gemm_false_false_true_false_vec4",
+            include_str!(r"../kernels/generated/sint4gemm_false_false_true_false_vec4.wgsl"),
+        );
+        m.insert("sint4gemm_false_false_true_true_false_false_false_scalar", include_str!(r"../kernels/generated/sint4gemm_false_false_true_true_false_false_false_scalar.wgsl"));
+        m.insert(
+            "sint4gemm_false_false_true_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/sint4gemm_false_false_true_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "sint4gemm_false_false_true_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/sint4gemm_false_false_true_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "sint4gemm_false_false_true_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/sint4gemm_false_false_true_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "sint4gemm_false_false_true_true_vec4",
+            include_str!(r"../kernels/generated/sint4gemm_false_false_true_true_vec4.wgsl"),
+        );
+        m.insert("sint4gemm_false_true_false_false_false_false_false_scalar", include_str!(r"../kernels/generated/sint4gemm_false_true_false_false_false_false_false_scalar.wgsl"));
+        m.insert("sint4gemm_false_true_false_false_false_false_true_scalar", include_str!(r"../kernels/generated/sint4gemm_false_true_false_false_false_false_true_scalar.wgsl"));
+        m.insert("sint4gemm_false_true_false_false_false_true_false_scalar", include_str!(r"../kernels/generated/sint4gemm_false_true_false_false_false_true_false_scalar.wgsl"));
+        m.insert(
+            "sint4gemm_false_true_false_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/sint4gemm_false_true_false_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "sint4gemm_false_true_false_false_vec4",
+            include_str!(r"../kernels/generated/sint4gemm_false_true_false_false_vec4.wgsl"),
+        );
+        m.insert("sint4gemm_false_true_false_true_false_false_false_scalar", include_str!(r"../kernels/generated/sint4gemm_false_true_false_true_false_false_false_scalar.wgsl"));
+        m.insert(
+            "sint4gemm_false_true_false_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/sint4gemm_false_true_false_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "sint4gemm_false_true_false_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/sint4gemm_false_true_false_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "sint4gemm_false_true_false_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/sint4gemm_false_true_false_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "sint4gemm_false_true_false_true_vec4",
+            include_str!(r"../kernels/generated/sint4gemm_false_true_false_true_vec4.wgsl"),
+        );
+        m.insert("sint4gemm_false_true_true_false_false_false_false_scalar", include_str!(r"../kernels/generated/sint4gemm_false_true_true_false_false_false_false_scalar.wgsl"));
+        m.insert(
+            "sint4gemm_false_true_true_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/sint4gemm_false_true_true_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "sint4gemm_false_true_true_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/sint4gemm_false_true_true_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "sint4gemm_false_true_true_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/sint4gemm_false_true_true_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "sint4gemm_false_true_true_false_vec4",
+            include_str!(r"../kernels/generated/sint4gemm_false_true_true_false_vec4.wgsl"),
+        );
+        m.insert(
+            "sint4gemm_false_true_true_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/sint4gemm_false_true_true_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "sint4gemm_false_true_true_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/sint4gemm_false_true_true_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "sint4gemm_false_true_true_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/sint4gemm_false_true_true_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "sint4gemm_false_true_true_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/sint4gemm_false_true_true_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "sint4gemm_false_true_true_true_vec4",
+            include_str!(r"../kernels/generated/sint4gemm_false_true_true_true_vec4.wgsl"),
+        );
+        m.insert("sint4gemm_true_false_false_false_false_false_false_scalar", include_str!(r"../kernels/generated/sint4gemm_true_false_false_false_false_false_false_scalar.wgsl"));
+        m.insert("sint4gemm_true_false_false_false_false_false_true_scalar", include_str!(r"../kernels/generated/sint4gemm_true_false_false_false_false_false_true_scalar.wgsl"));
+        m.insert("sint4gemm_true_false_false_false_false_true_false_scalar", include_str!(r"../kernels/generated/sint4gemm_true_false_false_false_false_true_false_scalar.wgsl"));
+        m.insert(
+            "sint4gemm_true_false_false_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/sint4gemm_true_false_false_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "sint4gemm_true_false_false_false_vec4",
+            include_str!(r"../kernels/generated/sint4gemm_true_false_false_false_vec4.wgsl"),
+        );
+        m.insert("sint4gemm_true_false_false_true_false_false_false_scalar", include_str!(r"../kernels/generated/sint4gemm_true_false_false_true_false_false_false_scalar.wgsl"));
+        m.insert(
+            "sint4gemm_true_false_false_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/sint4gemm_true_false_false_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "sint4gemm_true_false_false_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/sint4gemm_true_false_false_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "sint4gemm_true_false_false_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/sint4gemm_true_false_false_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "sint4gemm_true_false_false_true_vec4",
+            include_str!(r"../kernels/generated/sint4gemm_true_false_false_true_vec4.wgsl"),
+        );
+        m.insert("sint4gemm_true_false_true_false_false_false_false_scalar", include_str!(r"../kernels/generated/sint4gemm_true_false_true_false_false_false_false_scalar.wgsl"));
+        m.insert(
+            "sint4gemm_true_false_true_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/sint4gemm_true_false_true_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "sint4gemm_true_false_true_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/sint4gemm_true_false_true_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "sint4gemm_true_false_true_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/sint4gemm_true_false_true_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "sint4gemm_true_false_true_false_vec4",
+            include_str!(r"../kernels/generated/sint4gemm_true_false_true_false_vec4.wgsl"),
+        );
+        m.insert(
+            "sint4gemm_true_false_true_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/sint4gemm_true_false_true_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "sint4gemm_true_false_true_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/sint4gemm_true_false_true_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "sint4gemm_true_false_true_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/sint4gemm_true_false_true_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "sint4gemm_true_false_true_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/sint4gemm_true_false_true_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "sint4gemm_true_false_true_true_vec4",
+            include_str!(r"../kernels/generated/sint4gemm_true_false_true_true_vec4.wgsl"),
+        );
+        m.insert("sint4gemm_true_true_false_false_false_false_false_scalar", include_str!(r"../kernels/generated/sint4gemm_true_true_false_false_false_false_false_scalar.wgsl"));
+        m.insert(
+            "sint4gemm_true_true_false_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/sint4gemm_true_true_false_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "sint4gemm_true_true_false_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/sint4gemm_true_true_false_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "sint4gemm_true_true_false_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/sint4gemm_true_true_false_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "sint4gemm_true_true_false_false_vec4",
+            include_str!(r"../kernels/generated/sint4gemm_true_true_false_false_vec4.wgsl"),
+        );
+        m.insert(
+            "sint4gemm_true_true_false_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/sint4gemm_true_true_false_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "sint4gemm_true_true_false_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/sint4gemm_true_true_false_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "sint4gemm_true_true_false_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/sint4gemm_true_true_false_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "sint4gemm_true_true_false_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/sint4gemm_true_true_false_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "sint4gemm_true_true_false_true_vec4",
+            include_str!(r"../kernels/generated/sint4gemm_true_true_false_true_vec4.wgsl"),
+        );
+        m.insert(
+            "sint4gemm_true_true_true_false_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/sint4gemm_true_true_true_false_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "sint4gemm_true_true_true_false_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/sint4gemm_true_true_true_false_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "sint4gemm_true_true_true_false_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/sint4gemm_true_true_true_false_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "sint4gemm_true_true_true_false_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/sint4gemm_true_true_true_false_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "sint4gemm_true_true_true_false_vec4",
+            include_str!(r"../kernels/generated/sint4gemm_true_true_true_false_vec4.wgsl"),
+        );
+        m.insert(
+            "sint4gemm_true_true_true_true_false_false_false_scalar",
+            include_str!(
+                r"../kernels/generated/sint4gemm_true_true_true_true_false_false_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "sint4gemm_true_true_true_true_false_false_true_scalar",
+            include_str!(
+                r"../kernels/generated/sint4gemm_true_true_true_true_false_false_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "sint4gemm_true_true_true_true_false_true_false_scalar",
+            include_str!(
+                r"../kernels/generated/sint4gemm_true_true_true_true_false_true_false_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "sint4gemm_true_true_true_true_false_true_true_scalar",
+            include_str!(
+                r"../kernels/generated/sint4gemm_true_true_true_true_false_true_true_scalar.wgsl"
+            ),
+        );
+        m.insert(
+            "sint4gemm_true_true_true_true_vec4",
+            include_str!(r"../kernels/generated/sint4gemm_true_true_true_true_vec4.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_false_16_16_false_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_false_16_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_false_16_16_true_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_false_16_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_false_16_32_false_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_false_16_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_false_16_32_true_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_false_16_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_false_16_4_false_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_false_16_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_false_16_4_true_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_false_16_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_false_16_8_false_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_false_16_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_false_16_8_true_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_false_16_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_false_32_16_false_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_false_32_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_false_32_16_true_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_false_32_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_false_32_32_false_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_false_32_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_false_32_32_true_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_false_32_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_false_32_4_false_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_false_32_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_false_32_4_true_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_false_32_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_false_32_8_false_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_false_32_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_false_32_8_true_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_false_32_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_false_4_16_false_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_false_4_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_false_4_16_true_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_false_4_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_false_4_256_false_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_false_4_256_false_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_false_4_256_true_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_false_4_256_true_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_false_4_32_false_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_false_4_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_false_4_32_true_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_false_4_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_false_4_4_false_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_false_4_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_false_4_4_true_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_false_4_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_false_4_8_false_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_false_4_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_false_4_8_true_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_false_4_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_false_64_16_false_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_false_64_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_false_64_16_true_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_false_64_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_false_64_4_false_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_false_64_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_false_64_4_true_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_false_64_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_false_64_8_false_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_false_64_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_false_64_8_true_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_false_64_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_false_8_16_false_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_false_8_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_false_8_16_true_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_false_8_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_false_8_32_false_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_false_8_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_false_8_32_true_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_false_8_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_false_8_4_false_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_false_8_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_false_8_4_true_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_false_8_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_false_8_8_false_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_false_8_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_false_8_8_true_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_false_8_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_true_16_16_false_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_true_16_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_true_16_16_true_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_true_16_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_true_16_32_false_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_true_16_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_true_16_32_true_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_true_16_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_true_16_4_false_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_true_16_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_true_16_4_true_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_true_16_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_true_16_8_false_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_true_16_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_true_16_8_true_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_true_16_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_true_32_16_false_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_true_32_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_true_32_16_true_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_true_32_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_true_32_32_false_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_true_32_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_true_32_32_true_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_true_32_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_true_32_4_false_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_true_32_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_true_32_4_true_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_true_32_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_true_32_8_false_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_true_32_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_true_32_8_true_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_true_32_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_true_4_16_false_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_true_4_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_true_4_16_true_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_true_4_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_true_4_256_false_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_true_4_256_false_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_true_4_256_true_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_true_4_256_true_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_true_4_32_false_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_true_4_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_true_4_32_true_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_true_4_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_true_4_4_false_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_true_4_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_true_4_4_true_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_true_4_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_true_4_8_false_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_true_4_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_true_4_8_true_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_true_4_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_true_64_16_false_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_true_64_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_true_64_16_true_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_true_64_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_true_64_4_false_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_true_64_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_true_64_4_true_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_true_64_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_true_64_8_false_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_true_64_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_true_64_8_true_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_true_64_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_true_8_16_false_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_true_8_16_false_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_true_8_16_true_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_true_8_16_true_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_true_8_32_false_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_true_8_32_false_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_true_8_32_true_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_true_8_32_true_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_true_8_4_false_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_true_8_4_false_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_true_8_4_true_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_true_8_4_true_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_true_8_8_false_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_true_8_8_false_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4gemv_true_8_8_true_scalar",
+            include_str!(r"../kernels/generated/sint4gemv_true_8_8_true_scalar.wgsl"),
+        );
+        m.insert(
+            "q4_0_index_select_vec4",
+            include_str!(r"../kernels/generated/q4_0_index_select_vec4.wgsl"),
+        );
+        m.insert(
+            "q4_1_index_select_vec4",
+            include_str!(r"../kernels/generated/q4_1_index_select_vec4.wgsl"),
+        );
+        m.insert(
+            "q4k_index_select_scalar",
+            include_str!(r"../kernels/generated/q4k_index_select_scalar.wgsl"),
+        );
+        m.insert(
+            "q4k_index_select_vec4",
+            include_str!(r"../kernels/generated/q4k_index_select_vec4.wgsl"),
+        );
+        m.insert(
+            "q5_0_index_select_vec4",
+            include_str!(r"../kernels/generated/q5_0_index_select_vec4.wgsl"),
+        );
+        m.insert(
+            "q5_1_index_select_vec4",
+            include_str!(r"../kernels/generated/q5_1_index_select_vec4.wgsl"),
+        );
+        m.insert(
+            "q6k_index_select_scalar",
+            include_str!(r"../kernels/generated/q6k_index_select_scalar.wgsl"),
+        );
+        m.insert(
+            "q6k_index_select_vec4",
+            include_str!(r"../kernels/generated/q6k_index_select_vec4.wgsl"),
+        );
+        m.insert(
+            "q8_1_index_select_vec4",
+            include_str!(r"../kernels/generated/q8_1_index_select_vec4.wgsl"),
+        );
+        m.insert(
+            "q_index_select_scalar",
+            include_str!(r"../kernels/generated/q_index_select_scalar.wgsl"),
+        );
+        m.insert(
+            "q_index_select_vec4",
+            include_str!(r"../kernels/generated/q_index_select_vec4.wgsl"),
+        );
+        m.insert(
+            "sint4_index_select_scalar",
+            include_str!(r"../kernels/generated/sint4_index_select_scalar.wgsl"),
+        );
+        m.insert(
+            "sint4_index_select_vec4",
+            include_str!(r"../kernels/generated/sint4_index_select_vec4.wgsl"),
+        );
+        m.insert(
+            "where_cond_scalar_f16",
+            include_str!(r"../kernels/generated/where_cond_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "where_cond_vec2_f16",
+            include_str!(r"../kernels/generated/where_cond_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "where_cond_vec4_f16",
+            include_str!(r"../kernels/generated/where_cond_vec4_f16.wgsl"),
+        );
+        m.insert(
+            "cache_inplace_scalar",
+            include_str!(r"../kernels/cache_inplace_scalar.wgsl"),
+        );
+        m.insert(
+            "reduce_argmax_scalar_f16",
+            include_str!(r"../kernels/generated/reduce_argmax_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "reduce_argmin_scalar_f16",
+            include_str!(r"../kernels/generated/reduce_argmin_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "reduce_max_scalar_f16",
+            include_str!(r"../kernels/generated/reduce_max_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "reduce_mean_scalar_f16",
+            include_str!(r"../kernels/generated/reduce_mean_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "reduce_min_scalar_f16",
+            include_str!(r"../kernels/generated/reduce_min_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "reduce_sum_scalar_f16",
+            include_str!(r"../kernels/generated/reduce_sum_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "softmax_prologue_scalar",
+            include_str!(r"../kernels/generated/softmax_prologue_scalar.wgsl"),
+        );
+        m.insert(
+            "softmax_prologue_scalar_f16",
+            include_str!(r"../kernels/generated/softmax_prologue_scalar_f16.wgsl"),
+        );
+        m.insert(
+            "softmax_prologue_vec2",
+            include_str!(r"../kernels/generated/softmax_prologue_vec2.wgsl"),
+        );
+        m.insert(
+            "softmax_prologue_vec2_f16",
+            include_str!(r"../kernels/generated/softmax_prologue_vec2_f16.wgsl"),
+        );
+        m.insert(
+            "softmax_prologue_vec4",
+            include_str!(r"../kernels/generated/softmax_prologue_vec4.wgsl"),
+        );
+        m.insert(
+            "softmax_prologue_vec4_f16",
+            include_str!(r"../kernels/generated/softmax_prologue_vec4_f16.wgsl"),
         );
         m
     };
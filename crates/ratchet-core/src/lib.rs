@@ -1,9 +1,11 @@
 #![allow(non_snake_case)]
 mod compiled_op;
+mod cpu;
 mod device;
 mod dtype;
 mod enforcer;
 mod executable;
+mod fusion;
 mod gpu;
 mod kernels;
 mod ndarray_ext;
@@ -33,6 +35,8 @@ pub use strides::*;
 pub use tensor::*;
 pub use tensor_id::*;
 
+pub use gpu::{ActivationStats, EvictionPolicy, MemoryStats, PipelineEntry, PipelineManifest};
+
 #[cfg(feature = "plotting")]
 pub use plot::render_to_file;
 
@@ -94,7 +98,7 @@ pub mod prelude {
 
 #[cfg(feature = "pyo3")]
 pub mod test_util {
-    use crate::{DType, Tensor};
+    use crate::{gguf::GGUFDType, DType, Device, Shape, Tensor};
     use regex::Regex;
     use {
         numpy::PyArrayDyn,
@@ -128,4 +132,66 @@ pub mod test_util {
             Ok(Tensor::from(py_result))
         })
     }
+
+    /// Runs `f` in F32 on the CPU, and with every input cast to F16 on `device`, then checks
+    /// that both agree within `atol` and `rtol`.
+    ///
+    /// Fails on devices without `shader-f16`, rather than passing without running anything.
+    pub fn check_f16<F>(
+        device: &Device,
+        inputs: &[Tensor],
+        f: F,
+        atol: f32,
+        rtol: f32,
+    ) -> anyhow::Result<()>
+    where
+        F: Fn(&[Tensor]) -> anyhow::Result<Tensor>,
+    {
+        anyhow::ensure!(
+            device.try_gpu()?.supports_f16(),
+            "{:?} lacks shader-f16",
+            device
+        );
+        let ground = f(inputs)?.resolve()?;
+        let halves = inputs
+            .iter()
+            .map(|t| t.to(device)?.cast(DType::F16))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let ours = f(&halves)?;
+        assert_eq!(ours.dt(), DType::F16);
+        let ours = ours.cast(DType::F32)?.resolve()?.to(&Device::CPU)?;
+        ground.all_close(&ours, atol, rtol)
+    }
+
+    /// Random weights in a GGUF format.
+    ///
+    /// Every bit pattern of the quants is valid, so only the f32 scales and mins need to be
+    /// kept in range.
+    #[cfg(feature = "rand")]
+    pub fn randq(dt: DType, shape: Shape) -> Tensor {
+        use rand::Rng;
+        let numel = shape.numel();
+        let mut rng = rand::thread_rng();
+        let mut bytes = vec![0u8; dt.num_bytes(numel)];
+        rng.fill(bytes.as_mut_slice());
+
+        let DType::GGUF(g) = dt else { unreachable!() };
+        let segments = dt.segments(numel);
+        let scales = match g {
+            GGUFDType::Q8_0(_) | GGUFDType::Q4_0(_) | GGUFDType::Q8_1(_) => vec![1],
+            GGUFDType::Q4_1(_) => vec![1, 2],
+            GGUFDType::Q5_0(_) => vec![2],
+            GGUFDType::Q5_1(_) => vec![2, 3],
+            GGUFDType::Q4K(_) => vec![0, 1],
+            GGUFDType::Q6K(_) => vec![3],
+        };
+        for segment in scales.into_iter().map(|i| segments[i]) {
+            let start = segment.offset as usize;
+            let ds = &mut bytes[start..start + numel / g.block_numel() * 4];
+            for d in ds.chunks_exact_mut(4) {
+                d.copy_from_slice(&rng.gen_range(0.0f32..0.002).to_le_bytes());
+            }
+        }
+        Tensor::from_bytes(&bytes, dt, shape, Device::CPU).unwrap()
+    }
 }
@@ -1,8 +1,5 @@
-use crate::gpu::{
-    BindGroupLayoutDescriptor, ComputePipelineDescriptor, CpuUniform, PipelineLayoutDescriptor,
-    PoolError, WgpuDevice, WorkgroupCount,
-};
-use crate::{ops::*, rvec, CompiledOp, InvariantError, RVec, StorageView, Tensor};
+use crate::gpu::{BindGroupLayoutDescriptor, CpuUniform, PoolError, WgpuDevice, WorkgroupCount};
+use crate::{ops::*, rvec, CPUBuffer, CompiledOp, InvariantError, RVec, StorageView, Tensor};
 use encase::internal::WriteInto;
 use encase::ShaderType;
 use std::fmt::Debug;
@@ -16,6 +13,11 @@ pub enum LazyOp {
     Unary(Unary),
     Reindex(Reindex),
     Concat(Concat),
+    Reduce(Reduce),
+    Cmp(Cmp),
+    WhereCond(WhereCond),
+    Cast(Cast),
+    SDPA(SDPA),
     // ---- Everything below this line shouldn't exist ----
     RoPE(RoPE),
     Softmax(Softmax),
@@ -25,6 +27,7 @@ pub enum LazyOp {
     Select(IndexSelect),    //Can probably be Reindex
     IndexWrite(IndexWrite), //Above 2 should be merged
     Cache(Cache),           //Should be a general class
+    Trilu(Trilu),           //Mask generation, could be fused into consumers
 }
 
 impl LazyOp {
@@ -36,12 +39,18 @@ impl LazyOp {
             LazyOp::Unary(u) => u.kernel_name(),
             LazyOp::Reindex(r) => r.kernel_name(),
             LazyOp::Concat(c) => c.kernel_name(),
+            LazyOp::Reduce(r) => r.kernel_name(),
+            LazyOp::Cmp(c) => c.kernel_name(),
+            LazyOp::WhereCond(w) => w.kernel_name(),
+            LazyOp::Cast(c) => c.kernel_name(),
+            LazyOp::SDPA(s) => s.kernel_name(),
             LazyOp::Norm(n) => n.kernel_name(),
             LazyOp::Conv(c) => c.kernel_name(),
             LazyOp::Select(s) => s.kernel_name(),
             LazyOp::IndexWrite(iw) => iw.kernel_name(),
             LazyOp::RoPE(r) => r.kernel_name(),
             LazyOp::Cache(c) => c.kernel_name(),
+            LazyOp::Trilu(t) => t.kernel_name(),
             LazyOp::View(_) => "View".to_string(),
             LazyOp::Const => "Const".to_string(),
         }
@@ -56,11 +65,17 @@ impl LazyOp {
             LazyOp::Unary(u) => u.srcs(),
             LazyOp::Reindex(r) => r.srcs(),
             LazyOp::Concat(c) => c.srcs(),
+            LazyOp::Reduce(r) => r.srcs(),
+            LazyOp::Cmp(c) => c.srcs(),
+            LazyOp::WhereCond(w) => w.srcs(),
+            LazyOp::Cast(c) => c.srcs(),
+            LazyOp::SDPA(s) => s.srcs(),
             LazyOp::Norm(n) => n.srcs(),
             LazyOp::Conv(c) => c.srcs(),
             LazyOp::Select(s) => s.srcs(),
             LazyOp::IndexWrite(iw) => iw.srcs(),
             LazyOp::Cache(c) => c.srcs(),
+            LazyOp::Trilu(t) => t.srcs(),
             LazyOp::View(v) => rvec![v.input()],
             LazyOp::Const => rvec![], //end of the line kid
         }
@@ -75,16 +90,28 @@ impl LazyOp {
             LazyOp::Unary(u) => u.supports_inplace(),
             LazyOp::Reindex(r) => r.supports_inplace(),
             LazyOp::Concat(c) => c.supports_inplace(),
+            LazyOp::Reduce(r) => r.supports_inplace(),
+            LazyOp::Cmp(c) => c.supports_inplace(),
+            LazyOp::WhereCond(w) => w.supports_inplace(),
+            LazyOp::Cast(c) => c.supports_inplace(),
+            LazyOp::SDPA(s) => s.supports_inplace(),
             LazyOp::Norm(n) => n.supports_inplace(),
             LazyOp::Conv(c) => c.supports_inplace(),
             LazyOp::Select(s) => s.supports_inplace(),
             LazyOp::IndexWrite(iw) => iw.supports_inplace(),
             LazyOp::Cache(c) => c.supports_inplace(),
+            LazyOp::Trilu(t) => t.supports_inplace(),
             LazyOp::View(_v) => true,
             LazyOp::Const => false,
         }
     }
 
+    /// Whether the op writes its result into its first source, even if that source has other
+    /// consumers. Only a dynamic [Cache] does, so that a KV cache is updated in place every step.
+    pub fn aliases_src(&self) -> bool {
+        matches!(self, LazyOp::Cache(c) if c.is_dynamic())
+    }
+
     pub fn is_const(&self) -> bool {
         matches!(self, LazyOp::Const)
     }
@@ -103,13 +130,21 @@ impl LazyOp {
                 Reindex::Broadcast(b) => b.check_invariants(),
             },
             LazyOp::Concat(c) => c.check_invariants(),
+            LazyOp::Reduce(r) => r.check_invariants(),
+            LazyOp::Cmp(c) => c.check_invariants(),
+            LazyOp::WhereCond(w) => w.check_invariants(),
+            LazyOp::Cast(c) => c.check_invariants(),
+            LazyOp::SDPA(s) => s.check_invariants(),
             LazyOp::Norm(n) => match n {
                 Norm::LayerNorm(ln) => ln.check_invariants(),
+                Norm::RMSNorm(rms) => rms.check_invariants(),
+                Norm::GroupNorm(gn) => gn.check_invariants(),
             },
             LazyOp::Conv(c) => c.check_invariants(),
             LazyOp::Select(s) => s.check_invariants(),
             LazyOp::IndexWrite(iw) => iw.check_invariants(),
             LazyOp::Cache(c) => c.check_invariants(),
+            LazyOp::Trilu(t) => t.check_invariants(),
             LazyOp::View(v) => v.check_invariants(),
             LazyOp::Const => {}
         }
@@ -127,7 +162,11 @@ pub enum OperationError {
     #[error(transparent)]
     UniformError(#[from] encase::internal::Error),
     #[error(transparent)]
+    DeviceError(#[from] crate::DeviceError),
+    #[error(transparent)]
     UnknownError(#[from] anyhow::Error),
+    #[error("Kernel {0} not found")]
+    KernelNotFound(String),
 }
 
 /// # OpMetadata
@@ -188,6 +227,24 @@ pub trait MetaOperation: Debug + 'static {
         kernel_element: &KernelElement,
     ) -> Result<u64, OperationError>;
 
+    /// Everything that determines how this operation is dispatched, other than the buffers bound
+    /// to it. The metadata is written to `uniform`, as it would be during compilation.
+    fn signature(
+        &self,
+        dst: &Tensor,
+        uniform: &mut CpuUniform,
+        can_inplace: bool,
+    ) -> Result<String, OperationError> {
+        let kernel_element = self.kernel_element(dst);
+        self.write_metadata(uniform, dst, &kernel_element)?;
+        let workgroup_count = self.calculate_dispatch(dst)?;
+        Ok(format!(
+            "{}{:?}",
+            self.kernel_key(can_inplace, dst),
+            workgroup_count.as_slice()
+        ))
+    }
+
     fn compile(
         &self,
         dst: &Tensor,
@@ -200,20 +257,9 @@ pub trait MetaOperation: Debug + 'static {
 
         let workgroup_count = self.calculate_dispatch(dst)?;
 
-        let storage_layout = device
-            .get_or_create_bind_group_layout(&self.storage_bind_group_layout(can_inplace)?)?;
-        let uniform_layout =
-            device.get_or_create_bind_group_layout(&BindGroupLayoutDescriptor::uniform())?;
-        let pipeline_layout = device.get_or_create_pipeline_layout(&PipelineLayoutDescriptor {
-            entries: rvec![storage_layout, uniform_layout],
-        })?;
-
         let kernel_key = self.kernel_key(can_inplace, dst);
-        let pipeline_descriptor = ComputePipelineDescriptor {
-            pipeline_layout,
-            kernel_key: kernel_key.clone(),
-        };
-        let pipeline_handle = device.get_or_create_compute_pipeline(&pipeline_descriptor)?;
+        let (storage_layout, pipeline_handle) = device
+            .get_or_create_kernel(&kernel_key, &self.storage_bind_group_layout(can_inplace)?)?;
 
         //TODO: Not sure i like this call here
         let storage_bind_groups = CompiledOp::create_storage_bind_groups(
@@ -231,8 +277,31 @@ pub trait MetaOperation: Debug + 'static {
             storage_bind_groups,
             offset as _,
             kernel_key,
+            dst.id(),
         ))
     }
+
+    /// Creates the pipeline this operation compiles to, without writing any metadata or binding
+    /// any buffers, see [Tensor::warm].
+    fn warm(
+        &self,
+        dst: &Tensor,
+        device: &WgpuDevice,
+        can_inplace: bool,
+    ) -> Result<(), OperationError> {
+        let kernel_key = self.kernel_key(can_inplace, dst);
+        device.get_or_create_kernel(&kernel_key, &self.storage_bind_group_layout(can_inplace)?)?;
+        Ok(())
+    }
+}
+
+/// # CPUOperation
+///
+/// Host implementation of an operation, used when resolving tensors on [crate::Device::CPU].
+/// All sources are resolved before `apply_cpu` is called, and the result is written into a new
+/// buffer.
+pub trait CPUOperation: Debug + 'static {
+    fn apply_cpu(&self, dst: &Tensor) -> Result<CPUBuffer, OperationError>;
 }
 
 /// # Operation Guards - Runtime guards for operation correctness.
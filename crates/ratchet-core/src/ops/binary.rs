@@ -2,9 +2,10 @@ use derive_new::new;
 use encase::ShaderType;
 
 use crate::{
+    cpu::{broadcast_offset, cpu_f32, cpu_from_f32},
     gpu::{BindGroupLayoutDescriptor, CpuUniform, WorkgroupCount},
-    rvec, wgc, InvariantError, KernelElement, MetaOperation, OpGuards, OpMetadata, Operation,
-    OperationError, RVec, Shape, StorageView, Strides, Tensor,
+    rvec, wgc, CPUBuffer, CPUOperation, InvariantError, KernelElement, MetaOperation, OpGuards,
+    OpMetadata, Operation, OperationError, RVec, Shape, StorageView, Strides, Tensor,
 };
 #[cfg(test)]
 use test_strategy::Arbitrary;
@@ -16,6 +17,8 @@ pub enum BinaryOp {
     Sub,
     Mul,
     Div,
+    /// `silu(lhs) * rhs`, with the gate as the left operand.
+    SwiGLU,
 }
 
 impl BinaryOp {
@@ -25,6 +28,17 @@ impl BinaryOp {
             BinaryOp::Sub => "sub",
             BinaryOp::Mul => "mul",
             BinaryOp::Div => "div",
+            BinaryOp::SwiGLU => "swiglu",
+        }
+    }
+
+    fn apply(&self, a: f32, b: f32) -> f32 {
+        match self {
+            BinaryOp::Add => a + b,
+            BinaryOp::Sub => a - b,
+            BinaryOp::Mul => a * b,
+            BinaryOp::Div => a / b,
+            BinaryOp::SwiGLU => a / (1. + (-a).exp()) * b,
         }
     }
 }
@@ -40,6 +54,14 @@ impl Binary {
     pub fn op(&self) -> &BinaryOp {
         &self.op
     }
+
+    pub fn lhs(&self) -> &Tensor {
+        &self.lhs
+    }
+
+    pub fn rhs(&self) -> &Tensor {
+        &self.rhs
+    }
 }
 
 #[derive(Debug, ShaderType)]
@@ -93,10 +115,11 @@ impl MetaOperation for Binary {
     fn kernel_key(&self, inplace: bool, dst: &Tensor) -> String {
         let kn = self.kernel_name();
         let ke = self.kernel_element(dst).as_str();
+        let suffix = dst.dt().kernel_suffix();
         if inplace {
-            format!("{}_inplace_{}", kn, ke)
+            format!("{}_inplace_{}{}", kn, ke, suffix)
         } else {
-            format!("{}_{}", kn, ke)
+            format!("{}_{}{}", kn, ke, suffix)
         }
     }
 
@@ -151,9 +174,25 @@ impl MetaOperation for Binary {
     }
 }
 
+impl CPUOperation for Binary {
+    fn apply_cpu(&self, dst: &Tensor) -> Result<CPUBuffer, OperationError> {
+        let (lhs, rhs) = (cpu_f32(&self.lhs)?, cpu_f32(&self.rhs)?);
+        let dst_shape = dst.shape();
+        let result = (0..dst_shape.numel())
+            .map(|i| {
+                let l = lhs[broadcast_offset(self.lhs.shape(), dst_shape, i)];
+                let r = rhs[broadcast_offset(self.rhs.shape(), dst_shape, i)];
+                self.op.apply(l, r)
+            })
+            .collect::<Vec<_>>();
+        cpu_from_f32(&result, dst.dt(), dst_shape)
+    }
+}
+
 #[cfg(all(test, feature = "pyo3"))]
 mod tests {
-    use crate::{test_util::run_py_prg, BinaryOp, Device, DeviceRequest, Shape, Tensor};
+    use crate::test_util::{check_f16, run_py_prg};
+    use crate::{shape, BinaryOp, Device, DeviceRequest, Shape, Tensor};
     use test_strategy::{proptest, Arbitrary};
 
     thread_local! {
@@ -169,13 +208,19 @@ mod tests {
 
     fn ground_truth(a: &Tensor, b: &Tensor, op: &BinaryOp) -> anyhow::Result<Tensor> {
         let kn = op.kernel_name();
+        let expr = match op {
+            BinaryOp::SwiGLU => "F.silu(a) * b".to_string(),
+            _ => format!("torch.{}(a, b)", kn),
+        };
         let prg = format!(
             r#"
 import torch
+import torch.nn.functional as F
 def {}(a, b):
-    return torch.{}(torch.from_numpy(a), torch.from_numpy(b)).numpy()
+    (a, b) = (torch.from_numpy(a), torch.from_numpy(b))
+    return ({}).numpy()
 "#,
-            kn, kn
+            kn, expr
         );
         run_py_prg(prg.to_string(), &[a, b], &[])
     }
@@ -195,6 +240,7 @@ def {}(a, b):
             BinaryOp::Sub => a_gpu.sub(b_gpu)?,
             BinaryOp::Mul => a_gpu.mul(b_gpu)?,
             BinaryOp::Div => a_gpu.div(b_gpu)?,
+            BinaryOp::SwiGLU => a_gpu.swiglu(b_gpu)?,
         }
         .resolve()?;
 
@@ -207,4 +253,54 @@ def {}(a, b):
     fn test_binary(prob: BinaryProblem) {
         run_binary_trial(prob).unwrap();
     }
+
+    #[test]
+    #[ignore = "needs shader-f16, which only browsers grant, see tests/f16.rs"]
+    fn test_binary_f16() -> anyhow::Result<()> {
+        let device = GPU_DEVICE.with(|d| d.clone());
+        //Vec4, vec2 and scalar kernels, then a broadcast rhs
+        let shapes = [
+            (shape![2, 3, 64], shape![2, 3, 64]),
+            (shape![2, 3, 33], shape![2, 3, 33]),
+            (shape![3, 3, 11], shape![3, 3, 11]),
+            (shape![2, 3, 64], shape![1, 64]),
+        ];
+        let ops = [BinaryOp::Add, BinaryOp::Sub, BinaryOp::Mul, BinaryOp::Div];
+        for (lhs_shape, rhs_shape) in shapes {
+            for op in ops.iter() {
+                let lhs = Tensor::randn::<f32>(lhs_shape.clone(), Device::CPU);
+                //Keep the divisor away from 0
+                let rhs = Tensor::randn::<f32>(rhs_shape.clone(), Device::CPU)
+                    .abs()?
+                    .add(Tensor::from_data([0.5f32], shape![1], Device::CPU))?
+                    .resolve()?;
+                let binary = |t: &[Tensor]| {
+                    let (l, r) = (t[0].clone(), t[1].clone());
+                    match op {
+                        BinaryOp::Add => l.add(r),
+                        BinaryOp::Sub => l.sub(r),
+                        BinaryOp::Mul => l.mul(r),
+                        BinaryOp::Div => l.div(r),
+                        BinaryOp::SwiGLU => l.swiglu(r),
+                    }
+                };
+                check_f16(&device, &[lhs, rhs], binary, 1e-2, 1e-2)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod cpu_tests {
+    use crate::{shape, Device, Tensor};
+
+    #[test]
+    fn test_cpu_binary_broadcast() -> anyhow::Result<()> {
+        let a = Tensor::from_data(vec![1., 2., 3., 4., 5., 6.], shape![2, 3], Device::CPU);
+        let b = Tensor::from_data(vec![10., 20., 30.], shape![3], Device::CPU);
+        let c = a.add(b)?.resolve()?;
+        assert_eq!(c.to_vec::<f32>()?, vec![11., 22., 33., 14., 25., 36.]);
+        Ok(())
+    }
 }
@@ -4,9 +4,10 @@ use glam::UVec4;
 use wgpu::BindGroupLayoutEntry;
 
 use crate::{
+    cpu::{contiguous_strides, cpu_bytes, elementwise_size, gather_bytes, ravel, unravel},
     gpu::{BindGroupLayoutDescriptor, BindGroupLayoutEntryExt, CpuUniform, WorkgroupCount},
-    rvec, wgc, KernelElement, MetaOperation, OpGuards, OpMetadata, Operation, OperationError, RVec,
-    Shape, StorageView, Strides, Tensor,
+    rvec, wgc, CPUBuffer, CPUOperation, InvariantError, KernelElement, MetaOperation, OpGuards,
+    OpMetadata, Operation, OperationError, RVec, Shape, Storage, StorageView, Strides, Tensor,
 };
 
 /// # Cache
@@ -17,12 +18,46 @@ use crate::{
 /// 1. Cache, large partially filled tensors. E.g [1, 512, 1024], with [1, 5, 1024] filled.
 /// 2. Source, new K or V tensor, e.g [1, 1, 1024]
 /// 3. offset, where to start the write in the cache tensor, e.g [1, 5, 1024], [1, 1, 1024], offset = 5 -> [1, 6, 1024]
+///
+/// A dynamic cache produces the entire cache instead, e.g [1, 512, 1024], where only the first
+/// `offset` + source entries are valid. Its shape no longer depends on the offset, which is only
+/// read from the uniform buffer at dispatch time, so the same compiled op serves every step.
+/// On the GPU it only writes the source into the cache, and its result aliases the cache buffer.
 #[derive(new, Debug, Clone)]
 pub struct Cache {
     cache: Tensor,
     source: Tensor,
     dim: usize,
     offset: usize,
+    #[new(default)]
+    dynamic: bool,
+}
+
+impl Cache {
+    pub(crate) fn into_dynamic(self) -> Self {
+        Self {
+            dynamic: true,
+            ..self
+        }
+    }
+
+    pub(crate) fn is_dynamic(&self) -> bool {
+        self.dynamic
+    }
+
+    /// The source must fit in the cache after `offset`. A dynamic cache writes in place, so
+    /// overflowing it would write past the end of the cache buffer.
+    fn check_capacity(&self) -> Result<(), InvariantError> {
+        let (len, capacity) = (self.source.shape()[self.dim], self.cache.shape()[self.dim]);
+        if self.offset + len > capacity {
+            return Err(InvariantError::CacheOverflow {
+                offset: self.offset,
+                len,
+                capacity,
+            });
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug, derive_new::new, ShaderType)]
@@ -30,6 +65,7 @@ pub struct CacheMeta {
     cache_stride: glam::UVec4,
     source_stride: glam::UVec4,
     dst_stride: glam::UVec4,
+    //Elements dispatched over: of the source when writing in place, else of the output
     dst_numel: u32,
     cum0: u32,
     cum1: u32,
@@ -41,7 +77,7 @@ impl OpMetadata for CacheMeta {}
 impl OpGuards for Cache {
     fn check_shapes(&self) {
         assert!(self.cache.rank() >= 3);
-        assert!(self.offset <= self.cache.shape()[self.dim]);
+        assert!(self.check_capacity().is_ok());
     }
 
     fn check_dtypes(&self) {
@@ -51,8 +87,11 @@ impl OpGuards for Cache {
 
 impl Operation for Cache {
     fn compute_view(&self) -> Result<StorageView, OperationError> {
+        self.check_capacity()?;
         let mut result_shape = self.cache.shape().clone();
-        result_shape[self.dim] = self.offset + self.source.shape()[self.dim];
+        if !self.dynamic {
+            result_shape[self.dim] = self.offset + self.source.shape()[self.dim];
+        }
         let result_strides = Strides::from(&result_shape);
         Ok(StorageView::new(
             result_shape,
@@ -68,15 +107,20 @@ impl MetaOperation for Cache {
     }
 
     fn supports_inplace(&self) -> bool {
-        false
+        self.dynamic
     }
 
     fn srcs(&self) -> RVec<&Tensor> {
         rvec![&self.cache, &self.source]
     }
 
-    fn kernel_key(&self, _: bool, dst: &Tensor) -> String {
-        format!("cache_{}", self.kernel_element(dst).as_str())
+    fn kernel_key(&self, inplace: bool, dst: &Tensor) -> String {
+        let ke = self.kernel_element(dst);
+        if inplace {
+            format!("cache_inplace_{}", ke.as_str())
+        } else {
+            format!("cache_{}", ke.as_str())
+        }
     }
 
     fn kernel_element(&self, _dst: &Tensor) -> KernelElement {
@@ -84,7 +128,11 @@ impl MetaOperation for Cache {
     }
 
     fn calculate_dispatch(&self, dst: &Tensor) -> Result<WorkgroupCount, OperationError> {
-        let numel = dst.shape().numel();
+        let numel = if self.dynamic {
+            self.source.shape().numel()
+        } else {
+            dst.shape().numel()
+        };
         let x_groups = WorkgroupCount::div_ceil(numel as _, 64);
         let (x_groups, y_groups) = if x_groups > WorkgroupCount::MAX_WGS_PER_DIM {
             let y_groups = WorkgroupCount::div_ceil(x_groups, WorkgroupCount::MAX_WGS_PER_DIM);
@@ -98,8 +146,11 @@ impl MetaOperation for Cache {
 
     fn storage_bind_group_layout(
         &self,
-        _: bool,
+        inplace: bool,
     ) -> Result<BindGroupLayoutDescriptor, OperationError> {
+        if inplace {
+            return Ok(BindGroupLayoutDescriptor::binary_inplace());
+        }
         Ok(BindGroupLayoutDescriptor {
             entries: rvec![
                 BindGroupLayoutEntry::compute_storage_buffer(0, false),
@@ -135,7 +186,11 @@ impl MetaOperation for Cache {
             cache_stride: UVec4::from(&cache_strides),
             source_stride: UVec4::from(&source_strides),
             dst_stride: UVec4::from(&dst_strides),
-            dst_numel: dst_shape.numel() as u32,
+            dst_numel: if self.dynamic {
+                source_shape.numel() as u32
+            } else {
+                dst_shape.numel() as u32
+            },
             cum0,
             cum1,
             dim: promoted_dim as u32,
@@ -145,6 +200,32 @@ impl MetaOperation for Cache {
     }
 }
 
+impl CPUOperation for Cache {
+    fn apply_cpu(&self, dst: &Tensor) -> Result<CPUBuffer, OperationError> {
+        let elem_size = elementwise_size(self.cache.dt())?;
+        let (cache, source) = (cpu_bytes(&self.cache)?, cpu_bytes(&self.source)?);
+        let cache_strides = contiguous_strides(self.cache.shape());
+        let source_strides = contiguous_strides(self.source.shape());
+        let dst_strides = contiguous_strides(dst.shape());
+
+        //Like the kernel, the source is also written into the cache itself
+        let mut updated = cache.clone();
+        for (i, chunk) in source.chunks_exact(elem_size).enumerate() {
+            let mut index = unravel(i, &source_strides);
+            index[self.dim] += self.offset;
+            let o = ravel(&index, &cache_strides) * elem_size;
+            updated[o..o + elem_size].copy_from_slice(chunk);
+        }
+
+        let result = gather_bytes(&updated, elem_size, dst.shape().numel(), |i| {
+            ravel(&unravel(i, &dst_strides), &cache_strides)
+        });
+        self.cache
+            .update_storage(Storage::CPU(CPUBuffer::from_bytes(&updated, elem_size)));
+        Ok(CPUBuffer::from_bytes(&result, elem_size))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{rvec, shape, Device, DeviceRequest, Tensor};
@@ -189,4 +270,71 @@ mod tests {
         result.all_close(&ground_truth, 1e-5, 1e-5).unwrap();
         Ok(())
     }
+
+    #[test]
+    fn test_cache_overflow() -> anyhow::Result<()> {
+        let cache = Tensor::zeros::<f32>(&shape![1, 2, 4, 16], &Device::CPU);
+        let source = Tensor::randn::<f32>(shape![1, 2, 2, 16], Device::CPU);
+        for offset in [0, 2] {
+            cache.clone().cache(source.clone(), 2, offset)?;
+            cache.clone().cache_dynamic(source.clone(), 2, offset)?;
+        }
+
+        //Once full, the next write would land past the end of the cache
+        for offset in [3, 4] {
+            let err = cache.clone().cache_dynamic(source.clone(), 2, offset);
+            assert!(err.unwrap_err().to_string().contains("Cache overflow"));
+            assert!(cache.clone().cache(source.clone(), 2, offset).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_cpu_cache() -> anyhow::Result<()> {
+        let populated = 2;
+        let prev = Tensor::randn::<f32>(shape![1, 2, populated, 4], Device::CPU);
+        let blank = Tensor::zeros::<f32>(&shape![1, 2, 4, 4], &Device::CPU);
+        let cache = Tensor::cat(rvec![prev.clone(), blank], 2)?.resolve()?;
+        let src = Tensor::randn::<f32>(shape![1, 2, 1, 4], Device::CPU);
+
+        let ground = Tensor::cat(rvec![prev, src.clone()], 2)?.resolve()?;
+        let result = cache.clone().cache(src, 2, populated)?.resolve()?;
+        assert_eq!(result.to_vec::<f32>()?, ground.to_vec::<f32>()?);
+
+        //The source is also written into the cache itself
+        let cache = cache.to_vec::<f32>()?;
+        let ground = ground.to_vec::<f32>()?;
+        assert_eq!(&cache[..12], &ground[..12]);
+        assert_eq!(&cache[24..36], &ground[12..24]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_cpu_dynamic_cache() -> anyhow::Result<()> {
+        //Attending to the valid prefix of the whole cache must match the trimmed cache
+        let (populated, capacity) = (3, 8);
+        let cache = Tensor::randn::<f32>(shape![1, 2, capacity, 4], Device::CPU);
+        let src = Tensor::randn::<f32>(shape![1, 2, 1, 4], Device::CPU);
+        let q = Tensor::randn::<f32>(shape![1, 2, 1, 4], Device::CPU);
+
+        let trimmed = cache
+            .deep_clone()
+            .cache(src.clone(), 2, populated)?
+            .resolve()?;
+        let whole = cache
+            .deep_clone()
+            .cache_dynamic(src, 2, populated)?
+            .resolve()?;
+        assert_eq!(whole.shape(), &shape![1, 2, capacity, 4]);
+
+        let ground = q
+            .clone()
+            .sdpa(trimmed.clone(), trimmed, None, 0.5, true)?
+            .resolve()?;
+        let ours = q
+            .sdpa_dynamic(whole.clone(), whole, None, 0.5, true, populated + 1)?
+            .resolve()?;
+        ground.all_close(&ours, 1e-5, 1e-5)?;
+        Ok(())
+    }
 }
@@ -0,0 +1,325 @@
+use derive_new::new;
+use encase::ShaderType;
+
+use crate::{
+    cpu::{cpu_bytes, cpu_f32, cpu_from_f32, cpu_i32, cpu_u32},
+    gpu::{BindGroupLayoutDescriptor, CpuUniform, WorkgroupCount},
+    rvec, wgc, CPUBuffer, CPUOperation, DType, KernelElement, MetaOperation, OpGuards, OpMetadata,
+    Operation, OperationError, RVec, StorageView, Strides, Tensor,
+};
+
+/// # Cast
+///
+/// Elementwise conversion of the input to `dst_dt`.
+///
+/// Floats are truncated towards zero when cast to integers, and I32 <-> U32 reinterprets the bits.
+/// 16 bit types are handled as packed pairs of u32s, unless the device supports `shader-f16`.
+#[derive(new, Debug, Clone)]
+pub struct Cast {
+    input: Tensor,
+    dst_dt: DType,
+}
+
+impl Cast {
+    pub fn dst_dt(&self) -> DType {
+        self.dst_dt
+    }
+
+    fn kernel_dt(dt: DType) -> &'static str {
+        match dt {
+            DType::F32 => "f32",
+            DType::F16 => "f16",
+            DType::BF16 => "bf16",
+            DType::I32 => "i32",
+            DType::U32 => "u32",
+            _ => unreachable!("Cast does not support {:?}", dt),
+        }
+    }
+
+    /// BF16 has no WGSL type, and F16 requires `shader-f16`.
+    /// Otherwise, halves are unpacked from & packed into u32s 2 at a time.
+    fn is_packed(&self, dst: &Tensor) -> bool {
+        let dts = [self.input.dt(), self.dst_dt];
+        if dts.contains(&DType::BF16) {
+            return true;
+        }
+        dts.contains(&DType::F16)
+            && !dst
+                .device()
+                .try_gpu()
+                .map(|gpu| gpu.supports_f16())
+                .unwrap_or(false)
+    }
+}
+
+#[derive(Debug, ShaderType)]
+pub struct CastMeta {
+    numel: u32,
+}
+
+impl OpMetadata for CastMeta {}
+
+impl OpGuards for Cast {
+    fn check_shapes(&self) {}
+
+    fn check_dtypes(&self) {
+        let supported = [DType::F32, DType::F16, DType::BF16, DType::I32, DType::U32];
+        assert!(supported.contains(&self.input.dt()));
+        assert!(supported.contains(&self.dst_dt));
+        assert_ne!(self.input.dt(), self.dst_dt);
+    }
+}
+
+impl Operation for Cast {
+    fn compute_view(&self) -> Result<StorageView, OperationError> {
+        let shape = self.input.shape().clone();
+        let strides = Strides::from(&shape);
+        Ok(StorageView::new(shape, self.dst_dt, strides))
+    }
+}
+
+impl MetaOperation for Cast {
+    fn kernel_name(&self) -> String {
+        "cast".to_string()
+    }
+
+    fn kernel_key(&self, _: bool, dst: &Tensor) -> String {
+        let (src_dt, dst_dt) = (
+            Self::kernel_dt(self.input.dt()),
+            Self::kernel_dt(self.dst_dt),
+        );
+        if self.is_packed(dst) {
+            format!("cast_{}_{}_packed", src_dt, dst_dt)
+        } else {
+            format!(
+                "cast_{}_{}_{}",
+                src_dt,
+                dst_dt,
+                self.kernel_element(dst).as_str()
+            )
+        }
+    }
+
+    fn srcs(&self) -> RVec<&Tensor> {
+        rvec![&self.input]
+    }
+
+    fn kernel_element(&self, dst: &Tensor) -> KernelElement {
+        let numel = dst.shape().numel();
+
+        if self.is_packed(dst) {
+            KernelElement::Scalar
+        } else if numel % 4 == 0 {
+            KernelElement::Vec4
+        } else if numel % 2 == 0 {
+            KernelElement::Vec2
+        } else {
+            KernelElement::Scalar
+        }
+    }
+
+    fn calculate_dispatch(&self, dst: &Tensor) -> Result<WorkgroupCount, OperationError> {
+        let numel = dst.shape().numel();
+        let x_groups = WorkgroupCount::div_ceil(numel as _, 64);
+        let (x_groups, y_groups) = if x_groups > WorkgroupCount::MAX_WGS_PER_DIM {
+            let y_groups = WorkgroupCount::div_ceil(x_groups, WorkgroupCount::MAX_WGS_PER_DIM);
+            (WorkgroupCount::MAX_WGS_PER_DIM, y_groups)
+        } else {
+            (x_groups, 1)
+        };
+        Ok(wgc![x_groups as _, y_groups as _, 1])
+    }
+
+    fn storage_bind_group_layout(
+        &self,
+        _: bool,
+    ) -> Result<BindGroupLayoutDescriptor, OperationError> {
+        Ok(BindGroupLayoutDescriptor::unary())
+    }
+
+    fn write_metadata(
+        &self,
+        uniform: &mut CpuUniform,
+        dst: &Tensor,
+        _: &KernelElement,
+    ) -> Result<u64, OperationError> {
+        let numel = dst.shape().numel() as _;
+        let meta = CastMeta { numel };
+        Ok(uniform.write(&meta)?)
+    }
+}
+
+impl CPUOperation for Cast {
+    fn apply_cpu(&self, dst: &Tensor) -> Result<CPUBuffer, OperationError> {
+        let shape = dst.shape();
+        let is_int = |dt: DType| matches!(dt, DType::I32 | DType::U32);
+        if is_int(self.input.dt()) && is_int(self.dst_dt) {
+            let bytes = cpu_bytes(&self.input)?;
+            return Ok(CPUBuffer::from_bytes(&bytes[..shape.numel() * 4], 4));
+        }
+
+        let input = match self.input.dt() {
+            DType::I32 => cpu_i32(&self.input)?.iter().map(|&x| x as f32).collect(),
+            DType::U32 => cpu_u32(&self.input)?.iter().map(|&x| x as f32).collect(),
+            _ => cpu_f32(&self.input)?,
+        };
+        match self.dst_dt {
+            DType::I32 => {
+                let result = input.iter().map(|&x| x as i32).collect::<Vec<_>>();
+                Ok(CPUBuffer::from_slice(&result, shape))
+            }
+            DType::U32 => {
+                let result = input.iter().map(|&x| x as u32).collect::<Vec<_>>();
+                Ok(CPUBuffer::from_slice(&result, shape))
+            }
+            dt => cpu_from_f32(&input, dt, shape),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "pyo3"))]
+mod tests {
+    use crate::{test_util::run_py_prg, DType, Device, DeviceRequest, Shape, Tensor};
+    use test_strategy::{proptest, Arbitrary};
+
+    thread_local! {
+        static GPU_DEVICE: Device = Device::request_device(DeviceRequest::GPU).unwrap();
+    }
+
+    fn dtypes() -> impl proptest::strategy::Strategy<Value = DType> {
+        proptest::sample::select(vec![
+            DType::F32,
+            DType::F16,
+            DType::BF16,
+            DType::I32,
+            DType::U32,
+        ])
+    }
+
+    #[derive(Arbitrary, Debug)]
+    struct CastProblem {
+        #[strategy(dtypes())]
+        src_dt: DType,
+        #[strategy(dtypes())]
+        dst_dt: DType,
+        #[any(vec![1..=4, 1..=4, 1..=1, 1..=257])]
+        shape: Shape,
+    }
+
+    //Inputs are non-negative, so U32 can be checked against int64
+    fn torch_dt(dt: DType) -> &'static str {
+        match dt {
+            DType::F32 => "torch.float32",
+            DType::F16 => "torch.float16",
+            DType::BF16 => "torch.bfloat16",
+            DType::I32 => "torch.int32",
+            DType::U32 => "torch.int64",
+            _ => unreachable!(),
+        }
+    }
+
+    fn ground_truth(a: &Tensor, src_dt: DType, dst_dt: DType) -> anyhow::Result<Tensor> {
+        let prg = format!(
+            r#"
+import torch
+def cast(a):
+    return torch.from_numpy(a).to({}).to({}).float().numpy()
+"#,
+            torch_dt(src_dt),
+            torch_dt(dst_dt)
+        );
+        run_py_prg(prg.to_string(), &[a], &[])
+    }
+
+    fn run_cast_trial(prob: CastProblem) -> anyhow::Result<()> {
+        let device = GPU_DEVICE.with(|d| d.clone());
+        let CastProblem {
+            src_dt,
+            dst_dt,
+            shape,
+        } = prob;
+        //Multiples of 0.5 in [0, 128) are exactly representable in every type
+        let a = Tensor::randn::<f32>(shape.clone(), Device::CPU);
+        let data = a
+            .to_vec::<f32>()?
+            .into_iter()
+            .map(|x| ((x.abs() * 64.).floor() / 2.).min(127.5))
+            .collect::<Vec<_>>();
+        let a = Tensor::from_data(data, shape, Device::CPU);
+        let ground = ground_truth(&a, src_dt, dst_dt)?;
+
+        let ours = a
+            .to(&device)?
+            .cast(src_dt)?
+            .cast(dst_dt)?
+            .cast(DType::F32)?
+            .resolve()?;
+        let ours = ours.to(&Device::CPU)?;
+        ground.all_close(&ours, 0., 0.)?;
+        Ok(())
+    }
+
+    #[proptest(cases = 16)]
+    fn test_cast(prob: CastProblem) {
+        run_cast_trial(prob).unwrap();
+    }
+}
+
+#[cfg(test)]
+mod cpu_tests {
+    use crate::{shape, DType, Device, Tensor};
+    use half::{bf16, f16};
+
+    #[test]
+    fn test_cpu_cast() -> anyhow::Result<()> {
+        let x = Tensor::from_data(vec![-2.75, -0.5, 1.0009766, 65520.], shape![4], Device::CPU);
+        let half = x.clone().cast(DType::F16)?.resolve()?;
+        let expected = [-2.75, -0.5, 1.0009766, f32::INFINITY].map(f16::from_f32);
+        assert_eq!(half.to_vec::<f16>()?, expected);
+        let brain = x.clone().cast(DType::BF16)?.resolve()?;
+        let expected = [-2.75, -0.5, 1., 65536.].map(bf16::from_f32);
+        assert_eq!(brain.to_vec::<bf16>()?, expected);
+
+        let ints = x.cast(DType::I32)?.resolve()?;
+        assert_eq!(ints.to_vec::<i32>()?, vec![-2, 0, 1, 65520]);
+        let uints = ints.cast(DType::U32)?.resolve()?;
+        assert_eq!(uints.to_vec::<u32>()?, vec![u32::MAX - 1, 0, 1, 65520]);
+        let back = uints.cast(DType::F16)?.cast(DType::F32)?.resolve()?;
+        assert_eq!(
+            back.to_vec::<f32>()?,
+            vec![f32::INFINITY, 0., 1., f32::INFINITY]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_cpu_half_activations() -> anyhow::Result<()> {
+        let run = |dt: DType| -> anyhow::Result<Vec<f32>> {
+            let x = Tensor::from_data(
+                (0..24).map(|v| (v as f32 - 12.) / 8.).collect::<Vec<_>>(),
+                shape![2, 3, 4],
+                Device::CPU,
+            )
+            .cast(dt)?;
+            let w = Tensor::from_data(
+                (0..16).map(|v| v as f32 / 16.).collect::<Vec<_>>(),
+                shape![4, 4],
+                Device::CPU,
+            )
+            .cast(dt)?;
+            let scale =
+                Tensor::from_data(vec![0.5, 1., 1.5, 2.], shape![4], Device::CPU).cast(dt)?;
+            let bias = Tensor::from_data(vec![0.25; 4], shape![4], Device::CPU).cast(dt)?;
+
+            let h = x.clone().matmul(w, false, false)?.gelu()?;
+            let h = h.add(x)?.layer_norm(scale, Some(bias), 1e-5)?;
+            let y = h.softmax(2)?.cast(DType::F32)?.resolve()?;
+            y.to_vec::<f32>()
+        };
+        let (full, half) = (run(DType::F32)?, run(DType::F16)?);
+        for (f, h) in full.iter().zip(half.iter()) {
+            assert!((f - h).abs() < 1e-2, "{} vs {}", f, h);
+        }
+        Ok(())
+    }
+}
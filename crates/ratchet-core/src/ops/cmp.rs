@@ -0,0 +1,262 @@
+use derive_new::new;
+use encase::ShaderType;
+
+use crate::{
+    cpu::{broadcast_offset, cpu_f32},
+    gpu::{BindGroupLayoutDescriptor, CpuUniform, WorkgroupCount},
+    rvec, wgc, CPUBuffer, CPUOperation, DType, InvariantError, KernelElement, MetaOperation,
+    OpGuards, OpMetadata, Operation, OperationError, RVec, Shape, StorageView, Strides, Tensor,
+};
+#[cfg(test)]
+use test_strategy::Arbitrary;
+
+#[cfg_attr(test, derive(Arbitrary))]
+#[derive(Debug, Clone)]
+pub enum CmpOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl CmpOp {
+    pub fn kernel_name(&self) -> &'static str {
+        match self {
+            CmpOp::Eq => "eq",
+            CmpOp::Ne => "ne",
+            CmpOp::Lt => "lt",
+            CmpOp::Le => "le",
+            CmpOp::Gt => "gt",
+            CmpOp::Ge => "ge",
+        }
+    }
+
+    fn apply(&self, a: f32, b: f32) -> bool {
+        match self {
+            CmpOp::Eq => a == b,
+            CmpOp::Ne => a != b,
+            CmpOp::Lt => a < b,
+            CmpOp::Le => a <= b,
+            CmpOp::Gt => a > b,
+            CmpOp::Ge => a >= b,
+        }
+    }
+}
+
+/// # Cmp
+///
+/// Elementwise comparison of two tensors.
+/// The result is a U32 tensor, containing 1 where the comparison holds and 0 elsewhere.
+#[derive(new, Debug, Clone)]
+pub struct Cmp {
+    lhs: Tensor,
+    rhs: Tensor,
+    op: CmpOp,
+}
+
+impl Cmp {
+    pub fn op(&self) -> &CmpOp {
+        &self.op
+    }
+}
+
+#[derive(Debug, ShaderType)]
+pub struct CmpMeta {
+    numel: u32,
+}
+
+impl OpMetadata for CmpMeta {}
+
+impl OpGuards for Cmp {
+    fn check_shapes(&self) {
+        let shapes = [self.lhs.shape(), self.rhs.shape()];
+        let broadcasted = Shape::multi_broadcast(&shapes);
+        assert!(broadcasted.is_some());
+    }
+
+    fn check_dtypes(&self) {
+        assert_eq!(self.lhs.dt(), DType::F32);
+        assert_eq!(self.lhs.dt(), self.rhs.dt());
+    }
+}
+
+impl Operation for Cmp {
+    fn compute_view(&self) -> Result<StorageView, OperationError> {
+        let shapes = &[self.lhs.shape(), self.rhs.shape()];
+        let broadcasted = Shape::multi_broadcast(shapes).ok_or_else(|| {
+            let failed = shapes.iter().map(|s| (*s).clone()).collect::<Vec<_>>();
+            InvariantError::BroadcastingFailed(failed)
+        })?;
+        let ostrides = Strides::from(&broadcasted);
+        Ok(StorageView::new(broadcasted, DType::U32, ostrides))
+    }
+}
+
+impl MetaOperation for Cmp {
+    fn kernel_name(&self) -> String {
+        self.op.kernel_name().to_string()
+    }
+
+    fn kernel_key(&self, _: bool, dst: &Tensor) -> String {
+        format!(
+            "{}_{}",
+            self.kernel_name(),
+            self.kernel_element(dst).as_str()
+        )
+    }
+
+    fn srcs(&self) -> RVec<&Tensor> {
+        rvec![&self.lhs, &self.rhs]
+    }
+
+    fn kernel_element(&self, dst: &Tensor) -> KernelElement {
+        let numel = dst.shape().numel();
+
+        if numel % 4 == 0 {
+            KernelElement::Vec4
+        } else if numel % 2 == 0 {
+            KernelElement::Vec2
+        } else {
+            KernelElement::Scalar
+        }
+    }
+
+    fn calculate_dispatch(&self, dst: &Tensor) -> Result<WorkgroupCount, OperationError> {
+        let numel = dst.shape().numel();
+        let x_groups = WorkgroupCount::div_ceil(numel as _, 64);
+        let (x_groups, y_groups) = if x_groups > WorkgroupCount::MAX_WGS_PER_DIM {
+            let y_groups = WorkgroupCount::div_ceil(x_groups, WorkgroupCount::MAX_WGS_PER_DIM);
+            (WorkgroupCount::MAX_WGS_PER_DIM, y_groups)
+        } else {
+            (x_groups, 1)
+        };
+        Ok(wgc![x_groups as _, y_groups as _, 1])
+    }
+
+    fn storage_bind_group_layout(
+        &self,
+        _: bool,
+    ) -> Result<BindGroupLayoutDescriptor, OperationError> {
+        Ok(BindGroupLayoutDescriptor::binary())
+    }
+
+    fn write_metadata(
+        &self,
+        uniform: &mut CpuUniform,
+        dst: &Tensor,
+        _: &KernelElement,
+    ) -> Result<u64, OperationError> {
+        let numel = dst.shape().numel() as _;
+        let meta = CmpMeta { numel };
+        Ok(uniform.write(&meta)?)
+    }
+}
+
+impl CPUOperation for Cmp {
+    fn apply_cpu(&self, dst: &Tensor) -> Result<CPUBuffer, OperationError> {
+        let (lhs, rhs) = (cpu_f32(&self.lhs)?, cpu_f32(&self.rhs)?);
+        let dst_shape = dst.shape();
+        let result = (0..dst_shape.numel())
+            .map(|i| {
+                let l = lhs[broadcast_offset(self.lhs.shape(), dst_shape, i)];
+                let r = rhs[broadcast_offset(self.rhs.shape(), dst_shape, i)];
+                self.op.apply(l, r) as u32
+            })
+            .collect::<Vec<_>>();
+        Ok(CPUBuffer::from_slice(&result, dst_shape))
+    }
+}
+
+#[cfg(all(test, feature = "pyo3"))]
+mod tests {
+    use crate::{shape, test_util::run_py_prg, CmpOp, Device, DeviceRequest, Shape, Tensor};
+    use test_strategy::{proptest, Arbitrary};
+
+    thread_local! {
+        static GPU_DEVICE: Device = Device::request_device(DeviceRequest::GPU).unwrap();
+    }
+
+    #[derive(Arbitrary, Debug)]
+    struct CmpProblem {
+        op: CmpOp,
+        #[any(vec![1..=4, 1..=4, 1..=1, 1..=256])]
+        shape: Shape,
+    }
+
+    fn ground_truth(a: &Tensor, b: &Tensor, op: &CmpOp) -> anyhow::Result<Tensor> {
+        let kn = op.kernel_name();
+        let prg = format!(
+            r#"
+import torch
+def {}(a, b):
+    return torch.{}(torch.from_numpy(a), torch.from_numpy(b)).float().numpy()
+"#,
+            kn, kn
+        );
+        run_py_prg(prg.to_string(), &[a, b], &[])
+    }
+
+    fn run_cmp_trial(prob: CmpProblem) -> anyhow::Result<()> {
+        let device = GPU_DEVICE.with(|d| d.clone());
+        let CmpProblem { op, shape } = prob;
+        //Small integers, so that equality is exercised
+        let a = Tensor::randint(-2, 3, shape.clone(), Device::CPU);
+        let b = Tensor::randint(-2, 3, shape, Device::CPU);
+        let (a, b) = (to_f32(a)?, to_f32(b)?);
+        let ground = ground_truth(&a, &b, &op)?;
+
+        let a_gpu = a.to(&device)?;
+        let b_gpu = b.to(&device)?;
+        let c_gpu = match op {
+            CmpOp::Eq => a_gpu.eq(b_gpu)?,
+            CmpOp::Ne => a_gpu.ne(b_gpu)?,
+            CmpOp::Lt => a_gpu.lt(b_gpu)?,
+            CmpOp::Le => a_gpu.le(b_gpu)?,
+            CmpOp::Gt => a_gpu.gt(b_gpu)?,
+            CmpOp::Ge => a_gpu.ge(b_gpu)?,
+        }
+        .resolve()?;
+
+        let d_gpu = c_gpu.to(&Device::CPU)?;
+        let ours = d_gpu
+            .to_vec::<u32>()?
+            .into_iter()
+            .map(|x| x as f32)
+            .collect::<Vec<_>>();
+        assert_eq!(ours, ground.to_vec::<f32>()?);
+        Ok(())
+    }
+
+    fn to_f32(t: Tensor) -> anyhow::Result<Tensor> {
+        let data = t
+            .to_vec::<i32>()?
+            .into_iter()
+            .map(|x| x as f32)
+            .collect::<Vec<_>>();
+        Ok(Tensor::from_data(data, t.shape().clone(), Device::CPU))
+    }
+
+    #[proptest(cases = 8)]
+    fn test_cmp(prob: CmpProblem) {
+        run_cmp_trial(prob).unwrap();
+    }
+
+    #[test]
+    fn test_cmp_broadcast() -> anyhow::Result<()> {
+        let device = GPU_DEVICE.with(|d| d.clone());
+        let a = Tensor::randn::<f32>(shape![2, 7, 1], Device::CPU);
+        let b = Tensor::randn::<f32>(shape![1, 9], Device::CPU);
+        let ground = a.clone().lt(b.clone())?.resolve()?.to_vec::<u32>()?;
+
+        let ours = a
+            .to(&device)?
+            .lt(b.to(&device)?)?
+            .resolve()?
+            .to(&Device::CPU)?;
+        assert_eq!(ours.shape(), &shape![2, 7, 9]);
+        assert_eq!(ours.to_vec::<u32>()?, ground);
+        Ok(())
+    }
+}
@@ -2,9 +2,10 @@ use derive_new::new;
 use glam::UVec4;
 
 use crate::{
+    cpu::{cpu_bytes, elementwise_size},
     gpu::{BindGroupLayoutDescriptor, CpuUniform, WorkgroupCount, UNIFORM_ALIGN},
-    wgc, KernelElement, MetaOperation, OpGuards, Operation, OperationError, RVec, Shape,
-    StorageView, Strides, Tensor,
+    wgc, CPUBuffer, CPUOperation, KernelElement, MetaOperation, OpGuards, Operation,
+    OperationError, RVec, Shape, StorageView, Strides, Tensor,
 };
 
 #[derive(new, Debug, Clone)]
@@ -139,6 +140,28 @@ impl MetaOperation for Concat {
     }
 }
 
+impl CPUOperation for Concat {
+    fn apply_cpu(&self, dst: &Tensor) -> Result<CPUBuffer, OperationError> {
+        let elem_size = elementwise_size(dst.dt())?;
+        let outer = dst.shape()[..self.dim].iter().product::<usize>();
+
+        //Each input contributes a contiguous block of `shape[dim..]` elements per outer index
+        let mut blocks = Vec::with_capacity(self.inputs.len());
+        for input in self.inputs.iter() {
+            let block_size = input.shape()[self.dim..].iter().product::<usize>() * elem_size;
+            blocks.push((cpu_bytes(input)?, block_size));
+        }
+
+        let mut result = Vec::with_capacity(dst.shape().numel() * elem_size);
+        for o in 0..outer {
+            for (bytes, block_size) in blocks.iter() {
+                result.extend_from_slice(&bytes[o * block_size..(o + 1) * block_size]);
+            }
+        }
+        Ok(CPUBuffer::from_bytes(&result, elem_size))
+    }
+}
+
 #[cfg(all(test, feature = "pyo3"))]
 mod tests {
     use crate::{rvec, shape, test_util::run_py_prg, Device, DeviceRequest, Tensor};
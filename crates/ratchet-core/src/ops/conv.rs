@@ -3,9 +3,10 @@ use derive_new::new;
 use encase::ShaderType;
 
 use crate::{
+    cpu::cpu_f32,
     gpu::{BindGroupLayoutDescriptor, CpuUniform, WorkgroupCount},
-    rvec, shape, wgc, KernelElement, MetaOperation, OpGuards, OpMetadata, Operation,
-    OperationError, RVec, StorageView, Strides, Tensor,
+    rvec, shape, wgc, CPUBuffer, CPUOperation, KernelElement, MetaOperation, OpGuards, OpMetadata,
+    Operation, OperationError, RVec, StorageView, Strides, Tensor,
 };
 
 #[derive(new, Debug, Clone)]
@@ -125,6 +126,39 @@ impl MetaOperation for Conv {
     }
 }
 
+impl CPUOperation for Conv {
+    fn apply_cpu(&self, dst: &Tensor) -> Result<CPUBuffer, OperationError> {
+        let (input, weight) = (cpu_f32(&self.input)?, cpu_f32(&self.weight)?);
+        let bias = self.bias.as_ref().map(cpu_f32).transpose()?;
+
+        let [N, Cin, Lin]: [usize; 3] = self.input.shape().try_into()?;
+        let [Cout, _, KS]: [usize; 3] = self.weight.shape().try_into()?;
+        let [_, _, Lout]: [usize; 3] = dst.shape().try_into()?;
+
+        let mut result = vec![0f32; N * Cout * Lout];
+        for n in 0..N {
+            for co in 0..Cout {
+                for l in 0..Lout {
+                    let mut acc = bias.as_ref().map_or(0., |b| b[co]);
+                    for ci in 0..Cin {
+                        for k in 0..KS {
+                            //Position in the unpadded input
+                            let pos = (l * self.stride + k) as isize - self.padding as isize;
+                            if pos < 0 || pos >= Lin as isize {
+                                continue;
+                            }
+                            let x = input[n * Cin * Lin + ci * Lin + pos as usize];
+                            acc += x * weight[co * Cin * KS + ci * KS + k];
+                        }
+                    }
+                    result[n * Cout * Lout + co * Lout + l] = acc;
+                }
+            }
+        }
+        Ok(CPUBuffer::from_slice(&result, dst.shape()))
+    }
+}
+
 #[cfg(all(test, feature = "pyo3"))]
 mod tests {
     use test_strategy::{proptest, Arbitrary};
@@ -2,9 +2,10 @@ use derive_new::new;
 use encase::ShaderType;
 
 use crate::{
+    cpu::{contiguous_strides, cpu_bytes, elementwise_size, ravel},
     gpu::{BindGroupLayoutDescriptor, CpuUniform, WorkgroupCount},
-    rvec, wgc, KernelElement, MetaOperation, OpGuards, OpMetadata, Operation, OperationError, RVec,
-    Shape, StorageView, Strides, Tensor,
+    rvec, wgc, CPUBuffer, CPUOperation, KernelElement, MetaOperation, OpGuards, OpMetadata,
+    Operation, OperationError, RVec, Shape, StorageView, Strides, Tensor,
 };
 
 #[derive(new, Debug, Clone)]
@@ -107,6 +108,19 @@ impl MetaOperation for IndexWrite {
     }
 }
 
+impl CPUOperation for IndexWrite {
+    fn apply_cpu(&self, _: &Tensor) -> Result<CPUBuffer, OperationError> {
+        let elem_size = elementwise_size(self.dst.dt())?;
+        let dst_strides = contiguous_strides(self.dst.shape());
+        let offset = ravel(&self.write_start, &dst_strides) * elem_size;
+
+        let mut result = cpu_bytes(&self.dst)?;
+        let src = cpu_bytes(&self.src)?;
+        result[offset..offset + src.len()].copy_from_slice(&src);
+        Ok(CPUBuffer::from_bytes(&result, elem_size))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{rvec, shape, Device, DeviceRequest, Tensor};
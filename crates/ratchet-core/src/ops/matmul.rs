@@ -3,10 +3,11 @@ use std::cmp::Ordering;
 use encase::ShaderType;
 
 use crate::{
-    gguf::{GGUFDType, Q8_0},
+    cpu::{broadcast_offset, cpu_f32, cpu_from_f32},
     gpu::{BindGroupLayoutDescriptor, CpuUniform, WorkgroupCount},
-    rvec, wgc, DType, InvariantError, KernelElement, MetaOperation, OpGuards, OpMetadata,
-    Operation, OperationError, RVec, Shape, StorageView, Strides, Tensor,
+    rvec, wgc, CPUBuffer, CPUOperation, DType, InvariantError, KernelElement, MetaOperation,
+    OpGuards, OpMetadata, Operation, OperationError, RVec, Shape, StorageView, Strides, Tensor,
+    UnaryOp,
 };
 
 //https://link.springer.com/chapter/10.1007/978-3-642-29737-3_42
@@ -232,6 +233,7 @@ pub struct GEMM {
     trans_lhs: bool,
     trans_rhs: bool,
     trans_out: bool,
+    epilogue: Option<UnaryOp>,
 }
 
 impl GEMM {
@@ -247,7 +249,7 @@ impl GEMM {
             panic!("Bias must be a vector: {:?}", bias);
         }
 
-        if matches!(lhs.dt(), DType::GGUF(GGUFDType::Q8_0(_))) && trans_lhs {
+        if lhs.dt().is_quantized() && trans_lhs {
             panic!("Transposed quantized inputs are not supported");
         }
 
@@ -258,6 +260,35 @@ impl GEMM {
             trans_lhs,
             trans_rhs,
             trans_out,
+            epilogue: None,
+        }
+    }
+
+    pub fn bias(&self) -> Option<&Tensor> {
+        self.bias.as_ref()
+    }
+
+    /// Elementwise op applied to the output after the bias, set by the fusion pass.
+    pub fn epilogue(&self) -> Option<&UnaryOp> {
+        self.epilogue.as_ref()
+    }
+
+    /// Matrix-vector products are dispatched to the GEMV kernels, which have no epilogue.
+    pub fn is_gemv(&self) -> bool {
+        self.rhs.shape().is_vector() && !self.trans_lhs
+    }
+
+    pub(crate) fn with_bias(self, bias: Tensor) -> Self {
+        Self {
+            bias: Some(bias),
+            ..self
+        }
+    }
+
+    pub(crate) fn with_epilogue(self, op: UnaryOp) -> Self {
+        Self {
+            epilogue: Some(op),
+            ..self
         }
     }
 
@@ -357,10 +388,11 @@ impl GEMM {
         let (a_fit, b_fit, out_fit) = spec.tile_fit();
         let ke = spec.select_kernel_element();
 
-        let kernel_stem = if matches!(self.lhs.dt(), DType::GGUF(GGUFDType::Q8_0(_))) {
-            "qgemm"
-        } else {
-            "sgemm"
+        let kernel_stem = match self.lhs.dt() {
+            DType::GGUF(g) => format!("{}gemm", g.kernel_prefix()),
+            DType::SInt4(_) => "sint4gemm".to_string(),
+            DType::F16 => "hgemm".to_string(),
+            _ => "sgemm".to_string(),
         };
 
         let has_bias = self.bias.is_some();
@@ -399,10 +431,11 @@ impl GEMM {
 
         let ke = spec.select_kernel_element();
 
-        let kernel_stem = if matches!(self.lhs.dt(), DType::GGUF(GGUFDType::Q8_0(_))) {
-            "qgemv"
-        } else {
-            "sgemv"
+        let kernel_stem = match self.lhs.dt() {
+            DType::GGUF(g) => format!("{}gemv", g.kernel_prefix()),
+            DType::SInt4(_) => "sint4gemv".to_string(),
+            DType::F16 => "hgemv".to_string(),
+            _ => "sgemv".to_string(),
         };
 
         let has_bias = self.bias.is_some();
@@ -435,6 +468,8 @@ pub struct MatmulMeta {
     dimAOuter: i32,
     dimBOuter: i32,
     dimInner: i32,
+    epilogue: u32,
+    groupSize: i32,
 }
 
 impl OpMetadata for MatmulMeta {}
@@ -464,20 +499,31 @@ impl OpGuards for GEMM {
             self.trans_out,
         );
         assert!(c_shape.is_ok());
+
+        //Quantized blocks never straddle rows
+        if self.lhs.dt().is_quantized() {
+            let K = self.lhs.shape()[self.lhs.rank() - 1];
+            assert_eq!(K % self.lhs.dt().block_numel(), 0);
+        }
     }
 
     fn check_dtypes(&self) {
-        let allowed_pairs = [
-            (DType::F32, DType::F32),
-            (DType::GGUF(GGUFDType::Q8_0(Q8_0)), DType::F32),
-        ];
-        if !allowed_pairs.contains(&(self.lhs.dt(), self.rhs.dt())) {
+        let allowed = matches!(
+            (self.lhs.dt(), self.rhs.dt()),
+            (DType::F32, DType::F32)
+                | (DType::F16, DType::F16)
+                | (DType::GGUF(_) | DType::SInt4(_), DType::F32)
+        );
+        if !allowed {
             panic!(
                 "Failed to validate DTypes: {:?}, {:?}",
                 self.lhs.dt(),
                 self.rhs.dt()
             );
         }
+        if let Some(bias) = &self.bias {
+            assert_eq!(bias.dt(), self.rhs.dt());
+        }
     }
 }
 
@@ -487,7 +533,7 @@ impl MetaOperation for GEMM {
     }
 
     fn kernel_key(&self, inplace: bool, dst: &Tensor) -> String {
-        if self.rhs.shape().is_vector() && !self.trans_lhs {
+        if self.is_gemv() {
             self.gemv_kernel_key(inplace, dst)
         } else {
             self.gemm_kernel_key(inplace, dst)
@@ -545,11 +591,19 @@ impl MetaOperation for GEMM {
         _inplace: bool,
     ) -> Result<BindGroupLayoutDescriptor, OperationError> {
         let (A, B, bias) = (&self.lhs, &self.rhs, &self.bias);
+        //Each segment of a quantized input is bound separately
+        let segments = A.dt().segments(A.shape().numel()).len();
         let layout = match (A.dt(), B.dt(), bias.is_some()) {
             (DType::F32, DType::F32, false) => BindGroupLayoutDescriptor::binary(),
             (DType::F32, DType::F32, true) => BindGroupLayoutDescriptor::ternary(),
-            (DType::GGUF(_), DType::F32, false) => BindGroupLayoutDescriptor::ternary(),
-            (DType::GGUF(_), DType::F32, true) => BindGroupLayoutDescriptor::nthary(4),
+            (DType::F16, DType::F16, false) => BindGroupLayoutDescriptor::binary(),
+            (DType::F16, DType::F16, true) => BindGroupLayoutDescriptor::ternary(),
+            (DType::GGUF(_) | DType::SInt4(_), DType::F32, false) => {
+                BindGroupLayoutDescriptor::nthary(segments + 1)
+            }
+            (DType::GGUF(_) | DType::SInt4(_), DType::F32, true) => {
+                BindGroupLayoutDescriptor::nthary(segments + 2)
+            }
             _ => return Err(InvariantError::UnsupportedDType(B.dt()).into()),
         };
         Ok(layout)
@@ -589,17 +643,90 @@ impl MetaOperation for GEMM {
             dimAOuter,
             dimBOuter,
             dimInner,
+            epilogue: self.epilogue().and_then(UnaryOp::epilogue_id).unwrap_or(0),
+            groupSize: self.lhs.dt().block_numel() as i32,
         };
         Ok(uniform.write(&meta)?)
     }
 }
 
+impl CPUOperation for GEMM {
+    fn apply_cpu(&self, dst: &Tensor) -> Result<CPUBuffer, OperationError> {
+        let (lhs, rhs) = (cpu_f32(&self.lhs)?, cpu_f32(&self.rhs)?);
+        let bias = self.bias.as_ref().map(cpu_f32).transpose()?;
+
+        let (mut a_shape, mut b_shape) = (self.lhs.shape().clone(), self.rhs.shape().clone());
+        if a_shape.rank() < 2 {
+            a_shape.insert(self.trans_lhs as usize, 1);
+        }
+        if b_shape.rank() < 2 {
+            b_shape.insert(!self.trans_rhs as usize, 1);
+        }
+        let rank = a_shape.rank().max(b_shape.rank());
+        a_shape.left_pad_to(1, rank);
+        b_shape.left_pad_to(1, rank);
+
+        let (a_rows, a_cols) = (a_shape[rank - 2], a_shape[rank - 1]);
+        let (b_rows, b_cols) = (b_shape[rank - 2], b_shape[rank - 1]);
+        let (M, K) = if self.trans_lhs {
+            (a_cols, a_rows)
+        } else {
+            (a_rows, a_cols)
+        };
+        let N = if self.trans_rhs { b_rows } else { b_cols };
+
+        let a_prefix: Shape = a_shape[..rank - 2].into();
+        let b_prefix: Shape = b_shape[..rank - 2].into();
+        let c_prefix = Shape::multi_broadcast(&[&a_prefix, &b_prefix]).ok_or_else(|| {
+            InvariantError::BroadcastingFailed(vec![a_prefix.clone(), b_prefix.clone()])
+        })?;
+
+        let mut result = vec![0f32; c_prefix.numel() * M * N];
+        for (batch, c) in result.chunks_exact_mut(M * N).enumerate() {
+            let a_start = broadcast_offset(&a_prefix, &c_prefix, batch) * M * K;
+            let b_start = broadcast_offset(&b_prefix, &c_prefix, batch) * K * N;
+            let (a, b) = (
+                &lhs[a_start..a_start + M * K],
+                &rhs[b_start..b_start + K * N],
+            );
+            for i in 0..M {
+                for j in 0..N {
+                    let mut acc = 0f32;
+                    for k in 0..K {
+                        let a_ik = if self.trans_lhs {
+                            a[k * M + i]
+                        } else {
+                            a[i * K + k]
+                        };
+                        let b_kj = if self.trans_rhs {
+                            b[j * K + k]
+                        } else {
+                            b[k * N + j]
+                        };
+                        acc += a_ik * b_kj;
+                    }
+                    //Bias is always applied along the last dimension of the output
+                    let (c_index, bias_index) = if self.trans_out {
+                        (j * M + i, i)
+                    } else {
+                        (i * N + j, j)
+                    };
+                    let val = acc + bias.as_ref().map_or(0., |b| b[bias_index]);
+                    c[c_index] = self.epilogue.as_ref().map_or(val, |op| op.apply(val));
+                }
+            }
+        }
+        cpu_from_f32(&result, dst.dt(), dst.shape())
+    }
+}
+
 #[cfg(all(test, feature = "pyo3"))]
 mod tests {
     use test_strategy::{proptest, Arbitrary};
 
-    use crate::test_util::run_py_prg;
+    use crate::test_util::{check_f16, randq, run_py_prg};
 
+    use crate::gguf::{GGUFDType, Q4K, Q4_0, Q4_1, Q5_0, Q5_1, Q6K, Q8_1};
     use crate::{shape, Device, DeviceRequest, Quantization, Quantizer};
 
     use super::*;
@@ -815,4 +942,187 @@ def matmul(a, b{}):
 
         Ok(())
     }
+
+    const KQUANTS: [DType; 2] = [
+        DType::GGUF(GGUFDType::Q4K(Q4K)),
+        DType::GGUF(GGUFDType::Q6K(Q6K)),
+    ];
+
+    const LEGACY: [DType; 5] = [
+        DType::GGUF(GGUFDType::Q4_0(Q4_0)),
+        DType::GGUF(GGUFDType::Q4_1(Q4_1)),
+        DType::GGUF(GGUFDType::Q5_0(Q5_0)),
+        DType::GGUF(GGUFDType::Q5_1(Q5_1)),
+        DType::GGUF(GGUFDType::Q8_1(Q8_1)),
+    ];
+
+    #[test]
+    fn test_quantized_gemm() -> anyhow::Result<()> {
+        let device = Device::request_device(DeviceRequest::GPU)?;
+        for dt in KQUANTS.into_iter().chain(LEGACY) {
+            let w = randq(dt, shape![96, 512]);
+            let x = Tensor::randn::<f32>(shape![2, 33, 512], Device::CPU);
+            let bias = Tensor::randn::<f32>(shape![96], Device::CPU);
+            let ground = w
+                .clone()
+                .gemm(x.clone(), Some(bias.clone()), false, true, true)?
+                .resolve()?;
+
+            let (w, x, bias) = (w.to(&device)?, x.to(&device)?, bias.to(&device)?);
+            let ours = w.gemm(x, Some(bias), false, true, true)?.resolve()?;
+            ground.all_close(&ours.to(&Device::CPU)?, 1e-3, 1e-3)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_quantized_gemm_vectorized() -> anyhow::Result<()> {
+        let device = Device::request_device(DeviceRequest::GPU)?;
+        for dt in KQUANTS.into_iter().chain(LEGACY) {
+            let w = randq(dt, shape![64, 768]);
+            let x = Tensor::randn::<f32>(shape![768, 48], Device::CPU);
+            let ground = w.clone().matmul(x.clone(), false, false)?.resolve()?;
+
+            let ours = w
+                .to(&device)?
+                .matmul(x.to(&device)?, false, false)?
+                .resolve()?;
+            ground.all_close(&ours.to(&Device::CPU)?, 1e-3, 1e-3)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_quantized_gemv() -> anyhow::Result<()> {
+        let device = Device::request_device(DeviceRequest::GPU)?;
+        for dt in KQUANTS.into_iter().chain(LEGACY) {
+            let w = randq(dt, shape![1, 1000, 1024]);
+            let x = Tensor::randn::<f32>(shape![1, 1, 1024], Device::CPU);
+            let ground = w
+                .clone()
+                .gemm(x.clone(), None, false, true, true)?
+                .resolve()?;
+
+            let ours = w
+                .to(&device)?
+                .gemm(x.to(&device)?, None, false, true, true)?
+                .resolve()?;
+            ground.all_close(&ours.to(&Device::CPU)?, 1e-3, 1e-3)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_sint4_gemm() -> anyhow::Result<()> {
+        let device = Device::request_device(DeviceRequest::GPU)?;
+        let quantizer = Quantizer::new(Quantization::SInt4 { group_size: 64 });
+        let w = quantizer.quantize(Tensor::randn::<f32>(shape![96, 512], Device::CPU));
+        let bias = Tensor::randn::<f32>(shape![96], Device::CPU);
+        for x_shape in [shape![2, 33, 512], shape![1, 1, 512]] {
+            let x = Tensor::randn::<f32>(x_shape, Device::CPU);
+            let ground = w
+                .clone()
+                .gemm(x.clone(), Some(bias.clone()), false, true, true)?
+                .resolve()?;
+
+            let ours = w
+                .to(&device)?
+                .gemm(x.to(&device)?, Some(bias.to(&device)?), false, true, true)?
+                .resolve()?;
+            ground.all_close(&ours.to(&Device::CPU)?, 1e-3, 1e-3)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[ignore = "needs shader-f16, which only browsers grant, see tests/f16.rs"]
+    fn test_hgemm() -> anyhow::Result<()> {
+        let device = Device::request_device(DeviceRequest::GPU)?;
+        let transposes = [
+            (false, false, false),
+            (true, false, false),
+            (true, false, true),
+            (false, true, false),
+            (false, true, true),
+            (false, false, true),
+        ];
+        //Unaligned shapes take the scalar kernels, aligned ones the vectorized kernels
+        for [m, k, n] in [[33, 70, 47], [64, 128, 96]] {
+            for (trans_lhs, trans_rhs, trans_out) in transposes {
+                let a_shape = if trans_lhs {
+                    shape![2, k, m]
+                } else {
+                    shape![2, m, k]
+                };
+                let b_shape = if trans_rhs {
+                    shape![2, n, k]
+                } else {
+                    shape![2, k, n]
+                };
+                let inputs = [
+                    Tensor::randn::<f32>(a_shape, Device::CPU),
+                    Tensor::randn::<f32>(b_shape, Device::CPU),
+                    Tensor::randn::<f32>(shape![n], Device::CPU),
+                ];
+                let matmul = |t: &[Tensor]| {
+                    let bias = (!trans_out).then(|| t[2].clone());
+                    t[0].clone()
+                        .gemm(t[1].clone(), bias, trans_lhs, trans_rhs, trans_out)
+                };
+                check_f16(&device, &inputs, matmul, 1e-1, 1e-2)?;
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[ignore = "needs shader-f16, which only browsers grant, see tests/f16.rs"]
+    fn test_hgemv() -> anyhow::Result<()> {
+        let device = Device::request_device(DeviceRequest::GPU)?;
+        let inputs = [
+            Tensor::randn::<f32>(shape![1, 1000, 256], Device::CPU),
+            Tensor::randn::<f32>(shape![1, 1, 256], Device::CPU),
+            Tensor::randn::<f32>(shape![1000], Device::CPU),
+        ];
+        let gemv = |t: &[Tensor]| {
+            let bias = Some(t[2].clone());
+            t[0].clone().gemm(t[1].clone(), bias, false, true, true)
+        };
+        check_f16(&device, &inputs, gemv, 1e-1, 1e-2)
+    }
+}
+
+#[cfg(test)]
+mod cpu_tests {
+    use crate::{shape, Device, Tensor};
+
+    #[test]
+    fn test_cpu_matmul() -> anyhow::Result<()> {
+        let a = Tensor::from_data(vec![1., 2., 3., 4., 5., 6.], shape![2, 3], Device::CPU);
+        let b = Tensor::from_data(vec![1., 0., 0., 1., 1., 1.], shape![3, 2], Device::CPU);
+        let c = a.clone().matmul(b.clone(), false, false)?.resolve()?;
+        assert_eq!(c.shape(), &shape![2, 2]);
+        assert_eq!(c.to_vec::<f32>()?, vec![4., 5., 10., 11.]);
+
+        //Transposed inputs & output: (A^T^T B^T^T)^T = (AB)^T
+        let d = a
+            .permute(&[1, 0])?
+            .gemm(b.permute(&[1, 0])?, None, true, true, true)?
+            .resolve()?;
+        assert_eq!(d.shape(), &shape![2, 2]);
+        assert_eq!(d.to_vec::<f32>()?, vec![4., 10., 5., 11.]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_cpu_linear() -> anyhow::Result<()> {
+        //Mirrors `ratchet_nn::Linear`: W [N, K] @ x [B, S, K]^T -> [B, S, N]
+        let w = Tensor::from_data(vec![1., 0., 0., 1., 1., 1.], shape![3, 2], Device::CPU);
+        let b = Tensor::from_data(vec![0.5, 0.5, 0.5], shape![3], Device::CPU);
+        let x = Tensor::from_data(vec![1., 2.], shape![1, 1, 2], Device::CPU);
+        let y = w.gemm(x, Some(b), false, true, true)?.resolve()?;
+        assert_eq!(y.shape(), &shape![1, 1, 3]);
+        assert_eq!(y.to_vec::<f32>()?, vec![1.5, 2.5, 3.5]);
+        Ok(())
+    }
 }
@@ -1,28 +1,40 @@
 mod binary;
 mod cache;
+mod cast;
+mod cmp;
 mod concat;
 mod conv;
 mod index_write;
 mod matmul;
 mod norm;
+mod reduce;
 mod reindex;
 mod rope;
+mod sdpa;
 mod select;
 mod softmax;
+mod trilu;
 mod unary;
+mod where_cond;
 
 pub use binary::*;
 pub use cache::*;
+pub use cast::*;
+pub use cmp::*;
 pub use concat::*;
 pub use conv::*;
 pub use index_write::*;
 pub use matmul::*;
 pub use norm::*;
+pub use reduce::*;
 pub use reindex::*;
 pub use rope::*;
+pub use sdpa::*;
 pub use select::*;
 pub use softmax::*;
+pub use trilu::*;
 pub use unary::*;
+pub use where_cond::*;
 
 use crate::{OpGuards, Operation, Shape, StorageView, Strides, Tensor};
 
@@ -3,9 +3,10 @@ use derive_new::new;
 use encase::ShaderType;
 
 use crate::{
+    cpu::{cpu_f32, cpu_from_f32},
     gpu::{BindGroupLayoutDescriptor, CpuUniform, WorkgroupCount},
-    rvec, wgc, DType, KernelElement, MetaOperation, OpGuards, OpMetadata, Operation,
-    OperationError, RVec, StorageView, Tensor,
+    rvec, wgc, CPUBuffer, CPUOperation, DType, KernelElement, MetaOperation, OpGuards, OpMetadata,
+    Operation, OperationError, RVec, StorageView, Tensor,
 };
 
 #[derive(new, Debug, Clone)]
@@ -22,9 +23,10 @@ impl OpGuards for LayerNorm {
     }
 
     fn check_dtypes(&self) {
-        assert!(self.input.dt() == DType::F32);
-        assert!(self.scale.dt() == DType::F32);
-        assert!(self.bias.as_ref().map(|t| t.dt()) == Some(DType::F32));
+        let dt = self.input.dt();
+        assert!(matches!(dt, DType::F32 | DType::F16));
+        assert!(self.scale.dt() == dt);
+        assert!(self.bias.as_ref().map(|t| t.dt()) == Some(dt));
     }
 }
 
@@ -34,9 +36,115 @@ impl Operation for LayerNorm {
     }
 }
 
+/// # RMSNorm
+///
+/// LayerNorm without mean subtraction or bias, as used by Llama & friends.
+#[derive(new, Debug, Clone)]
+pub struct RMSNorm {
+    input: Tensor,
+    scale: Tensor,
+    eps: f32,
+}
+
+impl OpGuards for RMSNorm {
+    fn check_shapes(&self) {
+        let rank = self.input.rank();
+        assert!(rank >= 2);
+        assert_eq!(self.scale.shape().numel(), self.input.shape()[rank - 1]);
+    }
+
+    fn check_dtypes(&self) {
+        let dt = self.input.dt();
+        assert!(matches!(dt, DType::F32 | DType::F16));
+        assert!(self.scale.dt() == dt);
+    }
+}
+
+impl Operation for RMSNorm {
+    fn compute_view(&self) -> Result<StorageView, OperationError> {
+        Ok(self.input.storage_view().clone())
+    }
+}
+
+/// # GroupNorm
+///
+/// Normalizes an input of shape [B, C, *] over `num_groups` groups of channels, followed by a
+/// per channel affine transform. Matches `torch.nn.functional.group_norm`.
+#[derive(new, Debug, Clone)]
+pub struct GroupNorm {
+    input: Tensor,
+    scale: Tensor,
+    bias: Tensor,
+    num_groups: usize,
+    eps: f32,
+}
+
+impl GroupNorm {
+    /// Number of elements per channel.
+    fn spatial_len(&self) -> usize {
+        self.input.shape().slice(2..self.input.rank()).numel()
+    }
+}
+
+impl OpGuards for GroupNorm {
+    fn check_shapes(&self) {
+        assert!(self.input.rank() >= 2);
+        let C = self.input.shape()[1];
+        assert!(self.num_groups > 0 && C % self.num_groups == 0);
+        assert_eq!(self.scale.shape().numel(), C);
+        assert_eq!(self.bias.shape().numel(), C);
+    }
+
+    fn check_dtypes(&self) {
+        let dt = self.input.dt();
+        assert!(matches!(dt, DType::F32 | DType::F16));
+        assert!(self.scale.dt() == dt);
+        assert!(self.bias.dt() == dt);
+    }
+}
+
+impl Operation for GroupNorm {
+    fn compute_view(&self) -> Result<StorageView, OperationError> {
+        Ok(self.input.storage_view().clone())
+    }
+}
+
+#[allow(clippy::enum_variant_names)]
 #[derive(Debug, Clone)]
 pub enum Norm {
     LayerNorm(LayerNorm),
+    RMSNorm(RMSNorm),
+    GroupNorm(GroupNorm),
+}
+
+impl Norm {
+    /// Each workgroup normalizes `N` contiguous elements, returns (M, N, stacks).
+    fn dims(&self) -> (usize, usize, usize) {
+        match self {
+            Norm::GroupNorm(gn) => {
+                let shape = gn.input.shape();
+                let group_len = shape.numel() / (shape[0] * gn.num_groups);
+                (gn.num_groups, group_len, shape[0])
+            }
+            _ => {
+                let shape = self.srcs()[0].shape();
+                let rank = shape.rank();
+                (
+                    shape[rank - 2],
+                    shape[rank - 1],
+                    shape.slice(0..rank - 2).numel(),
+                )
+            }
+        }
+    }
+
+    fn eps(&self) -> f32 {
+        match self {
+            Norm::LayerNorm(LayerNorm { eps, .. })
+            | Norm::RMSNorm(RMSNorm { eps, .. })
+            | Norm::GroupNorm(GroupNorm { eps, .. }) => *eps,
+        }
+    }
 }
 
 #[derive(Debug, derive_new::new, ShaderType)]
@@ -50,10 +158,24 @@ pub struct NormMeta {
 
 impl OpMetadata for NormMeta {}
 
+#[derive(Debug, derive_new::new, ShaderType)]
+pub struct GroupNormMeta {
+    M: u32,
+    N: u32,
+    ND2: u32,
+    ND4: u32,
+    eps: f32,
+    L: u32,
+}
+
+impl OpMetadata for GroupNormMeta {}
+
 impl MetaOperation for Norm {
     fn kernel_name(&self) -> String {
         match self {
             Norm::LayerNorm(_) => "layernorm".to_string(),
+            Norm::RMSNorm(_) => "rmsnorm".to_string(),
+            Norm::GroupNorm(_) => "groupnorm".to_string(),
         }
     }
 
@@ -65,20 +187,28 @@ impl MetaOperation for Norm {
                 Some(bias) => rvec![input, scale, bias],
                 None => rvec![input, scale],
             },
+            Norm::RMSNorm(RMSNorm { input, scale, .. }) => rvec![input, scale],
+            Norm::GroupNorm(GroupNorm {
+                input, scale, bias, ..
+            }) => rvec![input, scale, bias],
         }
     }
 
-    fn kernel_key(&self, inplace: bool, dst: &Tensor) -> String {
-        let op_key = match self {
-            Norm::LayerNorm(_) => "layernorm",
-        };
-        format!("{}_{}", op_key, self.kernel_element(dst).as_str())
+    fn kernel_key(&self, _: bool, dst: &Tensor) -> String {
+        format!(
+            "{}_{}{}",
+            self.kernel_name(),
+            self.kernel_element(dst).as_str(),
+            dst.dt().kernel_suffix()
+        )
     }
 
     fn kernel_element(&self, _dst: &Tensor) -> KernelElement {
-        let input = self.srcs()[0];
-        let rank = input.rank();
-        let N = input.shape()[rank - 1] as u32;
+        //GroupNorm vectorizes within a channel
+        let N = match self {
+            Norm::GroupNorm(gn) => gn.spatial_len(),
+            _ => self.dims().1,
+        };
         if N % 4 == 0 {
             KernelElement::Vec4
         } else if N % 2 == 0 {
@@ -89,11 +219,7 @@ impl MetaOperation for Norm {
     }
 
     fn calculate_dispatch(&self, _dst: &Tensor) -> Result<WorkgroupCount, OperationError> {
-        let input = self.srcs()[0];
-        let rank = input.rank();
-
-        let M = input.shape()[rank - 2] as u32;
-        let stacks = input.shape().slice(0..rank - 2).numel();
+        let (M, _, stacks) = self.dims();
         Ok(wgc![M as _, stacks as _, 1])
     }
 
@@ -101,7 +227,10 @@ impl MetaOperation for Norm {
         &self,
         _inplace: bool,
     ) -> Result<BindGroupLayoutDescriptor, OperationError> {
-        Ok(BindGroupLayoutDescriptor::ternary())
+        match self {
+            Norm::RMSNorm(_) => Ok(BindGroupLayoutDescriptor::binary()),
+            _ => Ok(BindGroupLayoutDescriptor::ternary()),
+        }
     }
 
     fn write_metadata(
@@ -110,17 +239,95 @@ impl MetaOperation for Norm {
         _: &Tensor,
         _: &KernelElement,
     ) -> Result<u64, OperationError> {
-        let input = self.srcs()[0];
-        let rank = input.rank();
-        let M = input.shape()[rank - 2] as u32;
-        let N = input.shape()[rank - 1] as u32;
+        let (M, N, _) = self.dims();
+        let (M, N) = (M as u32, N as u32);
         let ND2 = N / 2;
         let ND4 = N / 4;
-        let eps = match self {
-            Norm::LayerNorm(LayerNorm { eps, .. }) => *eps,
-        };
-        let meta = NormMeta::new(M, N, ND2, ND4, eps);
-        Ok(uniform.write(&meta)?)
+        let eps = self.eps();
+        match self {
+            Norm::GroupNorm(gn) => {
+                let L = gn.spatial_len() as u32;
+                let meta = GroupNormMeta::new(M, N, ND2, ND4, eps, L);
+                Ok(uniform.write(&meta)?)
+            }
+            _ => {
+                let meta = NormMeta::new(M, N, ND2, ND4, eps);
+                Ok(uniform.write(&meta)?)
+            }
+        }
+    }
+}
+
+impl CPUOperation for LayerNorm {
+    fn apply_cpu(&self, dst: &Tensor) -> Result<CPUBuffer, OperationError> {
+        let input = cpu_f32(&self.input)?;
+        let scale = cpu_f32(&self.scale)?;
+        let bias = self.bias.as_ref().map(cpu_f32).transpose()?;
+
+        let N = self.input.shape()[self.input.rank() - 1];
+        let mut result = vec![0f32; input.len()];
+        for (x, y) in input.chunks_exact(N).zip(result.chunks_exact_mut(N)) {
+            let mu = x.iter().sum::<f32>() / N as f32;
+            let sigma = x.iter().map(|v| (v - mu) * (v - mu)).sum::<f32>() / N as f32;
+            let denom = (sigma + self.eps).sqrt();
+            for (i, (v, out)) in x.iter().zip(y.iter_mut()).enumerate() {
+                *out = (v - mu) / denom * scale[i] + bias.as_ref().map_or(0., |b| b[i]);
+            }
+        }
+        cpu_from_f32(&result, dst.dt(), dst.shape())
+    }
+}
+
+impl CPUOperation for RMSNorm {
+    fn apply_cpu(&self, dst: &Tensor) -> Result<CPUBuffer, OperationError> {
+        let input = cpu_f32(&self.input)?;
+        let scale = cpu_f32(&self.scale)?;
+
+        let N = self.input.shape()[self.input.rank() - 1];
+        let mut result = vec![0f32; input.len()];
+        for (x, y) in input.chunks_exact(N).zip(result.chunks_exact_mut(N)) {
+            let ms = x.iter().map(|v| v * v).sum::<f32>() / N as f32;
+            let denom = (ms + self.eps).sqrt();
+            for (i, (v, out)) in x.iter().zip(y.iter_mut()).enumerate() {
+                *out = v / denom * scale[i];
+            }
+        }
+        cpu_from_f32(&result, dst.dt(), dst.shape())
+    }
+}
+
+impl CPUOperation for GroupNorm {
+    fn apply_cpu(&self, dst: &Tensor) -> Result<CPUBuffer, OperationError> {
+        let input = cpu_f32(&self.input)?;
+        let scale = cpu_f32(&self.scale)?;
+        let bias = cpu_f32(&self.bias)?;
+
+        let L = self.spatial_len();
+        let channels_per_group = self.input.shape()[1] / self.num_groups;
+        let N = channels_per_group * L;
+        let mut result = vec![0f32; input.len()];
+        let groups = input.chunks_exact(N).zip(result.chunks_exact_mut(N));
+        for (g, (x, y)) in groups.enumerate() {
+            let mu = x.iter().sum::<f32>() / N as f32;
+            let sigma = x.iter().map(|v| (v - mu) * (v - mu)).sum::<f32>() / N as f32;
+            let denom = (sigma + self.eps).sqrt();
+            let first_channel = (g % self.num_groups) * channels_per_group;
+            for (i, (v, out)) in x.iter().zip(y.iter_mut()).enumerate() {
+                let c = first_channel + i / L;
+                *out = (v - mu) / denom * scale[c] + bias[c];
+            }
+        }
+        cpu_from_f32(&result, dst.dt(), dst.shape())
+    }
+}
+
+impl CPUOperation for Norm {
+    fn apply_cpu(&self, dst: &Tensor) -> Result<CPUBuffer, OperationError> {
+        match self {
+            Norm::LayerNorm(ln) => ln.apply_cpu(dst),
+            Norm::RMSNorm(rms) => rms.apply_cpu(dst),
+            Norm::GroupNorm(gn) => gn.apply_cpu(dst),
+        }
     }
 }
 
@@ -128,7 +335,7 @@ impl MetaOperation for Norm {
 mod tests {
     use test_strategy::{proptest, Arbitrary};
 
-    use crate::test_util::run_py_prg;
+    use crate::test_util::{check_f16, run_py_prg};
     use crate::{shape, Device, DeviceRequest, Tensor};
 
     fn ground_truth(input: &Tensor, scale: &Tensor, bias: &Tensor) -> anyhow::Result<Tensor> {
@@ -180,4 +387,201 @@ def layernorm(input, scale, bias):
         println!("B = {}, M = {}, N = {}", B, M, N);
         run_norm_trial(&device, prob).unwrap();
     }
+
+    fn rms_ground_truth(input: &Tensor, scale: &Tensor) -> anyhow::Result<Tensor> {
+        let prg = r#"
+import torch
+
+def rmsnorm(input, scale):
+    (input, scale) = (torch.from_numpy(input), torch.from_numpy(scale))
+    return (input * torch.rsqrt(input.pow(2).mean(-1, keepdim=True) + 1e-5) * scale).numpy()
+"#;
+        run_py_prg(prg.to_string(), &[input, scale], &[])
+    }
+
+    #[proptest(cases = 16)]
+    fn test_rms_norm(prob: NormProblem) {
+        let device = Device::request_device(DeviceRequest::GPU).unwrap();
+        let NormProblem { B, M, N } = prob;
+        let input = Tensor::randn::<f32>(shape![B, M, N], Device::CPU);
+        let scale = Tensor::randn::<f32>(shape![N], Device::CPU);
+        let ground = rms_ground_truth(&input, &scale).unwrap();
+
+        let input_gpu = input.to(&device).unwrap();
+        let scale_gpu = scale.to(&device).unwrap();
+        let result = input_gpu
+            .rms_norm(scale_gpu, 1e-5)
+            .unwrap()
+            .resolve()
+            .unwrap();
+
+        let ours = result.to(&Device::CPU).unwrap();
+        ground.all_close(&ours, 1e-4, 1e-4).unwrap();
+    }
+
+    fn group_ground_truth(
+        input: &Tensor,
+        scale: &Tensor,
+        bias: &Tensor,
+        num_groups: usize,
+    ) -> anyhow::Result<Tensor> {
+        let prg = format!(
+            r#"
+import torch
+import torch.nn.functional as F
+
+def groupnorm(input, scale, bias):
+    (input, scale, bias) = (torch.from_numpy(input), torch.from_numpy(scale), torch.from_numpy(bias))
+    return F.group_norm(input, {}, weight=scale, bias=bias, eps=1e-5).numpy()
+"#,
+            num_groups
+        );
+        run_py_prg(prg, &[input, scale, bias], &[])
+    }
+
+    #[derive(Arbitrary, Debug)]
+    struct GroupNormProblem {
+        #[strategy(1..=2usize)]
+        B: usize,
+        #[strategy(1..=8usize)]
+        G: usize,
+        #[strategy(1..=8usize)]
+        channels_per_group: usize,
+        #[strategy(1..=256usize)]
+        L: usize,
+    }
+
+    #[proptest(cases = 16)]
+    fn test_group_norm(prob: GroupNormProblem) {
+        let device = Device::request_device(DeviceRequest::GPU).unwrap();
+        let GroupNormProblem {
+            B,
+            G,
+            channels_per_group,
+            L,
+        } = prob;
+        let C = G * channels_per_group;
+        let input = Tensor::randn::<f32>(shape![B, C, L], Device::CPU);
+        let scale = Tensor::randn::<f32>(shape![C], Device::CPU);
+        let bias = Tensor::randn::<f32>(shape![C], Device::CPU);
+        let ground = group_ground_truth(&input, &scale, &bias, G).unwrap();
+
+        let input_gpu = input.to(&device).unwrap();
+        let scale_gpu = scale.to(&device).unwrap();
+        let bias_gpu = bias.to(&device).unwrap();
+        let result = input_gpu
+            .group_norm(G, scale_gpu, bias_gpu, 1e-5)
+            .unwrap()
+            .resolve()
+            .unwrap();
+
+        let ours = result.to(&Device::CPU).unwrap();
+        ground.all_close(&ours, 1e-4, 1e-4).unwrap();
+    }
+
+    #[test]
+    #[ignore = "needs shader-f16, which only browsers grant, see tests/f16.rs"]
+    fn test_norm_f16() -> anyhow::Result<()> {
+        let device = Device::request_device(DeviceRequest::GPU)?;
+        //Rows of 4, 2 and 1 elements per thread
+        for n in [256, 130, 129] {
+            let inputs = [
+                Tensor::randn::<f32>(shape![2, 17, n], Device::CPU),
+                Tensor::randn::<f32>(shape![n], Device::CPU),
+                Tensor::randn::<f32>(shape![n], Device::CPU),
+            ];
+            let layer_norm = |t: &[Tensor]| {
+                t[0].clone()
+                    .layer_norm(t[1].clone(), Some(t[2].clone()), 1e-5)
+            };
+            check_f16(&device, &inputs, layer_norm, 2e-2, 1e-2)?;
+            let rms_norm = |t: &[Tensor]| t[0].clone().rms_norm(t[1].clone(), 1e-5);
+            check_f16(&device, &inputs, rms_norm, 2e-2, 1e-2)?;
+        }
+
+        let inputs = [
+            Tensor::randn::<f32>(shape![2, 8, 48], Device::CPU),
+            Tensor::randn::<f32>(shape![8], Device::CPU),
+            Tensor::randn::<f32>(shape![8], Device::CPU),
+        ];
+        let group_norm =
+            |t: &[Tensor]| t[0].clone().group_norm(4, t[1].clone(), t[2].clone(), 1e-5);
+        check_f16(&device, &inputs, group_norm, 2e-2, 1e-2)
+    }
+}
+
+#[cfg(test)]
+mod cpu_tests {
+    use crate::{shape, Device, Tensor};
+
+    #[test]
+    fn test_cpu_layer_norm() -> anyhow::Result<()> {
+        let x = Tensor::from_data(
+            vec![1., 2., 3., 4., 1., 1., 1., 1.],
+            shape![2, 4],
+            Device::CPU,
+        );
+        let scale = Tensor::from_data(vec![1.; 4], shape![4], Device::CPU);
+        let bias = Tensor::from_data(vec![0.; 4], shape![4], Device::CPU);
+        let n = x.layer_norm(scale, Some(bias), 1e-5)?.resolve()?;
+        let n = n.to_vec::<f32>()?;
+        let mean: f32 = n[..4].iter().sum::<f32>() / 4.;
+        assert!(mean.abs() < 1e-5);
+        assert!(n[4..].iter().all(|&v| v.abs() < 1e-5));
+        Ok(())
+    }
+
+    #[test]
+    fn test_cpu_rms_and_group_norm() -> anyhow::Result<()> {
+        let x = Tensor::from_data(
+            vec![1., -1., 3., -3., 2., 2., 0., 0.],
+            shape![1, 2, 4],
+            Device::CPU,
+        );
+        let rms = x
+            .clone()
+            .rms_norm(Tensor::from_data(vec![2.; 4], shape![4], Device::CPU), 0.)?;
+        let rms = rms.resolve()?.to_vec::<f32>()?;
+        let expected = [
+            0.8944272, -0.8944272, 2.6832817, -2.6832817, 2.828427, 2.828427, 0., 0.,
+        ];
+        for (o, e) in rms.iter().zip(expected.iter()) {
+            assert!((o - e).abs() < 1e-5);
+        }
+
+        //One group per channel, then both channels in 1 group
+        let (scale, bias) = (
+            Tensor::from_data(vec![1., 2.], shape![2], Device::CPU),
+            Tensor::from_data(vec![0., 1.], shape![2], Device::CPU),
+        );
+        let per_channel = x.clone().group_norm(2, scale.clone(), bias.clone(), 0.)?;
+        let per_channel = per_channel.resolve()?.to_vec::<f32>()?;
+        let expected = [
+            0.4472136, -0.4472136, 1.3416408, -1.3416408, 3., 3., -1., -1.,
+        ];
+        for (o, e) in per_channel.iter().zip(expected.iter()) {
+            assert!((o - e).abs() < 1e-5);
+        }
+        let grouped = x
+            .group_norm(1, scale, bias, 0.)?
+            .resolve()?
+            .to_vec::<f32>()?;
+        let ln = Tensor::from_data(
+            vec![1., -1., 3., -3., 2., 2., 0., 0.],
+            shape![1, 8],
+            Device::CPU,
+        )
+        .layer_norm(
+            Tensor::from_data(vec![1.; 8], shape![8], Device::CPU),
+            Some(Tensor::from_data(vec![0.; 8], shape![8], Device::CPU)),
+            0.,
+        )?
+        .resolve()?
+        .to_vec::<f32>()?;
+        for (i, (o, n)) in grouped.iter().zip(ln.iter()).enumerate() {
+            let (s, b) = if i < 4 { (1., 0.) } else { (2., 1.) };
+            assert!((o - (n * s + b)).abs() < 1e-5);
+        }
+        Ok(())
+    }
 }
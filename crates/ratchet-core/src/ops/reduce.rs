@@ -0,0 +1,465 @@
+use derive_new::new;
+use encase::ShaderType;
+use glam::UVec4;
+
+use crate::{
+    cpu::{contiguous_strides, cpu_f32, cpu_from_f32, ravel, unravel},
+    gpu::{BindGroupLayoutDescriptor, CpuUniform, WorkgroupCount},
+    rvec, wgc, CPUBuffer, CPUOperation, DType, KernelElement, MetaOperation, OpGuards, OpMetadata,
+    Operation, OperationError, RVec, Shape, StorageView, Strides, Tensor,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReduceOp {
+    Sum,
+    Mean,
+    Max,
+    Min,
+    ArgMax,
+    ArgMin,
+}
+
+impl ReduceOp {
+    pub fn kernel_name(&self) -> &'static str {
+        match self {
+            ReduceOp::Sum => "sum",
+            ReduceOp::Mean => "mean",
+            ReduceOp::Max => "max",
+            ReduceOp::Min => "min",
+            ReduceOp::ArgMax => "argmax",
+            ReduceOp::ArgMin => "argmin",
+        }
+    }
+
+    /// Arg reductions return the (I32) index of the selected element, rather than its value.
+    pub fn is_arg(&self) -> bool {
+        matches!(self, ReduceOp::ArgMax | ReduceOp::ArgMin)
+    }
+}
+
+/// # Reduce
+///
+/// Reduces the input over one or more axes.
+///
+/// If `keepdim` is true, the reduced axes are retained with size 1, otherwise they are removed.
+/// Arg reductions only support a single axis.
+#[derive(new, Debug, Clone)]
+pub struct Reduce {
+    input: Tensor,
+    op: ReduceOp,
+    axes: RVec<usize>,
+    keepdim: bool,
+}
+
+impl Reduce {
+    pub fn op(&self) -> &ReduceOp {
+        &self.op
+    }
+
+    /// Input shape with all reduced axes set to 1.
+    fn kept_shape(&self) -> Shape {
+        let mut shape = self.input.shape().clone();
+        for &axis in self.axes.iter() {
+            shape[axis] = 1;
+        }
+        shape
+    }
+
+    /// Input shape with all non-reduced axes set to 1.
+    fn reduced_shape(&self) -> Shape {
+        let input_shape = self.input.shape();
+        let mut shape = Shape::new(rvec![1; input_shape.rank()]);
+        for &axis in self.axes.iter() {
+            shape[axis] = input_shape[axis];
+        }
+        shape
+    }
+}
+
+#[derive(Debug, derive_new::new, ShaderType)]
+pub struct ReduceMeta {
+    src_stride: glam::UVec4,
+    dst_stride: glam::UVec4,
+    red_stride: glam::UVec4,
+    dst_numel: u32,
+    red_numel: u32,
+}
+
+impl OpMetadata for ReduceMeta {}
+
+impl OpGuards for Reduce {
+    fn check_shapes(&self) {
+        let rank = self.input.rank();
+        assert!(rank <= 4);
+        assert!(!self.axes.is_empty());
+        assert!(self.axes.iter().all(|&axis| axis < rank));
+        let mut axes = self.axes.clone();
+        axes.sort();
+        axes.dedup();
+        assert_eq!(axes.len(), self.axes.len(), "Duplicate reduction axes");
+    }
+
+    fn check_dtypes(&self) {
+        assert!(matches!(self.input.dt(), DType::F32 | DType::F16));
+    }
+
+    fn check_custom(&self) {
+        if self.op.is_arg() {
+            assert_eq!(self.axes.len(), 1, "Arg reductions require a single axis");
+        }
+    }
+}
+
+impl Operation for Reduce {
+    fn compute_view(&self) -> Result<StorageView, OperationError> {
+        let mut output_shape = self.kept_shape();
+        if !self.keepdim {
+            let mut axes = self.axes.clone();
+            axes.sort();
+            for &axis in axes.iter().rev() {
+                output_shape.remove(axis);
+            }
+        }
+        let dt = if self.op.is_arg() {
+            DType::I32
+        } else {
+            self.input.dt()
+        };
+        let strides = Strides::from(&output_shape);
+        Ok(StorageView::new(output_shape, dt, strides))
+    }
+}
+
+impl MetaOperation for Reduce {
+    fn kernel_name(&self) -> String {
+        self.op.kernel_name().to_string()
+    }
+
+    fn kernel_key(&self, _: bool, dst: &Tensor) -> String {
+        format!(
+            "reduce_{}_{}{}",
+            self.kernel_name(),
+            self.kernel_element(dst).as_str(),
+            self.input.dt().kernel_suffix()
+        )
+    }
+
+    fn srcs(&self) -> RVec<&Tensor> {
+        rvec![&self.input]
+    }
+
+    fn kernel_element(&self, _dst: &Tensor) -> KernelElement {
+        KernelElement::Scalar
+    }
+
+    fn calculate_dispatch(&self, dst: &Tensor) -> Result<WorkgroupCount, OperationError> {
+        let x_groups = dst.shape().numel();
+        let (x_groups, y_groups) = if x_groups > WorkgroupCount::MAX_WGS_PER_DIM {
+            let y_groups = WorkgroupCount::div_ceil(x_groups, WorkgroupCount::MAX_WGS_PER_DIM);
+            (WorkgroupCount::MAX_WGS_PER_DIM, y_groups)
+        } else {
+            (x_groups, 1)
+        };
+        Ok(wgc![x_groups as _, y_groups as _, 1])
+    }
+
+    fn storage_bind_group_layout(
+        &self,
+        _: bool,
+    ) -> Result<BindGroupLayoutDescriptor, OperationError> {
+        Ok(BindGroupLayoutDescriptor::unary())
+    }
+
+    fn write_metadata(
+        &self,
+        uniform: &mut CpuUniform,
+        dst: &Tensor,
+        _: &KernelElement,
+    ) -> Result<u64, OperationError> {
+        let src_shape = Shape::promote(self.input.shape().clone(), 4);
+        let kept_shape = Shape::promote(self.kept_shape(), 4);
+        let reduced_shape = Shape::promote(self.reduced_shape(), 4);
+
+        let meta = ReduceMeta {
+            src_stride: UVec4::from(&Strides::from(&src_shape)),
+            dst_stride: UVec4::from(&Strides::from(&kept_shape)),
+            red_stride: UVec4::from(&Strides::from(&reduced_shape)),
+            dst_numel: dst.shape().numel() as u32,
+            red_numel: reduced_shape.numel() as u32,
+        };
+        Ok(uniform.write(&meta)?)
+    }
+}
+
+impl CPUOperation for Reduce {
+    fn apply_cpu(&self, dst: &Tensor) -> Result<CPUBuffer, OperationError> {
+        let input = cpu_f32(&self.input)?;
+        let (kept_shape, reduced_shape) = (self.kept_shape(), self.reduced_shape());
+        let src_strides = contiguous_strides(self.input.shape());
+        let kept_strides = contiguous_strides(&kept_shape);
+        let red_strides = contiguous_strides(&reduced_shape);
+        let red_numel = reduced_shape.numel();
+
+        //Offsets of each reduced element, relative to the first element of the reduction
+        let red_offsets = (0..red_numel)
+            .map(|r| ravel(&unravel(r, &red_strides), &src_strides))
+            .collect::<Vec<_>>();
+        let reduce_row = |o: usize| {
+            let (input, anchor) = (&input, ravel(&unravel(o, &kept_strides), &src_strides));
+            red_offsets.iter().map(move |r| input[anchor + r])
+        };
+
+        let numel = kept_shape.numel();
+        if self.op.is_arg() {
+            let result = (0..numel)
+                .map(|o| {
+                    let mut best = (0, reduce_row(o).next().unwrap_or_default());
+                    for (i, v) in reduce_row(o).enumerate().skip(1) {
+                        let better = match self.op {
+                            ReduceOp::ArgMax => v > best.1,
+                            _ => v < best.1,
+                        };
+                        if better {
+                            best = (i, v);
+                        }
+                    }
+                    best.0 as i32
+                })
+                .collect::<Vec<_>>();
+            return Ok(CPUBuffer::from_slice(&result, dst.shape()));
+        }
+
+        let result = (0..numel)
+            .map(|o| match self.op {
+                ReduceOp::Sum => reduce_row(o).sum::<f32>(),
+                ReduceOp::Mean => reduce_row(o).sum::<f32>() / red_numel as f32,
+                ReduceOp::Max => reduce_row(o).fold(f32::NEG_INFINITY, f32::max),
+                ReduceOp::Min => reduce_row(o).fold(f32::INFINITY, f32::min),
+                ReduceOp::ArgMax | ReduceOp::ArgMin => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+        cpu_from_f32(&result, dst.dt(), dst.shape())
+    }
+}
+
+#[cfg(all(test, feature = "pyo3"))]
+mod tests {
+    use test_strategy::{proptest, Arbitrary};
+
+    use crate::test_util::{check_f16, run_py_prg};
+    use crate::{shape, DType, Device, DeviceRequest, ReduceOp, Tensor};
+
+    thread_local! {
+        static GPU_DEVICE: Device = Device::request_device(DeviceRequest::GPU).unwrap();
+    }
+
+    fn ground_truth(a: &Tensor, op: &str, dim: usize, keepdim: bool) -> anyhow::Result<Tensor> {
+        let prg = format!(
+            r#"
+import torch
+import numpy as np
+def reduce(a):
+    out = torch.{}(torch.from_numpy(a), dim={}, keepdim={})
+    out = out.values if hasattr(out, "values") else out
+    return np.ascontiguousarray(out.float().numpy())
+"#,
+            op,
+            dim,
+            if keepdim { "True" } else { "False" }
+        );
+        run_py_prg(prg.to_string(), &[a], &[])
+    }
+
+    fn run_reduce_trial(problem: ReduceProblem) -> anyhow::Result<()> {
+        let device = GPU_DEVICE.with(|d| d.clone());
+        let ReduceProblem {
+            B,
+            M,
+            N,
+            dim,
+            keepdim,
+            op,
+        } = problem;
+        let a = Tensor::randn::<f32>(shape![B, M, N], Device::CPU);
+        let ground = ground_truth(&a, op.kernel_name(), dim, keepdim)?;
+
+        let a_gpu = a.to(&device)?;
+        let b = match op {
+            ReduceOp::Sum => a_gpu.sum(&[dim], keepdim)?,
+            ReduceOp::Mean => a_gpu.mean(&[dim], keepdim)?,
+            ReduceOp::Max => a_gpu.max(&[dim], keepdim)?,
+            ReduceOp::Min => a_gpu.min(&[dim], keepdim)?,
+            ReduceOp::ArgMax => a_gpu.argmax(dim, keepdim)?,
+            ReduceOp::ArgMin => a_gpu.argmin(dim, keepdim)?,
+        }
+        .resolve()?;
+
+        let ours = b.to(&Device::CPU)?;
+        assert_eq!(ours.shape(), ground.shape());
+        if op.is_arg() {
+            let ours = ours
+                .to_vec::<i32>()?
+                .iter()
+                .map(|&x| x as f32)
+                .collect::<Vec<_>>();
+            assert_eq!(ours, ground.to_vec::<f32>()?);
+        } else {
+            ground.all_close(&ours, 1e-4, 1e-4)?;
+        }
+        Ok(())
+    }
+
+    #[derive(Arbitrary, Debug)]
+    struct ReduceProblem {
+        #[strategy(1..=3usize)]
+        B: usize,
+        #[strategy(1..=64usize)]
+        M: usize,
+        #[strategy(1..=512usize)]
+        N: usize,
+        #[strategy(0..=2usize)]
+        dim: usize,
+        keepdim: bool,
+        #[strategy(proptest::sample::select(vec![
+            ReduceOp::Sum,
+            ReduceOp::Mean,
+            ReduceOp::Max,
+            ReduceOp::Min,
+            ReduceOp::ArgMax,
+            ReduceOp::ArgMin,
+        ]))]
+        op: ReduceOp,
+    }
+
+    #[proptest(cases = 16)]
+    fn test_reduce(prob: ReduceProblem) {
+        run_reduce_trial(prob).unwrap();
+    }
+
+    #[test]
+    fn test_arg_reduce_degenerate_rows() -> anyhow::Result<()> {
+        let device = GPU_DEVICE.with(|d| d.clone());
+        //Rows that never compare greater or less than the initial value
+        let rows = [
+            f32::NAN,
+            f32::MAX,
+            f32::MIN,
+            f32::INFINITY,
+            f32::NEG_INFINITY,
+        ];
+        let data = rows
+            .iter()
+            .flat_map(|&x| std::iter::repeat(x).take(200))
+            .collect::<Vec<_>>();
+        let a = Tensor::from_data(data, shape![rows.len(), 200], Device::CPU);
+
+        for op in [ReduceOp::ArgMax, ReduceOp::ArgMin] {
+            let run = |a: Tensor| match op {
+                ReduceOp::ArgMax => a.argmax(1, false),
+                _ => a.argmin(1, false),
+            };
+            let ground = run(a.clone())?.resolve()?.to_vec::<i32>()?;
+            let ours = run(a.to(&device)?)?
+                .resolve()?
+                .to(&Device::CPU)?
+                .to_vec::<i32>()?;
+            assert_eq!(ground, vec![0; rows.len()]);
+            assert_eq!(ours, ground, "{:?}", op);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_reduce_multi_axis() -> anyhow::Result<()> {
+        let device = GPU_DEVICE.with(|d| d.clone());
+        let a = Tensor::randn::<f32>(shape![2, 3, 4, 5], Device::CPU);
+        let ground = a.clone().sum(&[1, 3], false)?.resolve()?;
+
+        let ours = a
+            .to(&device)?
+            .sum(&[1, 3], false)?
+            .resolve()?
+            .to(&Device::CPU)?;
+        assert_eq!(ours.shape(), &shape![2, 4]);
+        ground.all_close(&ours, 1e-4, 1e-4)?;
+        Ok(())
+    }
+
+    #[test]
+    #[ignore = "needs shader-f16, which only browsers grant, see tests/f16.rs"]
+    fn test_reduce_f16() -> anyhow::Result<()> {
+        let device = GPU_DEVICE.with(|d| d.clone());
+        let inputs = [Tensor::randn::<f32>(shape![3, 19, 131], Device::CPU)];
+        check_f16(
+            &device,
+            &inputs,
+            |t| t[0].clone().sum(&[2], false),
+            1e-1,
+            1e-2,
+        )?;
+        check_f16(
+            &device,
+            &inputs,
+            |t| t[0].clone().mean(&[1], true),
+            1e-2,
+            1e-2,
+        )?;
+        check_f16(
+            &device,
+            &inputs,
+            |t| t[0].clone().max(&[0, 2], false),
+            1e-3,
+            1e-2,
+        )?;
+        check_f16(
+            &device,
+            &inputs,
+            |t| t[0].clone().min(&[2], true),
+            1e-3,
+            1e-2,
+        )?;
+
+        //Arg reductions compare the same halves on both devices, so must agree exactly
+        let halves = inputs[0].clone().cast(DType::F16)?.resolve()?;
+        let ground = halves
+            .clone()
+            .argmax(2, false)?
+            .resolve()?
+            .to_vec::<i32>()?;
+        let ours = halves
+            .to(&device)?
+            .argmax(2, false)?
+            .resolve()?
+            .to(&Device::CPU)?
+            .to_vec::<i32>()?;
+        assert_eq!(ours, ground);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod cpu_tests {
+    use crate::{shape, Device, Tensor};
+
+    #[test]
+    fn test_cpu_reduce() -> anyhow::Result<()> {
+        let x = Tensor::from_data(vec![1., 5., 3., 4., 2., 6.], shape![2, 3], Device::CPU);
+        let sum = x.clone().sum(&[1], false)?.resolve()?;
+        assert_eq!(sum.shape(), &shape![2]);
+        assert_eq!(sum.to_vec::<f32>()?, vec![9., 12.]);
+
+        let mean = x.clone().mean(&[0], true)?.resolve()?;
+        assert_eq!(mean.shape(), &shape![1, 3]);
+        assert_eq!(mean.to_vec::<f32>()?, vec![2.5, 3.5, 4.5]);
+
+        let max = x.clone().max(&[0, 1], false)?.resolve()?;
+        assert_eq!(max.to_vec::<f32>()?, vec![6.]);
+
+        let argmax = x.clone().argmax(1, false)?.resolve()?;
+        assert_eq!(argmax.to_vec::<i32>()?, vec![1, 2]);
+        let argmin = x.argmin(0, true)?.resolve()?;
+        assert_eq!(argmin.shape(), &shape![1, 3]);
+        assert_eq!(argmin.to_vec::<i32>()?, vec![0, 1, 0]);
+        Ok(())
+    }
+}
@@ -1,11 +1,13 @@
 use derive_new::new;
 
-use crate::{OpGuards, Operation, OperationError, Shape, StorageView, Strides, Tensor};
+use crate::{OpGuards, Operation, OperationError, Shape, StorageView, Strides, Tensor, UnaryOp};
 
 #[derive(new, Debug, Clone)]
 pub struct Broadcast {
     pub src: Tensor,
     to: Shape,
+    #[new(default)]
+    pub(crate) epilogue: Option<UnaryOp>,
 }
 
 impl Broadcast {
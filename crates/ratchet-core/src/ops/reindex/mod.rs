@@ -10,9 +10,13 @@ use derive_new::new;
 use encase::ShaderType;
 
 use crate::{
+    cpu::{
+        broadcast_offset, bytes_to_f32, contiguous_strides, cpu_bytes, cpu_from_f32,
+        elementwise_size, gather_bytes, ravel, unravel,
+    },
     gpu::{BindGroupLayoutDescriptor, CpuUniform, WorkgroupCount},
-    rvec, wgc, KernelElement, MetaOperation, OpMetadata, OperationError, RVec, Shape, Strides,
-    Tensor,
+    rvec, wgc, CPUBuffer, CPUOperation, KernelElement, MetaOperation, OpMetadata, OperationError,
+    RVec, Shape, Strides, Tensor, UnaryOp,
 };
 use glam::UVec4;
 
@@ -23,6 +27,44 @@ pub enum Reindex {
     Broadcast(Broadcast),
 }
 
+impl Reindex {
+    pub fn src(&self) -> &Tensor {
+        match self {
+            Reindex::Permute(p) => &p.src,
+            Reindex::Slice(s) => &s.src,
+            Reindex::Broadcast(b) => &b.src,
+        }
+    }
+
+    /// Elementwise op applied to every element as it is moved, set by the fusion pass.
+    pub fn epilogue(&self) -> Option<&UnaryOp> {
+        match self {
+            Reindex::Permute(p) => p.epilogue.as_ref(),
+            Reindex::Slice(s) => s.epilogue.as_ref(),
+            Reindex::Broadcast(b) => b.epilogue.as_ref(),
+        }
+    }
+
+    pub(crate) fn with_epilogue(mut self, op: UnaryOp) -> Self {
+        let epilogue = match &mut self {
+            Reindex::Permute(p) => &mut p.epilogue,
+            Reindex::Slice(s) => &mut s.epilogue,
+            Reindex::Broadcast(b) => &mut b.epilogue,
+        };
+        *epilogue = Some(op);
+        self
+    }
+
+    pub(crate) fn with_src(mut self, src: Tensor) -> Self {
+        match &mut self {
+            Reindex::Permute(p) => p.src = src,
+            Reindex::Slice(s) => s.src = src,
+            Reindex::Broadcast(b) => b.src = src,
+        }
+        self
+    }
+}
+
 #[derive(Debug, ShaderType)]
 pub struct ReindexMeta {
     src_shape: glam::UVec4,
@@ -34,6 +76,7 @@ pub struct ReindexMeta {
     //"Optional" fields below (if not present, they are set to 0) this is dumb
     permute: glam::UVec4,
     src_offsets: glam::UVec4,
+    epilogue: u32,
 }
 
 impl OpMetadata for ReindexMeta {}
@@ -86,7 +129,7 @@ impl MetaOperation for Reindex {
             Reindex::Broadcast(_) => "broadcast",
         };
 
-        format!("{}_{}", op_key, ke.as_str())
+        format!("{}_{}{}", op_key, ke.as_str(), dst.dt().kernel_suffix())
     }
 
     fn write_metadata(
@@ -146,7 +189,105 @@ impl MetaOperation for Reindex {
             dst_numel,
             permute,
             src_offsets,
+            epilogue: self.epilogue().and_then(UnaryOp::epilogue_id).unwrap_or(0),
         };
         Ok(uniform.write(&meta)?)
     }
 }
+
+impl CPUOperation for Reindex {
+    fn apply_cpu(&self, dst: &Tensor) -> Result<CPUBuffer, OperationError> {
+        let src = self.srcs()[0];
+        let elem_size = elementwise_size(src.dt())?;
+        let src_bytes = cpu_bytes(src)?;
+
+        let (src_shape, dst_shape) = (src.shape(), dst.shape());
+        let src_strides = contiguous_strides(src_shape);
+        let dst_strides = contiguous_strides(dst_shape);
+
+        let src_offset = |dst_offset: usize| match self {
+            Reindex::Permute(p) => {
+                let dst_index = unravel(dst_offset, &dst_strides);
+                let mut src_index: RVec<usize> = rvec![0; dst_index.len()];
+                for (i, &d) in p.dims.iter().enumerate() {
+                    src_index[d] = dst_index[i];
+                }
+                ravel(&src_index, &src_strides)
+            }
+            Reindex::Slice(s) => {
+                let src_index = unravel(dst_offset, &dst_strides)
+                    .iter()
+                    .zip(s.indices())
+                    .map(|(i, range)| i + range.start)
+                    .collect::<RVec<_>>();
+                ravel(&src_index, &src_strides)
+            }
+            Reindex::Broadcast(_) => broadcast_offset(src_shape, dst_shape, dst_offset),
+        };
+
+        let bytes = gather_bytes(&src_bytes, elem_size, dst_shape.numel(), src_offset);
+        match self.epilogue() {
+            Some(op) => {
+                let result = bytes_to_f32(&bytes, dst.dt(), dst_shape.numel())?
+                    .into_iter()
+                    .map(|x| op.apply(x))
+                    .collect::<Vec<_>>();
+                cpu_from_f32(&result, dst.dt(), dst_shape)
+            }
+            None => Ok(CPUBuffer::from_bytes(&bytes, elem_size)),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "pyo3"))]
+mod tests {
+    use crate::test_util::check_f16;
+    use crate::{shape, Device, DeviceRequest, Tensor};
+
+    #[test]
+    #[ignore = "needs shader-f16, which only browsers grant, see tests/f16.rs"]
+    fn test_reindex_f16() -> anyhow::Result<()> {
+        let device = Device::request_device(DeviceRequest::GPU)?;
+        //Only rounding to f16 separates the two
+        let (atol, rtol) = (1e-3, 1e-3);
+        let inputs = [Tensor::randn::<f32>(shape![2, 3, 17, 32], Device::CPU)];
+        let permute = |t: &[Tensor]| t[0].clone().permute(&[0, 2, 3, 1]);
+        check_f16(&device, &inputs, permute, atol, rtol)?;
+        let slice = |t: &[Tensor]| t[0].clone().slice(&[0..1, 1..3, 2..15, 0..32]);
+        check_f16(&device, &inputs, slice, atol, rtol)?;
+
+        let inputs = [Tensor::randn::<f32>(shape![1, 3, 1, 32], Device::CPU)];
+        let broadcast = |t: &[Tensor]| t[0].clone().broadcast_to(shape![4, 3, 5, 32]);
+        check_f16(&device, &inputs, broadcast, atol, rtol)
+    }
+}
+
+#[cfg(test)]
+mod cpu_tests {
+    use crate::{rvec, shape, Device, Tensor};
+
+    #[test]
+    fn test_cpu_reindex() -> anyhow::Result<()> {
+        let x = Tensor::from_data(
+            (0..6).map(|v| v as f32).collect::<Vec<_>>(),
+            shape![2, 3],
+            Device::CPU,
+        );
+        let p = x.clone().permute(&[1, 0])?.resolve()?;
+        assert_eq!(p.to_vec::<f32>()?, vec![0., 3., 1., 4., 2., 5.]);
+
+        let s = x.clone().slice(&[0..2, 1..3])?.resolve()?;
+        assert_eq!(s.to_vec::<f32>()?, vec![1., 2., 4., 5.]);
+
+        let c = Tensor::cat(rvec![x.clone(), x.clone()], 1)?.resolve()?;
+        assert_eq!(
+            c.to_vec::<f32>()?,
+            vec![0., 1., 2., 0., 1., 2., 3., 4., 5., 3., 4., 5.]
+        );
+
+        let indices = Tensor::from_data(vec![1i32, 0], shape![2], Device::CPU);
+        let sel = x.index_select(indices, 0)?.resolve()?;
+        assert_eq!(sel.to_vec::<f32>()?, vec![3., 4., 5., 0., 1., 2.]);
+        Ok(())
+    }
+}
@@ -4,12 +4,15 @@ use derive_new::new;
 
 use crate::{
     DType, InvariantError, OpGuards, Operation, OperationError, StorageView, Strides, Tensor,
+    UnaryOp,
 };
 
 #[derive(new, Debug, Clone)]
 pub struct Permute {
     pub src: Tensor,
     pub dims: Vec<usize>,
+    #[new(default)]
+    pub(crate) epilogue: Option<UnaryOp>,
 }
 
 impl Permute {
@@ -49,7 +52,7 @@ impl OpGuards for Permute {
     }
 
     fn check_dtypes(&self) {
-        assert!(self.src.dt() == DType::F32);
+        assert!(matches!(self.src.dt(), DType::F32 | DType::F16));
     }
 }
 
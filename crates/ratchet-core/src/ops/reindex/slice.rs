@@ -1,5 +1,5 @@
 use crate::{prelude::*, OpGuards, OperationError, StorageView, Strides};
-use crate::{Operation, RVec};
+use crate::{Operation, RVec, UnaryOp};
 use std::ops::Range;
 
 /// # Slice
@@ -9,6 +9,8 @@ use std::ops::Range;
 pub struct Slice {
     pub src: Tensor,
     indices: RVec<Range<usize>>,
+    #[new(default)]
+    pub(crate) epilogue: Option<UnaryOp>,
 }
 
 impl Slice {
@@ -2,9 +2,10 @@ use derive_new::new;
 use encase::ShaderType;
 
 use crate::{
+    cpu::cpu_f32,
     gpu::{BindGroupLayoutDescriptor, CpuUniform, WorkgroupCount},
-    rvec, wgc, KernelElement, MetaOperation, OpGuards, OpMetadata, Operation, OperationError, RVec,
-    StorageView, Strides, Tensor,
+    rvec, wgc, CPUBuffer, CPUOperation, KernelElement, MetaOperation, OpGuards, OpMetadata,
+    Operation, OperationError, RVec, StorageView, Strides, Tensor,
 };
 
 #[derive(new, Debug, Clone)]
@@ -124,6 +125,30 @@ impl MetaOperation for RoPE {
     }
 }
 
+impl CPUOperation for RoPE {
+    fn apply_cpu(&self, dst: &Tensor) -> Result<CPUBuffer, OperationError> {
+        let mut result = cpu_f32(&self.input)?;
+        let [_, _, SL, HD]: [usize; 4] = self.input.shape().try_into()?;
+        let half = self.dim / 2;
+
+        //Pairs x[i] with x[i + dim / 2], rotating by theta = pos * base^(-i / (dim / 2))
+        for head in result.chunks_exact_mut(SL * HD) {
+            for (pos, row) in head.chunks_exact_mut(HD).enumerate() {
+                let L = (pos + self.offset) as f32;
+                for i in 0..half {
+                    let d = i as f32 / half as f32;
+                    let theta = L * f32::exp2(-d * self.base);
+                    let (sintheta, costheta) = theta.sin_cos();
+                    let (x1, x2) = (row[i], row[i + half]);
+                    row[i] = x1 * costheta - x2 * sintheta;
+                    row[i + half] = x1 * sintheta + x2 * costheta;
+                }
+            }
+        }
+        Ok(CPUBuffer::from_slice(&result, dst.shape()))
+    }
+}
+
 #[cfg(all(test, feature = "pyo3"))]
 mod tests {
     use test_strategy::{proptest, Arbitrary};
@@ -0,0 +1,387 @@
+use derive_new::new;
+use encase::ShaderType;
+
+use crate::{
+    cpu::{cpu_f32, cpu_from_f32},
+    gpu::{BindGroupLayoutDescriptor, CpuUniform, WorkgroupCount},
+    rvec, shape, wgc, CPUBuffer, CPUOperation, DType, KernelElement, MetaOperation, OpGuards,
+    OpMetadata, Operation, OperationError, RVec, StorageView, Strides, Tensor,
+};
+
+/// # Scaled Dot Product Attention
+///
+/// `softmax(Q @ K^T * scale + mask) @ V`, for Q [B, H, Sq, D], K [B, H, Sk, D] & V [B, H, Sk, Dv].
+///
+/// The optional additive mask is of shape [Sq, Sk]. When `causal`, the queries are the last Sq
+/// positions of the sequence, and each may only attend to keys at or before its own position.
+///
+/// If `kv_len` is set, only the first `kv_len` keys & values are valid, e.g when K & V span an
+/// entire dynamic KV cache. It is read at dispatch time, so it may change between replays.
+#[derive(new, Debug, Clone)]
+pub struct SDPA {
+    q: Tensor,
+    k: Tensor,
+    v: Tensor,
+    mask: Option<Tensor>,
+    scale: f32,
+    causal: bool,
+    #[new(default)]
+    kv_len: Option<usize>,
+}
+
+impl SDPA {
+    /// Largest head dimension supported by the fused kernel.
+    pub const MAX_HEAD_DIM: usize = 256;
+
+    pub(crate) fn with_kv_len(self, kv_len: usize) -> Self {
+        Self {
+            kv_len: Some(kv_len),
+            ..self
+        }
+    }
+
+    fn kv_len(&self) -> usize {
+        self.kv_len.unwrap_or(self.k.shape()[2])
+    }
+
+    fn supports_shapes(
+        q: &Tensor,
+        k: &Tensor,
+        v: &Tensor,
+        mask: Option<&Tensor>,
+        causal: bool,
+    ) -> bool {
+        let (qs, ks, vs) = (q.shape(), k.shape(), v.shape());
+        if qs.rank() != 4 || ks.rank() != 4 || vs.rank() != 4 {
+            return false;
+        }
+        let (Sq, Sk) = (qs[2], ks[2]);
+        qs[..2] == ks[..2]
+            && ks[..3] == vs[..3]
+            && qs[3] == ks[3]
+            && qs[3] <= Self::MAX_HEAD_DIM
+            && vs[3] <= Self::MAX_HEAD_DIM
+            && mask.iter().all(|m| m.shape() == &shape![Sq, Sk])
+            && (!causal || Sk >= Sq)
+    }
+
+    fn supports_dtypes(q: &Tensor, k: &Tensor, v: &Tensor, mask: Option<&Tensor>) -> bool {
+        let dt = q.dt();
+        matches!(dt, DType::F32 | DType::F16)
+            && k.dt() == dt
+            && v.dt() == dt
+            && mask.iter().all(|m| m.dt() == dt)
+    }
+
+    /// Whether the inputs can be handled by the fused op, rather than the decomposition.
+    pub fn is_fusable(
+        q: &Tensor,
+        k: &Tensor,
+        v: &Tensor,
+        mask: Option<&Tensor>,
+        causal: bool,
+    ) -> bool {
+        Self::supports_shapes(q, k, v, mask, causal) && Self::supports_dtypes(q, k, v, mask)
+    }
+}
+
+#[derive(Debug, ShaderType)]
+pub struct SDPAMeta {
+    Sq: u32,
+    Sk: u32,
+    D: u32,
+    Dv: u32,
+    scale: f32,
+    causal: u32,
+    kv_len: u32,
+}
+
+impl OpMetadata for SDPAMeta {}
+
+impl OpGuards for SDPA {
+    fn check_shapes(&self) {
+        let (q, k, v) = (&self.q, &self.k, &self.v);
+        assert!(Self::supports_shapes(
+            q,
+            k,
+            v,
+            self.mask.as_ref(),
+            self.causal
+        ));
+        assert!(self.kv_len() <= k.shape()[2]);
+        assert!(!self.causal || self.kv_len() >= q.shape()[2]);
+    }
+
+    fn check_dtypes(&self) {
+        let (q, k, v) = (&self.q, &self.k, &self.v);
+        assert!(Self::supports_dtypes(q, k, v, self.mask.as_ref()));
+    }
+}
+
+impl Operation for SDPA {
+    fn compute_view(&self) -> Result<StorageView, OperationError> {
+        let (qs, vs) = (self.q.shape(), self.v.shape());
+        let out_shape = shape![qs[0], qs[1], qs[2], vs[3]];
+        let strides = Strides::from(&out_shape);
+        Ok(StorageView::new(out_shape, self.q.dt(), strides))
+    }
+}
+
+impl MetaOperation for SDPA {
+    fn kernel_name(&self) -> String {
+        "sdpa".to_string()
+    }
+
+    fn kernel_key(&self, _: bool, dst: &Tensor) -> String {
+        format!("sdpa_{}{}", self.mask.is_some(), dst.dt().kernel_suffix())
+    }
+
+    fn srcs(&self) -> RVec<&Tensor> {
+        match &self.mask {
+            Some(mask) => rvec![&self.q, &self.k, &self.v, mask],
+            None => rvec![&self.q, &self.k, &self.v],
+        }
+    }
+
+    fn kernel_element(&self, _: &Tensor) -> KernelElement {
+        KernelElement::Scalar
+    }
+
+    fn calculate_dispatch(&self, _: &Tensor) -> Result<WorkgroupCount, OperationError> {
+        let [B, H, Sq, _]: [usize; 4] = self.q.shape().try_into()?;
+        Ok(wgc![Sq as _, (B * H) as _, 1])
+    }
+
+    fn storage_bind_group_layout(
+        &self,
+        _: bool,
+    ) -> Result<BindGroupLayoutDescriptor, OperationError> {
+        if self.mask.is_some() {
+            Ok(BindGroupLayoutDescriptor::nthary(4))
+        } else {
+            Ok(BindGroupLayoutDescriptor::ternary())
+        }
+    }
+
+    fn write_metadata(
+        &self,
+        uniform: &mut CpuUniform,
+        _: &Tensor,
+        _: &KernelElement,
+    ) -> Result<u64, OperationError> {
+        let (qs, ks, vs) = (self.q.shape(), self.k.shape(), self.v.shape());
+        let meta = SDPAMeta {
+            Sq: qs[2] as _,
+            Sk: ks[2] as _,
+            D: qs[3] as _,
+            Dv: vs[3] as _,
+            scale: self.scale,
+            causal: self.causal as _,
+            kv_len: self.kv_len() as _,
+        };
+        Ok(uniform.write(&meta)?)
+    }
+}
+
+impl CPUOperation for SDPA {
+    fn apply_cpu(&self, dst: &Tensor) -> Result<CPUBuffer, OperationError> {
+        let (q, k, v) = (cpu_f32(&self.q)?, cpu_f32(&self.k)?, cpu_f32(&self.v)?);
+        let mask = self.mask.as_ref().map(cpu_f32).transpose()?;
+        let [B, H, Sq, D]: [usize; 4] = self.q.shape().try_into()?;
+        let (Sk, Dv) = (self.k.shape()[2], self.v.shape()[3]);
+        let valid = self.kv_len();
+
+        let mut result = vec![0f32; B * H * Sq * Dv];
+        let mut scores = vec![0f32; Sk];
+        for head in 0..B * H {
+            let (k, v) = (&k[head * Sk * D..], &v[head * Sk * Dv..]);
+            for row in 0..Sq {
+                let q_row = &q[(head * Sq + row) * D..][..D];
+                let kv_len = if self.causal {
+                    row + 1 + valid - Sq
+                } else {
+                    valid
+                };
+                for (j, s) in scores[..kv_len].iter_mut().enumerate() {
+                    let dot = q_row.iter().zip(&k[j * D..][..D]).map(|(a, b)| a * b);
+                    *s = dot.sum::<f32>() * self.scale
+                        + mask.as_ref().map_or(0., |m| m[row * Sk + j]);
+                }
+                let max = scores[..kv_len]
+                    .iter()
+                    .fold(f32::NEG_INFINITY, |a, &b| a.max(b));
+                let mut sum = 0f32;
+                for s in scores[..kv_len].iter_mut() {
+                    *s = (*s - max).exp();
+                    sum += *s;
+                }
+                let out = &mut result[(head * Sq + row) * Dv..][..Dv];
+                for (j, s) in scores[..kv_len].iter().enumerate() {
+                    for (o, x) in out.iter_mut().zip(&v[j * Dv..][..Dv]) {
+                        *o += s / sum * x;
+                    }
+                }
+            }
+        }
+        cpu_from_f32(&result, dst.dt(), dst.shape())
+    }
+}
+
+#[cfg(all(test, feature = "pyo3"))]
+mod tests {
+    use test_strategy::{proptest, Arbitrary};
+
+    use crate::test_util::{check_f16, run_py_prg};
+    use crate::{shape, Device, DeviceRequest, Tensor};
+
+    thread_local! {
+        static GPU_DEVICE: Device = Device::request_device(DeviceRequest::GPU).unwrap();
+    }
+
+    fn ground_truth(
+        q: &Tensor,
+        k: &Tensor,
+        v: &Tensor,
+        mask: &Tensor,
+        scale: f32,
+        causal: bool,
+    ) -> anyhow::Result<Tensor> {
+        let prg = format!(
+            r#"
+import torch
+
+def sdpa(q, k, v, mask):
+    (q, k, v, mask) = (torch.from_numpy(q), torch.from_numpy(k), torch.from_numpy(v), torch.from_numpy(mask))
+    (Sq, Sk) = (q.shape[2], k.shape[2])
+    scores = q @ k.transpose(-1, -2) * {} + mask
+    if {}:
+        allowed = torch.ones(Sq, Sk, dtype=torch.bool).tril(Sk - Sq)
+        scores = scores.masked_fill(~allowed, float("-inf"))
+    return (torch.softmax(scores, dim=-1) @ v).numpy()
+"#,
+            scale,
+            if causal { "True" } else { "False" }
+        );
+        run_py_prg(prg, &[q, k, v, mask], &[])
+    }
+
+    #[derive(Arbitrary, Debug)]
+    struct SDPAProblem {
+        #[strategy(1..=2usize)]
+        B: usize,
+        #[strategy(1..=4usize)]
+        H: usize,
+        #[strategy(1..=96usize)]
+        Sq: usize,
+        #[strategy(0..=96usize)]
+        past: usize,
+        #[strategy(1..=128usize)]
+        D: usize,
+        #[strategy(1..=128usize)]
+        Dv: usize,
+        has_mask: bool,
+        causal: bool,
+    }
+
+    fn run_sdpa_trial(prob: SDPAProblem) -> anyhow::Result<()> {
+        let device = GPU_DEVICE.with(|d| d.clone());
+        let SDPAProblem {
+            B,
+            H,
+            Sq,
+            past,
+            D,
+            Dv,
+            has_mask,
+            causal,
+        } = prob;
+        let Sk = Sq + past;
+        let q = Tensor::randn::<f32>(shape![B, H, Sq, D], Device::CPU);
+        let k = Tensor::randn::<f32>(shape![B, H, Sk, D], Device::CPU);
+        let v = Tensor::randn::<f32>(shape![B, H, Sk, Dv], Device::CPU);
+        let mask = if has_mask {
+            Tensor::randn::<f32>(shape![Sq, Sk], Device::CPU)
+        } else {
+            Tensor::zeros::<f32>(&shape![Sq, Sk], &Device::CPU)
+        };
+        let scale = 1. / (D as f32).sqrt();
+        let ground = ground_truth(&q, &k, &v, &mask, scale, causal)?;
+
+        let mask = has_mask.then(|| mask.to(&device)).transpose()?;
+        let ours = q
+            .to(&device)?
+            .sdpa(k.to(&device)?, v.to(&device)?, mask, scale, causal)?
+            .resolve()?;
+        let ours = ours.to(&Device::CPU)?;
+        ground.all_close(&ours, 1e-4, 1e-4)?;
+        Ok(())
+    }
+
+    #[proptest(cases = 16)]
+    fn test_sdpa(prob: SDPAProblem) {
+        run_sdpa_trial(prob).unwrap();
+    }
+
+    fn run_sdpa_f16_trial(prob: SDPAProblem) -> anyhow::Result<()> {
+        let device = GPU_DEVICE.with(|d| d.clone());
+        let SDPAProblem {
+            B,
+            H,
+            Sq,
+            past,
+            D,
+            Dv,
+            has_mask,
+            causal,
+        } = prob;
+        let Sk = Sq + past;
+        let mut inputs = vec![
+            Tensor::randn::<f32>(shape![B, H, Sq, D], Device::CPU),
+            Tensor::randn::<f32>(shape![B, H, Sk, D], Device::CPU),
+            Tensor::randn::<f32>(shape![B, H, Sk, Dv], Device::CPU),
+        ];
+        if has_mask {
+            inputs.push(Tensor::randn::<f32>(shape![Sq, Sk], Device::CPU));
+        }
+        let scale = 1. / (D as f32).sqrt();
+        let sdpa = |t: &[Tensor]| {
+            let mask = t.get(3).cloned();
+            t[0].clone()
+                .sdpa(t[1].clone(), t[2].clone(), mask, scale, causal)
+        };
+        check_f16(&device, &inputs, sdpa, 2e-2, 1e-2)
+    }
+
+    #[proptest(cases = 8)]
+    #[ignore = "needs shader-f16, which only browsers grant, see tests/f16.rs"]
+    fn test_sdpa_f16(prob: SDPAProblem) {
+        run_sdpa_f16_trial(prob).unwrap();
+    }
+}
+
+#[cfg(test)]
+mod cpu_tests {
+    use crate::{shape, Device, Tensor};
+
+    #[test]
+    fn test_cpu_sdpa() -> anyhow::Result<()> {
+        //Fused & decomposed paths must agree, including the bottom right causal alignment
+        let q = Tensor::randn::<f32>(shape![1, 2, 3, 8], Device::CPU);
+        let k = Tensor::randn::<f32>(shape![1, 2, 5, 8], Device::CPU);
+        let v = Tensor::randn::<f32>(shape![1, 2, 5, 4], Device::CPU);
+        let mask = Tensor::randn::<f32>(shape![3, 5], Device::CPU);
+        for (mask, causal) in [(None, true), (Some(mask), false), (None, false)] {
+            let fused = q
+                .clone()
+                .sdpa(k.clone(), v.clone(), mask.clone(), 0.35, causal)?
+                .resolve()?;
+            let decomposed = q
+                .clone()
+                .sdpa_decomposed(k.clone(), v.clone(), mask, 0.35, causal)?
+                .resolve()?;
+            assert_eq!(fused.shape(), &shape![1, 2, 3, 4]);
+            fused.all_close(&decomposed, 1e-5, 1e-5)?;
+        }
+        Ok(())
+    }
+}
@@ -2,9 +2,10 @@ use derive_new::new;
 use encase::ShaderType;
 
 use crate::{
+    cpu::{cpu_f32, cpu_i32},
     gpu::{BindGroupLayoutDescriptor, CpuUniform, WorkgroupCount},
-    rvec, wgc, DType, KernelElement, MetaOperation, OpGuards, OpMetadata, Operation,
-    OperationError, RVec, StorageView, Strides, Tensor,
+    rvec, wgc, CPUBuffer, CPUOperation, DType, KernelElement, MetaOperation, OpGuards, OpMetadata,
+    Operation, OperationError, RVec, StorageView, Strides, Tensor,
 };
 
 #[derive(new, Debug, Clone)]
@@ -20,6 +21,7 @@ pub struct IndexSelectMeta {
     right_numel: u32,
     ids_numel: u32,
     src_dim_numel: u32,
+    group_size: i32,
 }
 
 impl OpMetadata for IndexSelectMeta {}
@@ -32,19 +34,26 @@ impl Operation for IndexSelect {
         let mut output_shape = input_shape.clone();
         output_shape[self.dim] = indices_shape[0];
         let strides = Strides::from(&output_shape);
-        Ok(StorageView::new(output_shape, DType::F32, strides))
+        //Only the selected rows are dequantized
+        let dt = if input.dt().is_quantized() {
+            DType::F32
+        } else {
+            input.dt()
+        };
+        Ok(StorageView::new(output_shape, dt, strides))
     }
 }
 
 impl OpGuards for IndexSelect {
     fn check_shapes(&self) {
         let (input, indices) = (&self.input, &self.indices);
-        assert_eq!(input.rank(), 2);
+        assert!(self.dim < input.rank());
         assert_eq!(indices.rank(), 1);
     }
 
     fn check_dtypes(&self) {
-        let indices = &self.indices;
+        let (input, indices) = (&self.input, &self.indices);
+        assert!(input.dt() == DType::F32 || input.dt().is_quantized());
         assert_eq!(indices.dt(), DType::I32);
     }
 }
@@ -60,23 +69,28 @@ impl MetaOperation for IndexSelect {
 
     fn kernel_key(&self, _: bool, dst: &Tensor) -> String {
         let op_key = match self.input.dt() {
-            DType::F32 => "f32_index_select",
-            DType::GGUF(_) => "wq8_index_select",
+            DType::F32 => "f32_index_select".to_string(),
+            DType::GGUF(g) => format!("{}_index_select", g.kernel_prefix()),
+            DType::SInt4(_) => "sint4_index_select".to_string(),
             _ => unimplemented!(),
         };
         format!("{}_{}", op_key, self.kernel_element(dst).as_str())
     }
 
     fn kernel_element(&self, _dst: &Tensor) -> KernelElement {
-        KernelElement::Scalar
+        //Quantized rows are dequantized 4 elements at a time, when they can be
+        let right_numel = self.input.shape()[(self.dim + 1)..]
+            .iter()
+            .product::<usize>();
+        if self.input.dt().is_quantized() && right_numel % 4 == 0 {
+            KernelElement::Vec4
+        } else {
+            KernelElement::Scalar
+        }
     }
 
     fn calculate_dispatch(&self, dst: &Tensor) -> Result<WorkgroupCount, OperationError> {
-        let numel = match self.input.dt() {
-            DType::F32 => dst.shape().numel(),
-            DType::GGUF(_) => dst.shape().numel() / 4,
-            _ => unimplemented!(),
-        };
+        let numel = dst.shape().numel() / self.kernel_element(dst).as_size();
         let wgcx = WorkgroupCount::div_ceil(numel, 64);
         Ok(wgc![wgcx as _, 1, 1])
     }
@@ -87,7 +101,10 @@ impl MetaOperation for IndexSelect {
     ) -> Result<BindGroupLayoutDescriptor, OperationError> {
         match self.input.dt() {
             DType::F32 => Ok(BindGroupLayoutDescriptor::binary()),
-            DType::GGUF(_) => Ok(BindGroupLayoutDescriptor::ternary()),
+            DType::GGUF(_) | DType::SInt4(_) => {
+                let segments = self.input.dt().segments(self.input.shape().numel()).len();
+                Ok(BindGroupLayoutDescriptor::nthary(segments + 1))
+            }
             _ => unimplemented!(),
         }
     }
@@ -104,25 +121,51 @@ impl MetaOperation for IndexSelect {
             .product::<usize>() as u32;
         let ids_numel = self.indices.shape().numel() as u32;
         let src_dim_numel = self.input.shape()[self.dim] as u32;
+        let group_size = self.input.dt().block_numel() as i32;
 
         let meta = IndexSelectMeta {
             dst_numel,
             right_numel,
             ids_numel,
             src_dim_numel,
+            group_size,
         };
         Ok(uniform.write(&meta)?)
     }
 }
 
+impl CPUOperation for IndexSelect {
+    fn apply_cpu(&self, dst: &Tensor) -> Result<CPUBuffer, OperationError> {
+        let (input, indices) = (cpu_f32(&self.input)?, cpu_i32(&self.indices)?);
+        let input_shape = self.input.shape();
+        let src_dim_numel = input_shape[self.dim];
+        let right_numel = input_shape[(self.dim + 1)..].iter().product::<usize>();
+        let ids_numel = indices.len();
+
+        let result = (0..dst.shape().numel())
+            .map(|tid| {
+                let right = tid % right_numel;
+                let id = (tid / right_numel) % ids_numel;
+                let left = tid / (right_numel * ids_numel);
+                let index = (indices[id].max(0) as usize).min(src_dim_numel - 1);
+                input[left * src_dim_numel * right_numel + index * right_numel + right]
+            })
+            .collect::<Vec<_>>();
+        Ok(CPUBuffer::from_slice(&result, dst.shape()))
+    }
+}
+
 #[cfg(all(test, feature = "pyo3"))]
 mod tests {
     use proptest::arbitrary::Arbitrary;
     use proptest::strategy::{BoxedStrategy, Just, Strategy};
     use test_strategy::proptest;
 
-    use crate::test_util::run_py_prg;
-    use crate::{rvec, shape, Device, DeviceRequest, Quantization, Quantizer, Shape, Tensor};
+    use crate::gguf::{GGUFDType, Q4K, Q4_0, Q4_1, Q5_0, Q5_1, Q6K, Q8_0, Q8_1};
+    use crate::test_util::{randq, run_py_prg};
+    use crate::{
+        rvec, shape, DType, Device, DeviceRequest, Quantization, Quantizer, Shape, Tensor,
+    };
 
     thread_local! {
         static GPU_DEVICE: Device = Device::request_device(DeviceRequest::GPU).unwrap();
@@ -190,6 +233,60 @@ def index_select(input, indices):
         run_index_select_trial(prob, true);
     }
 
+    #[test]
+    fn test_index_select_nd() -> anyhow::Result<()> {
+        let device = GPU_DEVICE.with(|d| d.clone());
+        let input = Tensor::randn::<f32>(shape![3, 5, 7], Device::CPU);
+        for dim in 0..input.rank() {
+            let indices = Tensor::randint(0, input.shape()[dim] as i32, shape![4], Device::CPU);
+            let ground = ground_truth(&input, &indices, dim)?;
+
+            let ours = input
+                .to(&device)?
+                .index_select(indices.to(&device)?, dim)?
+                .resolve()?;
+            ground.all_close(&ours.to(&Device::CPU)?, 1e-5, 1e-5)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_quantized_index_select() -> anyhow::Result<()> {
+        let device = GPU_DEVICE.with(|d| d.clone());
+        let input_shape = shape![2, 6, 256];
+        let sint4 = Quantizer::new(Quantization::SInt4 { group_size: 32 })
+            .quantize(Tensor::randn::<f32>(input_shape.clone(), Device::CPU));
+        let inputs = [
+            GGUFDType::Q8_0(Q8_0),
+            GGUFDType::Q4_0(Q4_0),
+            GGUFDType::Q4_1(Q4_1),
+            GGUFDType::Q5_0(Q5_0),
+            GGUFDType::Q5_1(Q5_1),
+            GGUFDType::Q8_1(Q8_1),
+            GGUFDType::Q4K(Q4K),
+            GGUFDType::Q6K(Q6K),
+        ]
+        .map(|g| randq(DType::GGUF(g), input_shape.clone()));
+
+        for input in inputs.into_iter().chain([sint4]) {
+            //Selecting along the last dimension falls back to the scalar kernels
+            for dim in 0..input.rank() {
+                let indices = Tensor::randint(0, input.shape()[dim] as i32, shape![5], Device::CPU);
+                let ground = input
+                    .clone()
+                    .index_select(indices.clone(), dim)?
+                    .resolve()?;
+
+                let ours = input
+                    .to(&device)?
+                    .index_select(indices.to(&device)?, dim)?
+                    .resolve()?;
+                ground.all_close(&ours.to(&Device::CPU)?, 1e-5, 1e-5)?;
+            }
+        }
+        Ok(())
+    }
+
     #[derive(Debug, Clone)]
     struct IndexSelectProblem {
         input_shape: Shape,
@@ -2,15 +2,27 @@ use derive_new::new;
 use encase::ShaderType;
 
 use crate::{
+    cpu::{cpu_f32, cpu_from_f32},
     gpu::{BindGroupLayoutDescriptor, CpuUniform, WorkgroupCount},
-    rvec, wgc, KernelElement, MetaOperation, OpGuards, OpMetadata, Operation, OperationError, RVec,
-    StorageView, Tensor,
+    rvec, wgc, CPUBuffer, CPUOperation, KernelElement, MetaOperation, OpGuards, OpMetadata,
+    Operation, OperationError, RVec, StorageView, Tensor,
 };
 
 #[derive(new, Debug, Clone)]
 pub struct Softmax {
     input: Tensor,
     dim: usize,
+    #[new(default)]
+    prologue: Option<SoftmaxPrologue>,
+}
+
+/// Scale & additive mask applied to the input before the softmax, set by the fusion pass.
+#[derive(Debug, Clone)]
+pub struct SoftmaxPrologue {
+    /// A single element, multiplying every input.
+    scale: Tensor,
+    /// Either the shape of the input, or its last two dimensions shared by every batch.
+    mask: Tensor,
 }
 
 #[derive(Debug, derive_new::new, ShaderType)]
@@ -19,6 +31,61 @@ pub struct SoftmaxMeta {
     N: u32,
     ND2: u32,
     ND4: u32,
+    mask_stride: u32,
+}
+
+impl Softmax {
+    pub fn input(&self) -> &Tensor {
+        &self.input
+    }
+
+    pub fn dim(&self) -> usize {
+        self.dim
+    }
+
+    pub fn prologue(&self) -> Option<&SoftmaxPrologue> {
+        self.prologue.as_ref()
+    }
+
+    /// Computes `softmax(input * scale + mask)` instead, see [SoftmaxPrologue].
+    pub(crate) fn with_prologue(self, input: Tensor, scale: Tensor, mask: Tensor) -> Self {
+        Self {
+            input,
+            prologue: Some(SoftmaxPrologue { scale, mask }),
+            ..self
+        }
+    }
+}
+
+impl SoftmaxPrologue {
+    pub fn scale(&self) -> &Tensor {
+        &self.scale
+    }
+
+    pub fn mask(&self) -> &Tensor {
+        &self.mask
+    }
+
+    /// Whether `mask` can be read by the prologue of a softmax over the last dimension of
+    /// `input`.
+    pub fn supports_mask(input: &Tensor, mask: &Tensor) -> bool {
+        let (shape, mask_shape) = (input.shape(), mask.shape());
+        let rows = shape.slice(shape.rank() - 2..shape.rank());
+        let trailing = mask_shape.rank() >= 2
+            && mask_shape.slice(mask_shape.rank() - 2..mask_shape.rank()) == rows
+            && mask_shape.numel() == rows.numel();
+        mask_shape == shape || trailing
+    }
+
+    /// Elements between the masks of consecutive batches, 0 if they share a single mask.
+    fn mask_stride(&self, input: &Tensor) -> usize {
+        if self.mask.shape() == input.shape() {
+            let rank = input.rank();
+            input.shape().slice(rank - 2..rank).numel()
+        } else {
+            0
+        }
+    }
 }
 
 impl OpMetadata for SoftmaxMeta {}
@@ -28,11 +95,20 @@ impl OpGuards for Softmax {
         let input = &self.input;
         assert!(input.rank() >= 2);
         assert!(self.dim < input.rank());
+        if let Some(prologue) = &self.prologue {
+            assert_eq!(self.dim, input.rank() - 1);
+            assert_eq!(prologue.scale.shape().numel(), 1);
+            assert!(SoftmaxPrologue::supports_mask(input, &prologue.mask));
+        }
     }
 
     fn check_dtypes(&self) {
         let input = &self.input;
-        assert!(input.dt() == crate::DType::F32);
+        assert!(matches!(input.dt(), crate::DType::F32 | crate::DType::F16));
+        if let Some(prologue) = &self.prologue {
+            assert_eq!(prologue.scale.dt(), input.dt());
+            assert_eq!(prologue.mask.dt(), input.dt());
+        }
     }
 }
 
@@ -52,11 +128,23 @@ impl MetaOperation for Softmax {
     }
 
     fn srcs(&self) -> RVec<&Tensor> {
-        rvec![&self.input]
+        match &self.prologue {
+            Some(p) => rvec![&self.input, &p.scale, &p.mask],
+            None => rvec![&self.input],
+        }
     }
 
     fn kernel_key(&self, _: bool, dst: &Tensor) -> String {
-        format!("softmax_{}", self.kernel_element(dst).as_str())
+        let stem = match self.prologue {
+            Some(_) => "softmax_prologue",
+            None => "softmax",
+        };
+        format!(
+            "{}_{}{}",
+            stem,
+            self.kernel_element(dst).as_str(),
+            dst.dt().kernel_suffix()
+        )
     }
 
     fn kernel_element(&self, _dst: &Tensor) -> KernelElement {
@@ -85,30 +173,72 @@ impl MetaOperation for Softmax {
         if !inplace {
             panic!("Only inplace softmax is supported");
         }
-        Ok(BindGroupLayoutDescriptor::unary_inplace())
+        match self.prologue {
+            Some(_) => Ok(BindGroupLayoutDescriptor::ternary_inplace()),
+            None => Ok(BindGroupLayoutDescriptor::unary_inplace()),
+        }
     }
 
     fn write_metadata(
         &self,
         uniform: &mut CpuUniform,
         _: &Tensor,
-        _: &KernelElement,
+        kernel_element: &KernelElement,
     ) -> Result<u64, OperationError> {
         let input = &self.input;
         let M = input.shape()[self.dim - 1] as u32;
         let N = input.shape()[self.dim] as u32;
         let ND2 = N / 2;
         let ND4 = N / 4;
-        let meta = SoftmaxMeta { M, N, ND2, ND4 };
+        let mask_stride = self.prologue.as_ref().map_or(0, |p| p.mask_stride(input));
+        let mask_stride = (mask_stride / kernel_element.as_size()) as u32;
+        let meta = SoftmaxMeta {
+            M,
+            N,
+            ND2,
+            ND4,
+            mask_stride,
+        };
         Ok(uniform.write(&meta)?)
     }
 }
 
+impl CPUOperation for Softmax {
+    fn apply_cpu(&self, dst: &Tensor) -> Result<CPUBuffer, OperationError> {
+        let mut result = cpu_f32(&self.input)?;
+        if let Some(SoftmaxPrologue { scale, mask }) = &self.prologue {
+            let (scale, mask) = (cpu_f32(scale)?[0], cpu_f32(mask)?);
+            for (i, x) in result.iter_mut().enumerate() {
+                *x = *x * scale + mask[i % mask.len()];
+            }
+        }
+        let shape = self.input.shape();
+        let N = shape[self.dim];
+        let inner = shape[self.dim + 1..].iter().product::<usize>();
+
+        for block in result.chunks_exact_mut(N * inner) {
+            for r in 0..inner {
+                let idx = |n: usize| n * inner + r;
+                let max = (0..N).fold(f32::NEG_INFINITY, |acc, n| acc.max(block[idx(n)]));
+                let mut sum = 0f32;
+                for n in 0..N {
+                    block[idx(n)] = (block[idx(n)] - max).exp();
+                    sum += block[idx(n)];
+                }
+                for n in 0..N {
+                    block[idx(n)] /= sum;
+                }
+            }
+        }
+        cpu_from_f32(&result, dst.dt(), dst.shape())
+    }
+}
+
 #[cfg(all(test, feature = "pyo3"))]
 mod tests {
     use test_strategy::{proptest, Arbitrary};
 
-    use crate::test_util::run_py_prg;
+    use crate::test_util::{check_f16, run_py_prg};
     use crate::{shape, Device, DeviceRequest, Tensor};
 
     thread_local! {
@@ -156,4 +286,38 @@ def softmax(a):
         println!("B = {}, M = {}, N = {}", B, M, N);
         run_softmax_trial(prob);
     }
+
+    #[test]
+    #[ignore = "needs shader-f16, which only browsers grant, see tests/f16.rs"]
+    fn test_softmax_f16() -> anyhow::Result<()> {
+        let device = GPU_DEVICE.with(|d| d.clone());
+        //Vectorized and scalar rows
+        for n in [256, 131] {
+            let inputs = [Tensor::randn::<f32>(shape![3, 19, n], Device::CPU)];
+            check_f16(&device, &inputs, |t| t[0].clone().softmax(2), 1e-3, 1e-2)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod cpu_tests {
+    use crate::{shape, Device, Tensor};
+
+    #[test]
+    fn test_cpu_softmax() -> anyhow::Result<()> {
+        let x = Tensor::from_data(
+            vec![1., 2., 3., 4., 1., 1., 1., 1.],
+            shape![2, 4],
+            Device::CPU,
+        );
+        let s = x.softmax(1)?.resolve()?.to_vec::<f32>()?;
+        let row0 = [1f32, 2., 3., 4.].map(|v| v.exp());
+        let sum0: f32 = row0.iter().sum();
+        for (o, e) in s[..4].iter().zip(row0.iter()) {
+            assert!((o - e / sum0).abs() < 1e-6);
+        }
+        assert!(s[4..].iter().all(|&v| (v - 0.25).abs() < 1e-6));
+        Ok(())
+    }
 }
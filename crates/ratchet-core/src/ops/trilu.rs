@@ -0,0 +1,209 @@
+use derive_new::new;
+use encase::ShaderType;
+
+use crate::{
+    cpu::cpu_f32,
+    gpu::{BindGroupLayoutDescriptor, CpuUniform, WorkgroupCount},
+    rvec, wgc, CPUBuffer, CPUOperation, DType, KernelElement, MetaOperation, OpGuards, OpMetadata,
+    Operation, OperationError, RVec, StorageView, Tensor,
+};
+
+/// # Trilu
+///
+/// Retains the upper (triu) or lower (tril) triangle of the trailing 2 dimensions, zeroing
+/// everything else. Matches `torch.triu` & `torch.tril`.
+///
+/// `k` offsets the diagonal: positive values move it up & right, negative values down & left.
+#[derive(new, Debug, Clone)]
+pub struct Trilu {
+    src: Tensor,
+    upper: bool,
+    k: i32,
+}
+
+#[derive(Debug, ShaderType)]
+pub struct TriluMeta {
+    M: u32,
+    N: u32,
+    numel: u32,
+    k: i32,
+}
+
+impl OpMetadata for TriluMeta {}
+
+impl OpGuards for Trilu {
+    fn check_shapes(&self) {
+        assert!(self.src.rank() >= 2);
+    }
+
+    fn check_dtypes(&self) {
+        assert_eq!(self.src.dt(), DType::F32);
+    }
+}
+
+impl Operation for Trilu {
+    fn compute_view(&self) -> Result<StorageView, OperationError> {
+        Ok(self.src.storage_view().clone())
+    }
+}
+
+impl MetaOperation for Trilu {
+    fn kernel_name(&self) -> String {
+        if self.upper {
+            "triu".to_string()
+        } else {
+            "tril".to_string()
+        }
+    }
+
+    fn kernel_key(&self, _: bool, dst: &Tensor) -> String {
+        format!(
+            "{}_{}",
+            self.kernel_name(),
+            self.kernel_element(dst).as_str()
+        )
+    }
+
+    fn srcs(&self) -> RVec<&Tensor> {
+        rvec![&self.src]
+    }
+
+    fn kernel_element(&self, _: &Tensor) -> KernelElement {
+        KernelElement::Scalar
+    }
+
+    fn calculate_dispatch(&self, dst: &Tensor) -> Result<WorkgroupCount, OperationError> {
+        let numel = dst.shape().numel();
+        let x_groups = WorkgroupCount::div_ceil(numel as _, 64);
+        let (x_groups, y_groups) = if x_groups > WorkgroupCount::MAX_WGS_PER_DIM {
+            let y_groups = WorkgroupCount::div_ceil(x_groups, WorkgroupCount::MAX_WGS_PER_DIM);
+            (WorkgroupCount::MAX_WGS_PER_DIM, y_groups)
+        } else {
+            (x_groups, 1)
+        };
+        Ok(wgc![x_groups as _, y_groups as _, 1])
+    }
+
+    fn storage_bind_group_layout(
+        &self,
+        _: bool,
+    ) -> Result<BindGroupLayoutDescriptor, OperationError> {
+        Ok(BindGroupLayoutDescriptor::unary())
+    }
+
+    fn write_metadata(
+        &self,
+        uniform: &mut CpuUniform,
+        dst: &Tensor,
+        _: &KernelElement,
+    ) -> Result<u64, OperationError> {
+        let shape = dst.shape();
+        let rank = shape.rank();
+        let meta = TriluMeta {
+            M: shape[rank - 2] as u32,
+            N: shape[rank - 1] as u32,
+            numel: shape.numel() as u32,
+            k: self.k,
+        };
+        Ok(uniform.write(&meta)?)
+    }
+}
+
+impl CPUOperation for Trilu {
+    fn apply_cpu(&self, dst: &Tensor) -> Result<CPUBuffer, OperationError> {
+        let mut result = cpu_f32(&self.src)?;
+        let shape = dst.shape();
+        let (M, N) = (shape[shape.rank() - 2], shape[shape.rank() - 1]);
+        for (i, x) in result.iter_mut().enumerate() {
+            let (row, col) = (((i / N) % M) as i32, (i % N) as i32);
+            let keep = if self.upper {
+                col - row >= self.k
+            } else {
+                col - row <= self.k
+            };
+            if !keep {
+                *x = 0.;
+            }
+        }
+        Ok(CPUBuffer::from_slice(&result, shape))
+    }
+}
+
+#[cfg(all(test, feature = "pyo3"))]
+mod tests {
+    use crate::{shape, test_util::run_py_prg, Device, DeviceRequest, Tensor};
+    use test_strategy::{proptest, Arbitrary};
+
+    thread_local! {
+        static GPU_DEVICE: Device = Device::request_device(DeviceRequest::GPU).unwrap();
+    }
+
+    #[derive(Arbitrary, Debug)]
+    struct TriluProblem {
+        #[strategy(1..=4usize)]
+        B: usize,
+        #[strategy(1..=128usize)]
+        M: usize,
+        #[strategy(1..=128usize)]
+        N: usize,
+        #[strategy(-4..=4i32)]
+        k: i32,
+        upper: bool,
+    }
+
+    fn ground_truth(a: &Tensor, upper: bool, k: i32) -> anyhow::Result<Tensor> {
+        let func = if upper { "triu" } else { "tril" };
+        let prg = format!(
+            r#"
+import torch
+def {}(a):
+    return torch.{}(torch.from_numpy(a), {}).numpy()
+"#,
+            func, func, k
+        );
+        run_py_prg(prg.to_string(), &[a], &[])
+    }
+
+    fn run_trilu_trial(prob: TriluProblem) -> anyhow::Result<()> {
+        let device = GPU_DEVICE.with(|d| d.clone());
+        let TriluProblem { B, M, N, k, upper } = prob;
+        let a = Tensor::randn::<f32>(shape![B, M, N], Device::CPU);
+        let ground = ground_truth(&a, upper, k)?;
+
+        let a = a.to(&device)?;
+        let ours = if upper { a.triu(k)? } else { a.tril(k)? }.resolve()?;
+        let ours = ours.to(&Device::CPU)?;
+        ground.all_close(&ours, 1e-6, 1e-6)?;
+        Ok(())
+    }
+
+    #[proptest(cases = 8)]
+    fn test_trilu(prob: TriluProblem) {
+        run_trilu_trial(prob).unwrap();
+    }
+}
+
+#[cfg(test)]
+mod cpu_tests {
+    use crate::{shape, Device, Tensor};
+
+    #[test]
+    fn test_cpu_trilu() -> anyhow::Result<()> {
+        let x = Tensor::from_data(
+            vec![1., 2., 3., 4., 5., 6., 7., 8., 9.],
+            shape![3, 3],
+            Device::CPU,
+        );
+        let upper = x.clone().triu(1)?.resolve()?;
+        assert_eq!(
+            upper.to_vec::<f32>()?,
+            vec![0., 2., 3., 0., 0., 6., 0., 0., 0.]
+        );
+        let lower = x.tril(0)?.resolve()?;
+        assert_eq!(
+            lower.to_vec::<f32>()?,
+            vec![1., 0., 0., 4., 5., 0., 7., 8., 9.]
+        );
+        Ok(())
+    }
+}
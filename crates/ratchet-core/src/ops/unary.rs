@@ -2,9 +2,10 @@ use derive_new::new;
 use encase::ShaderType;
 
 use crate::{
+    cpu::{cpu_f32, cpu_from_f32},
     gpu::{BindGroupLayoutDescriptor, CpuUniform, WorkgroupCount},
-    rvec, wgc, KernelElement, MetaOperation, OpGuards, OpMetadata, Operation, OperationError, RVec,
-    StorageView, Tensor,
+    rvec, wgc, CPUBuffer, CPUOperation, KernelElement, MetaOperation, OpGuards, OpMetadata,
+    Operation, OperationError, RVec, StorageView, Tensor,
 };
 
 #[cfg(test)]
@@ -25,6 +26,16 @@ pub enum UnaryOp {
     Floor,
     Ceil,
     Neg,
+    Silu,
+    Sigmoid,
+    /// Exact GELU, using erf rather than the tanh approximation.
+    GeluErf,
+    /// Negative slope.
+    LeakyRelu(#[cfg_attr(test, strategy(0.0f32..1.0))] f32),
+    /// Alpha.
+    Elu(#[cfg_attr(test, strategy(0.1f32..2.0))] f32),
+    Softplus,
+    Reciprocal,
 }
 
 impl UnaryOp {
@@ -42,8 +53,84 @@ impl UnaryOp {
             UnaryOp::Floor => "floor",
             UnaryOp::Ceil => "ceil",
             UnaryOp::Neg => "neg",
+            UnaryOp::Silu => "silu",
+            UnaryOp::Sigmoid => "sigmoid",
+            UnaryOp::GeluErf => "gelu_erf",
+            UnaryOp::LeakyRelu(_) => "leaky_relu",
+            UnaryOp::Elu(_) => "elu",
+            UnaryOp::Softplus => "softplus",
+            UnaryOp::Reciprocal => "reciprocal",
         }
     }
+
+    /// The scalar parameter of the op, if any.
+    fn alpha(&self) -> f32 {
+        match self {
+            UnaryOp::LeakyRelu(alpha) | UnaryOp::Elu(alpha) => *alpha,
+            _ => 0.,
+        }
+    }
+
+    /// Index of the op in `epilogue.wgsl`, if it can be fused onto the output of another kernel.
+    pub(crate) fn epilogue_id(&self) -> Option<u32> {
+        match self {
+            UnaryOp::Gelu => Some(1),
+            UnaryOp::Relu => Some(2),
+            UnaryOp::Silu => Some(3),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn apply(&self, x: f32) -> f32 {
+        match self {
+            UnaryOp::Gelu => {
+                const SQRT_2_OVER_PI: f32 = 0.797_884_6;
+                const SCALED_SQRT_2_OVER_PI: f32 = 0.035_677_407;
+                let cdf =
+                    0.5 + 0.5 * (x * (SCALED_SQRT_2_OVER_PI * (x * x) + SQRT_2_OVER_PI)).tanh();
+                x * cdf
+            }
+            UnaryOp::Tanh => x.tanh(),
+            UnaryOp::Exp => x.exp(),
+            UnaryOp::Log => x.ln(),
+            UnaryOp::Sin => x.sin(),
+            UnaryOp::Cos => x.cos(),
+            UnaryOp::Abs => x.abs(),
+            UnaryOp::Sqrt => x.sqrt(),
+            UnaryOp::Relu => x.max(0.0),
+            UnaryOp::Floor => x.floor(),
+            UnaryOp::Ceil => x.ceil(),
+            UnaryOp::Neg => -x,
+            UnaryOp::Silu => x / (1. + (-x).exp()),
+            UnaryOp::Sigmoid => 1. / (1. + (-x).exp()),
+            UnaryOp::GeluErf => 0.5 * x * (1. + erf(x * std::f32::consts::FRAC_1_SQRT_2)),
+            UnaryOp::LeakyRelu(slope) => {
+                if x >= 0. {
+                    x
+                } else {
+                    x * slope
+                }
+            }
+            UnaryOp::Elu(alpha) => {
+                if x > 0. {
+                    x
+                } else {
+                    alpha * (x.exp() - 1.)
+                }
+            }
+            UnaryOp::Softplus => x.max(0.) + (-x.abs()).exp().ln_1p(),
+            UnaryOp::Reciprocal => 1. / x,
+        }
+    }
+}
+
+/// Abramowitz & Stegun 7.1.26, matching the kernel.
+fn erf(x: f32) -> f32 {
+    let t = 1. / (1. + 0.327_591_1 * x.abs());
+    let poly = ((((1.061_405_4 * t - 1.453_152_1) * t + 1.421_413_8) * t - 0.284_496_74) * t
+        + 0.254_829_6)
+        * t;
+    x.signum() * (1. - poly * (-x * x).exp())
 }
 
 #[derive(new, Debug, Clone)]
@@ -56,11 +143,16 @@ impl Unary {
     pub fn op(&self) -> &UnaryOp {
         &self.op
     }
+
+    pub fn input(&self) -> &Tensor {
+        &self.input
+    }
 }
 
 #[derive(Debug, ShaderType)]
 pub struct UnaryMeta {
     numel: u32,
+    alpha: f32,
 }
 
 impl OpMetadata for UnaryMeta {}
@@ -70,7 +162,7 @@ impl OpGuards for Unary {
 
     fn check_dtypes(&self) {
         let a = &self.input;
-        assert!(matches!(a.dt(), crate::DType::F32));
+        assert!(matches!(a.dt(), crate::DType::F32 | crate::DType::F16));
     }
 }
 
@@ -88,10 +180,11 @@ impl MetaOperation for Unary {
     fn kernel_key(&self, inplace: bool, dst: &Tensor) -> String {
         let kn = self.kernel_name();
         let ke = self.kernel_element(dst).as_str();
+        let suffix = dst.dt().kernel_suffix();
         if inplace {
-            format!("{}_inplace_{}", kn, ke)
+            format!("{}_inplace_{}{}", kn, ke, suffix)
         } else {
-            format!("{}_{}", kn, ke)
+            format!("{}_{}{}", kn, ke, suffix)
         }
     }
 
@@ -147,16 +240,28 @@ impl MetaOperation for Unary {
     ) -> Result<u64, OperationError> {
         let a = &self.input;
         let numel = a.shape().numel() as u32;
-        let meta = UnaryMeta { numel };
+        let alpha = self.op.alpha();
+        let meta = UnaryMeta { numel, alpha };
         Ok(uniform.write(&meta)?)
     }
 }
 
+impl CPUOperation for Unary {
+    fn apply_cpu(&self, dst: &Tensor) -> Result<CPUBuffer, OperationError> {
+        let result = cpu_f32(&self.input)?
+            .into_iter()
+            .map(|x| self.op.apply(x))
+            .collect::<Vec<_>>();
+        cpu_from_f32(&result, dst.dt(), dst.shape())
+    }
+}
+
 #[cfg(all(test, feature = "pyo3"))]
 mod tests {
     use test_strategy::{proptest, Arbitrary};
 
-    use crate::{shape, test_util::run_py_prg, Device, DeviceRequest, Tensor, UnaryOp};
+    use crate::test_util::{check_f16, run_py_prg};
+    use crate::{shape, Device, DeviceRequest, Tensor, UnaryOp};
 
     #[derive(Arbitrary, Debug)]
     struct UnaryProblem {
@@ -170,7 +275,10 @@ mod tests {
     }
 
     fn ground_truth(a: &Tensor, op: &UnaryOp, args: &str) -> anyhow::Result<Tensor> {
-        let kn = op.kernel_name();
+        let kn = match op {
+            UnaryOp::GeluErf => "gelu",
+            _ => op.kernel_name(),
+        };
         let func_prg = format!(
             r#"
 import torch
@@ -191,13 +299,42 @@ def {}(a):
         );
 
         let prg = match op {
-            UnaryOp::Gelu => func_prg,
+            UnaryOp::Gelu
+            | UnaryOp::GeluErf
+            | UnaryOp::Silu
+            | UnaryOp::LeakyRelu(_)
+            | UnaryOp::Elu(_)
+            | UnaryOp::Softplus => func_prg,
             _ => imp_prg,
         };
 
         run_py_prg(prg.to_string(), &[a], &[])
     }
 
+    fn apply(op: &UnaryOp, a: Tensor) -> anyhow::Result<Tensor> {
+        match *op {
+            UnaryOp::Gelu => a.gelu(),
+            UnaryOp::Tanh => a.tanh(),
+            UnaryOp::Exp => a.exp(),
+            UnaryOp::Log => a.log(),
+            UnaryOp::Sin => a.sin(),
+            UnaryOp::Cos => a.cos(),
+            UnaryOp::Abs => a.abs(),
+            UnaryOp::Sqrt => a.sqrt(),
+            UnaryOp::Relu => a.relu(),
+            UnaryOp::Floor => a.floor(),
+            UnaryOp::Ceil => a.ceil(),
+            UnaryOp::Neg => a.neg(),
+            UnaryOp::Silu => a.silu(),
+            UnaryOp::Sigmoid => a.sigmoid(),
+            UnaryOp::GeluErf => a.gelu_erf(),
+            UnaryOp::LeakyRelu(slope) => a.leaky_relu(slope),
+            UnaryOp::Elu(alpha) => a.elu(alpha),
+            UnaryOp::Softplus => a.softplus(),
+            UnaryOp::Reciprocal => a.reciprocal(),
+        }
+    }
+
     thread_local! {
         static GPU_DEVICE: Device = Device::request_device(DeviceRequest::GPU).unwrap();
     }
@@ -209,30 +346,19 @@ def {}(a):
         let a = Tensor::randn::<f32>(shape![B, M], Device::CPU);
 
         let args = match op {
-            UnaryOp::Gelu => "approximate=\"tanh\"",
-            _ => "",
+            UnaryOp::Gelu => "approximate=\"tanh\"".to_string(),
+            UnaryOp::LeakyRelu(slope) => format!("negative_slope={}", slope),
+            UnaryOp::Elu(alpha) => format!("alpha={}", alpha),
+            _ => "".to_string(),
         };
-        let ground = ground_truth(&a, &op, args)?;
-
-        let a_gpu = a.to(&device)?;
-        let c_gpu = match op {
-            UnaryOp::Gelu => a_gpu.gelu()?,
-            UnaryOp::Tanh => a_gpu.tanh()?,
-            UnaryOp::Exp => a_gpu.exp()?,
-            UnaryOp::Log => a_gpu.log()?,
-            UnaryOp::Sin => a_gpu.sin()?,
-            UnaryOp::Cos => a_gpu.cos()?,
-            UnaryOp::Abs => a_gpu.abs()?,
-            UnaryOp::Sqrt => a_gpu.sqrt()?,
-            UnaryOp::Relu => a_gpu.relu()?,
-            UnaryOp::Floor => a_gpu.floor()?,
-            UnaryOp::Ceil => a_gpu.ceil()?,
-            UnaryOp::Neg => a_gpu.neg()?,
-        }
-        .resolve()?;
+        let ground = ground_truth(&a, &op, &args)?;
+
+        let c_gpu = apply(&op, a.to(&device)?)?.resolve()?;
 
         let (atol, rtol) = match op {
             UnaryOp::Gelu | UnaryOp::Tanh => (5e-2, 5e-2),
+            //randn can land arbitrarily close to 0
+            UnaryOp::Reciprocal => (1e-4, 1e-3),
             _ => (1e-4, 1e-4),
         };
 
@@ -245,4 +371,92 @@ def {}(a):
     fn test_unary(prob: UnaryProblem) {
         run_unary_trial(prob).unwrap();
     }
+
+    #[test]
+    #[ignore = "needs shader-f16, which only browsers grant, see tests/f16.rs"]
+    fn test_unary_f16() -> anyhow::Result<()> {
+        let device = GPU_DEVICE.with(|d| d.clone());
+        let ops = [
+            UnaryOp::Gelu,
+            UnaryOp::Tanh,
+            UnaryOp::Exp,
+            UnaryOp::Log,
+            UnaryOp::Sin,
+            UnaryOp::Cos,
+            UnaryOp::Abs,
+            UnaryOp::Sqrt,
+            UnaryOp::Relu,
+            UnaryOp::Floor,
+            UnaryOp::Ceil,
+            UnaryOp::Neg,
+            UnaryOp::Silu,
+            UnaryOp::Sigmoid,
+            UnaryOp::GeluErf,
+            UnaryOp::LeakyRelu(0.1),
+            UnaryOp::Elu(1.),
+            UnaryOp::Softplus,
+            UnaryOp::Reciprocal,
+        ];
+        for op in ops {
+            //Vec4, vec2 and scalar kernels
+            for n in [64, 66, 67] {
+                //Multiples of 1/8 are exact in f16, so floor & ceil agree
+                let data = (0..2 * n).map(|i| match op {
+                    UnaryOp::Log | UnaryOp::Sqrt | UnaryOp::Reciprocal => {
+                        0.25 + (i % 23) as f32 / 8.
+                    }
+                    _ => ((i % 41) as f32 - 20.) / 8.,
+                });
+                let a = Tensor::from_data(data.collect::<Vec<_>>(), shape![2, n], Device::CPU);
+                let (atol, rtol) = match op {
+                    UnaryOp::Gelu | UnaryOp::Tanh => (5e-2, 5e-2),
+                    _ => (1e-2, 1e-2),
+                };
+                check_f16(&device, &[a], |t| apply(&op, t[0].clone()), atol, rtol)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod cpu_tests {
+    use crate::{shape, Device, Tensor};
+
+    #[test]
+    fn test_cpu_activations() -> anyhow::Result<()> {
+        let x = Tensor::from_data(vec![-2., -0.5, 0.5, 2.], shape![4], Device::CPU);
+        let close = |t: Tensor, expected: [f32; 4]| -> anyhow::Result<()> {
+            let ours = t.resolve()?.to_vec::<f32>()?;
+            for (o, e) in ours.iter().zip(expected.iter()) {
+                assert!((o - e).abs() < 1e-5, "{} vs {}", o, e);
+            }
+            Ok(())
+        };
+        close(
+            x.clone().sigmoid()?,
+            [0.1192029, 0.3775407, 0.6224593, 0.8807971],
+        )?;
+        close(
+            x.clone().silu()?,
+            [-0.2384058, -0.1887703, 0.3112297, 1.7615942],
+        )?;
+        close(
+            x.clone().gelu_erf()?,
+            [-0.0455003, -0.1542687, 0.3457313, 1.9544997],
+        )?;
+        close(x.clone().leaky_relu(0.1)?, [-0.2, -0.05, 0.5, 2.])?;
+        close(x.clone().elu(1.)?, [-0.8646647, -0.3934693, 0.5, 2.])?;
+        close(
+            x.clone().softplus()?,
+            [0.126928, 0.474077, 0.974077, 2.126928],
+        )?;
+        close(x.clone().reciprocal()?, [-0.5, -2., 2., 0.5])?;
+        let up = Tensor::from_data(vec![1., 2., 3., 4.], shape![4], Device::CPU);
+        close(
+            x.swiglu(up)?,
+            [-0.2384058, -0.3775407, 0.9336892, 7.0463767],
+        )?;
+        Ok(())
+    }
 }
@@ -0,0 +1,261 @@
+use derive_new::new;
+use encase::ShaderType;
+
+use crate::{
+    cpu::{broadcast_offset, cpu_f32, cpu_from_f32, cpu_u32},
+    gpu::{BindGroupLayoutDescriptor, CpuUniform, WorkgroupCount},
+    rvec, wgc, CPUBuffer, CPUOperation, DType, InvariantError, KernelElement, MetaOperation,
+    OpGuards, OpMetadata, Operation, OperationError, RVec, Shape, StorageView, Strides, Tensor,
+};
+
+/// # WhereCond
+///
+/// Selects elements from `on_true` where `cond` is non-zero, and from `on_false` elsewhere.
+#[derive(new, Debug, Clone)]
+pub struct WhereCond {
+    cond: Tensor,
+    on_true: Tensor,
+    on_false: Tensor,
+}
+
+#[derive(Debug, ShaderType)]
+pub struct WhereCondMeta {
+    numel: u32,
+}
+
+impl OpMetadata for WhereCondMeta {}
+
+impl OpGuards for WhereCond {
+    fn check_shapes(&self) {
+        let shapes = [
+            self.cond.shape(),
+            self.on_true.shape(),
+            self.on_false.shape(),
+        ];
+        let broadcasted = Shape::multi_broadcast(&shapes);
+        assert!(broadcasted.is_some());
+    }
+
+    fn check_dtypes(&self) {
+        assert_eq!(self.cond.dt(), DType::U32);
+        assert!(matches!(self.on_true.dt(), DType::F32 | DType::F16));
+        assert_eq!(self.on_true.dt(), self.on_false.dt());
+    }
+}
+
+impl Operation for WhereCond {
+    fn compute_view(&self) -> Result<StorageView, OperationError> {
+        let shapes = &[
+            self.cond.shape(),
+            self.on_true.shape(),
+            self.on_false.shape(),
+        ];
+        let broadcasted = Shape::multi_broadcast(shapes).ok_or_else(|| {
+            let failed = shapes.iter().map(|s| (*s).clone()).collect::<Vec<_>>();
+            InvariantError::BroadcastingFailed(failed)
+        })?;
+        let ostrides = Strides::from(&broadcasted);
+        Ok(StorageView::new(broadcasted, self.on_true.dt(), ostrides))
+    }
+}
+
+impl MetaOperation for WhereCond {
+    fn kernel_name(&self) -> String {
+        "where_cond".to_string()
+    }
+
+    fn kernel_key(&self, _: bool, dst: &Tensor) -> String {
+        format!(
+            "where_cond_{}{}",
+            self.kernel_element(dst).as_str(),
+            dst.dt().kernel_suffix()
+        )
+    }
+
+    fn srcs(&self) -> RVec<&Tensor> {
+        rvec![&self.cond, &self.on_true, &self.on_false]
+    }
+
+    fn kernel_element(&self, dst: &Tensor) -> KernelElement {
+        let numel = dst.shape().numel();
+
+        if numel % 4 == 0 {
+            KernelElement::Vec4
+        } else if numel % 2 == 0 {
+            KernelElement::Vec2
+        } else {
+            KernelElement::Scalar
+        }
+    }
+
+    fn calculate_dispatch(&self, dst: &Tensor) -> Result<WorkgroupCount, OperationError> {
+        let numel = dst.shape().numel();
+        let x_groups = WorkgroupCount::div_ceil(numel as _, 64);
+        let (x_groups, y_groups) = if x_groups > WorkgroupCount::MAX_WGS_PER_DIM {
+            let y_groups = WorkgroupCount::div_ceil(x_groups, WorkgroupCount::MAX_WGS_PER_DIM);
+            (WorkgroupCount::MAX_WGS_PER_DIM, y_groups)
+        } else {
+            (x_groups, 1)
+        };
+        Ok(wgc![x_groups as _, y_groups as _, 1])
+    }
+
+    fn storage_bind_group_layout(
+        &self,
+        _: bool,
+    ) -> Result<BindGroupLayoutDescriptor, OperationError> {
+        Ok(BindGroupLayoutDescriptor::ternary())
+    }
+
+    fn write_metadata(
+        &self,
+        uniform: &mut CpuUniform,
+        dst: &Tensor,
+        _: &KernelElement,
+    ) -> Result<u64, OperationError> {
+        let numel = dst.shape().numel() as _;
+        let meta = WhereCondMeta { numel };
+        Ok(uniform.write(&meta)?)
+    }
+}
+
+impl CPUOperation for WhereCond {
+    fn apply_cpu(&self, dst: &Tensor) -> Result<CPUBuffer, OperationError> {
+        let cond = cpu_u32(&self.cond)?;
+        let (on_true, on_false) = (cpu_f32(&self.on_true)?, cpu_f32(&self.on_false)?);
+        let dst_shape = dst.shape();
+        let result = (0..dst_shape.numel())
+            .map(|i| {
+                if cond[broadcast_offset(self.cond.shape(), dst_shape, i)] != 0 {
+                    on_true[broadcast_offset(self.on_true.shape(), dst_shape, i)]
+                } else {
+                    on_false[broadcast_offset(self.on_false.shape(), dst_shape, i)]
+                }
+            })
+            .collect::<Vec<_>>();
+        cpu_from_f32(&result, dst.dt(), dst_shape)
+    }
+}
+
+#[cfg(all(test, feature = "pyo3"))]
+mod tests {
+    use crate::{shape, test_util::run_py_prg, DType, Device, DeviceRequest, Tensor};
+    use test_strategy::{proptest, Arbitrary};
+
+    thread_local! {
+        static GPU_DEVICE: Device = Device::request_device(DeviceRequest::GPU).unwrap();
+    }
+
+    #[derive(Arbitrary, Debug)]
+    struct WhereCondProblem {
+        #[strategy(1..=4usize)]
+        B: usize,
+        #[strategy(1..=64usize)]
+        M: usize,
+        #[strategy(1..=256usize)]
+        N: usize,
+    }
+
+    fn ground_truth(a: &Tensor, b: &Tensor) -> anyhow::Result<Tensor> {
+        let prg = r#"
+import torch
+def where_cond(a, b):
+    (a, b) = (torch.from_numpy(a), torch.from_numpy(b))
+    return torch.where(a > b, a, b.masked_fill(b < 0, -1e9)).numpy()
+"#;
+        run_py_prg(prg.to_string(), &[a, b], &[])
+    }
+
+    fn run_where_cond_trial(prob: WhereCondProblem) -> anyhow::Result<()> {
+        let device = GPU_DEVICE.with(|d| d.clone());
+        let WhereCondProblem { B, M, N } = prob;
+        let a = Tensor::randn::<f32>(shape![B, M, N], Device::CPU);
+        let b = Tensor::randn::<f32>(shape![B, M, N], Device::CPU);
+        let ground = ground_truth(&a, &b)?;
+
+        let (a, b) = (a.to(&device)?, b.to(&device)?);
+        let zero = Tensor::zeros::<f32>(&shape![1], &device);
+        let filled = b.clone().masked_fill(b.clone().lt(zero)?, -1e9)?;
+        let ours = a.clone().gt(b)?.where_cond(a, filled)?.resolve()?;
+        let ours = ours.to(&Device::CPU)?;
+        ground.all_close(&ours, 1e-6, 1e-6)?;
+        Ok(())
+    }
+
+    #[proptest(cases = 8)]
+    fn test_where_cond(prob: WhereCondProblem) {
+        run_where_cond_trial(prob).unwrap();
+    }
+
+    #[test]
+    fn test_where_cond_broadcast() -> anyhow::Result<()> {
+        let device = GPU_DEVICE.with(|d| d.clone());
+        let cond = Tensor::randn::<f32>(shape![3, 1, 5], Device::CPU);
+        let on_true = Tensor::randn::<f32>(shape![1, 4, 5], Device::CPU);
+        let on_false = Tensor::randn::<f32>(shape![5], Device::CPU);
+        let run = |device: &Device| -> anyhow::Result<Tensor> {
+            let zero = Tensor::zeros::<f32>(&shape![1], device);
+            let mask = cond.to(device)?.gt(zero)?;
+            let out = mask.where_cond(on_true.to(device)?, on_false.to(device)?)?;
+            Ok(out.resolve()?.to(&Device::CPU)?)
+        };
+
+        let ground = run(&Device::CPU)?;
+        let ours = run(&device)?;
+        assert_eq!(ours.shape(), &shape![3, 4, 5]);
+        ground.all_close(&ours, 0., 0.)?;
+        Ok(())
+    }
+
+    #[test]
+    #[ignore = "needs shader-f16, which only browsers grant, see tests/f16.rs"]
+    fn test_masked_fill_f16() -> anyhow::Result<()> {
+        let device = GPU_DEVICE.with(|d| d.clone());
+        anyhow::ensure!(
+            device.try_gpu()?.supports_f16(),
+            "{:?} lacks shader-f16",
+            device
+        );
+        let a = Tensor::randn::<f32>(shape![4, 33], Device::CPU);
+        let run = |device: &Device| -> anyhow::Result<Tensor> {
+            let a = a.to(device)?;
+            let mask = a.clone().lt(Tensor::zeros::<f32>(&shape![1], device))?;
+            let filled = a.cast(DType::F16)?.masked_fill(mask, -1e4)?;
+            assert_eq!(filled.dt(), DType::F16);
+            Ok(filled
+                .resolve()?
+                .to(&Device::CPU)?
+                .cast(DType::F32)?
+                .resolve()?)
+        };
+
+        let ground = run(&Device::CPU)?;
+        let ours = run(&device)?;
+        ground.all_close(&ours, 0., 0.)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod cpu_tests {
+    use crate::{shape, Device, Tensor};
+
+    #[test]
+    fn test_cpu_masked_fill() -> anyhow::Result<()> {
+        let x = Tensor::from_data(
+            vec![1., 2., 3., 4., 5., 6., 7., 8., 9.],
+            shape![3, 3],
+            Device::CPU,
+        );
+        let threshold = Tensor::from_data(vec![5.], shape![1], Device::CPU);
+        let gt = x.clone().gt(threshold)?.resolve()?;
+        assert_eq!(gt.to_vec::<u32>()?, vec![0, 0, 0, 0, 0, 1, 1, 1, 1]);
+
+        let filled = x.masked_fill(gt, -1.)?.resolve()?;
+        assert_eq!(
+            filled.to_vec::<f32>()?,
+            vec![1., 2., 3., 4., 5., -1., -1., -1., -1.]
+        );
+        Ok(())
+    }
+}
@@ -1,12 +1,9 @@
 use num::integer::div_floor;
-use num_traits::{AsPrimitive, Float};
-
-use std::fmt::Debug;
 
 use crate::{
-    gguf::{GGUFDType, Q8_0},
+    gguf::{GGUFDType, Q4K, Q6K, Q8_0, QK_K},
     gpu::STORAGE_BUFFER_ALIGN,
-    DType, Device, Tensor,
+    DType, Device, SInt4, Segments, Tensor,
 };
 
 /// Quantizer
@@ -22,13 +19,94 @@ impl Quantizer {
         match self.format {
             Quantization::None => tensor,
             Quantization::SInt8 => self.sint8_quantize(tensor),
-            Quantization::SInt4 => todo!(),
+            Quantization::SInt4 { .. } => self.sint4_quantize(tensor),
+        }
+    }
+
+    /// Like [Quantizer::quantize], but picks the scale of each group to minimise the rounding
+    /// error weighted by `importance`, which holds one value per column (last dimension), e.g
+    /// the mean squared activation feeding it.
+    pub fn quantize_weighted(&self, tensor: Tensor, importance: &[f32]) -> Tensor {
+        assert_eq!(tensor.shape()[tensor.rank() - 1], importance.len());
+        match self.format {
+            Quantization::None => tensor,
+            Quantization::SInt8 => self.sint8_pack(tensor, Some(importance)),
+            Quantization::SInt4 { .. } => self.sint4_pack(tensor, Some(importance)),
+        }
+    }
+
+    /// Inverse of [Quantizer::quantize].
+    pub fn dequantize(&self, quantized: Tensor) -> Tensor {
+        match self.format {
+            Quantization::None => quantized,
+            Quantization::SInt8 => self.sint8_dequantize(quantized),
+            Quantization::SInt4 { .. } => self.sint4_dequantize(quantized),
         }
     }
 
+    /// Scale of each group of `group_size` elements, such that it is quantized to `[-nmax, nmax]`.
+    ///
+    /// Without `importance`, the largest magnitude of the group maps onto `nmax`. With it, a few
+    /// smaller and larger scales are tried as well, and the one with the lowest importance
+    /// weighted error is kept: clipping a rarely activated outlier can buy precision for the
+    /// columns that matter. Rows that don't split into whole groups are quantized naively, as
+    /// their groups would straddle two rows.
+    fn group_scales(
+        matrix: &[f32],
+        group_size: usize,
+        nmax: f32,
+        importance: Option<&[f32]>,
+    ) -> Vec<f32> {
+        let importance = importance.filter(|importance| {
+            let whole = importance.len() % group_size == 0;
+            if !whole {
+                log::warn!(
+                    "Rows of {} don't split into groups of {}, ignoring importance",
+                    importance.len(),
+                    group_size
+                );
+            }
+            whole
+        });
+        matrix
+            .chunks_exact(group_size)
+            .enumerate()
+            .map(|(g, group)| {
+                let amax = group.iter().fold(0f32, |acc, &x| acc.max(x.abs()));
+                let Some(importance) = importance.filter(|_| amax > 0.) else {
+                    return amax / nmax;
+                };
+                let start = (g * group_size) % importance.len();
+                let weights = &importance[start..start + group_size];
+                let error = |d: f32| {
+                    group
+                        .iter()
+                        .zip(weights)
+                        .map(|(&x, &w)| {
+                            let q = (x / d).round().clamp(-nmax, nmax);
+                            w * (x - q * d).powi(2)
+                        })
+                        .sum::<f32>()
+                };
+                //The naive scale comes first, so it wins ties
+                std::iter::once(0)
+                    .chain(-9..=9)
+                    .map(|i| amax / (nmax + 0.1 * i as f32))
+                    .map(|d| (d, error(d)))
+                    .min_by(|(_, a), (_, b)| a.total_cmp(b))
+                    .map(|(d, _)| d)
+                    .unwrap()
+            })
+            .collect()
+    }
+
     /// Quantizes a float 32 tensor into a packed uint32 tensor.
     /// GGUF Q8_0
     pub fn sint8_quantize(&self, tensor: Tensor) -> Tensor {
+        self.sint8_pack(tensor, None)
+    }
+
+    fn sint8_pack(&self, tensor: Tensor, importance: Option<&[f32]>) -> Tensor {
         let numel = tensor.shape().numel();
         let pack_size = self.format.pack_size();
         let group_size = self.format.group_size();
@@ -54,22 +132,17 @@ impl Quantizer {
         let mut quantized_matrix = vec![0u32; aligner(qmatrix_len, std::mem::size_of::<u32>())];
         let mut absmax_matrix = vec![0f32; aligner(amatrix_len, std::mem::size_of::<f32>())];
 
-        let mut block_absmax = f32::NEG_INFINITY;
-
         let matrix = tensor.to_vec::<f32>().unwrap();
+        let nmax = ((1 << 7) - 1) as f32;
+        let scales = Self::group_scales(&matrix, group_size, nmax, importance);
 
         for i in (0..numel).step_by(pack_size) {
-            if i % group_size == 0 {
-                let amax = matrix[i..i + group_size]
-                    .iter()
-                    .fold(f32::NEG_INFINITY, |acc, &x| acc.max(x.abs()));
-                let d = amax / ((1 << 7) - 1) as f32;
-                block_absmax = d;
-            }
-            let packed_value: i32 = ((matrix[i] / block_absmax).round() as i32 & 0xFF)
-                | (((matrix[i + 1] / block_absmax).round() as i32 & 0xFF) << 8)
-                | (((matrix[i + 2] / block_absmax).round() as i32 & 0xFF) << 16)
-                | (((matrix[i + 3] / block_absmax).round() as i32 & 0xFF) << 24);
+            let block_absmax = scales[i / group_size];
+            let q = |x: f32| (x / block_absmax).round().clamp(-nmax, nmax) as i32 & 0xFF;
+            let packed_value: i32 = q(matrix[i])
+                | (q(matrix[i + 1]) << 8)
+                | (q(matrix[i + 2]) << 16)
+                | (q(matrix[i + 3]) << 24);
             quantized_matrix[i / pack_size] = packed_value as u32;
             absmax_matrix[i / group_size] = block_absmax;
         }
@@ -88,6 +161,13 @@ impl Quantizer {
         assert!(matches!(quantized.dt(), DType::GGUF(GGUFDType::Q8_0(_))));
         let numel = quantized.shape().numel();
         let original_shape = quantized.shape().clone();
+        let raw_bytes = unsafe { quantized.into_bytes().unwrap() };
+        let dequantized = Self::sint8_dequantize_raw(&raw_bytes, numel);
+        Tensor::from_data(dequantized, original_shape, Device::CPU)
+    }
+
+    /// Dequantizes the raw bytes of a GGUF Q8_0 tensor, as laid out by [Quantizer::sint8_quantize].
+    pub(crate) fn sint8_dequantize_raw(raw_bytes: &[u8], numel: usize) -> Vec<f32> {
         let aligner = |numel: usize, size_t: usize| -> usize {
             let nbytes = numel * size_t;
 
@@ -98,8 +178,8 @@ impl Quantizer {
             }
         };
 
-        let pack_size = self.format.pack_size();
-        let group_size = self.format.group_size();
+        let pack_size = Quantization::SInt8.pack_size();
+        let group_size = Quantization::SInt8.group_size();
 
         let num_q = numel / pack_size;
         let num_q_bytes = num_q * std::mem::size_of::<u32>();
@@ -108,8 +188,6 @@ impl Quantizer {
         let num_absmax = numel / group_size;
         let num_absmax_bytes = num_absmax * std::mem::size_of::<f32>();
 
-        let raw_bytes = unsafe { quantized.into_bytes().unwrap() };
-
         let quantized_matrix = bytemuck::cast_slice::<u8, u32>(&raw_bytes[..num_q_bytes]);
         let absmax_matrix = bytemuck::cast_slice::<u8, f32>(
             &raw_bytes[aligned_q_bytes..aligned_q_bytes + num_absmax_bytes],
@@ -126,53 +204,206 @@ impl Quantizer {
             dequantized[i + 3] = (packed_value >> 24) as f32 * block_absmax;
         }
 
-        Tensor::from_data(dequantized, original_shape, Device::CPU)
+        dequantized
     }
 
-    pub fn sint4_quantize<F: Float + AsPrimitive<i32> + Debug>(
-        matrix: &[F],
-        K: usize,
-        N: usize,
-    ) -> (Vec<u32>, F) {
-        assert!(matrix.len() == K * N);
-        assert!(matrix.len() % 4 == 0);
-        let pack_size = 8;
-        let mut quantized_matrix = vec![0u32; K * N / pack_size];
+    /// Dequantizes the raw bytes of a GGUF Q4_K tensor, as laid out by [Q4K::segments].
+    pub(crate) fn q4k_dequantize_raw(raw_bytes: &[u8], numel: usize) -> Vec<f32> {
+        let segments = Q4K::segments(numel);
+        let segment = |i: usize| &raw_bytes[segments[i].offset as usize..];
+        let nblocks = numel / QK_K;
+        let ds = bytemuck::cast_slice::<u8, f32>(&segment(0)[..nblocks * 4]);
+        let dmins = bytemuck::cast_slice::<u8, f32>(&segment(1)[..nblocks * 4]);
+        let (scales, qs) = (segment(2), segment(3));
+
+        //6 bit scales and mins, packed into 12 bytes per block
+        let scale_min = |scales: &[u8], j: usize| -> (f32, f32) {
+            if j < 4 {
+                ((scales[j] & 63) as f32, (scales[j + 4] & 63) as f32)
+            } else {
+                let sc = (scales[j + 4] & 0xF) | ((scales[j - 4] >> 6) << 4);
+                let m = (scales[j + 4] >> 4) | ((scales[j] >> 6) << 4);
+                (sc as f32, m as f32)
+            }
+        };
+
+        let mut dequantized = vec![0.0f32; numel];
+        for (b, y) in dequantized.chunks_exact_mut(QK_K).enumerate() {
+            let scales = &scales[b * 12..(b + 1) * 12];
+            let q = &qs[b * QK_K / 2..(b + 1) * QK_K / 2];
+            for (chunk, (y, q)) in y.chunks_exact_mut(64).zip(q.chunks_exact(32)).enumerate() {
+                let (sc1, m1) = scale_min(scales, 2 * chunk);
+                let (sc2, m2) = scale_min(scales, 2 * chunk + 1);
+                let (d1, d2) = (ds[b] * sc1, ds[b] * sc2);
+                let (m1, m2) = (dmins[b] * m1, dmins[b] * m2);
+                for l in 0..32 {
+                    y[l] = d1 * (q[l] & 0xF) as f32 - m1;
+                    y[l + 32] = d2 * (q[l] >> 4) as f32 - m2;
+                }
+            }
+        }
+        dequantized
+    }
 
-        let absmax = matrix.iter().fold(F::zero(), |acc, &x| acc.max(x.abs()));
-        let sf = F::from(7.).unwrap();
+    /// Dequantizes the raw bytes of a GGUF Q6_K tensor, as laid out by [Q6K::segments].
+    pub(crate) fn q6k_dequantize_raw(raw_bytes: &[u8], numel: usize) -> Vec<f32> {
+        let segments = Q6K::segments(numel);
+        let segment = |i: usize| &raw_bytes[segments[i].offset as usize..];
+        let nblocks = numel / QK_K;
+        let (ql, qh) = (segment(0), segment(1));
+        let scales = bytemuck::cast_slice::<u8, i8>(&segment(2)[..numel / 16]);
+        let ds = bytemuck::cast_slice::<u8, f32>(&segment(3)[..nblocks * 4]);
 
-        for i in (0..(K * N)).step_by(pack_size) {
-            let packed_value: i32 = ((matrix[i] / absmax * sf).round().as_() & 0xF)
-                | (((matrix[i + 1] / absmax * sf).round().as_() & 0xF) << 4)
-                | (((matrix[i + 2] / absmax * sf).round().as_() & 0xF) << 8)
-                | (((matrix[i + 3] / absmax * sf).round().as_() & 0xF) << 12)
-                | (((matrix[i + 4] / absmax * sf).round().as_() & 0xF) << 16)
-                | (((matrix[i + 5] / absmax * sf).round().as_() & 0xF) << 20)
-                | (((matrix[i + 6] / absmax * sf).round().as_() & 0xF) << 24)
-                | (((matrix[i + 7] / absmax * sf).round().as_() & 0xF) << 28);
-            quantized_matrix[i / pack_size] = packed_value as u32
+        let mut dequantized = vec![0.0f32; numel];
+        for (b, y) in dequantized.chunks_exact_mut(QK_K).enumerate() {
+            //Each half of a block has 64 bytes of ql, 32 bytes of qh and 8 scales
+            for (n, y) in y.chunks_exact_mut(128).enumerate() {
+                let ql = &ql[b * 128 + n * 64..];
+                let qh = &qh[b * 64 + n * 32..];
+                let sc = &scales[b * 16 + n * 8..];
+                let d = ds[b];
+                for l in 0..32 {
+                    let is = l / 16;
+                    let q1 = ((ql[l] & 0xF) | ((qh[l] & 3) << 4)) as i32 - 32;
+                    let q2 = ((ql[l + 32] & 0xF) | (((qh[l] >> 2) & 3) << 4)) as i32 - 32;
+                    let q3 = ((ql[l] >> 4) | (((qh[l] >> 4) & 3) << 4)) as i32 - 32;
+                    let q4 = ((ql[l + 32] >> 4) | (((qh[l] >> 6) & 3) << 4)) as i32 - 32;
+                    y[l] = d * sc[is] as f32 * q1 as f32;
+                    y[l + 32] = d * sc[is + 2] as f32 * q2 as f32;
+                    y[l + 64] = d * sc[is + 4] as f32 * q3 as f32;
+                    y[l + 96] = d * sc[is + 6] as f32 * q4 as f32;
+                }
+            }
         }
-        (quantized_matrix, absmax)
+        dequantized
     }
 
-    pub fn sint4_dequantize(quantized_matrix: &[u32], absmax: f32, K: usize, N: usize) -> Vec<f32> {
-        let pack_size = 8;
-        let mut matrix = vec![0.0; K * N];
+    /// Dequantizes the raw bytes of a GGUF Q4_0, Q4_1, Q5_0, Q5_1 or Q8_1 tensor, as laid out by
+    /// their segments.
+    pub(crate) fn legacy_dequantize_raw(dt: GGUFDType, raw_bytes: &[u8], numel: usize) -> Vec<f32> {
+        let segments = dt.segments(numel);
+        let segment = |i: usize| &raw_bytes[segments[i].offset as usize..];
+        let words = |i: usize, len: usize| bytemuck::cast_slice::<u8, u32>(&segment(i)[..len * 4]);
+        let floats = |i: usize, len: usize| bytemuck::cast_slice::<u8, f32>(&segment(i)[..len * 4]);
+        let nblocks = numel / 32;
 
-        for i in (0..(K * N)).step_by(pack_size) {
-            let packed_value = quantized_matrix[div_floor(i, pack_size)] as i32;
-            matrix[i] = ((packed_value << 28) >> 28) as f32 / 7.0 * absmax;
-            matrix[i + 1] = ((packed_value << 24) >> 28) as f32 / 7.0 * absmax;
-            matrix[i + 2] = ((packed_value << 20) >> 28) as f32 / 7.0 * absmax;
-            matrix[i + 3] = ((packed_value << 16) >> 28) as f32 / 7.0 * absmax;
-            matrix[i + 4] = ((packed_value << 12) >> 28) as f32 / 7.0 * absmax;
-            matrix[i + 5] = ((packed_value << 8) >> 28) as f32 / 7.0 * absmax;
-            matrix[i + 6] = ((packed_value << 4) >> 28) as f32 / 7.0 * absmax;
-            matrix[i + 7] = (packed_value >> 28) as f32 / 7.0 * absmax;
+        let mut dequantized = vec![0.0f32; numel];
+        if let GGUFDType::Q8_1(_) = dt {
+            let qs = bytemuck::cast_slice::<u8, i8>(&segment(0)[..numel]);
+            let ds = floats(1, nblocks);
+            for (i, y) in dequantized.iter_mut().enumerate() {
+                *y = qs[i] as f32 * ds[i / 32];
+            }
+            return dequantized;
         }
 
-        matrix
+        //| qs | qh? | d | m? |
+        let (qh, ds, ms) = match dt {
+            GGUFDType::Q4_0(_) => (None, floats(1, nblocks), None),
+            GGUFDType::Q4_1(_) => (None, floats(1, nblocks), Some(floats(2, nblocks))),
+            GGUFDType::Q5_0(_) => (Some(words(1, nblocks)), floats(2, nblocks), None),
+            GGUFDType::Q5_1(_) => (
+                Some(words(1, nblocks)),
+                floats(2, nblocks),
+                Some(floats(3, nblocks)),
+            ),
+            _ => unreachable!(),
+        };
+        //Without a min, quants are centered around zero
+        let offset = match (qh, ms) {
+            (_, Some(_)) => 0.,
+            (Some(_), None) => 16.,
+            (None, None) => 8.,
+        };
+
+        let qs = segment(0);
+        for (b, y) in dequantized.chunks_exact_mut(32).enumerate() {
+            //Low nibbles fill the first 16 elements, high nibbles the last 16
+            for l in 0..16 {
+                let q = qs[b * 16 + l] as u32;
+                let (mut x0, mut x1) = (q & 0xF, q >> 4);
+                if let Some(qh) = qh {
+                    x0 |= ((qh[b] >> l) & 1) << 4;
+                    x1 |= ((qh[b] >> (l + 16)) & 1) << 4;
+                }
+                let m = ms.map_or(0., |ms| ms[b]);
+                y[l] = (x0 as f32 - offset) * ds[b] + m;
+                y[l + 16] = (x1 as f32 - offset) * ds[b] + m;
+            }
+        }
+        dequantized
+    }
+
+    /// Quantizes a float 32 tensor into groups of signed 4 bit integers, see [SInt4].
+    pub fn sint4_quantize(&self, tensor: Tensor) -> Tensor {
+        self.sint4_pack(tensor, None)
+    }
+
+    fn sint4_pack(&self, tensor: Tensor, importance: Option<&[f32]>) -> Tensor {
+        let numel = tensor.shape().numel();
+        let group_size = self.format.group_size();
+
+        //Groups are whole words, and never share a vec4 when dequantized
+        assert_eq!(group_size % self.format.pack_size(), 0);
+        assert_eq!(numel % group_size, 0);
+        assert!(tensor.dt() == DType::F32); //TODO: f16, bf16
+
+        let matrix = tensor.to_vec::<f32>().unwrap();
+        let mut qs = vec![0u32; numel / 8];
+        let ds = Self::group_scales(&matrix, group_size, 7., importance);
+        for (g, group) in matrix.chunks_exact(group_size).enumerate() {
+            let id = if ds[g] == 0. { 0. } else { 1. / ds[g] };
+            for (i, &x) in group.iter().enumerate() {
+                let index = g * group_size + i;
+                let q = ((x * id).round().clamp(-7., 7.) as i32 & 0xF) as u32;
+                qs[index / 8] |= q << (4 * (index % 8));
+            }
+        }
+
+        let dt = SInt4::new(group_size);
+        let segments = dt.segments(numel);
+        let mut bytes = vec![0u8; DType::SInt4(dt).num_bytes(numel)];
+        for (segment, part) in segments.iter().zip([
+            bytemuck::cast_slice::<u32, u8>(&qs),
+            bytemuck::cast_slice::<f32, u8>(&ds),
+        ]) {
+            let start = segment.offset as usize;
+            bytes[start..start + part.len()].copy_from_slice(part);
+        }
+        Tensor::from_bytes(
+            &bytes,
+            DType::SInt4(dt),
+            tensor.shape().clone(),
+            Device::CPU,
+        )
+        .unwrap()
+    }
+
+    pub fn sint4_dequantize(&self, quantized: Tensor) -> Tensor {
+        let DType::SInt4(dt) = quantized.dt() else {
+            panic!("Expected SInt4, got {:?}", quantized.dt());
+        };
+        let numel = quantized.shape().numel();
+        let original_shape = quantized.shape().clone();
+        let raw_bytes = unsafe { quantized.into_bytes().unwrap() };
+        let dequantized = Self::sint4_dequantize_raw(dt, &raw_bytes, numel);
+        Tensor::from_data(dequantized, original_shape, Device::CPU)
+    }
+
+    /// Dequantizes the raw bytes of an SInt4 tensor, as laid out by [SInt4::segments].
+    pub(crate) fn sint4_dequantize_raw(dt: SInt4, raw_bytes: &[u8], numel: usize) -> Vec<f32> {
+        let segments = dt.segments(numel);
+        let segment = |i: usize| &raw_bytes[segments[i].offset as usize..];
+        let qs = bytemuck::cast_slice::<u8, u32>(&segment(0)[..numel / 2]);
+        let ds = bytemuck::cast_slice::<u8, f32>(&segment(1)[..numel / dt.group_size() * 4]);
+
+        (0..numel)
+            .map(|i| {
+                //Shift the nibble to the top, so it is sign extended on the way back down
+                let q = ((qs[i / 8] << (28 - 4 * (i % 8))) as i32) >> 28;
+                q as f32 * ds[i / dt.group_size()]
+            })
+            .collect()
     }
 }
 
@@ -180,7 +411,7 @@ impl Quantizer {
 pub enum Quantization {
     None,
     SInt8,
-    SInt4,
+    SInt4 { group_size: usize },
 }
 
 impl Quantization {
@@ -188,7 +419,7 @@ impl Quantization {
         match self {
             Quantization::None => 1,
             Quantization::SInt8 => 4,
-            Quantization::SInt4 => 8,
+            Quantization::SInt4 { .. } => 8,
         }
     }
 
@@ -196,18 +427,178 @@ impl Quantization {
         match self {
             Quantization::None => 1,
             Quantization::SInt8 => 32,
-            Quantization::SInt4 => 8,
+            Quantization::SInt4 { group_size } => *group_size,
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{shape, Device, Quantization, Quantizer, Tensor};
+    use crate::{
+        gguf::{GGUFDType, Q4K, Q4_0, Q5_1, Q6K},
+        shape, DType, Device, Quantization, Quantizer, SInt4, Segments, Tensor,
+    };
     #[test]
     pub fn test_sint8_qdq() {
         let ground = Tensor::randn::<f32>(shape![64, 64], Device::CPU);
         let quantizer = Quantizer::new(Quantization::SInt8);
         let _quantized = quantizer.sint8_quantize(ground.deep_clone());
     }
+
+    #[test]
+    pub fn test_sint4_qdq() {
+        let group_size = 32;
+        let ground = Tensor::randn::<f32>(shape![64, 128], Device::CPU);
+        let quantizer = Quantizer::new(Quantization::SInt4 { group_size });
+        let quantized = quantizer.quantize(ground.deep_clone());
+        assert_eq!(quantized.dt(), DType::SInt4(SInt4::new(group_size)));
+
+        let dequantized = quantizer
+            .sint4_dequantize(quantized)
+            .to_vec::<f32>()
+            .unwrap();
+        let ground = ground.to_vec::<f32>().unwrap();
+        for (x, y) in ground
+            .chunks(group_size)
+            .zip(dequantized.chunks(group_size))
+        {
+            //Rounding is off by at most half a step
+            let d = x.iter().fold(0f32, |acc, &x| acc.max(x.abs())) / 7.;
+            for (x, y) in x.iter().zip(y) {
+                assert!((x - y).abs() <= d / 2. + 1e-6, "{} {}", x, y);
+            }
+        }
+    }
+
+    #[test]
+    pub fn test_quantize_weighted() {
+        let ground = Tensor::randn::<f32>(shape![32, 128], Device::CPU);
+        //A handful of columns carry most of the activation energy
+        let importance = (0..128)
+            .map(|i| if i % 13 == 0 { 100. } else { 0.1 })
+            .collect::<Vec<f32>>();
+        let x = ground.to_vec::<f32>().unwrap();
+        let error = |y: Tensor| {
+            let y = y.to_vec::<f32>().unwrap();
+            x.iter()
+                .zip(y)
+                .enumerate()
+                .map(|(i, (x, y))| importance[i % 128] * (x - y).powi(2))
+                .sum::<f32>()
+        };
+
+        for format in [Quantization::SInt8, Quantization::SInt4 { group_size: 32 }] {
+            let quantizer = Quantizer::new(format);
+            let naive = quantizer.dequantize(quantizer.quantize(ground.deep_clone()));
+            let weighted = quantizer.quantize_weighted(ground.deep_clone(), &importance);
+            assert_eq!(weighted.dt(), quantizer.quantize(ground.deep_clone()).dt());
+            let weighted = quantizer.dequantize(weighted);
+            assert!(error(weighted) <= error(naive), "{:?}", format);
+        }
+    }
+
+    #[test]
+    pub fn test_quantize_weighted_straddling_groups() {
+        //Rows of 48 columns don't split into groups of 32
+        let ground = Tensor::randn::<f32>(shape![32, 48], Device::CPU);
+        let importance = vec![1.; 48];
+        let quantizer = Quantizer::new(Quantization::SInt4 { group_size: 32 });
+        let naive = quantizer.dequantize(quantizer.quantize(ground.deep_clone()));
+        let weighted = quantizer.quantize_weighted(ground, &importance);
+        let weighted = quantizer.dequantize(weighted);
+        assert_eq!(
+            naive.to_vec::<f32>().unwrap(),
+            weighted.to_vec::<f32>().unwrap()
+        );
+    }
+
+    /// Lays out a single block per segment, as the GGUF transcoders do.
+    fn pack(segments: &[crate::BufferSegment], parts: &[&[u8]]) -> Vec<u8> {
+        let last = segments.last().unwrap();
+        let mut bytes = vec![0u8; (last.offset + last.size.get()) as usize];
+        for (segment, part) in segments.iter().zip(parts) {
+            let start = segment.offset as usize;
+            bytes[start..start + part.len()].copy_from_slice(part);
+        }
+        bytes
+    }
+
+    #[test]
+    pub fn test_q4_0_dequantize() {
+        let d = 0.5f32;
+        let bytes = pack(&Q4_0::segments(32), &[&[0x21; 16], &d.to_le_bytes()]);
+
+        let dequantized = Quantizer::legacy_dequantize_raw(GGUFDType::Q4_0(Q4_0), &bytes, 32);
+        for (i, y) in dequantized.iter().enumerate() {
+            let q = if i < 16 { 1. } else { 2. };
+            assert_eq!(*y, (q - 8.) * d, "element {}", i);
+        }
+    }
+
+    #[test]
+    pub fn test_q5_1_dequantize() {
+        let (d, m) = (0.5f32, -1f32);
+        //The fifth bit is only set for the high nibbles
+        let qh = 0xFFFF0000u32;
+        let bytes = pack(
+            &Q5_1::segments(32),
+            &[
+                &[0x21; 16],
+                &qh.to_le_bytes(),
+                &d.to_le_bytes(),
+                &m.to_le_bytes(),
+            ],
+        );
+
+        let dequantized = Quantizer::legacy_dequantize_raw(GGUFDType::Q5_1(Q5_1), &bytes, 32);
+        for (i, y) in dequantized.iter().enumerate() {
+            let q = if i < 16 { 1. } else { 18. };
+            assert_eq!(*y, q * d + m, "element {}", i);
+        }
+    }
+
+    #[test]
+    pub fn test_q4k_dequantize() {
+        //Sub-blocks 0..4 hold their scales and mins in 6 bits, 4..8 split across the high bits
+        let scales = [1u8, 2, 3, 4, 0, 1, 2, 3, 0x05, 0x16, 0x27, 0x38];
+        let (sc, m) = (
+            [1., 2., 3., 4., 5., 6., 7., 8.],
+            [0., 1., 2., 3., 0., 1., 2., 3.],
+        );
+        let (d, dmin) = (0.5f32, 0.25f32);
+        let bytes = pack(
+            &Q4K::segments(256),
+            &[&d.to_le_bytes(), &dmin.to_le_bytes(), &scales, &[0x21; 128]],
+        );
+
+        let dequantized = Quantizer::q4k_dequantize_raw(&bytes, 256);
+        for (i, y) in dequantized.iter().enumerate() {
+            //Low nibbles fill the first 32 elements of every 64, high nibbles the rest
+            let (j, q) = if i % 64 < 32 {
+                (i / 64 * 2, 1.)
+            } else {
+                (i / 64 * 2 + 1, 2.)
+            };
+            assert_eq!(*y, d * sc[j] * q - dmin * m[j], "element {}", i);
+        }
+    }
+
+    #[test]
+    pub fn test_q6k_dequantize() {
+        let scales = (0..16).map(|s| (s as i8 - 8) as u8).collect::<Vec<_>>();
+        let d = 0.5f32;
+        //Each quarter of 32 elements takes the next 2 bits of qh
+        let bytes = pack(
+            &Q6K::segments(256),
+            &[&[0x21; 128], &[0b11100100; 64], &scales, &d.to_le_bytes()],
+        );
+        let q = [1. - 32., 17. - 32., 34. - 32., 50. - 32.];
+
+        let dequantized = Quantizer::q6k_dequantize_raw(&bytes, 256);
+        for (i, y) in dequantized.iter().enumerate() {
+            let quarter = i % 128 / 32;
+            let scale = (i / 128 * 8 + i % 32 / 16 + 2 * quarter) as f32 - 8.;
+            assert_eq!(*y, d * scale * q[quarter], "element {}", i);
+        }
+    }
 }
@@ -10,15 +10,38 @@ use wgpu::BufferUsages;
 
 use crate::DType;
 
-#[derive(Clone, Debug, derive_new::new)]
+#[derive(Clone, Debug)]
 pub struct GPUBuffer {
     pub(crate) inner: PooledGPUBuffer,
     pub(crate) alignment: usize,
+    /// The range of `inner` holding the data.
+    /// Activations are bound as sub-ranges of shared arenas, see [crate::gpu::MemoryPlan].
+    pub(crate) offset: wgpu::BufferAddress,
+    pub(crate) size: wgpu::BufferAddress,
 }
 
 impl GPUBuffer {
     const MIN_SIZE: usize = 16;
 
+    pub(crate) fn new(inner: PooledGPUBuffer, alignment: usize) -> Self {
+        let size = inner.size();
+        Self::slice(inner, alignment, 0, size)
+    }
+
+    pub(crate) fn slice(
+        inner: PooledGPUBuffer,
+        alignment: usize,
+        offset: wgpu::BufferAddress,
+        size: wgpu::BufferAddress,
+    ) -> Self {
+        Self {
+            inner,
+            alignment,
+            offset,
+            size,
+        }
+    }
+
     pub fn from_slice<T: NoUninit>(data: &[T], shape: &Shape, device: &WgpuDevice) -> Self {
         assert_eq!(data.len(), shape.numel());
         Self::from_bytes(
@@ -51,9 +74,19 @@ impl GPUBuffer {
     pub(crate) fn from_bytes(bytes: &[u8], alignment: usize, device: &WgpuDevice) -> Self {
         let num_bytes = bytes.len();
         let mut min_bytes = [0; Self::MIN_SIZE];
+        let padded_bytes;
         let bytes = if num_bytes < Self::MIN_SIZE {
             min_bytes[..num_bytes].copy_from_slice(bytes);
             &min_bytes
+        } else if num_bytes % wgpu::COPY_BUFFER_ALIGNMENT as usize != 0 {
+            //Writes must be a multiple of 4 bytes
+            let mut padded = bytes.to_vec();
+            padded.resize(
+                num_bytes.next_multiple_of(wgpu::COPY_BUFFER_ALIGNMENT as _),
+                0,
+            );
+            padded_bytes = padded;
+            &padded_bytes
         } else {
             bytes
         };
@@ -66,7 +99,7 @@ impl GPUBuffer {
             .unwrap();
         device.queue().submit(None);
         device.poll(wgpu::Maintain::Wait);
-        Self { inner, alignment }
+        Self::new(inner, alignment)
     }
 
     /// Returns true if the buffer has all the given usages.
@@ -85,23 +118,51 @@ impl GPUBuffer {
         self.inner.usage()
     }
 
+    pub fn offset(&self) -> wgpu::BufferAddress {
+        self.offset
+    }
+
     #[allow(unused)]
     pub fn deep_clone(&self, device: &WgpuDevice) -> Self {
         let clone = device
             .get_or_create_buffer(
-                &BufferDescriptor::new(self.inner.size(), self.inner.usage(), false),
+                &BufferDescriptor::new(self.size, self.inner.usage(), false),
                 true,
             )
             .unwrap();
         let mut encoder =
             device.create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
-        encoder.copy_buffer_to_buffer(&self.inner, 0, &clone, 0, self.inner.size());
+        encoder.copy_buffer_to_buffer(&self.inner, self.offset, &clone, 0, self.size);
         device.queue().submit(Some(encoder.finish()));
         device.poll(wgpu::Maintain::Wait);
-        Self {
-            inner: clone,
-            alignment: self.alignment,
-        }
+        Self::new(clone, self.alignment)
+    }
+
+    /// Creates a copy of the buffer on the CPU, without blocking the calling thread while the
+    /// GPU catches up.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn to_cpu_async(&self, device: &WgpuDevice) -> Result<CPUBuffer, DeviceError> {
+        self.validate_usages(BufferUsages::COPY_SRC)?;
+        let buffer_slice = self.inner.slice(self.offset..self.offset + self.size);
+        let (tx, rx) = futures_intrusive::channel::shared::oneshot_channel();
+        let alignment = self.alignment;
+
+        wgpu::util::DownloadBuffer::read_buffer(
+            device,
+            device.queue(),
+            &buffer_slice,
+            move |buffer| {
+                tx.send(match buffer {
+                    Ok(db) => Ok(CPUBuffer::from_bytes(&db, alignment)),
+                    Err(error) => Err(error),
+                })
+                .expect("Failed to send result of read_buffer");
+            },
+        );
+        //Mapping callbacks fire when the device is polled past the copy
+        let index = device.queue().submit(None);
+        device.on_submitted_work_done(index).await;
+        Ok(rx.receive().await.unwrap()?)
     }
 
     pub fn from_disk<T: TensorDType, R: std::io::BufRead + std::io::Seek>(
@@ -126,7 +187,7 @@ impl GPUBuffer {
     #[cfg(feature = "plotting")]
     pub fn plot_fmt(&self) -> String {
         let id_string = Self::trim_id(self.inner().global_id()).unwrap_or_default();
-        format!("GPU:#{}\n{} bytes", id_string, self.inner.size())
+        format!("GPU:#{}\n{} bytes", id_string, self.size)
     }
 }
 
@@ -140,7 +201,7 @@ impl DeviceStorage for GPUBuffer {
     async fn to_cpu(&self, device: &Device) -> Result<CPUBuffer, DeviceError> {
         self.validate_usages(BufferUsages::COPY_SRC)?;
         let device = device.try_gpu()?;
-        let buffer_slice = self.inner.slice(..);
+        let buffer_slice = self.inner.slice(self.offset..self.offset + self.size);
         let (tx, rx) = futures_intrusive::channel::shared::oneshot_channel();
         let alignment = self.alignment;
 
@@ -164,7 +225,7 @@ impl DeviceStorage for GPUBuffer {
     fn to_cpu(&self, device: &Device) -> Result<CPUBuffer, DeviceError> {
         self.validate_usages(BufferUsages::COPY_SRC)?;
         let device = device.try_gpu()?;
-        let buffer_slice = self.inner.slice(..);
+        let buffer_slice = self.inner.slice(self.offset..self.offset + self.size);
         let (tx, rx) = std::sync::mpsc::channel();
         let alignment = self.alignment;
 
@@ -187,14 +248,14 @@ impl DeviceStorage for GPUBuffer {
     }
 
     fn n_bytes(&self) -> usize {
-        self.inner.size() as usize
+        self.size as usize
     }
 
     fn dump(&self, _: DType, _: bool) -> String {
         let mut result = String::new();
         let id_string = Self::trim_id(self.inner().global_id()).unwrap_or_default();
         result.push_str(&format!("GPU Buffer #{}\n", id_string));
-        result.push_str(&format!("Size: {} bytes\n", self.inner.size()));
+        result.push_str(&format!("Size: {} bytes\n", self.size));
         result
     }
 }
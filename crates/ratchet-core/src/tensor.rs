@@ -1,16 +1,21 @@
-use crate::gpu::{BindGroupEntry, CpuUniform, WgpuDevice};
+use crate::fusion::FusionPlan;
+use crate::gpu::{BindGroupEntry, BufferDescriptor, CpuUniform, WgpuDevice};
 use crate::{
-    ops::*, rvec, CPUBuffer, CompiledOp, DType, Device, DeviceStorage, Executable, GPUBuffer,
-    InvariantError, LazyOp, MetaOperation, Operation, OperationError, RVec, RawCPUBuffer, Shape,
-    Storage, Strides, TensorDType, TensorId,
+    ops::*, rvec, shape, CPUBuffer, CPUOperation, CapturedExecutable, ChunkProgress, CompiledOp,
+    DType, Device, DeviceStorage, Executable, ExecutableCache, GPUBuffer, InvariantError, LazyOp,
+    MetaOperation, Operation, OperationError, RVec, RawCPUBuffer, Shape, Storage, Strides,
+    TensorDType, TensorId,
 };
 use derive_new::new;
 use parking_lot::{RwLock, RwLockReadGuard};
+use rustc_hash::{FxHashMap, FxHasher};
 use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
 use std::io::{BufRead, Seek};
-use std::ops::Bound;
+use std::ops::{Bound, ControlFlow};
 use std::path::Path;
 use std::sync::Arc;
+use wgpu::SubmissionIndex;
 
 #[cfg(feature = "rand")]
 use {rand::prelude::*, rand_distr::StandardNormal};
@@ -34,6 +39,17 @@ pub enum TensorError {
     TransferError,
     #[error(transparent)]
     OperationError(#[from] OperationError),
+    #[error(transparent)]
+    ExecutionError(#[from] crate::ExecutionError),
+    #[error("Tensor {0:?} is not a leaf of the graph being resolved")]
+    UnusedInput(TensorId),
+    #[error("Failed to compile {op} ({kernel_key}) for {id:?}: {source}")]
+    CompileError {
+        op: String,
+        kernel_key: String,
+        id: TensorId,
+        source: OperationError,
+    },
 }
 
 /// A multi-dimensional array of data.
@@ -75,7 +91,7 @@ impl Tensor {
         Arc::strong_count(&self.inner)
     }
 
-    fn update_storage(&self, storage: Storage) {
+    pub(crate) fn update_storage(&self, storage: Storage) {
         *self.inner.storage.write() = Some(storage);
     }
 }
@@ -189,9 +205,8 @@ impl Tensor {
         &self.view.strides
     }
 
-    //WARNING: very wrong for quantized types!
     pub fn num_bytes(&self) -> usize {
-        self.view.shape.numel() * self.view.dt.size_of()
+        self.view.dt.num_bytes(self.view.shape.numel())
     }
 
     pub fn device(&self) -> &Device {
@@ -260,6 +275,19 @@ macro_rules! impl_binary_op {
     };
 }
 
+macro_rules! impl_cmp_op {
+    ($method_name:ident, $op:expr) => {
+        #[allow(clippy::should_implement_trait)]
+        pub fn $method_name(self, other: Tensor) -> anyhow::Result<Tensor> {
+            let device = self.device.clone();
+            let [lhs, rhs] = Self::broadcast_operands([self, other])?;
+            let cmp = Cmp::new(lhs, rhs, $op);
+            let new_view = cmp.compute_view()?;
+            Ok(Tensor::lazy(LazyOp::Cmp(cmp), new_view, device))
+        }
+    };
+}
+
 macro_rules! impl_unary_op {
     ($method_name:ident, $op:expr) => {
         pub fn $method_name(self) -> anyhow::Result<Tensor> {
@@ -269,6 +297,36 @@ macro_rules! impl_unary_op {
             Ok(Tensor::lazy(LazyOp::Unary(unary), new_view, device))
         }
     };
+    ($method_name:ident, $op:path, $param:ident) => {
+        pub fn $method_name(self, $param: f32) -> anyhow::Result<Tensor> {
+            let device = self.device.clone();
+            let unary = Unary::new(self.clone(), $op($param));
+            let new_view = unary.compute_view()?;
+            Ok(Tensor::lazy(LazyOp::Unary(unary), new_view, device))
+        }
+    };
+}
+
+macro_rules! impl_reduce_op {
+    ($method_name:ident, $op:expr) => {
+        pub fn $method_name(self, axes: &[usize], keepdim: bool) -> anyhow::Result<Tensor> {
+            let device = self.device.clone();
+            let reduce = Reduce::new(self, $op, axes.into(), keepdim);
+            let new_view = reduce.compute_view()?;
+            Ok(Tensor::lazy(LazyOp::Reduce(reduce), new_view, device))
+        }
+    };
+}
+
+macro_rules! impl_arg_reduce_op {
+    ($method_name:ident, $op:expr) => {
+        pub fn $method_name(self, dim: usize, keepdim: bool) -> anyhow::Result<Tensor> {
+            let device = self.device.clone();
+            let reduce = Reduce::new(self, $op, rvec![dim], keepdim);
+            let new_view = reduce.compute_view()?;
+            Ok(Tensor::lazy(LazyOp::Reduce(reduce), new_view, device))
+        }
+    };
 }
 
 impl Tensor {
@@ -276,6 +334,14 @@ impl Tensor {
     impl_binary_op!(sub, BinaryOp::Sub);
     impl_binary_op!(mul, BinaryOp::Mul);
     impl_binary_op!(div, BinaryOp::Div);
+    impl_binary_op!(swiglu, BinaryOp::SwiGLU);
+
+    impl_cmp_op!(eq, CmpOp::Eq);
+    impl_cmp_op!(ne, CmpOp::Ne);
+    impl_cmp_op!(lt, CmpOp::Lt);
+    impl_cmp_op!(le, CmpOp::Le);
+    impl_cmp_op!(gt, CmpOp::Gt);
+    impl_cmp_op!(ge, CmpOp::Ge);
 
     impl_unary_op!(gelu, UnaryOp::Gelu);
     impl_unary_op!(tanh, UnaryOp::Tanh);
@@ -289,6 +355,104 @@ impl Tensor {
     impl_unary_op!(floor, UnaryOp::Floor);
     impl_unary_op!(ceil, UnaryOp::Ceil);
     impl_unary_op!(neg, UnaryOp::Neg);
+    impl_unary_op!(silu, UnaryOp::Silu);
+    impl_unary_op!(sigmoid, UnaryOp::Sigmoid);
+    impl_unary_op!(gelu_erf, UnaryOp::GeluErf);
+    impl_unary_op!(leaky_relu, UnaryOp::LeakyRelu, negative_slope);
+    impl_unary_op!(elu, UnaryOp::Elu, alpha);
+    impl_unary_op!(softplus, UnaryOp::Softplus);
+    impl_unary_op!(reciprocal, UnaryOp::Reciprocal);
+
+    impl_reduce_op!(sum, ReduceOp::Sum);
+    impl_reduce_op!(mean, ReduceOp::Mean);
+    impl_reduce_op!(max, ReduceOp::Max);
+    impl_reduce_op!(min, ReduceOp::Min);
+
+    impl_arg_reduce_op!(argmax, ReduceOp::ArgMax);
+    impl_arg_reduce_op!(argmin, ReduceOp::ArgMin);
+
+    /// # Where
+    ///
+    /// Elementwise select, taking `on_true` where `self` is non-zero and `on_false` elsewhere.
+    /// `self` should be a U32 tensor, as produced by the comparison operators.
+    pub fn where_cond(self, on_true: Tensor, on_false: Tensor) -> anyhow::Result<Tensor> {
+        let device = self.device.clone();
+        let [cond, on_true, on_false] = Self::broadcast_operands([self, on_true, on_false])?;
+        let where_cond = WhereCond::new(cond, on_true, on_false);
+        let new_view = where_cond.compute_view()?;
+        Ok(Tensor::lazy(
+            LazyOp::WhereCond(where_cond),
+            new_view,
+            device,
+        ))
+    }
+
+    /// Replaces every element of `self` where `mask` is non-zero with `value`.
+    pub fn masked_fill(self, mask: Tensor, value: f32) -> anyhow::Result<Tensor> {
+        let device = self.device.clone();
+        let fill = match self.dt() {
+            DType::F16 => Tensor::from_data([half::f16::from_f32(value)], shape![1], device),
+            _ => Tensor::from_data([value], shape![1], device),
+        };
+        mask.where_cond(fill, self)
+    }
+
+    /// Broadcasts every operand of an elementwise op to their common shape, as the kernels
+    /// index all of them at the output offset.
+    fn broadcast_operands<const N: usize>(operands: [Tensor; N]) -> anyhow::Result<[Tensor; N]> {
+        let shapes = operands.iter().map(|t| t.shape()).collect::<Vec<_>>();
+        let broadcasted = Shape::multi_broadcast(&shapes).ok_or_else(|| {
+            let failed = shapes.iter().map(|s| (*s).clone()).collect::<Vec<_>>();
+            InvariantError::BroadcastingFailed(failed)
+        })?;
+        let mut operands = operands
+            .into_iter()
+            .map(|t| {
+                if t.shape() == &broadcasted {
+                    Ok(t)
+                } else {
+                    t.broadcast_to(broadcasted.clone())
+                }
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?
+            .into_iter();
+        Ok(std::array::from_fn(|_| operands.next().unwrap()))
+    }
+
+    /// Converts the tensor to `dst_dt`. A no-op if the tensor is already of that type.
+    pub fn cast(self, dst_dt: DType) -> anyhow::Result<Tensor> {
+        if self.dt() == dst_dt {
+            return Ok(self);
+        }
+        let device = self.device.clone();
+        let cast = Cast::new(self, dst_dt);
+        let new_view = cast.compute_view()?;
+        Ok(Tensor::lazy(LazyOp::Cast(cast), new_view, device))
+    }
+
+    pub fn triu(self, k: i32) -> anyhow::Result<Tensor> {
+        let device = self.device.clone();
+        let trilu = Trilu::new(self, true, k);
+        let new_view = trilu.compute_view()?;
+        Ok(Tensor::lazy(LazyOp::Trilu(trilu), new_view, device))
+    }
+
+    pub fn tril(self, k: i32) -> anyhow::Result<Tensor> {
+        let device = self.device.clone();
+        let trilu = Trilu::new(self, false, k);
+        let new_view = trilu.compute_view()?;
+        Ok(Tensor::lazy(LazyOp::Trilu(trilu), new_view, device))
+    }
+
+    /// F32 `[q_len, kv_len]` additive mask, hiding every key after the position of each query,
+    /// where the queries are the last `q_len` of `kv_len` positions.
+    ///
+    /// Built on the device from a single element, rather than uploaded.
+    pub fn causal_mask(q_len: usize, kv_len: usize, device: &Device) -> anyhow::Result<Tensor> {
+        let neg_inf = Tensor::from_data([f32::NEG_INFINITY], shape![1], device.clone());
+        let diagonal = kv_len as i32 - q_len as i32 + 1;
+        neg_inf.broadcast_to(shape![q_len, kv_len])?.triu(diagonal)
+    }
 
     pub fn layer_norm(
         self,
@@ -303,6 +467,28 @@ impl Tensor {
         Ok(Tensor::lazy(op, new_view, device))
     }
 
+    pub fn rms_norm(self, weight: Tensor, eps: f32) -> anyhow::Result<Tensor> {
+        let device = self.device.clone();
+        let rms_norm = RMSNorm::new(self, weight, eps);
+        let new_view = rms_norm.compute_view()?;
+        let op = LazyOp::Norm(Norm::RMSNorm(rms_norm));
+        Ok(Tensor::lazy(op, new_view, device))
+    }
+
+    pub fn group_norm(
+        self,
+        num_groups: usize,
+        weight: Tensor,
+        bias: Tensor,
+        eps: f32,
+    ) -> anyhow::Result<Tensor> {
+        let device = self.device.clone();
+        let group_norm = GroupNorm::new(self, weight, bias, num_groups, eps);
+        let new_view = group_norm.compute_view()?;
+        let op = LazyOp::Norm(Norm::GroupNorm(group_norm));
+        Ok(Tensor::lazy(op, new_view, device))
+    }
+
     pub fn conv1d(
         self,
         weight: Tensor,
@@ -331,6 +517,96 @@ impl Tensor {
         Ok(Tensor::lazy(LazyOp::RoPE(rope), new_view, device))
     }
 
+    /// # Scaled Dot Product Attention
+    ///
+    /// `softmax(self @ k^T * scale + mask) @ v`, for `self` [B, H, Sq, D], `k` [B, H, Sk, D] and
+    /// `v` [B, H, Sk, Dv]. When `causal`, the queries are the last Sq positions of the sequence.
+    ///
+    /// Runs as a single fused op where possible, otherwise falls back to the decomposition.
+    pub fn sdpa(
+        self,
+        k: Tensor,
+        v: Tensor,
+        mask: Option<Tensor>,
+        scale: f32,
+        causal: bool,
+    ) -> anyhow::Result<Tensor> {
+        if !SDPA::is_fusable(&self, &k, &v, mask.as_ref(), causal) {
+            return self.sdpa_decomposed(k, v, mask, scale, causal);
+        }
+        let device = self.device.clone();
+        let sdpa = SDPA::new(self, k, v, mask, scale, causal);
+        let new_view = sdpa.compute_view()?;
+        Ok(Tensor::lazy(LazyOp::SDPA(sdpa), new_view, device))
+    }
+
+    /// [Tensor::sdpa], attending only to the first `kv_len` keys of `k` & `v`, such as the valid
+    /// entries of a [Tensor::cache_dynamic].
+    ///
+    /// `kv_len` is read at dispatch time, so a replayed graph may use a different length.
+    pub fn sdpa_dynamic(
+        self,
+        k: Tensor,
+        v: Tensor,
+        mask: Option<Tensor>,
+        scale: f32,
+        causal: bool,
+        kv_len: usize,
+    ) -> anyhow::Result<Tensor> {
+        if !SDPA::is_fusable(&self, &k, &v, mask.as_ref(), causal) {
+            let prefix = |t: Tensor| {
+                let mut ranges = t.shape().iter().map(|&d| 0..d).collect::<Vec<_>>();
+                ranges[2] = 0..kv_len;
+                t.slice(&ranges)
+            };
+            //The mask broadcasts against the scores [B, H, Sq, Sk], trim its keys alike
+            let mask = mask
+                .map(|m| {
+                    if !(2..=4).contains(&m.rank()) {
+                        return Err(OperationError::from(InvariantError::RankMismatch {
+                            accepted: 2..=4,
+                            actual: m.rank(),
+                        })
+                        .into());
+                    }
+                    let mut ranges = m.shape().iter().map(|&d| 0..d).collect::<Vec<_>>();
+                    *ranges.last_mut().unwrap() = 0..kv_len;
+                    m.slice(&ranges)
+                })
+                .transpose()?;
+            return self.sdpa_decomposed(prefix(k)?, prefix(v)?, mask, scale, causal);
+        }
+        let device = self.device.clone();
+        let sdpa = SDPA::new(self, k, v, mask, scale, causal).with_kv_len(kv_len);
+        let new_view = sdpa.compute_view()?;
+        Ok(Tensor::lazy(LazyOp::SDPA(sdpa), new_view, device))
+    }
+
+    pub(crate) fn sdpa_decomposed(
+        self,
+        k: Tensor,
+        v: Tensor,
+        mask: Option<Tensor>,
+        scale: f32,
+        causal: bool,
+    ) -> anyhow::Result<Tensor> {
+        let device = self.device.clone();
+        let dt = self.dt();
+        let (Sq, Sk) = (self.shape()[self.rank() - 2], k.shape()[k.rank() - 2]);
+
+        let scale = Tensor::from_data([scale], shape![1], device.clone()).cast(dt)?;
+        let mut scores = self.matmul(k, false, true)?.mul(scale)?;
+        if let Some(m) = mask {
+            scores = scores.add(m)?;
+        }
+        if causal {
+            let causal_mask = Tensor::causal_mask(Sq, Sk, &device)?.cast(dt)?;
+            scores = scores.add(causal_mask)?;
+        }
+        let dim = scores.rank() - 1;
+        scores.softmax(dim)?.matmul(v, false, false)
+    }
+
     //TODO: horrific interface
     pub fn matmul(self, rhs: Tensor, trans_lhs: bool, trans_rhs: bool) -> anyhow::Result<Tensor> {
         let device = self.device.clone();
@@ -420,6 +696,23 @@ impl Tensor {
         Ok(Tensor::lazy(LazyOp::Cache(cache), new_view, device))
     }
 
+    /// Like [Tensor::cache], but the result spans the entire cache, of which only the first
+    /// `offset` + source entries along `dim` are valid.
+    ///
+    /// The shape no longer depends on `offset`, so a graph built on it can be replayed from an
+    /// [ExecutableCache] at every decoding step, see [Tensor::sdpa_dynamic].
+    pub fn cache_dynamic(
+        self,
+        source: Tensor,
+        dim: usize,
+        offset: usize,
+    ) -> anyhow::Result<Tensor> {
+        let device = self.device.clone();
+        let cache = Cache::new(self, source, dim, offset).into_dynamic();
+        let new_view = cache.compute_view()?;
+        Ok(Tensor::lazy(LazyOp::Cache(cache), new_view, device))
+    }
+
     pub fn broadcast_to(self, shape: Shape) -> anyhow::Result<Tensor> {
         let device = self.device.clone();
         let broadcast = Broadcast::new(self, shape);
@@ -513,7 +806,7 @@ impl Tensor {
         shape: Shape,
         device: Device,
     ) -> anyhow::Result<Tensor> {
-        let storage = Storage::from_bytes(data, dt.size_of(), &device);
+        let storage = Storage::from_bytes(data, dt.alignment(), &device);
         let strides = Strides::from(&shape);
         let meta = StorageView::new(shape, dt, strides);
         Ok(Tensor::new(LazyOp::Const, meta, Some(storage), device))
@@ -580,7 +873,7 @@ impl Tensor {
         let handle = gpu_buf.inner().handle;
         let segments = self.dt().segments(self.shape().numel());
         segments.iter().fold(rvec![], |mut entries, segment| {
-            let (offset, size) = (segment.offset, segment.size);
+            let (offset, size) = (gpu_buf.offset() + segment.offset, segment.size);
             entries.push(BindGroupEntry {
                 handle,
                 offset,
@@ -602,42 +895,53 @@ impl Tensor {
     }
 
     pub(crate) fn execution_order(&self) -> Vec<&Tensor> {
+        Self::union_execution_order(&[self])
+    }
+
+    /// Topological order of the union of the graphs of `outputs`, with shared tensors appearing
+    /// only once.
+    pub(crate) fn union_execution_order<'a>(outputs: &[&'a Tensor]) -> Vec<&'a Tensor> {
         let mut done = HashSet::new();
         let mut pending = HashSet::new();
         let mut order = Vec::new();
 
-        let mut stack: Vec<(&Tensor, usize)> = vec![(self, 0)];
-        while let Some((cur_t, cur_src)) = stack.pop() {
-            let all_deps_done = cur_src == cur_t.op().srcs().len();
-
-            if all_deps_done {
-                done.insert(cur_t.id());
-                pending.remove(&cur_t.id());
-                order.push(cur_t);
+        for &output in outputs {
+            if done.contains(&output.id()) {
                 continue;
             }
-
-            let (srcs_with_deps, srcs_without_deps): (Vec<_>, Vec<_>) = cur_t
-                .op()
-                .srcs()
-                .iter()
-                .partition(|s| s.op().srcs().is_empty());
-
-            let all_srcs = srcs_with_deps
-                .into_iter()
-                .chain(srcs_without_deps)
-                .collect::<RVec<_>>();
-
-            let precursor: &Tensor = all_srcs[cur_src];
-
-            if done.contains(&precursor.id()) {
-                stack.push((cur_t, cur_src + 1));
-            } else if pending.contains(&precursor.id()) {
-                panic!("CYCLE");
-            } else {
-                pending.insert(precursor.id());
-                stack.push((cur_t, cur_src));
-                stack.push((precursor, 0));
+            let mut stack: Vec<(&Tensor, usize)> = vec![(output, 0)];
+            while let Some((cur_t, cur_src)) = stack.pop() {
+                let all_deps_done = cur_src == cur_t.op().srcs().len();
+
+                if all_deps_done {
+                    done.insert(cur_t.id());
+                    pending.remove(&cur_t.id());
+                    order.push(cur_t);
+                    continue;
+                }
+
+                let (srcs_with_deps, srcs_without_deps): (Vec<_>, Vec<_>) = cur_t
+                    .op()
+                    .srcs()
+                    .iter()
+                    .partition(|s| s.op().srcs().is_empty());
+
+                let all_srcs = srcs_with_deps
+                    .into_iter()
+                    .chain(srcs_without_deps)
+                    .collect::<RVec<_>>();
+
+                let precursor: &Tensor = all_srcs[cur_src];
+
+                if done.contains(&precursor.id()) {
+                    stack.push((cur_t, cur_src + 1));
+                } else if pending.contains(&precursor.id()) {
+                    panic!("CYCLE");
+                } else {
+                    pending.insert(precursor.id());
+                    stack.push((cur_t, cur_src));
+                    stack.push((precursor, 0));
+                }
             }
         }
 
@@ -649,34 +953,371 @@ impl Tensor {
         uniform: &mut CpuUniform,
         device: &WgpuDevice,
         can_inplace: bool,
-    ) -> Option<CompiledOp> {
+    ) -> Result<Option<CompiledOp>, TensorError> {
+        self.compile_op(self.op(), uniform, device, can_inplace)
+    }
+
+    /// Compiles `op` as the producer of this tensor, used when fusion replaces the tensor's own op.
+    pub(crate) fn compile_op(
+        &self,
+        op: &LazyOp,
+        uniform: &mut CpuUniform,
+        device: &WgpuDevice,
+        can_inplace: bool,
+    ) -> Result<Option<CompiledOp>, TensorError> {
+        let compiled = match op {
+            LazyOp::Binary(b) => self.compile_scoped(b, uniform, device, can_inplace),
+            LazyOp::GEMM(m) => self.compile_scoped(m, uniform, device, can_inplace),
+            LazyOp::Softmax(s) => self.compile_scoped(s, uniform, device, can_inplace),
+            LazyOp::RoPE(r) => self.compile_scoped(r, uniform, device, can_inplace),
+            LazyOp::Unary(u) => self.compile_scoped(u, uniform, device, can_inplace),
+            LazyOp::Reindex(r) => self.compile_scoped(r, uniform, device, can_inplace),
+            LazyOp::Concat(c) => self.compile_scoped(c, uniform, device, can_inplace),
+            LazyOp::Reduce(r) => self.compile_scoped(r, uniform, device, can_inplace),
+            LazyOp::Cmp(c) => self.compile_scoped(c, uniform, device, can_inplace),
+            LazyOp::WhereCond(w) => self.compile_scoped(w, uniform, device, can_inplace),
+            LazyOp::Cast(c) => self.compile_scoped(c, uniform, device, can_inplace),
+            LazyOp::SDPA(s) => self.compile_scoped(s, uniform, device, can_inplace),
+            LazyOp::Norm(n) => self.compile_scoped(n, uniform, device, can_inplace),
+            LazyOp::Conv(c) => self.compile_scoped(c, uniform, device, can_inplace),
+            LazyOp::Select(i) => self.compile_scoped(i, uniform, device, can_inplace),
+            LazyOp::IndexWrite(i) => self.compile_scoped(i, uniform, device, can_inplace),
+            LazyOp::Cache(c) => self.compile_scoped(c, uniform, device, can_inplace),
+            LazyOp::Trilu(t) => self.compile_scoped(t, uniform, device, can_inplace),
+            LazyOp::Const | LazyOp::View(_) => return Ok(None),
+        };
+        compiled.map(Some)
+    }
+
+    /// Compiles a single op. In checked mode it gets an error scope of its own, otherwise errors
+    /// raised by the device are caught by the scope around [Tensor::compile_gpu].
+    fn compile_scoped(
+        &self,
+        op: &impl MetaOperation,
+        uniform: &mut CpuUniform,
+        device: &WgpuDevice,
+        can_inplace: bool,
+    ) -> Result<CompiledOp, TensorError> {
+        //Popping a scope blocks on the device, which is too slow to do for every op
+        let checked = crate::gpu::checked();
+        if checked {
+            device.push_error_scope();
+        }
+        let compiled = op.compile(self, uniform, device, can_inplace);
+        let scoped = match checked {
+            true => device.pop_error_scope(),
+            false => Ok(()),
+        };
+        compiled
+            .and_then(|compiled| Ok(scoped.map(|_| compiled)?))
+            .map_err(|source| TensorError::CompileError {
+                op: op.kernel_name(),
+                kernel_key: op.kernel_key(can_inplace, self),
+                id: self.id(),
+                source,
+            })
+    }
+
+    /// Creates the pipeline of every op in the graph, without allocating or dispatching anything,
+    /// so that no shader is compiled when the graph, or one like it, is first resolved.
+    ///
+    /// Warming a model's graphs ahead of time, or warming a device from a
+    /// [crate::PipelineManifest] of a previous run, hides shader compilation from the first
+    /// inference.
+    pub fn warm(&self) -> Result<(), TensorError> {
+        let Device::GPU(device) = self.device() else {
+            return Ok(());
+        };
+        let execution_order = self.execution_order();
+        let fusion = FusionPlan::new(&execution_order);
+        device.push_error_scope();
+        let warmed = execution_order
+            .iter()
+            .filter(|t| !t.resolved() && !fusion.skips(t.id()))
+            .try_for_each(|t| match fusion.fused_op(t.id()) {
+                Some(op) => t.warm_op(op, device, t.can_inplace()),
+                None => t.warm_op(t.op(), device, t.can_inplace()),
+            });
+        let scoped = device.pop_error_scope();
+        warmed?;
+        scoped?;
+        Ok(())
+    }
+
+    fn warm_op(
+        &self,
+        op: &LazyOp,
+        device: &WgpuDevice,
+        can_inplace: bool,
+    ) -> Result<(), OperationError> {
+        match op {
+            LazyOp::Binary(b) => b.warm(self, device, can_inplace),
+            LazyOp::GEMM(m) => m.warm(self, device, can_inplace),
+            LazyOp::Softmax(s) => s.warm(self, device, can_inplace),
+            LazyOp::RoPE(r) => r.warm(self, device, can_inplace),
+            LazyOp::Unary(u) => u.warm(self, device, can_inplace),
+            LazyOp::Reindex(r) => r.warm(self, device, can_inplace),
+            LazyOp::Concat(c) => c.warm(self, device, can_inplace),
+            LazyOp::Reduce(r) => r.warm(self, device, can_inplace),
+            LazyOp::Cmp(c) => c.warm(self, device, can_inplace),
+            LazyOp::WhereCond(w) => w.warm(self, device, can_inplace),
+            LazyOp::Cast(c) => c.warm(self, device, can_inplace),
+            LazyOp::SDPA(s) => s.warm(self, device, can_inplace),
+            LazyOp::Norm(n) => n.warm(self, device, can_inplace),
+            LazyOp::Conv(c) => c.warm(self, device, can_inplace),
+            LazyOp::Select(i) => i.warm(self, device, can_inplace),
+            LazyOp::IndexWrite(i) => i.warm(self, device, can_inplace),
+            LazyOp::Cache(c) => c.warm(self, device, can_inplace),
+            LazyOp::Trilu(t) => t.warm(self, device, can_inplace),
+            LazyOp::Const | LazyOp::View(_) => Ok(()),
+        }
+    }
+
+    /// See [MetaOperation::signature], `None` if the tensor requires no dispatch.
+    fn signature(
+        &self,
+        op: &LazyOp,
+        uniform: &mut CpuUniform,
+        can_inplace: bool,
+    ) -> Result<Option<String>, OperationError> {
+        let signature = match op {
+            LazyOp::Binary(b) => b.signature(self, uniform, can_inplace),
+            LazyOp::GEMM(m) => m.signature(self, uniform, can_inplace),
+            LazyOp::Softmax(s) => s.signature(self, uniform, can_inplace),
+            LazyOp::RoPE(r) => r.signature(self, uniform, can_inplace),
+            LazyOp::Unary(u) => u.signature(self, uniform, can_inplace),
+            LazyOp::Reindex(r) => r.signature(self, uniform, can_inplace),
+            LazyOp::Concat(c) => c.signature(self, uniform, can_inplace),
+            LazyOp::Reduce(r) => r.signature(self, uniform, can_inplace),
+            LazyOp::Cmp(c) => c.signature(self, uniform, can_inplace),
+            LazyOp::WhereCond(w) => w.signature(self, uniform, can_inplace),
+            LazyOp::Cast(c) => c.signature(self, uniform, can_inplace),
+            LazyOp::SDPA(s) => s.signature(self, uniform, can_inplace),
+            LazyOp::Norm(n) => n.signature(self, uniform, can_inplace),
+            LazyOp::Conv(c) => c.signature(self, uniform, can_inplace),
+            LazyOp::Select(i) => i.signature(self, uniform, can_inplace),
+            LazyOp::IndexWrite(i) => i.signature(self, uniform, can_inplace),
+            LazyOp::Cache(c) => c.signature(self, uniform, can_inplace),
+            LazyOp::Trilu(t) => t.signature(self, uniform, can_inplace),
+            LazyOp::Const => return Ok(None),
+            LazyOp::View(_) => return Ok(None),
+        };
+        signature.map(Some)
+    }
+
+    /// Evaluates the operation that produces this tensor on the host.
+    /// All sources must already be resolved.
+    pub(crate) fn apply_cpu(&self) -> Result<Option<CPUBuffer>, OperationError> {
         match self.op() {
-            LazyOp::Binary(b) => b.compile(self, uniform, device, can_inplace).ok(),
-            LazyOp::GEMM(m) => m.compile(self, uniform, device, can_inplace).ok(),
-            LazyOp::Softmax(s) => s.compile(self, uniform, device, can_inplace).ok(),
-            LazyOp::RoPE(r) => r.compile(self, uniform, device, can_inplace).ok(),
-            LazyOp::Unary(u) => u.compile(self, uniform, device, can_inplace).ok(),
-            LazyOp::Reindex(r) => r.compile(self, uniform, device, can_inplace).ok(),
-            LazyOp::Concat(c) => c.compile(self, uniform, device, can_inplace).ok(),
-            LazyOp::Norm(n) => n.compile(self, uniform, device, can_inplace).ok(),
-            LazyOp::Conv(c) => c.compile(self, uniform, device, can_inplace).ok(),
-            LazyOp::Select(i) => i.compile(self, uniform, device, can_inplace).ok(),
-            LazyOp::IndexWrite(i) => i.compile(self, uniform, device, can_inplace).ok(),
-            LazyOp::Cache(c) => c.compile(self, uniform, device, can_inplace).ok(),
-            LazyOp::Const => None,
-            LazyOp::View(_) => None,
+            LazyOp::Binary(b) => b.apply_cpu(self).map(Some),
+            LazyOp::GEMM(m) => m.apply_cpu(self).map(Some),
+            LazyOp::Softmax(s) => s.apply_cpu(self).map(Some),
+            LazyOp::RoPE(r) => r.apply_cpu(self).map(Some),
+            LazyOp::Unary(u) => u.apply_cpu(self).map(Some),
+            LazyOp::Reindex(r) => r.apply_cpu(self).map(Some),
+            LazyOp::Concat(c) => c.apply_cpu(self).map(Some),
+            LazyOp::Reduce(r) => r.apply_cpu(self).map(Some),
+            LazyOp::Cmp(c) => c.apply_cpu(self).map(Some),
+            LazyOp::WhereCond(w) => w.apply_cpu(self).map(Some),
+            LazyOp::Cast(c) => c.apply_cpu(self).map(Some),
+            LazyOp::SDPA(s) => s.apply_cpu(self).map(Some),
+            LazyOp::Norm(n) => n.apply_cpu(self).map(Some),
+            LazyOp::Conv(c) => c.apply_cpu(self).map(Some),
+            LazyOp::Select(i) => i.apply_cpu(self).map(Some),
+            LazyOp::IndexWrite(i) => i.apply_cpu(self).map(Some),
+            LazyOp::Cache(c) => c.apply_cpu(self).map(Some),
+            LazyOp::Trilu(t) => t.apply_cpu(self).map(Some),
+            LazyOp::Const => Ok(None),
+            LazyOp::View(_) => Ok(None),
         }
     }
 
     pub fn resolve(self) -> Result<Tensor, TensorError> {
-        let mut uniform = CpuUniform::new();
+        match self.device() {
+            Device::CPU => self.resolve_cpu(),
+            Device::GPU(_) => self.resolve_gpu(),
+        }
+    }
+
+    fn resolve_cpu(self) -> Result<Tensor, TensorError> {
+        Self::apply_cpu_order(&self.execution_order())?;
+        Ok(self)
+    }
+
+    fn apply_cpu_order(execution_order: &[&Tensor]) -> Result<(), TensorError> {
+        for t in execution_order.iter() {
+            log::debug!("Applying: {:?}", t.op().name());
+            assert!(t.device().is_cpu());
+            if t.resolved() {
+                continue;
+            }
+
+            let buffer = t.apply_cpu()?.ok_or(TensorError::NoStorage(t.id()))?;
+            t.update_storage(Storage::CPU(buffer));
+        }
+        Ok(())
+    }
+
+    /// Only Cast can operate on F16 without `shader-f16`, as it falls back to packed kernels.
+    fn check_features(execution_order: &[&Tensor], device: &WgpuDevice) -> Result<(), TensorError> {
+        if device.supports_f16() {
+            return Ok(());
+        }
+        let needs_f16 = execution_order.iter().find(|t| {
+            !t.resolved()
+                && !matches!(t.op(), LazyOp::Cast(_) | LazyOp::View(_))
+                //Arg reductions read F16 without writing it
+                && (t.dt() == DType::F16 || t.op().srcs().iter().any(|s| s.dt() == DType::F16))
+        });
+        match needs_f16 {
+            Some(t) => Err(crate::DeviceError::MissingFeature(
+                wgpu::Features::SHADER_F16,
+                t.op().name(),
+            )
+            .into()),
+            None => Ok(()),
+        }
+    }
+
+    fn resolve_gpu(self) -> Result<Tensor, TensorError> {
         let device = self.device().try_gpu()?;
-        device.begin_pass();
+        let index = Self::submit_gpu(&self.execution_order(), &[&self], device)?;
+        device.poll(wgpu::MaintainBase::WaitForSubmissionIndex(index));
+        Ok(self)
+    }
+
+    /// Resolves every tensor in `outputs` in a single pass over the union of their graphs, so
+    /// that any subgraph they share is only computed once.
+    pub fn resolve_many(outputs: &[Tensor]) -> Result<Vec<Tensor>, TensorError> {
+        let Some(first) = outputs.first() else {
+            return Ok(vec![]);
+        };
+        if let Some(other) = outputs.iter().find(|t| t.device() != first.device()) {
+            let (expected, got) = (first.device().label(), other.device().label());
+            return Err(crate::DeviceError::DeviceMismatch(expected, got).into());
+        }
 
+        let outputs_ref = outputs.iter().collect::<Vec<_>>();
+        let execution_order = Self::union_execution_order(&outputs_ref);
+        match first.device() {
+            Device::CPU => Self::apply_cpu_order(&execution_order)?,
+            Device::GPU(device) => {
+                let index = Self::submit_gpu(&execution_order, &outputs_ref, device)?;
+                device.poll(wgpu::MaintainBase::WaitForSubmissionIndex(index));
+            }
+        }
+        Ok(outputs.to_vec())
+    }
+
+    /// Like [Tensor::resolve], but completes once the GPU has finished executing the graph,
+    /// rather than blocking the calling thread until it has.
+    pub async fn resolve_async(self) -> Result<Tensor, TensorError> {
+        let device = match self.device() {
+            Device::CPU => return self.resolve_cpu(),
+            Device::GPU(device) => device.clone(),
+        };
+        let index = Self::submit_gpu(&self.execution_order(), &[&self], &device)?;
+        device.on_submitted_work_done(index).await;
+        Ok(self)
+    }
+
+    /// Like [Tensor::resolve_async], but submits the graph `chunk_size` ops at a time, calling
+    /// `on_chunk` as each chunk completes. See [crate::Executable::dispatch_chunked].
+    ///
+    /// On the CPU the graph is resolved at once, and `on_chunk` is never called.
+    pub async fn resolve_chunked<F>(
+        self,
+        chunk_size: usize,
+        on_chunk: F,
+    ) -> Result<Tensor, TensorError>
+    where
+        F: FnMut(ChunkProgress) -> ControlFlow<()>,
+    {
+        let device = match self.device() {
+            Device::CPU => return self.resolve_cpu(),
+            Device::GPU(device) => device.clone(),
+        };
         let execution_order = self.execution_order();
+        device.begin_pass();
+        Self::check_features(&execution_order, &device)?;
+
+        let executable = Self::compile_gpu(&execution_order, &[&self], &device)?;
+        executable
+            .dispatch_chunked(&device, chunk_size, on_chunk)
+            .await?;
+        Ok(self)
+    }
+
+    /// Compiles the graph and submits it to the GPU, without waiting for it to execute.
+    fn submit_gpu(
+        execution_order: &[&Tensor],
+        outputs: &[&Tensor],
+        device: &WgpuDevice,
+    ) -> Result<SubmissionIndex, TensorError> {
+        device.begin_pass();
+        Self::check_features(execution_order, device)?;
+
+        let executable = Self::compile_gpu(execution_order, outputs, device)?;
+        Ok(executable.dispatch_operations(device)?)
+    }
+
+    /// Whether the op producing this tensor may write into the buffer of its first source.
+    fn can_inplace(&self) -> bool {
+        let op = self.op();
+        op.aliases_src() || (op.supports_inplace() && op.srcs()[0].strong_count() == 1)
+    }
+
+    /// Assigns a buffer to every unresolved tensor in `execution_order`, and compiles the ops
+    /// producing them into a single [Executable].
+    /// `outputs` are the tensors that must remain valid once it has been executed.
+    ///
+    /// Everything is compiled inside a single error scope. Pipelines are labelled with their
+    /// kernel key, so an error raised while creating one is attributed to the first op using it.
+    /// Set `RATCHET_CHECKED` to scope every op on its own instead.
+    fn compile_gpu(
+        execution_order: &[&Tensor],
+        outputs: &[&Tensor],
+        device: &WgpuDevice,
+    ) -> Result<Executable, TensorError> {
+        device.push_error_scope();
+        let executable = Self::compile_steps(execution_order, outputs, device);
+        let scoped = device.pop_error_scope();
+        let executable = executable?;
+        let Err(source) = scoped else {
+            return Ok(executable);
+        };
+
+        let message = source.to_string();
+        let culprit = executable
+            .steps()
+            .iter()
+            .find(|step| message.contains(&format!("`{}`", step.kernel_key())));
+        let Some(step) = culprit else {
+            return Err(source.into());
+        };
+        let op = execution_order
+            .iter()
+            .find(|t| t.id() == step.id())
+            .map(|t| t.op().name())
+            .unwrap_or_default();
+        Err(TensorError::CompileError {
+            op,
+            kernel_key: step.kernel_key().to_string(),
+            id: step.id(),
+            source: source.into(),
+        })
+    }
+
+    fn compile_steps(
+        execution_order: &[&Tensor],
+        outputs: &[&Tensor],
+        device: &WgpuDevice,
+    ) -> Result<Executable, TensorError> {
+        let mut uniform = CpuUniform::new();
+        let fusion = FusionPlan::new(execution_order);
 
         let mut compiled_ops = Vec::with_capacity(execution_order.len());
-        let mut allocations = device.allocate_cfg(&execution_order, device)?;
+        let mut allocations = device.allocate_cfg(execution_order, outputs, &fusion, device)?;
 
         for t in execution_order.iter() {
             log::debug!("Compiling: {:?}", t.op().name());
@@ -686,16 +1327,25 @@ impl Tensor {
             }
 
             let id = t.id();
-            let inner = allocations.remove(&id).ok_or(TensorError::NoStorage(id))?;
+            let allocation = allocations.remove(&id);
+            //Skipped tensors are never written, and only alias the buffer of their head, if any
+            if allocation.is_none() && fusion.skips(id) {
+                continue;
+            }
+            let allocation = allocation.ok_or(TensorError::NoStorage(id))?;
             t.update_storage(Storage::GPU(GPUBuffer {
-                inner,
-                alignment: t.dt().size_of(),
+                alignment: t.dt().alignment(),
+                ..allocation
             }));
 
-            let to_modify = t.op().srcs()[0];
-            let can_inplace = t.op().supports_inplace() && to_modify.strong_count() == 1;
-
-            if let Some(compiled_op) = t.compile(&mut uniform, device, can_inplace) {
+            if fusion.skips(id) {
+                continue;
+            }
+            let compiled_op = match fusion.fused_op(id) {
+                Some(op) => t.compile_op(op, &mut uniform, device, t.can_inplace()),
+                None => t.compile(&mut uniform, device, t.can_inplace()),
+            };
+            if let Some(compiled_op) = compiled_op? {
                 compiled_ops.push(compiled_op);
             }
         }
@@ -705,12 +1355,179 @@ impl Tensor {
             crate::plot::render_to_file(last, "allocations.svg").unwrap();
         }
 
-        let executable = Executable::new(compiled_ops, uniform.into_gpu(device)?);
-        let index = executable.dispatch_operations(device).unwrap();
+        Ok(Executable::new(compiled_ops, uniform.into_gpu(device)?))
+    }
+
+    /// Resolves the tensor through `cache`: the graph is captured the first time it is seen, and
+    /// replayed whenever a graph with the same structure is resolved again.
+    ///
+    /// `inputs` are the leaves whose contents change between calls, and are matched by position.
+    /// Leaves that only the graph holds, such as constants built by an op, are treated as inputs
+    /// too, see [Tensor::replay_inputs]. Every other leaf is baked into the captured executable,
+    /// so a graph reading from different weights is a different graph. Intermediate tensors are
+    /// not valid after a replay, only the returned tensor is.
+    pub fn resolve_cached(
+        self,
+        cache: &ExecutableCache,
+        inputs: &[&Tensor],
+    ) -> Result<Tensor, TensorError> {
+        let device = match self.device() {
+            Device::CPU => return self.resolve(),
+            Device::GPU(device) => device.clone(),
+        };
+        if self.resolved() {
+            return Ok(self);
+        }
+        device.begin_pass();
+
+        let execution_order = self.execution_order();
+        let inputs = Self::replay_inputs(&execution_order, inputs);
+        let (key, uniform) = Self::graph_key(&execution_order, &inputs)?;
+        let captured = match cache.get(key) {
+            Some(captured) => captured,
+            None => {
+                let captured = Arc::new(self.capture(&execution_order, &inputs, &device)?);
+                cache.insert(key, captured.clone());
+                captured
+            }
+        };
+
+        let storage = inputs.iter().map(|t| t.storage()).collect::<Vec<_>>();
+        let buffers = storage
+            .iter()
+            .map(|s| Ok(s.as_ref().ok_or(TensorError::NotResolved)?.try_gpu()?))
+            .collect::<Result<Vec<_>, TensorError>>()?;
+        let (result, index) = captured.replay(&buffers, uniform, &device)?;
         device.poll(wgpu::MaintainBase::WaitForSubmissionIndex(index));
+        self.update_storage(Storage::GPU(GPUBuffer::new(result, self.dt().alignment())));
+        drop(storage);
         Ok(self)
     }
 
+    /// `inputs`, followed by every other leaf that nothing outside of the graph holds on to.
+    ///
+    /// Those leaves are rebuilt on every call, like the scale & causal mask of
+    /// [Tensor::sdpa_decomposed], so keying on their identity would capture the graph anew at
+    /// every step. Instead they are matched by position, and copied in on every replay.
+    pub(crate) fn replay_inputs<'a>(
+        execution_order: &[&'a Tensor],
+        inputs: &[&'a Tensor],
+    ) -> Vec<&'a Tensor> {
+        let mut uses = FxHashMap::<TensorId, usize>::default();
+        for t in execution_order.iter().filter(|t| !t.resolved()) {
+            for src in t.op().srcs() {
+                *uses.entry(src.id()).or_default() += 1;
+            }
+        }
+        let owned_by_graph = |t: &&Tensor| {
+            t.resolved()
+                && !inputs.iter().any(|i| i.id() == t.id())
+                && uses.get(&t.id()) == Some(&t.strong_count())
+        };
+        let mut replay_inputs = inputs.to_vec();
+        replay_inputs.extend(execution_order.iter().copied().filter(owned_by_graph));
+        replay_inputs
+    }
+
+    /// Structural hash of a graph: the shape and op of every tensor, how they are connected, and
+    /// the identity of every leaf that isn't one of `inputs`.
+    ///
+    /// The metadata of each op is written to the returned uniform, exactly as compiling the graph
+    /// would. Only its layout is part of the key, as replaying a graph rewrites the uniform buffer.
+    /// This is what allows a single compiled decoder step to serve every offset into the KV cache.
+    pub(crate) fn graph_key(
+        execution_order: &[&Tensor],
+        inputs: &[&Tensor],
+    ) -> Result<(u64, CpuUniform), TensorError> {
+        let mut hasher = FxHasher::default();
+        let mut uniform = CpuUniform::new();
+        let fusion = FusionPlan::new(execution_order);
+        let positions = execution_order
+            .iter()
+            .enumerate()
+            .map(|(i, t)| (t.id(), i))
+            .collect::<FxHashMap<_, _>>();
+
+        for input in inputs {
+            if !input.resolved() {
+                return Err(TensorError::NotResolved);
+            }
+            if !positions.contains_key(&input.id()) {
+                return Err(TensorError::UnusedInput(input.id()));
+            }
+        }
+
+        for t in execution_order {
+            (t.shape(), t.strides(), t.dt(), t.strong_count() == 1).hash(&mut hasher);
+            if let Some(index) = inputs.iter().position(|i| i.id() == t.id()) {
+                index.hash(&mut hasher);
+            } else if t.resolved() {
+                t.id().hash(&mut hasher);
+            } else {
+                for src in t.op().srcs() {
+                    positions[&src.id()].hash(&mut hasher);
+                }
+                //Mirrors compile_gpu, so that the uniform is laid out identically
+                let signature = match fusion.fused_op(t.id()) {
+                    _ if fusion.skips(t.id()) => None,
+                    Some(op) => t.signature(op, &mut uniform, t.can_inplace())?,
+                    None => t.signature(t.op(), &mut uniform, t.can_inplace())?,
+                };
+                signature.hash(&mut hasher);
+            }
+        }
+        uniform.as_ref().len().hash(&mut hasher);
+        Ok((hasher.finish(), uniform))
+    }
+
+    /// Compiles the graph with `inputs` bound to static buffers owned by the executable, so
+    /// that they can be overwritten on every replay.
+    fn capture(
+        &self,
+        execution_order: &[&Tensor],
+        inputs: &[&Tensor],
+        device: &WgpuDevice,
+    ) -> Result<CapturedExecutable, TensorError> {
+        Self::check_features(execution_order, device)?;
+
+        let mut originals = Vec::with_capacity(inputs.len());
+        let mut statics = Vec::with_capacity(inputs.len());
+        for input in inputs {
+            let original = input
+                .storage()
+                .as_ref()
+                .ok_or(TensorError::NotResolved)?
+                .try_gpu()?
+                .clone();
+            let desc = BufferDescriptor::new(original.size, original.usage(), false);
+            let static_buffer = device.get_or_create_buffer(&desc, false)?;
+            input.update_storage(Storage::GPU(GPUBuffer::new(
+                static_buffer.clone(),
+                original.alignment,
+            )));
+            originals.push(original);
+            statics.push(static_buffer);
+        }
+
+        let executable = Self::compile_gpu(execution_order, &[self], device);
+        for (input, original) in inputs.iter().zip(originals) {
+            input.update_storage(Storage::GPU(original));
+        }
+
+        let output = self
+            .storage()
+            .as_ref()
+            .ok_or(TensorError::NoStorage(self.id()))?
+            .try_gpu()?
+            .clone();
+        Ok(CapturedExecutable::new(
+            executable?,
+            statics,
+            output,
+            device,
+        ))
+    }
+
     fn to_gpu(&self, dst_device: &Device) -> Result<Tensor, TensorError> {
         if self.device().is_gpu() || !self.resolved() {
             return Ok(self.clone());
@@ -794,6 +1611,36 @@ impl Tensor {
         }
     }
 
+    /// Like [Tensor::to], but doesn't block the calling thread while waiting on the GPU.
+    pub async fn to_async(&self, device: &Device) -> Result<Tensor, TensorError> {
+        match (self.device(), device) {
+            (Device::GPU(_), Device::CPU) => self.to_cpu_async().await,
+            (Device::CPU, Device::GPU(_)) => self.to_gpu(device),
+            _ => Ok(self.clone()),
+        }
+    }
+
+    async fn to_cpu_async(&self) -> Result<Tensor, TensorError> {
+        if self.device().is_cpu() || !self.resolved() {
+            log::warn!("Tensor may not have been resolved, try calling `resolve_async()` first.");
+            return Ok(self.clone());
+        }
+        let gpu_buf = self
+            .storage()
+            .as_ref()
+            .ok_or(TensorError::TransferError)?
+            .try_gpu()?
+            .clone();
+        let cpu_buf = gpu_buf.to_cpu_async(self.device.try_gpu()?).await?;
+
+        Ok(Tensor::new(
+            LazyOp::Const,
+            self.view.clone(),
+            Some(Storage::CPU(cpu_buf)),
+            Device::CPU,
+        ))
+    }
+
     fn to_cpu(&self) -> Result<Tensor, TensorError> {
         if self.device().is_cpu() || !self.resolved() {
             log::warn!("Tensor may not have been resolved, try calling `resolve()` first.");
@@ -989,3 +1836,88 @@ impl<T: TensorDType> From<ArrayD<T>> for Tensor {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{shape, Device, InvariantError, OperationError, Tensor};
+
+    fn heads(x: Tensor, w: Tensor) -> anyhow::Result<[Tensor; 3]> {
+        let h = x.matmul(w, false, false)?;
+        Ok([h.clone().gelu()?, h.clone().relu()?, h])
+    }
+
+    #[test]
+    fn test_union_execution_order() -> anyhow::Result<()> {
+        let x = Tensor::randn::<f32>(shape![8, 16], Device::CPU);
+        let w = Tensor::randn::<f32>(shape![16, 32], Device::CPU);
+        let [a, b, h] = heads(x, w)?;
+
+        let order = Tensor::union_execution_order(&[&a, &b, &h]);
+        //x, w, h, gelu & relu, with the shared matmul only once
+        assert_eq!(order.len(), 5);
+        let position = |t: &Tensor| order.iter().position(|o| o.id() == t.id()).unwrap();
+        assert!(position(&h) < position(&a) && position(&h) < position(&b));
+        Ok(())
+    }
+
+    #[test]
+    fn test_resolve_many() -> anyhow::Result<()> {
+        let x = Tensor::randn::<f32>(shape![8, 16], Device::CPU);
+        let w = Tensor::randn::<f32>(shape![16, 32], Device::CPU);
+        let outputs = Tensor::resolve_many(&heads(x.clone(), w.clone())?)?;
+        for (ours, ground) in outputs.iter().zip(heads(x, w)?) {
+            assert!(ours.resolved());
+            ground.resolve()?.all_close(ours, 1e-5, 1e-5)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_causal_mask() -> anyhow::Result<()> {
+        for (q_len, kv_len) in [(4, 4), (1, 7), (3, 7)] {
+            let ours = Tensor::causal_mask(q_len, kv_len, &Device::CPU)?.resolve()?;
+            let hidden = |i: usize, j: usize| j + q_len > i + kv_len;
+            let ground = (0..q_len)
+                .flat_map(|i| (0..kv_len).map(move |j| hidden(i, j)))
+                .map(|hidden| if hidden { f32::NEG_INFINITY } else { 0. })
+                .collect::<Vec<_>>();
+            assert_eq!(ours.shape(), &shape![q_len, kv_len]);
+            assert_eq!(ours.to_vec::<f32>()?, ground);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_sdpa_dynamic_mask_rank() -> anyhow::Result<()> {
+        //A 1D mask can't be fused, and can't be trimmed to the valid keys either
+        let q = Tensor::randn::<f32>(shape![1, 2, 3, 4], Device::CPU);
+        let cache = Tensor::randn::<f32>(shape![1, 2, 8, 4], Device::CPU);
+        let mask = Tensor::randn::<f32>(shape![8], Device::CPU);
+        let err = q
+            .sdpa_dynamic(cache.clone(), cache, Some(mask), 0.5, false, 5)
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<OperationError>(),
+            Some(OperationError::InvariantError(
+                InvariantError::RankMismatch { actual: 1, .. }
+            ))
+        ));
+        Ok(())
+    }
+
+    #[cfg(feature = "pyo3")]
+    #[test]
+    fn test_resolve_many_gpu() -> anyhow::Result<()> {
+        use crate::DeviceRequest;
+
+        let device = Device::request_device(DeviceRequest::GPU)?;
+        let x = Tensor::randn::<f32>(shape![33, 64], Device::CPU);
+        let w = Tensor::randn::<f32>(shape![64, 48], Device::CPU);
+        let ground = Tensor::resolve_many(&heads(x.clone(), w.clone())?)?;
+        let ours = Tensor::resolve_many(&heads(x.to(&device)?, w.to(&device)?)?)?;
+        for (ground, ours) in ground.iter().zip(ours) {
+            ground.all_close(&ours.to(&Device::CPU)?, 1e-4, 1e-4)?;
+        }
+        Ok(())
+    }
+}
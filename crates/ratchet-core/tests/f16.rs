@@ -0,0 +1,149 @@
+#![cfg(target_arch = "wasm32")]
+//! F16 kernels, checked against F32 on the CPU.
+//!
+//! naga can't parse `enable f16;` yet, so only browsers grant `shader-f16` and these run in the
+//! browser rather than alongside the native op tests.
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+use ratchet::{shape, DType, Device, DeviceRequest, Tensor};
+use wasm_bindgen_test::*;
+
+async fn gpu_device() -> Device {
+    let device = Device::request_device(DeviceRequest::GPU).await.unwrap();
+    assert!(
+        device.try_gpu().unwrap().supports_f16(),
+        "{:?} lacks shader-f16",
+        device
+    );
+    device
+}
+
+/// Runs `f` in F32 on the CPU, and with every input cast to F16 on `device`, then checks that
+/// both agree within `atol` and `rtol`.
+async fn check_f16<F>(device: &Device, inputs: &[Tensor], f: F, atol: f32, rtol: f32)
+where
+    F: Fn(&[Tensor]) -> anyhow::Result<Tensor>,
+{
+    let ground = f(inputs).unwrap().resolve().unwrap();
+    let mut halves = vec![];
+    for t in inputs {
+        halves.push(t.to(device).await.unwrap().cast(DType::F16).unwrap());
+    }
+    let ours = f(&halves).unwrap();
+    assert_eq!(ours.dt(), DType::F16);
+    let ours = ours.cast(DType::F32).unwrap().resolve().unwrap();
+    let ours = ours.to(&Device::CPU).await.unwrap();
+    ground.all_close(&ours, atol, rtol).unwrap();
+}
+
+#[wasm_bindgen_test]
+async fn test_hgemm() {
+    let device = gpu_device().await;
+    //Unaligned shapes take the scalar kernels, aligned ones the vectorized kernels
+    for [m, k, n] in [[33, 70, 47], [64, 128, 96]] {
+        let inputs = [
+            Tensor::randn::<f32>(shape![2, m, k], Device::CPU),
+            Tensor::randn::<f32>(shape![2, n, k], Device::CPU),
+            Tensor::randn::<f32>(shape![n], Device::CPU),
+        ];
+        let matmul = |t: &[Tensor]| {
+            t[0].clone()
+                .gemm(t[1].clone(), Some(t[2].clone()), false, true, false)
+        };
+        check_f16(&device, &inputs, matmul, 1e-1, 1e-2).await;
+    }
+}
+
+#[wasm_bindgen_test]
+async fn test_hgemv() {
+    let device = gpu_device().await;
+    let inputs = [
+        Tensor::randn::<f32>(shape![1, 1000, 256], Device::CPU),
+        Tensor::randn::<f32>(shape![1, 1, 256], Device::CPU),
+        Tensor::randn::<f32>(shape![1000], Device::CPU),
+    ];
+    let gemv = |t: &[Tensor]| {
+        t[0].clone()
+            .gemm(t[1].clone(), Some(t[2].clone()), false, true, true)
+    };
+    check_f16(&device, &inputs, gemv, 1e-1, 1e-2).await;
+}
+
+#[wasm_bindgen_test]
+async fn test_softmax_f16() {
+    let device = gpu_device().await;
+    for n in [256, 131] {
+        let inputs = [Tensor::randn::<f32>(shape![3, 19, n], Device::CPU)];
+        check_f16(&device, &inputs, |t| t[0].clone().softmax(2), 1e-3, 1e-2).await;
+    }
+}
+
+#[wasm_bindgen_test]
+async fn test_sdpa_f16() {
+    let device = gpu_device().await;
+    //Prefill with a mask, then a causal decode step against a longer past
+    for (sq, sk, masked) in [(17, 17, true), (1, 40, false)] {
+        let mut inputs = vec![
+            Tensor::randn::<f32>(shape![1, 4, sq, 64], Device::CPU),
+            Tensor::randn::<f32>(shape![1, 4, sk, 64], Device::CPU),
+            Tensor::randn::<f32>(shape![1, 4, sk, 64], Device::CPU),
+        ];
+        if masked {
+            inputs.push(Tensor::randn::<f32>(shape![sq, sk], Device::CPU));
+        }
+        let sdpa = |t: &[Tensor]| {
+            let mask = t.get(3).cloned();
+            t[0].clone()
+                .sdpa(t[1].clone(), t[2].clone(), mask, 0.125, !masked)
+        };
+        check_f16(&device, &inputs, sdpa, 2e-2, 1e-2).await;
+    }
+}
+
+#[wasm_bindgen_test]
+async fn test_norm_f16() {
+    let device = gpu_device().await;
+    for n in [256, 130, 129] {
+        let inputs = [
+            Tensor::randn::<f32>(shape![2, 17, n], Device::CPU),
+            Tensor::randn::<f32>(shape![n], Device::CPU),
+            Tensor::randn::<f32>(shape![n], Device::CPU),
+        ];
+        let layer_norm = |t: &[Tensor]| {
+            t[0].clone()
+                .layer_norm(t[1].clone(), Some(t[2].clone()), 1e-5)
+        };
+        check_f16(&device, &inputs, layer_norm, 2e-2, 1e-2).await;
+        let rms_norm = |t: &[Tensor]| t[0].clone().rms_norm(t[1].clone(), 1e-5);
+        check_f16(&device, &inputs, rms_norm, 2e-2, 1e-2).await;
+    }
+}
+
+#[wasm_bindgen_test]
+async fn test_elementwise_f16() {
+    let device = gpu_device().await;
+    let inputs = [
+        Tensor::randn::<f32>(shape![2, 3, 66], Device::CPU),
+        Tensor::randn::<f32>(shape![1, 66], Device::CPU),
+    ];
+    let binary = |t: &[Tensor]| t[0].clone().mul(t[1].clone())?.add(t[1].clone());
+    check_f16(&device, &inputs, binary, 1e-2, 1e-2).await;
+    let unary = |t: &[Tensor]| t[0].clone().gelu();
+    check_f16(&device, &inputs, unary, 5e-2, 5e-2).await;
+    let mask = (0..66u32).map(|i| i % 3 / 2).collect::<Vec<_>>();
+    let masked = |t: &[Tensor]| {
+        let mask = Tensor::from_data(&mask, shape![1, 66], t[0].device().clone());
+        t[0].clone().masked_fill(mask, -1e4)
+    };
+    check_f16(&device, &inputs, masked, 1e-3, 1e-3).await;
+}
+
+#[wasm_bindgen_test]
+async fn test_reindex_reduce_f16() {
+    let device = gpu_device().await;
+    let inputs = [Tensor::randn::<f32>(shape![2, 3, 17, 32], Device::CPU)];
+    let permute = |t: &[Tensor]| t[0].clone().permute(&[0, 2, 3, 1]);
+    check_f16(&device, &inputs, permute, 1e-3, 1e-3).await;
+    let sum = |t: &[Tensor]| t[0].clone().sum(&[3], false);
+    check_f16(&device, &inputs, sum, 1e-1, 1e-2).await;
+}
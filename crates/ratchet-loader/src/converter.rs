@@ -7,6 +7,141 @@ use ratchet::{Device, NDArrayExt, Quantization, Quantizer, Tensor};
 
 use crate::ggml::GGMLCompatible;
 
+/// # Calibration
+///
+/// Per channel activation statistics, gathered by running sample inputs through the model on
+/// any device and observing what flows into each weight, e.g with
+/// `ratchet_nn::record_linear_inputs` and [Calibration::observe_recorded].
+///
+/// During conversion, they weight the rounding error of every input channel when choosing
+/// quantization scales, so that channels with large activations are kept precise. A weight
+/// whose weighted relative error still exceeds `max_error` is left unquantized.
+#[derive(Debug, Clone)]
+pub struct Calibration {
+    stats: HashMap<String, ChannelStats>,
+    max_error: f32,
+}
+
+#[derive(Debug, Clone)]
+struct ChannelStats {
+    sum_sq: Vec<f32>,
+    count: usize,
+}
+
+impl Calibration {
+    pub fn new(max_error: f32) -> Self {
+        Self {
+            stats: HashMap::new(),
+            max_error,
+        }
+    }
+
+    /// Records `activations`, the input of the weight named `name`, whose last dimension holds
+    /// the input channels. The tensor must be resolved.
+    pub fn observe(&mut self, name: &str, activations: &Tensor) -> anyhow::Result<()> {
+        let channels = activations.shape()[activations.rank() - 1];
+        let data = activations.to(&Device::CPU)?.to_vec::<f32>()?;
+        let stats = self
+            .stats
+            .entry(name.to_string())
+            .or_insert_with(|| ChannelStats {
+                sum_sq: vec![0.; channels],
+                count: 0,
+            });
+        anyhow::ensure!(
+            stats.sum_sq.len() == channels,
+            "{} observed with {} channels, previously {}",
+            name,
+            channels,
+            stats.sum_sq.len()
+        );
+        for row in data.chunks_exact(channels) {
+            for (acc, x) in stats.sum_sq.iter_mut().zip(row) {
+                *acc += x * x;
+            }
+        }
+        stats.count += data.len() / channels;
+        Ok(())
+    }
+
+    /// Records the inputs of `(weight, input)` pairs gathered while scheduling a model, naming
+    /// each weight with `name_of`, e.g [crate::ggml::GGMLModel::name_of]. Weights without a name
+    /// are ignored. The inputs are resolved together, so that shared subgraphs run only once.
+    pub fn observe_recorded<F>(
+        &mut self,
+        recorded: Vec<(Tensor, Tensor)>,
+        name_of: F,
+    ) -> anyhow::Result<()>
+    where
+        F: Fn(&Tensor) -> Option<String>,
+    {
+        let (names, inputs): (Vec<_>, Vec<_>) = recorded
+            .into_iter()
+            .filter_map(|(weight, input)| Some((name_of(&weight)?, input)))
+            .unzip();
+        let inputs = Tensor::resolve_many(&inputs)?;
+        for (name, input) in names.iter().zip(inputs) {
+            self.observe(name, &input)?;
+        }
+        Ok(())
+    }
+
+    /// Mean squared activation of each input channel of `name`, if it was observed.
+    pub fn importance(&self, name: &str) -> Option<Vec<f32>> {
+        let stats = self.stats.get(name).filter(|s| s.count > 0)?;
+        Some(
+            stats
+                .sum_sq
+                .iter()
+                .map(|s| s / stats.count as f32)
+                .collect(),
+        )
+    }
+
+    /// Quantizes `tensor` with the statistics of `name`, or returns it untouched if that loses
+    /// too much. Unobserved tensors are quantized naively.
+    pub fn quantize(
+        &self,
+        quantizer: &Quantizer,
+        name: &str,
+        tensor: Tensor,
+    ) -> anyhow::Result<Tensor> {
+        let Some(importance) = self.importance(name) else {
+            return Ok(quantizer.quantize(tensor));
+        };
+        if importance.len() != tensor.shape()[tensor.rank() - 1] {
+            log::warn!("Calibration of {} doesn't match its shape, ignoring", name);
+            return Ok(quantizer.quantize(tensor));
+        }
+
+        let quantized = quantizer.quantize_weighted(tensor.deep_clone(), &importance);
+        let dequantized = quantizer.dequantize(quantized.deep_clone());
+        let error = Self::relative_error(&tensor, &dequantized, &importance)?;
+        Ok(if error > self.max_error {
+            log::info!(
+                "Keeping {} unquantized, relative error {:.2e} > {:.2e}",
+                name,
+                error,
+                self.max_error
+            );
+            tensor
+        } else {
+            quantized
+        })
+    }
+
+    fn relative_error(ground: &Tensor, ours: &Tensor, importance: &[f32]) -> anyhow::Result<f32> {
+        let (ground, ours) = (ground.to_vec::<f32>()?, ours.to_vec::<f32>()?);
+        let (mut error, mut energy) = (0., 0.);
+        for (i, (x, y)) in ground.iter().zip(ours).enumerate() {
+            let w = importance[i % importance.len()];
+            error += w * (x - y).powi(2);
+            energy += w * x * x;
+        }
+        Ok(if energy == 0. { 0. } else { error / energy })
+    }
+}
+
 pub struct Converter;
 
 impl Converter {
@@ -16,6 +151,7 @@ impl Converter {
         dst_quant: Quantization,
         to_quant: HashSet<&str>,
         to_pad: HashMap<&str, Vec<[usize; 2]>>,
+        calibration: Option<&Calibration>,
     ) -> anyhow::Result<()> {
         let mut reader = std::io::BufReader::new(std::fs::File::open(src_path).unwrap());
         let src = M::load_ggml(&mut reader)?;
@@ -37,7 +173,10 @@ impl Converter {
 
             let to_write = if to_quant.iter().any(|suffix| name.ends_with(suffix)) {
                 log::info!("Quantizing {}", name);
-                quantizer.quantize(maybe_padded)
+                match calibration {
+                    Some(calibration) => calibration.quantize(&quantizer, name, maybe_padded)?,
+                    None => quantizer.quantize(maybe_padded),
+                }
             } else {
                 maybe_padded
             };
@@ -48,3 +187,86 @@ impl Converter {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Calibration;
+    use ratchet::{shape, Device, Quantization, Quantizer, Tensor};
+
+    #[test]
+    fn test_observe_importance() -> anyhow::Result<()> {
+        let mut calibration = Calibration::new(1.);
+        let a = Tensor::from_data([1., -2., 3., 0., 2., 1.], shape![2, 3], Device::CPU);
+        let b = Tensor::from_data([-1., 2., 0.], shape![1, 1, 3], Device::CPU);
+        calibration.observe("w", &a)?;
+        calibration.observe("w", &b)?;
+
+        assert_eq!(
+            calibration.importance("w"),
+            Some(vec![2. / 3., 4., 10. / 3.])
+        );
+        assert_eq!(calibration.importance("other"), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_observe_shape_mismatch() -> anyhow::Result<()> {
+        let mut calibration = Calibration::new(1.);
+        calibration.observe("w", &Tensor::randn::<f32>(shape![4, 8], Device::CPU))?;
+        let mismatched = Tensor::randn::<f32>(shape![4, 16], Device::CPU);
+        assert!(calibration.observe("w", &mismatched).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_observe_recorded() -> anyhow::Result<()> {
+        let w = Tensor::randn::<f32>(shape![8, 4], Device::CPU);
+        let unnamed = Tensor::randn::<f32>(shape![8, 4], Device::CPU);
+        let x = Tensor::randn::<f32>(shape![2, 4], Device::CPU);
+        //Recorded inputs are lazy
+        let input = x.clone().mul(x.clone())?;
+        let recorded = vec![(w.clone(), input.clone()), (unnamed, input)];
+
+        let mut calibration = Calibration::new(1.);
+        calibration.observe_recorded(recorded, |t| (t.id() == w.id()).then(|| "w".to_string()))?;
+
+        let mut expected = Calibration::new(1.);
+        expected.observe("w", &x.clone().mul(x)?.resolve()?)?;
+        assert_eq!(calibration.importance("w"), expected.importance("w"));
+        assert_eq!(calibration.stats.len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_max_error_fallback() -> anyhow::Result<()> {
+        let quantizer = Quantizer::new(Quantization::SInt4 { group_size: 32 });
+        let w = Tensor::randn::<f32>(shape![32, 64], Device::CPU);
+        let x = Tensor::randn::<f32>(shape![16, 64], Device::CPU);
+
+        //4 bits can't reach a relative error of 1e-6, but can reach 1
+        let mut strict = Calibration::new(1e-6);
+        strict.observe("w", &x)?;
+        let kept = strict.quantize(&quantizer, "w", w.deep_clone())?;
+        assert_eq!(kept.dt(), w.dt());
+        assert_eq!(kept.to_vec::<f32>()?, w.to_vec::<f32>()?);
+
+        let mut lenient = Calibration::new(1.);
+        lenient.observe("w", &x)?;
+        let quantized = lenient.quantize(&quantizer, "w", w.deep_clone())?;
+        assert_ne!(quantized.dt(), w.dt());
+        Ok(())
+    }
+
+    #[test]
+    fn test_quantize_shape_mismatch() -> anyhow::Result<()> {
+        let quantizer = Quantizer::new(Quantization::SInt8);
+        let w = Tensor::randn::<f32>(shape![32, 64], Device::CPU);
+        let mut calibration = Calibration::new(1e-6);
+        calibration.observe("w", &Tensor::randn::<f32>(shape![4, 32], Device::CPU))?;
+
+        //Mismatched statistics are ignored, and the weight is quantized naively
+        let quantized = calibration.quantize(&quantizer, "w", w.deep_clone())?;
+        assert_eq!(quantized.dt(), quantizer.quantize(w).dt());
+        Ok(())
+    }
+}
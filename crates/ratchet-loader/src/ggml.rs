@@ -1,7 +1,7 @@
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
-use ratchet::{Device, Shape, Tensor};
+use ratchet::{Device, Shape, Tensor, TensorId};
 use std::{
-    cell::Cell,
+    cell::{Cell, RefCell},
     collections::HashMap,
     io::{BufRead, Seek, SeekFrom},
     mem::MaybeUninit,
@@ -115,6 +115,8 @@ pub struct GGMLModel<M: GGMLCompatible> {
     pub tensors: HashMap<String, TensorHeader>,
     pub total_bytes_loaded: Cell<usize>,
     pub total_loaded: Cell<usize>,
+    /// The key of every tensor loaded so far.
+    names: RefCell<HashMap<TensorId, String>>,
 }
 
 impl<M: GGMLCompatible> GGMLModel<M> {
@@ -124,9 +126,15 @@ impl<M: GGMLCompatible> GGMLModel<M> {
             tensors,
             total_bytes_loaded: Cell::new(0),
             total_loaded: Cell::new(0),
+            names: RefCell::new(HashMap::new()),
         }
     }
 
+    /// The key `tensor` was loaded from, if it was loaded from this model.
+    pub fn name_of(&self, tensor: &Tensor) -> Option<String> {
+        self.names.borrow().get(&tensor.id()).cloned()
+    }
+
     pub fn load_tensor<R: BufRead + Seek>(
         &self,
         key: &str,
@@ -138,8 +146,10 @@ impl<M: GGMLCompatible> GGMLModel<M> {
         })?;
         let data = header.read_data(reader)?;
         let shape = header.shape.clone();
-        ratchet_from_gguf(header.dtype, &data, shape, device)
-            .map_err(|e| LoadError::InvalidFormat(0)) //bad bad error
+        let tensor = ratchet_from_gguf(header.dtype, &data, shape, device)
+            .map_err(|e| LoadError::InvalidFormat(0))?; //bad bad error
+        self.names.borrow_mut().insert(tensor.id(), key.to_string());
+        Ok(tensor)
     }
 }
 
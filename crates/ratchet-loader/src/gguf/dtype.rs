@@ -74,7 +74,7 @@ impl GGUFInterop for Q4K {
             &ds_bytes,
             DType::GGUF(GGUFDType::Q4K(Q4K)),
             shape,
-            Device::CPU,
+            device.clone(),
         )
     }
 }
@@ -115,7 +115,7 @@ impl GGUFInterop for Q6K {
             &ql_bytes,
             DType::GGUF(GGUFDType::Q6K(Q6K)),
             shape,
-            Device::CPU,
+            device.clone(),
         )
     }
 }
@@ -158,6 +158,170 @@ impl GGUFInterop for Q8_0 {
     }
 }
 
+/// Aligns each segment to the storage buffer alignment, and lays them out one after the other.
+fn concat_segments(segments: Vec<Vec<u8>>) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(segments.iter().map(|s| s.len()).sum());
+    for mut segment in segments {
+        let _ = segment.align_standard();
+        bytes.append(&mut segment);
+    }
+    bytes
+}
+
+impl GGUFInterop for Q4_0 {
+    type GGUF_TYPE = BlockQ4_0;
+    const BLCK_NUMEL: usize = QK4_0;
+    const TYPE_SIZE_WEBGPU: usize = Self::TYPE_SIZE + 2;
+
+    fn transcode(
+        data: &[Self::GGUF_TYPE],
+        n_blocks: usize,
+        shape: Shape,
+        device: &Device,
+    ) -> anyhow::Result<Tensor> {
+        let mut qs_bytes = Vec::with_capacity(n_blocks * QK4_0 / 2);
+        let mut d_bytes = Vec::with_capacity(n_blocks * 4);
+
+        for block in data {
+            qs_bytes.extend_from_slice(&block.qs);
+            d_bytes.extend_from_slice(bytemuck::bytes_of(&block.d.to_f32()));
+        }
+
+        let bytes = concat_segments(vec![qs_bytes, d_bytes]);
+        Tensor::from_bytes(
+            &bytes,
+            DType::GGUF(GGUFDType::Q4_0(Q4_0)),
+            shape,
+            device.clone(),
+        )
+    }
+}
+
+impl GGUFInterop for Q4_1 {
+    type GGUF_TYPE = BlockQ4_1;
+    const BLCK_NUMEL: usize = QK4_1;
+    const TYPE_SIZE_WEBGPU: usize = Self::TYPE_SIZE + 4;
+
+    fn transcode(
+        data: &[Self::GGUF_TYPE],
+        n_blocks: usize,
+        shape: Shape,
+        device: &Device,
+    ) -> anyhow::Result<Tensor> {
+        let mut qs_bytes = Vec::with_capacity(n_blocks * QK4_1 / 2);
+        let mut d_bytes = Vec::with_capacity(n_blocks * 4);
+        let mut m_bytes = Vec::with_capacity(n_blocks * 4);
+
+        for block in data {
+            qs_bytes.extend_from_slice(&block.qs);
+            d_bytes.extend_from_slice(bytemuck::bytes_of(&block.d.to_f32()));
+            m_bytes.extend_from_slice(bytemuck::bytes_of(&block.m.to_f32()));
+        }
+
+        let bytes = concat_segments(vec![qs_bytes, d_bytes, m_bytes]);
+        Tensor::from_bytes(
+            &bytes,
+            DType::GGUF(GGUFDType::Q4_1(Q4_1)),
+            shape,
+            device.clone(),
+        )
+    }
+}
+
+impl GGUFInterop for Q5_0 {
+    type GGUF_TYPE = BlockQ5_0;
+    const BLCK_NUMEL: usize = QK5_0;
+    const TYPE_SIZE_WEBGPU: usize = Self::TYPE_SIZE + 2;
+
+    fn transcode(
+        data: &[Self::GGUF_TYPE],
+        n_blocks: usize,
+        shape: Shape,
+        device: &Device,
+    ) -> anyhow::Result<Tensor> {
+        let mut qs_bytes = Vec::with_capacity(n_blocks * QK5_0 / 2);
+        let mut qh_bytes = Vec::with_capacity(n_blocks * 4);
+        let mut d_bytes = Vec::with_capacity(n_blocks * 4);
+
+        for block in data {
+            qs_bytes.extend_from_slice(&block.qs);
+            qh_bytes.extend_from_slice(&block.qh);
+            d_bytes.extend_from_slice(bytemuck::bytes_of(&block.d.to_f32()));
+        }
+
+        let bytes = concat_segments(vec![qs_bytes, qh_bytes, d_bytes]);
+        Tensor::from_bytes(
+            &bytes,
+            DType::GGUF(GGUFDType::Q5_0(Q5_0)),
+            shape,
+            device.clone(),
+        )
+    }
+}
+
+impl GGUFInterop for Q5_1 {
+    type GGUF_TYPE = BlockQ5_1;
+    const BLCK_NUMEL: usize = QK5_1;
+    const TYPE_SIZE_WEBGPU: usize = Self::TYPE_SIZE + 4;
+
+    fn transcode(
+        data: &[Self::GGUF_TYPE],
+        n_blocks: usize,
+        shape: Shape,
+        device: &Device,
+    ) -> anyhow::Result<Tensor> {
+        let mut qs_bytes = Vec::with_capacity(n_blocks * QK5_1 / 2);
+        let mut qh_bytes = Vec::with_capacity(n_blocks * 4);
+        let mut d_bytes = Vec::with_capacity(n_blocks * 4);
+        let mut m_bytes = Vec::with_capacity(n_blocks * 4);
+
+        for block in data {
+            qs_bytes.extend_from_slice(&block.qs);
+            qh_bytes.extend_from_slice(&block.qh);
+            d_bytes.extend_from_slice(bytemuck::bytes_of(&block.d.to_f32()));
+            m_bytes.extend_from_slice(bytemuck::bytes_of(&block.m.to_f32()));
+        }
+
+        let bytes = concat_segments(vec![qs_bytes, qh_bytes, d_bytes, m_bytes]);
+        Tensor::from_bytes(
+            &bytes,
+            DType::GGUF(GGUFDType::Q5_1(Q5_1)),
+            shape,
+            device.clone(),
+        )
+    }
+}
+
+impl GGUFInterop for Q8_1 {
+    type GGUF_TYPE = BlockQ8_1;
+    const BLCK_NUMEL: usize = QK8_1;
+    //The block sum is dropped, making up for the wider scale
+    const TYPE_SIZE_WEBGPU: usize = Self::TYPE_SIZE;
+
+    fn transcode(
+        data: &[Self::GGUF_TYPE],
+        n_blocks: usize,
+        shape: Shape,
+        device: &Device,
+    ) -> anyhow::Result<Tensor> {
+        let mut qs_bytes = Vec::with_capacity(n_blocks * QK8_1);
+        let mut d_bytes = Vec::with_capacity(n_blocks * 4);
+
+        for block in data {
+            qs_bytes.extend_from_slice(bytemuck::cast_slice(&block.qs));
+            d_bytes.extend_from_slice(bytemuck::bytes_of(&block.d.to_f32()));
+        }
+
+        let bytes = concat_segments(vec![qs_bytes, d_bytes]);
+        Tensor::from_bytes(
+            &bytes,
+            DType::GGUF(GGUFDType::Q8_1(Q8_1)),
+            shape,
+            device.clone(),
+        )
+    }
+}
+
 impl GGUFInterop for f32 {
     type GGUF_TYPE = f32;
     const BLCK_NUMEL: usize = 1;
@@ -190,3 +354,218 @@ impl GGUFInterop for f16 {
         Ok(Tensor::from_data(f32_data, shape, device.clone()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratchet::shape;
+
+    /// Arbitrary but deterministic quants, every bit pattern of which is valid.
+    fn quants<const N: usize>(seed: usize) -> [u8; N] {
+        std::array::from_fn(|i| ((i * 37 + seed * 101 + 11) % 256) as u8)
+    }
+
+    fn scale(seed: usize) -> f16 {
+        f16::from_f32(0.01 * (seed + 1) as f32)
+    }
+
+    /// Transcodes 4 blocks laid out as 2 rows, and dequantizes them by selecting every row on
+    /// the CPU.
+    fn check<I: GGUFInterop>(blocks: &[I::GGUF_TYPE], expected: Vec<f32>) -> anyhow::Result<()> {
+        let shape = shape![2, blocks.len() / 2 * I::BLCK_NUMEL];
+        let transcoded = I::transcode(blocks, blocks.len(), shape.clone(), &Device::CPU)?;
+        let rows = Tensor::from_data(vec![0i32, 1], shape![2], Device::CPU);
+        let ours = transcoded.index_select(rows, 0)?.resolve()?;
+        let ground = Tensor::from_data(expected, shape, Device::CPU);
+        ground.all_close(&ours, 1e-5, 1e-5)
+    }
+
+    //The references below follow ggml's dequantize_row_* for each format.
+
+    fn legacy_reference(d: f16, m: Option<f16>, values: impl Fn(usize) -> i32) -> Vec<f32> {
+        let (d, m) = (d.to_f32(), m.map(f16::to_f32).unwrap_or(0.));
+        (0..32).map(|j| values(j) as f32 * d + m).collect()
+    }
+
+    fn nibble(qs: &[u8; 16], j: usize) -> i32 {
+        (if j < 16 { qs[j] & 0xF } else { qs[j - 16] >> 4 }) as i32
+    }
+
+    fn fifth_bit(qh: &[u8; 4], j: usize) -> i32 {
+        ((u32::from_le_bytes(*qh) >> j & 1) << 4) as i32
+    }
+
+    #[test]
+    fn test_transcode_q4_0() -> anyhow::Result<()> {
+        let blocks = (0..4)
+            .map(|b| BlockQ4_0 {
+                d: scale(b),
+                qs: quants(b),
+            })
+            .collect::<Vec<_>>();
+        let expected = blocks
+            .iter()
+            .flat_map(|b| legacy_reference(b.d, None, |j| nibble(&b.qs, j) - 8))
+            .collect();
+        check::<Q4_0>(&blocks, expected)
+    }
+
+    #[test]
+    fn test_transcode_q4_1() -> anyhow::Result<()> {
+        let blocks = (0..4)
+            .map(|b| BlockQ4_1 {
+                d: scale(b),
+                m: scale(b + 7),
+                qs: quants(b),
+            })
+            .collect::<Vec<_>>();
+        let expected = blocks
+            .iter()
+            .flat_map(|b| legacy_reference(b.d, Some(b.m), |j| nibble(&b.qs, j)))
+            .collect();
+        check::<Q4_1>(&blocks, expected)
+    }
+
+    #[test]
+    fn test_transcode_q5_0() -> anyhow::Result<()> {
+        let blocks = (0..4)
+            .map(|b| BlockQ5_0 {
+                d: scale(b),
+                qh: quants(b + 3),
+                qs: quants(b),
+            })
+            .collect::<Vec<_>>();
+        let expected = blocks
+            .iter()
+            .flat_map(|b| {
+                legacy_reference(b.d, None, |j| (nibble(&b.qs, j) | fifth_bit(&b.qh, j)) - 16)
+            })
+            .collect();
+        check::<Q5_0>(&blocks, expected)
+    }
+
+    #[test]
+    fn test_transcode_q5_1() -> anyhow::Result<()> {
+        let blocks = (0..4)
+            .map(|b| BlockQ5_1 {
+                d: scale(b),
+                m: scale(b + 7),
+                qh: quants(b + 3),
+                qs: quants(b),
+            })
+            .collect::<Vec<_>>();
+        let expected = blocks
+            .iter()
+            .flat_map(|b| {
+                legacy_reference(b.d, Some(b.m), |j| nibble(&b.qs, j) | fifth_bit(&b.qh, j))
+            })
+            .collect();
+        check::<Q5_1>(&blocks, expected)
+    }
+
+    #[test]
+    fn test_transcode_q8_0() -> anyhow::Result<()> {
+        let blocks = (0..4)
+            .map(|b| BlockQ8_0 {
+                d: scale(b),
+                qs: quants(b).map(|q: u8| q as i8),
+            })
+            .collect::<Vec<_>>();
+        let expected = blocks
+            .iter()
+            .flat_map(|b| legacy_reference(b.d, None, |j| b.qs[j] as i32))
+            .collect();
+        check::<Q8_0>(&blocks, expected)
+    }
+
+    #[test]
+    fn test_transcode_q8_1() -> anyhow::Result<()> {
+        let blocks = (0..4)
+            .map(|b| BlockQ8_1 {
+                d: scale(b),
+                s: scale(b + 7),
+                qs: quants(b).map(|q: u8| q as i8),
+            })
+            .collect::<Vec<_>>();
+        let expected = blocks
+            .iter()
+            .flat_map(|b| legacy_reference(b.d, None, |j| b.qs[j] as i32))
+            .collect();
+        check::<Q8_1>(&blocks, expected)
+    }
+
+    #[test]
+    fn test_transcode_q4k() -> anyhow::Result<()> {
+        let blocks = (0..4)
+            .map(|b| BlockQ4K {
+                d: scale(b),
+                dmin: scale(b + 7),
+                scales: quants(b + 3),
+                qs: quants(b),
+            })
+            .collect::<Vec<_>>();
+        let scale_min = |q: &[u8; K_SCALE_SIZE], j: usize| {
+            if j < 4 {
+                (q[j] & 63, q[j + 4] & 63)
+            } else {
+                (
+                    (q[j + 4] & 0xF) | ((q[j - 4] >> 6) << 4),
+                    (q[j + 4] >> 4) | ((q[j] >> 6) << 4),
+                )
+            }
+        };
+        let expected = blocks
+            .iter()
+            .flat_map(|b| {
+                let (d, dmin) = (b.d.to_f32(), b.dmin.to_f32());
+                (0..QK_K / 64).flat_map(move |chunk| {
+                    let q = &b.qs[chunk * 32..chunk * 32 + 32];
+                    let (sc1, m1) = scale_min(&b.scales, chunk * 2);
+                    let (sc2, m2) = scale_min(&b.scales, chunk * 2 + 1);
+                    let low = q
+                        .iter()
+                        .map(move |&q| d * sc1 as f32 * (q & 0xF) as f32 - dmin * m1 as f32);
+                    let high = q
+                        .iter()
+                        .map(move |&q| d * sc2 as f32 * (q >> 4) as f32 - dmin * m2 as f32);
+                    low.chain(high)
+                })
+            })
+            .collect();
+        check::<Q4K>(&blocks, expected)
+    }
+
+    #[test]
+    fn test_transcode_q6k() -> anyhow::Result<()> {
+        let blocks = (0..4)
+            .map(|b| BlockQ6K {
+                ql: quants(b),
+                qh: quants(b + 3),
+                scales: quants(b + 5).map(|s: u8| s as i8),
+                d: scale(b),
+            })
+            .collect::<Vec<_>>();
+        let expected = blocks
+            .iter()
+            .flat_map(|b| {
+                let mut y = vec![0f32; QK_K];
+                for n in 0..QK_K / 128 {
+                    let (ql, qh) = (&b.ql[n * 64..], &b.qh[n * 32..]);
+                    let (sc, y) = (&b.scales[n * 8..], &mut y[n * 128..]);
+                    for l in 0..32 {
+                        let is = l / 16;
+                        let q =
+                            |low: u8, shift: usize| (low | ((qh[l] >> shift) & 3) << 4) as i32 - 32;
+                        let d = |i: usize| b.d.to_f32() * sc[is + i] as f32;
+                        y[l] = d(0) * q(ql[l] & 0xF, 0) as f32;
+                        y[l + 32] = d(2) * q(ql[l + 32] & 0xF, 2) as f32;
+                        y[l + 64] = d(4) * q(ql[l] >> 4, 4) as f32;
+                        y[l + 96] = d(6) * q(ql[l + 32] >> 4, 6) as f32;
+                    }
+                }
+                y
+            })
+            .collect();
+        check::<Q6K>(&blocks, expected)
+    }
+}
@@ -8,7 +8,10 @@ use super::dtype::GGUFInterop;
 use crate::{error::Result, GgmlDType};
 
 use byteorder::{LittleEndian, ReadBytesExt};
-use ratchet::{gguf::Q8_0, Device, Shape, Tensor};
+use ratchet::{
+    gguf::{Q4K, Q4_0, Q4_1, Q5_0, Q5_1, Q6K, Q8_0, Q8_1},
+    Device, Shape, Tensor,
+};
 use std::collections::HashMap;
 use std::ops::Range;
 
@@ -129,7 +132,14 @@ pub fn ratchet_from_gguf(
     match ggml_dtype {
         GgmlDType::F32 => from_raw_data::<f32>(raw_data, size_in_bytes, shape, device),
         GgmlDType::F16 => from_raw_data::<half::f16>(raw_data, size_in_bytes, shape, device),
+        GgmlDType::Q4_0 => from_raw_data::<Q4_0>(raw_data, size_in_bytes, shape, device),
+        GgmlDType::Q4_1 => from_raw_data::<Q4_1>(raw_data, size_in_bytes, shape, device),
+        GgmlDType::Q5_0 => from_raw_data::<Q5_0>(raw_data, size_in_bytes, shape, device),
+        GgmlDType::Q5_1 => from_raw_data::<Q5_1>(raw_data, size_in_bytes, shape, device),
         GgmlDType::Q8_0 => from_raw_data::<Q8_0>(raw_data, size_in_bytes, shape, device),
+        GgmlDType::Q8_1 => from_raw_data::<Q8_1>(raw_data, size_in_bytes, shape, device),
+        GgmlDType::Q4K => from_raw_data::<Q4K>(raw_data, size_in_bytes, shape, device),
+        GgmlDType::Q6K => from_raw_data::<Q6K>(raw_data, size_in_bytes, shape, device),
         _ => anyhow::bail!("unsupported ggml dtype {ggml_dtype:?}"),
     }
 }
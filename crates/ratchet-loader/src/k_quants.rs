@@ -148,11 +148,31 @@ impl GGType for BlockQ4_0 {
     const BLCK_NUMEL: usize = QK4_0;
 }
 
+impl GGType for BlockQ4_1 {
+    const DTYPE: GgmlDType = GgmlDType::Q4_1;
+    const BLCK_NUMEL: usize = QK4_1;
+}
+
+impl GGType for BlockQ5_0 {
+    const DTYPE: GgmlDType = GgmlDType::Q5_0;
+    const BLCK_NUMEL: usize = QK5_0;
+}
+
+impl GGType for BlockQ5_1 {
+    const DTYPE: GgmlDType = GgmlDType::Q5_1;
+    const BLCK_NUMEL: usize = QK5_1;
+}
+
 impl GGType for BlockQ8_0 {
     const DTYPE: GgmlDType = GgmlDType::Q8_0;
     const BLCK_NUMEL: usize = QK8_0;
 }
 
+impl GGType for BlockQ8_1 {
+    const DTYPE: GgmlDType = GgmlDType::Q8_1;
+    const BLCK_NUMEL: usize = QK8_1;
+}
+
 impl GGType for f32 {
     const DTYPE: GgmlDType = GgmlDType::F32;
     const BLCK_NUMEL: usize = 1;
@@ -5,7 +5,7 @@ pub mod gguf;
 mod k_quants;
 
 pub use converter::*;
-use ratchet::gguf::{GGUFDType, Q8_0};
+use ratchet::gguf::{GGUFDType, Q4K, Q4_0, Q4_1, Q5_0, Q5_1, Q6K, Q8_0, Q8_1};
 
 pub const STORAGE_BUFFER_ALIGN: usize = 256;
 
@@ -53,7 +53,14 @@ impl From<GgmlDType> for ratchet::DType {
         match val {
             GgmlDType::F32 => ratchet::DType::F32,
             GgmlDType::F16 => ratchet::DType::F16,
+            GgmlDType::Q4_0 => ratchet::DType::GGUF(GGUFDType::Q4_0(Q4_0)),
+            GgmlDType::Q4_1 => ratchet::DType::GGUF(GGUFDType::Q4_1(Q4_1)),
+            GgmlDType::Q5_0 => ratchet::DType::GGUF(GGUFDType::Q5_0(Q5_0)),
+            GgmlDType::Q5_1 => ratchet::DType::GGUF(GGUFDType::Q5_1(Q5_1)),
             GgmlDType::Q8_0 => ratchet::DType::GGUF(GGUFDType::Q8_0(Q8_0)),
+            GgmlDType::Q8_1 => ratchet::DType::GGUF(GGUFDType::Q8_1(Q8_1)),
+            GgmlDType::Q4K => ratchet::DType::GGUF(GGUFDType::Q4K(Q4K)),
+            GgmlDType::Q6K => ratchet::DType::GGUF(GGUFDType::Q6K(Q6K)),
             _ => unimplemented!(),
         }
     }
@@ -0,0 +1,53 @@
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+/// A handle to stop a long running task, such as a transcription, early.
+///
+/// Clones share their state, so one can be handed to the task while another is kept to cancel it.
+/// Tasks check the token between steps, and return what they have produced so far.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+impl CancellationToken {
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(constructor))]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    /// Returns a token sharing this one's state.
+    /// Tokens passed to wasm are consumed, so JS keeps the original and passes a handle.
+    pub fn handle(&self) -> CancellationToken {
+        self.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CancellationToken;
+
+    #[test]
+    fn handles_share_state() {
+        let token = CancellationToken::new();
+        let handle = token.handle();
+        assert!(!handle.is_cancelled());
+        token.cancel();
+        assert!(handle.is_cancelled());
+    }
+}
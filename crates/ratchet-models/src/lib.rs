@@ -1,7 +1,10 @@
+mod cancellation;
 pub mod phi2;
 pub mod registry;
 pub mod whisper;
 
+pub use cancellation::CancellationToken;
+
 #[cfg(target_arch = "wasm32")]
 #[derive(Debug, derive_new::new)]
 pub struct WebTensor {
@@ -15,7 +15,7 @@ pub struct PhiSelfAttention {
     o: Linear,
     rope: RotaryEmbedding,
     n_heads: u32,
-    softmax_scale: Tensor,
+    softmax_scale: f32,
     n_kv_heads: u32,
 }
 
@@ -30,7 +30,7 @@ impl PhiSelfAttention {
             let key = format!("blk.{}.{}", layer_index, name);
             disk_model.tensor(reader, &key, device)
         };
-        Self::load_inner(disk_model, lt, device)
+        Self::load_inner(disk_model, lt)
     }
 
     #[cfg(target_arch = "wasm32")]
@@ -47,10 +47,10 @@ impl PhiSelfAttention {
                 .ok_or_else(|| anyhow::anyhow!("missing tensor"))?;
             ratchet_from_gguf_web(tensor, device)
         };
-        Self::load_inner(header, lt, device)
+        Self::load_inner(header, lt)
     }
 
-    fn load_inner<F>(header: &Header, mut lt: F, device: &Device) -> anyhow::Result<Self>
+    fn load_inner<F>(header: &Header, mut lt: F) -> anyhow::Result<Self>
     where
         F: FnMut(&str) -> anyhow::Result<Tensor>,
     {
@@ -70,7 +70,7 @@ impl PhiSelfAttention {
             .unwrap()
             .to_u32()?;
         //1 / head_dim
-        let softmax_scale = Tensor::from_data([1.0 / 80_f32.sqrt()], shape![1], device.clone());
+        let softmax_scale = 1.0 / 80_f32.sqrt();
         //TODO: hardcoded for Phi2, should read from meta
         let base = 10000.0;
         let dim = (0.4 * (2560f64 / 32f64)) as usize;
@@ -133,18 +133,8 @@ impl Module for PhiSelfAttention {
             (key_states, value_states)
         };
 
-        //TODO: can we just use the built in transposed matmul?
-        let mut attn_weights = query_states
-            .matmul(key_states.permute(&[0, 1, 3, 2])?, false, false)?
-            .mul(self.softmax_scale.clone())?;
-
-        if let Some(m) = mask {
-            attn_weights = attn_weights.add(m)?;
-        }
-
-        let w = attn_weights.softmax(3)?;
-        let wv = w
-            .matmul(value_states, false, false)?
+        let wv = query_states
+            .sdpa(key_states, value_states, mask, self.softmax_scale, false)?
             .permute(&[0, 2, 1, 3])?;
         let wv = wv.view(shape![batch_size as _, seq_len, n_state])?;
         self.o.schedule(wv)
@@ -1,5 +1,6 @@
 #![cfg(target_arch = "wasm32")]
 use crate::phi2::Phi2;
+use crate::CancellationToken;
 use ndarray::Axis;
 use ndarray_stats::QuantileExt;
 use ratchet::{shape, Device, Tensor};
@@ -10,6 +11,7 @@ pub async fn generate(
     model: &mut Phi2,
     tokenizer: Tokenizer,
     prompt: String,
+    token: Option<&CancellationToken>,
 ) -> anyhow::Result<()> {
     use web_time::Instant;
     log::warn!("Prompt: {}", prompt);
@@ -23,7 +25,10 @@ pub async fn generate(
     let mut all_tokens = tokens.clone();
     let mut loop_cnt = 0;
     let start = Instant::now();
-    while tokens[tokens.len() - 1] != 50256 && loop_cnt < 256 {
+    while tokens[tokens.len() - 1] != 50256
+        && loop_cnt < 256
+        && !token.is_some_and(|t| t.is_cancelled())
+    {
         let input = Tensor::from_data(
             tokens.clone(),
             shape![1, tokens.len()],
@@ -219,15 +219,7 @@ impl Phi2 {
     }
 
     pub fn generate_mask(seq_len: usize, device: &Device) -> anyhow::Result<Tensor> {
-        let mask: Vec<_> = (0..seq_len)
-            .flat_map(|i| (0..seq_len).map(move |j| if j > i { f32::NEG_INFINITY } else { 0f32 }))
-            .collect();
-
-        Ok(Tensor::from_data(
-            mask,
-            shape![seq_len, seq_len],
-            device.clone(),
-        ))
+        Tensor::causal_mask(seq_len, seq_len, device)
     }
 
     pub fn reset(&mut self) {
@@ -2,7 +2,7 @@ use std::io::{BufRead, Seek};
 
 use crate::whisper::model::Whisper;
 use crate::whisper::residual_block::*;
-use ratchet::prelude::*;
+use ratchet::{prelude::*, ExecutableCache};
 use ratchet_loader::ggml::GGMLModel;
 use ratchet_nn::{Embedding, KVCache, LayerNorm, Module};
 
@@ -58,6 +58,7 @@ pub struct WhisperDecoder {
     mask: Tensor,
     ln_post: LayerNorm,
     cache: KVCache,
+    executables: ExecutableCache,
     device: Device,
 }
 
@@ -92,6 +93,9 @@ impl Module for WhisperDecoder {
 
 impl WhisperDecoder {
     pub const MAX_CACHE: usize = 512;
+    /// The cache offset is only read at dispatch time, so every single token step replays the
+    /// same graph. Only prompts of different lengths need graphs of their own.
+    pub const MAX_EXECUTABLES: usize = 4;
 
     pub fn cache_mut(&mut self) -> &mut KVCache {
         &mut self.cache
@@ -101,11 +105,12 @@ impl WhisperDecoder {
         self.cache.reset();
     }
 
-    fn load_mask(n_ctx: usize, device: &Device) -> Tensor {
-        let mask: Vec<_> = (0..n_ctx)
-            .flat_map(|i| (0..n_ctx).map(move |j| if j > i { f32::NEG_INFINITY } else { 0f32 }))
-            .collect();
-        Tensor::from_data(mask, shape![n_ctx, n_ctx], device.clone())
+    pub fn executables(&self) -> &ExecutableCache {
+        &self.executables
+    }
+
+    fn load_mask(n_ctx: usize, device: &Device) -> anyhow::Result<Tensor> {
+        Ok(Tensor::causal_mask(n_ctx, n_ctx, device)?.resolve()?)
     }
 
     pub fn load<R: BufRead + Seek>(
@@ -142,9 +147,10 @@ impl WhisperDecoder {
         Ok(Self {
             stem,
             blocks,
-            mask: Self::load_mask(hparams.n_text_ctx as _, device),
+            mask: Self::load_mask(hparams.n_text_ctx as _, device)?,
             ln_post: LayerNorm::new(lt("weight")?, Some(lt("bias")?), 1e-5),
             cache: KVCache::new(n_layers, shape![1, Self::MAX_CACHE, n_state], device),
+            executables: ExecutableCache::new(Self::MAX_EXECUTABLES),
             device: device.clone(),
         })
     }
@@ -1,6 +1,6 @@
 use std::io::{BufRead, Seek};
 
-use ratchet::{Device, Tensor};
+use ratchet::{Device, ExecutableCache, Tensor};
 use ratchet_loader::ggml::GGMLModel;
 use ratchet_nn::{LayerNorm, Module};
 
@@ -70,6 +70,7 @@ pub struct WhisperEncoder {
     stem: EncoderStem,
     blocks: Vec<ResidualAttentionBlock>,
     ln_post: LayerNorm,
+    executables: ExecutableCache,
 }
 
 impl Module for WhisperEncoder {
@@ -91,6 +92,11 @@ impl Module for WhisperEncoder {
 }
 
 impl WhisperEncoder {
+    /// The encoder graph is identical for every segment, so a single capture is replayed.
+    pub fn executables(&self) -> &ExecutableCache {
+        &self.executables
+    }
+
     pub fn load<R: BufRead + Seek>(
         disk_model: &GGMLModel<Whisper>,
         reader: &mut R,
@@ -125,6 +131,7 @@ impl WhisperEncoder {
             stem,
             blocks,
             ln_post: LayerNorm::new(lt("weight")?, Some(lt("bias")?), 1e-5),
+            executables: ExecutableCache::new(1),
         })
     }
 }
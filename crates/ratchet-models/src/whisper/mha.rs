@@ -8,21 +8,16 @@ pub struct MultiHeadAttention {
     v: Linear,
     o: Linear,
     n_heads: usize,
-    dk: Tensor,
 }
 
 impl MultiHeadAttention {
     pub fn new(q: Linear, k: Linear, v: Linear, o: Linear, n_heads: usize) -> MultiHeadAttention {
-        let n_state = q.w.shape()[1];
-        let dk = (n_state / n_heads) as f32;
-        let dk = Tensor::from_data([dk.powf(-0.25)], shape![1], q.w.device().clone());
         MultiHeadAttention {
             q,
             k,
             v,
             o,
             n_heads,
-            dk,
         }
     }
 }
@@ -55,16 +50,18 @@ impl Module for MultiHeadAttention {
         let k = self.k.schedule(to_project.clone())?;
         let v = self.v.schedule(to_project)?;
 
-        let (k, v) = if let Some(kv) = cache {
+        //The caches keep their full length, so every decoding step shares the same shapes
+        let (k, v, kv_len) = if let Some(kv) = cache {
             let prev_entries = kv.entries;
-            let k_cache = kv.k_cache.cache(k, 1, prev_entries)?;
-            let v_cache = kv.v_cache.cache(v, 1, prev_entries)?;
-            (k_cache, v_cache)
+            let kv_len = prev_entries + k.shape()[1];
+            let k_cache = kv.k_cache.cache_dynamic(k, 1, prev_entries)?;
+            let v_cache = kv.v_cache.cache_dynamic(v, 1, prev_entries)?;
+            (k_cache, v_cache, Some(kv_len))
         } else {
-            (k, v)
+            (k, v, None)
         };
 
-        self.qkv_attention(q, k, v, mask, is_xattn, is_causal)
+        self.qkv_attention(q, k, v, mask, kv_len, is_xattn, is_causal)
     }
 }
 
@@ -75,6 +72,7 @@ impl MultiHeadAttention {
         k: Tensor,
         v: Tensor,
         mask: Option<Tensor>,
+        kv_len: Option<usize>,
         x_attn: bool,
         is_causal: bool,
     ) -> anyhow::Result<Tensor> {
@@ -88,28 +86,25 @@ impl MultiHeadAttention {
         let ks = shape![k0, k1, self.n_heads, hdim];
         let vs = shape![v0, v1, self.n_heads, hdim];
 
-        let q = q.view(qs)?.permute(&[0, 2, 1, 3])?.mul(self.dk.clone())?;
-        let k = k.view(ks)?.permute(&[0, 2, 3, 1])?.mul(self.dk.clone())?;
+        let q = q.view(qs)?.permute(&[0, 2, 1, 3])?;
+        let k = k.view(ks)?.permute(&[0, 2, 1, 3])?;
         let v = v.view(vs)?.permute(&[0, 2, 1, 3])?;
 
         if x_attn {
             //TODO: static caching
         }
 
-        let mut qk = q.matmul(k, false, false)?;
-
-        if let Some(m) = mask {
-            let prepared_mask = if is_causal {
-                m.slice(&[0..n_ctx, 0..n_ctx])?
-            } else {
-                m.clone()
-            };
-            qk = qk.add(prepared_mask)?;
-        }
-
-        let w = qk.softmax(3)?;
-        let wv = w
-            .matmul(v, false, false)?
+        //The causal mask is equivalent to the fused op's bottom right causal alignment
+        let (mask, causal) = match mask {
+            Some(_) if is_causal => (None, true),
+            mask => (mask, false),
+        };
+        let scale = 1. / (hdim as f32).sqrt();
+        let attn = match kv_len {
+            Some(kv_len) => q.sdpa_dynamic(k, v, mask, scale, causal, kv_len)?,
+            None => q.sdpa(k, v, mask, scale, causal)?,
+        };
+        let wv = attn
             .permute(&[0, 2, 1, 3])?
             .view(shape![bs, n_ctx, n_state])?;
 
@@ -142,6 +142,26 @@ impl Whisper {
         })
     }
 
+    /// Observes the input of every linear layer while encoding `mel` and decoding `tokens`
+    /// against it, for `calibration` to quantize the checkpoint of `disk_model` with.
+    /// `self` must have been loaded from `disk_model`.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn calibrate(
+        &mut self,
+        disk_model: &GGMLModel<Whisper>,
+        calibration: &mut ratchet_loader::Calibration,
+        mel: Tensor,
+        tokens: Tensor,
+    ) -> anyhow::Result<()> {
+        let (_, recorded) = ratchet_nn::record_linear_inputs(|| {
+            let audio_ctx = self.encoder.schedule(mel)?;
+            self.decoder.schedule([audio_ctx, tokens])
+        })?;
+        let observed = calibration.observe_recorded(recorded, |w| disk_model.name_of(w));
+        self.decoder.reset();
+        observed
+    }
+
     #[cfg(target_arch = "wasm32")]
     pub async fn from_bytes(bytes: &[u8]) -> anyhow::Result<Self> {
         let device = Device::request_device(ratchet::DeviceRequest::GPU).await?;
@@ -283,6 +303,7 @@ mod tests {
         model::Whisper, options::DecodingOptionsBuilder, transcribe::transcribe,
         transcript::StreamedSegment,
     };
+    use crate::CancellationToken;
 
     fn log_init() {
         let _ = env_logger::builder().is_test(true).try_init();
@@ -333,7 +354,7 @@ mod tests {
         let mut whisper = Whisper::load(&gg_disk, &mut reader, device).unwrap();
 
         let empty_cb: Option<fn(StreamedSegment)> = None;
-        let transcript = transcribe(&mut whisper, samples, options, empty_cb).unwrap();
+        let transcript = transcribe(&mut whisper, samples, options, empty_cb, None).unwrap();
 
         let all_tokens = transcript
             .segments
@@ -346,6 +367,43 @@ mod tests {
         println!("Processing time: {:?}", transcript.processing_time);
     }
 
+    #[test]
+    pub fn whisper_cancel_partway() {
+        log_init();
+        let api = Api::new().unwrap();
+        let model = api.model("FL33TW00D-HF/whisper-tiny".to_string());
+        let model_path = model.get("tiny_q8_0.bin").unwrap();
+        let dataset = api.dataset("FL33TW00D-HF/ratchet-util".to_string());
+        let samples = load_sample(dataset.get("mm0.wav").unwrap());
+
+        let options = DecodingOptionsBuilder::new().build();
+        let mut reader = std::io::BufReader::new(std::fs::File::open(model_path).unwrap());
+        let gg_disk = Whisper::load_ggml(&mut reader).unwrap();
+        let device = Device::request_device(DeviceRequest::GPU).unwrap();
+        let mut whisper = Whisper::load(&gg_disk, &mut reader, device).unwrap();
+
+        //Cancel as soon as the second segment has been streamed
+        let token = CancellationToken::new();
+        let streamed = std::cell::Cell::new(0);
+        let cb = |_: StreamedSegment| {
+            streamed.set(streamed.get() + 1);
+            if streamed.get() == 2 {
+                token.cancel();
+            }
+        };
+        let transcript =
+            transcribe(&mut whisper, samples, options, Some(cb), Some(&token)).unwrap();
+
+        assert!(transcript.cancelled);
+        assert_eq!(transcript.segments.len(), 2);
+        let all_tokens = transcript
+            .segments
+            .iter()
+            .flat_map(|s| s.tokens.clone().into_iter())
+            .collect::<Vec<_>>();
+        assert_eq!(all_tokens, MM0_Q8_GROUND[..all_tokens.len()]);
+    }
+
     /*
     #[test]
     pub fn convert_ggml_f32_to_wq8() {
@@ -380,7 +438,14 @@ mod tests {
             vec![[0, pad_size], [0, 0]],
         )]);
         let quantization = Quantization::None;
-        Converter::convert::<_, Whisper>(src_path, dst_path, quantization, to_quant, to_pad)
-            .unwrap();
+        Converter::convert::<_, Whisper>(
+            src_path,
+            dst_path,
+            quantization,
+            to_quant,
+            to_pad,
+            None,
+        )
+        .unwrap();
     }*/
 }
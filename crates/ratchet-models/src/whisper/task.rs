@@ -3,6 +3,7 @@ use super::{
     tokenizer::WhisperTokenizer, transcript::*,
 };
 use crate::whisper::options::{DecodingOptions, Prompt};
+use crate::CancellationToken;
 use ndarray::{s, Axis};
 use ratchet::{shape, Device, Tensor};
 use ratchet_nn::{Module, MutableModule};
@@ -84,6 +85,7 @@ impl DecodingTask {
         decoder: &mut WhisperDecoder,
         audio_ctx: Tensor,
         callback: &Option<impl Fn(StreamedSegment)>,
+        token: Option<&CancellationToken>,
     ) -> Result<Vec<i32>, DecodeError> {
         let mut tokens = self.get_initial_tokens();
         let sliced_vocab_size = self.tokenizer.vocab_size();
@@ -91,6 +93,9 @@ impl DecodingTask {
         let mut timestamps_seen = 0;
 
         for _ in 0..self.sample_len {
+            if token.is_some_and(|t| t.is_cancelled()) {
+                break;
+            }
             let input = if tokens.len() > self.initial_tokens_len.unwrap() {
                 &tokens[tokens.len() - 1..]
             } else {
@@ -98,7 +103,9 @@ impl DecodingTask {
             };
             let input_t = Tensor::from_data(input, shape![1, input.len()], device.clone());
 
-            let logits = decoder.schedule([audio_ctx.clone(), input_t])?.resolve()?;
+            let logits = decoder
+                .schedule([audio_ctx.clone(), input_t.clone()])?
+                .resolve_cached(decoder.executables(), &[&audio_ctx, &input_t])?;
             decoder.cache_mut().update(input.len());
 
             let mut logits = Self::slice_logits(logits.to(&Device::CPU)?, sliced_vocab_size);
@@ -127,6 +134,7 @@ impl DecodingTask {
         decoder: &mut WhisperDecoder,
         audio_ctx: Tensor,
         callback: &Option<impl Fn(StreamedSegment)>,
+        token: Option<&CancellationToken>,
     ) -> Result<Vec<i32>, DecodeError> {
         let mut tokens = self.get_initial_tokens();
         let device = audio_ctx.device().clone();
@@ -134,6 +142,9 @@ impl DecodingTask {
         let mut timestamps_seen = 0;
 
         for _ in 0..self.sample_len {
+            if token.is_some_and(|t| t.is_cancelled()) {
+                break;
+            }
             let input = if tokens.len() > self.initial_tokens_len.unwrap() {
                 &tokens[tokens.len() - 1..]
             } else {
@@ -141,7 +152,9 @@ impl DecodingTask {
             };
             let input_t = Tensor::from_data(input, shape![1, input.len()], device.clone());
 
-            let logits = decoder.schedule([audio_ctx.clone(), input_t])?.resolve()?;
+            let logits = decoder
+                .schedule([audio_ctx.clone(), input_t.clone()])?
+                .resolve_cached(decoder.executables(), &[&audio_ctx, &input_t])?;
             decoder.cache_mut().update(input.len());
 
             let mut logits = Self::slice_logits(logits.to(&Device::CPU).await?, sliced_vocab_size);
@@ -287,14 +300,17 @@ impl DecodingTask {
         (segments, advance)
     }
 
+    /// Decodes the tokens of a segment.
+    /// If `token` is cancelled, stops after the current step and returns the tokens so far.
     #[cfg(target_arch = "wasm32")]
     pub async fn run(
         &self,
         decoder: &mut WhisperDecoder,
         audio_ctx: Tensor,
         callback: &Option<impl Fn(StreamedSegment)>,
+        token: Option<&CancellationToken>,
     ) -> Result<Vec<i32>, DecodeError> {
-        let mut tokens = self.main_loop(decoder, audio_ctx, &callback).await?;
+        let mut tokens = self.main_loop(decoder, audio_ctx, &callback, token).await?;
 
         tokens = tokens.drain(self.initial_tokens_len.unwrap()..).collect();
         let eot_index = tokens.iter().position(|x| *x == WhisperTokenizer::EOT);
@@ -304,14 +320,17 @@ impl DecodingTask {
         Ok(tokens)
     }
 
+    /// Decodes the tokens of a segment.
+    /// If `token` is cancelled, stops after the current step and returns the tokens so far.
     #[cfg(not(target_arch = "wasm32"))]
     pub fn run(
         &self,
         decoder: &mut WhisperDecoder,
         audio_ctx: Tensor,
         callback: &Option<impl Fn(StreamedSegment)>,
+        token: Option<&CancellationToken>,
     ) -> Result<Vec<i32>, DecodeError> {
-        let mut tokens = self.main_loop(decoder, audio_ctx, callback)?;
+        let mut tokens = self.main_loop(decoder, audio_ctx, callback, token)?;
 
         tokens = tokens.drain(self.initial_tokens_len.unwrap()..).collect();
         let eot_index = tokens.iter().position(|x| *x == WhisperTokenizer::EOT);
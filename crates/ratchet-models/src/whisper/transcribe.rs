@@ -1,6 +1,7 @@
 use crate::whisper::model::Whisper;
 use crate::whisper::options::*;
 use crate::whisper::{spectrogram::*, task::*, tokenizer::*, transcript::*};
+use crate::CancellationToken;
 use ratchet_nn::Module;
 use std::cmp::min;
 use web_time::Instant;
@@ -11,6 +12,7 @@ pub fn transcribe(
     audio: Vec<f32>,
     mut decode_options: DecodingOptions,
     callback: Option<impl Fn(StreamedSegment)>,
+    token: Option<&CancellationToken>,
 ) -> anyhow::Result<TranscriptionResult> {
     let n_mels = model.hparams.n_mels as usize;
     let runtime = Instant::now();
@@ -38,13 +40,14 @@ pub fn transcribe(
     let mut all_segments = Vec::with_capacity(512);
     let prompt_since_reset = 0;
 
-    while seek < content_frames {
+    while seek < content_frames && !token.is_some_and(|t| t.is_cancelled()) {
         let mut decode_options = decode_options.clone();
         let time_offset = (seek * HOP_LENGTH) as f64 / SAMPLE_RATE as f64;
         decode_options.time_offset = Some(time_offset);
         let mel_segment = mel
             .clone()
-            .slice(&[0..1, 0..n_mels, seek..(seek + N_FRAMES)])?;
+            .slice(&[0..1, 0..n_mels, seek..(seek + N_FRAMES)])?
+            .resolve()?;
         log::info!("Processing segment: {} -> {}", seek, seek + N_FRAMES);
 
         let segment_size = min(N_FRAMES, content_frames - seek);
@@ -54,10 +57,13 @@ pub fn transcribe(
             decode_options.prompt = Some(Prompt::Tokens(all_tokens[prompt_since_reset..].to_vec()));
         }
 
-        let hs = model.encoder.schedule(mel_segment)?.resolve()?;
+        let hs = model
+            .encoder
+            .schedule(mel_segment.clone())?
+            .resolve_cached(model.encoder.executables(), &[&mel_segment])?;
 
         let task = DecodingTask::new(decode_options, tokenizer.clone());
-        let decoded = task.run(&mut model.decoder, hs, &callback)?;
+        let decoded = task.run(&mut model.decoder, hs, &callback, token)?;
         let (segments, advance) = DecodingTask::build_segments(
             &tokenizer,
             decoded,
@@ -78,6 +84,7 @@ pub fn transcribe(
     }
 
     let mut t = TranscriptionResult::new(runtime.elapsed(), all_segments, None);
+    t.cancelled = token.is_some_and(|t| t.is_cancelled());
     t.generate_formatted(&tokenizer);
     Ok(t)
 }
@@ -88,6 +95,7 @@ pub async fn transcribe(
     audio: Vec<f32>,
     mut decode_options: DecodingOptions,
     callback: Option<impl Fn(StreamedSegment)>,
+    token: Option<&CancellationToken>,
 ) -> anyhow::Result<TranscriptionResult> {
     let runtime = Instant::now();
     let n_mels = model.hparams.n_mels as usize;
@@ -116,13 +124,14 @@ pub async fn transcribe(
     let mut all_segments = Vec::with_capacity(512);
     let prompt_since_reset = 0;
 
-    while seek < content_frames {
+    while seek < content_frames && !token.is_some_and(|t| t.is_cancelled()) {
         let mut decode_options = decode_options.clone();
         let time_offset = (seek * HOP_LENGTH) as f64 / SAMPLE_RATE as f64;
         decode_options.time_offset = Some(time_offset);
         let mel_segment = mel
             .clone()
-            .slice(&[0..1, 0..n_mels, seek..(seek + N_FRAMES)])?;
+            .slice(&[0..1, 0..n_mels, seek..(seek + N_FRAMES)])?
+            .resolve()?;
         log::info!("Processing segment: {} -> {}", seek, seek + N_FRAMES);
 
         let segment_size = min(N_FRAMES, content_frames - seek);
@@ -132,10 +141,13 @@ pub async fn transcribe(
             decode_options.prompt = Some(Prompt::Tokens(all_tokens[prompt_since_reset..].to_vec()));
         }
 
-        let hs = model.encoder.schedule(mel_segment)?.resolve()?;
+        let hs = model
+            .encoder
+            .schedule(mel_segment.clone())?
+            .resolve_cached(model.encoder.executables(), &[&mel_segment])?;
 
         let task = DecodingTask::new(decode_options, tokenizer.clone());
-        let decoded = task.run(&mut model.decoder, hs, &callback).await?;
+        let decoded = task.run(&mut model.decoder, hs, &callback, token).await?;
 
         let (segments, advance) = DecodingTask::build_segments(
             &tokenizer,
@@ -166,6 +178,7 @@ pub async fn transcribe(
     }
 
     let mut t = TranscriptionResult::new(runtime.elapsed(), all_segments, None);
+    t.cancelled = token.is_some_and(|t| t.is_cancelled());
     t.generate_formatted(&tokenizer);
     Ok(t)
 }
@@ -13,6 +13,9 @@ pub struct TranscriptionResult {
     pub processing_time: Duration,
     pub segments: Vec<Segment>,
     pub formatted: Option<String>,
+    /// Whether the transcription was stopped early, `segments` only cover the audio before it.
+    #[new(default)]
+    pub cancelled: bool,
 }
 
 impl TranscriptionResult {
@@ -1,7 +1,13 @@
+use std::cell::RefCell;
+
 use ratchet::Tensor;
 
 use crate::Module;
 
+thread_local! {
+    static RECORDED: RefCell<Option<Vec<(Tensor, Tensor)>>> = const { RefCell::new(None) };
+}
+
 /// # Linear
 ///
 /// PyTorch case: y = xW^T + b
@@ -15,8 +21,26 @@ pub struct Linear {
 impl Module for Linear {
     type Input = Tensor;
     fn schedule(&self, input: Self::Input) -> anyhow::Result<Tensor> {
+        RECORDED.with(|recorded| {
+            if let Some(recorded) = recorded.borrow_mut().as_mut() {
+                recorded.push((self.w.clone(), input.clone()));
+            }
+        });
         self.w
             .clone()
             .gemm(input, self.b.clone(), false, true, true)
     }
 }
+
+/// Runs `f`, returning every `(weight, input)` pair of the [Linear] layers it schedules on this
+/// thread, e.g to gather calibration statistics before quantizing a model.
+///
+/// The inputs are lazy, and resolving them alongside the outputs of `f` keeps them valid.
+pub fn record_linear_inputs<R>(
+    f: impl FnOnce() -> anyhow::Result<R>,
+) -> anyhow::Result<(R, Vec<(Tensor, Tensor)>)> {
+    let previous = RECORDED.with(|recorded| recorded.borrow_mut().replace(vec![]));
+    let result = f();
+    let recorded = RECORDED.with(|recorded| recorded.replace(previous));
+    Ok((result?, recorded.unwrap_or_default()))
+}
@@ -33,6 +33,15 @@ impl LayerNorm {
         }
     }
 
+    pub fn from_config(weight: Tensor, bias: Option<Tensor>, config: LayerNormConfig) -> Self {
+        Self {
+            weight,
+            bias,
+            remove_mean: config.remove_mean,
+            eps: config.eps,
+        }
+    }
+
     pub fn weight(&self) -> &Tensor {
         &self.weight
     }
@@ -45,6 +54,75 @@ impl LayerNorm {
 impl crate::Module for LayerNorm {
     type Input = Tensor;
     fn schedule(&self, input: Self::Input) -> anyhow::Result<Tensor> {
-        input.layer_norm(self.weight.clone(), self.bias.clone(), self.eps)
+        if self.remove_mean {
+            return input.layer_norm(self.weight.clone(), self.bias.clone(), self.eps);
+        }
+        let x = input.rms_norm(self.weight.clone(), self.eps)?;
+        match &self.bias {
+            Some(bias) => x.add(bias.clone()),
+            None => Ok(x),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct RMSNorm {
+    weight: Tensor,
+    eps: f32,
+}
+
+impl RMSNorm {
+    pub fn new(weight: Tensor, eps: f32) -> Self {
+        Self { weight, eps }
+    }
+
+    pub fn weight(&self) -> &Tensor {
+        &self.weight
+    }
+}
+
+impl crate::Module for RMSNorm {
+    type Input = Tensor;
+    fn schedule(&self, input: Self::Input) -> anyhow::Result<Tensor> {
+        input.rms_norm(self.weight.clone(), self.eps)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct GroupNorm {
+    num_groups: usize,
+    weight: Tensor,
+    bias: Tensor,
+    eps: f32,
+}
+
+impl GroupNorm {
+    pub fn new(num_groups: usize, weight: Tensor, bias: Tensor, eps: f32) -> Self {
+        Self {
+            num_groups,
+            weight,
+            bias,
+            eps,
+        }
+    }
+
+    pub fn weight(&self) -> &Tensor {
+        &self.weight
+    }
+
+    pub fn bias(&self) -> &Tensor {
+        &self.bias
+    }
+}
+
+impl crate::Module for GroupNorm {
+    type Input = Tensor;
+    fn schedule(&self, input: Self::Input) -> anyhow::Result<Tensor> {
+        input.group_norm(
+            self.num_groups,
+            self.weight.clone(),
+            self.bias.clone(),
+            self.eps,
+        )
     }
 }
@@ -11,7 +11,7 @@ use ratchet_models::registry::Quantization;
 use ratchet_models::whisper::transcribe::transcribe;
 use ratchet_models::whisper::transcript::StreamedSegment;
 use ratchet_models::whisper::Whisper;
-use ratchet_models::TensorMap;
+use ratchet_models::{CancellationToken, TensorMap};
 use tokenizers::Tokenizer;
 use wasm_bindgen::prelude::*;
 
@@ -22,7 +22,11 @@ pub enum WebModel {
 }
 
 impl WebModel {
-    pub async fn run(&mut self, input: JsValue) -> Result<JsValue, JsValue> {
+    pub async fn run(
+        &mut self,
+        input: JsValue,
+        token: Option<&CancellationToken>,
+    ) -> Result<JsValue, JsValue> {
         match self {
             WebModel::Whisper(model) => {
                 let input: WhisperInputs = serde_wasm_bindgen::from_value(input)?;
@@ -40,7 +44,7 @@ impl WebModel {
                     None
                 };
 
-                let result = transcribe(model, input.audio, options, callback)
+                let result = transcribe(model, input.audio, options, callback, token)
                     .await
                     .unwrap();
                 serde_wasm_bindgen::to_value(&result).map_err(|e| e.into())
@@ -50,7 +54,7 @@ impl WebModel {
                 let model_repo = ApiBuilder::from_hf("microsoft/phi-2", RepoType::Model).build();
                 let model_bytes = model_repo.get("tokenizer.json").await?;
                 let tokenizer = Tokenizer::from_bytes(model_bytes.to_vec()).unwrap();
-                generate(model, tokenizer, input).await.unwrap();
+                generate(model, tokenizer, input, token).await.unwrap();
                 Ok(JsValue::NULL)
             }
         }
@@ -158,8 +162,13 @@ impl Model {
     /// User-facing method to run the model.
     ///
     /// Untyped input is required unfortunately.
-    pub async fn run(&mut self, input: JsValue) -> Result<JsValue, JsValue> {
-        self.inner.run(input).await
+    /// To stop it early, pass the `handle()` of a `CancellationToken` and cancel the token.
+    pub async fn run(
+        &mut self,
+        input: JsValue,
+        token: Option<CancellationToken>,
+    ) -> Result<JsValue, JsValue> {
+        self.inner.run(input, token.as_ref()).await
     }
 }
 
@@ -238,7 +247,7 @@ mod tests {
             callback: js_cb.clone(),
         };
         let input = serde_wasm_bindgen::to_value(&input).unwrap();
-        let result = model.run(input).await.unwrap();
+        let result = model.run(input, None).await.unwrap();
         log::warn!("Result: {:?}", result);
         Ok(())
     }*/
@@ -261,7 +270,7 @@ mod tests {
 
         let input = "A skier slides down a frictionless slope of height 40m and length 80m. What's the skier speed at the bottom?".to_string();
         let input = serde_wasm_bindgen::to_value(&input).unwrap();
-        let result = model.run(input).await.unwrap();
+        let result = model.run(input, None).await.unwrap();
         Ok(())
     }
     */
@@ -2,7 +2,7 @@
 import { FFmpeg } from '@ffmpeg/ffmpeg'
 import { toBlobURL } from '@ffmpeg/util';
 import { useEffect, useRef, useState } from "react";
-import { Model, DecodingOptionsBuilder, default as init, Task, AvailableModels, Quantization, Segment } from "@ratchet-ml/ratchet-web";
+import { Model, DecodingOptionsBuilder, default as init, Task, AvailableModels, Quantization, Segment, CancellationToken } from "@ratchet-ml/ratchet-web";
 import ConfigModal, { ConfigOptions } from './components/configModal';
 import ModelSelector from './components/modelSelector';
 import ProgressBar from './components/progressBar';
@@ -29,6 +29,7 @@ export default function Home() {
     });
     const [generating, setGenerating] = useState<boolean>(false);
     const [progress, setProgress] = useState<number>(0);
+    const tokenRef = useRef<CancellationToken | null>(null);
 
 
     useEffect(() => {
@@ -112,11 +113,18 @@ export default function Home() {
             }
             setSegments((currentSegments) => [...currentSegments, segment]);
         };
-        let result = await model.run({ audio: audioData, decode_options: options, callback: callback });
+        let token = new CancellationToken();
+        tokenRef.current = token;
+        let result = await model.run({ audio: audioData, decode_options: options, callback: callback }, token.handle());
+        tokenRef.current = null;
         console.log("Result: ", result);
         console.log("Processing time: ", result.processing_time);
     }
 
+    function stopModel() {
+        tokenRef.current?.cancel();
+    }
+
     const handleAudioFile = () => async (event: any) => {
         const file = event.target.files[0];
         if (!file) {
@@ -221,6 +229,7 @@ export default function Home() {
                                     : "Run Model"
                             }
                         </button>
+                        {generating ? <button className="outline outline-black text-black font-semibold py-3 px-4 cursor-pointer" onClick={stopModel}>Stop</button> : <></>}
                     </div>
                 </div>
                 <div className="flex flex-col relative w-full z-10 min-h-screen">